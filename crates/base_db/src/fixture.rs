@@ -47,6 +47,8 @@
 //! that are available in a real rust project:
 //! - crate names via `crate:cratename`
 //! - dependencies via `deps:dep1,dep2`
+//! - dev-dependencies, only available to the tests, via `dev-deps:dep1,dep2`
+//! - build-dependencies, only available to the build script, via `build-deps:dep1,dep2`
 //! - configuration settings via `cfg:dbg=false,opt_level=2`
 //! - environment variables via `env:PATH=/bin,RUST_LOG=debug`
 //!
@@ -65,8 +67,8 @@ use test_utils::{extract_range_or_offset, Fixture, RangeOrOffset, CURSOR_MARKER}
 use vfs::{file_set::FileSet, VfsPath};
 
 use crate::{
-    input::CrateName, Change, CrateGraph, CrateId, Edition, Env, FileId, FilePosition,
-    SourceDatabaseExt, SourceRoot, SourceRootId,
+    input::CrateName, Change, CrateGraph, CrateId, DependencyKind, Edition, Env, FileId,
+    FilePosition, SourceDatabaseExt, SourceRoot, SourceRootId,
 };
 
 pub const WORKSPACE: SourceRootId = SourceRootId(0);
@@ -153,21 +155,33 @@ impl ChangeFixture {
             let meta = FileMeta::from(entry);
             assert!(meta.path.starts_with(&source_root_prefix));
 
+            let is_dev_target =
+                ["/tests/", "/benches/", "/examples/"].iter().any(|dir| meta.path.starts_with(dir));
             if let Some(krate) = meta.krate {
                 let crate_name = CrateName::normalize_dashes(&krate);
                 let crate_id = crate_graph.add_crate_root(
                     file_id,
                     meta.edition,
                     Some(crate_name.clone().into()),
+                    meta.cfg.clone(),
                     meta.cfg,
                     meta.env,
+                    is_dev_target,
                     Default::default(),
                 );
                 let prev = crates.insert(crate_name.clone(), crate_id);
                 assert!(prev.is_none());
                 for dep in meta.deps {
                     let dep = CrateName::normalize_dashes(&dep);
-                    crate_deps.push((crate_name.clone(), dep))
+                    crate_deps.push((crate_name.clone(), dep, DependencyKind::Normal))
+                }
+                for dep in meta.dev_deps {
+                    let dep = CrateName::normalize_dashes(&dep);
+                    crate_deps.push((crate_name.clone(), dep, DependencyKind::Dev))
+                }
+                for dep in meta.build_deps {
+                    let dep = CrateName::normalize_dashes(&dep);
+                    crate_deps.push((crate_name.clone(), dep, DependencyKind::Build))
                 }
             } else if meta.path == "/main.rs" || meta.path == "/lib.rs" {
                 assert!(default_crate_root.is_none());
@@ -188,15 +202,24 @@ impl ChangeFixture {
                 crate_root,
                 Edition::Edition2018,
                 Some(CrateName::new("test").unwrap().into()),
+                default_cfg.clone(),
                 default_cfg,
                 Env::default(),
+                false,
                 Default::default(),
             );
         } else {
-            for (from, to) in crate_deps {
+            for (from, to, kind) in crate_deps {
                 let from_id = crates[&from];
                 let to_id = crates[&to];
-                crate_graph.add_dep(from_id, CrateName::new(&to).unwrap(), to_id).unwrap();
+                let name = CrateName::new(&to).unwrap();
+                match kind {
+                    DependencyKind::Normal => crate_graph.add_dep(from_id, name, to_id).unwrap(),
+                    DependencyKind::Dev => crate_graph.add_dev_dep(from_id, name, to_id).unwrap(),
+                    DependencyKind::Build => {
+                        crate_graph.add_build_dep(from_id, name, to_id).unwrap()
+                    }
+                }
             }
         }
 
@@ -211,6 +234,8 @@ struct FileMeta {
     path: String,
     krate: Option<String>,
     deps: Vec<String>,
+    dev_deps: Vec<String>,
+    build_deps: Vec<String>,
     cfg: CfgOptions,
     edition: Edition,
     env: Env,
@@ -226,6 +251,8 @@ impl From<Fixture> for FileMeta {
             path: f.path,
             krate: f.krate,
             deps: f.deps,
+            dev_deps: f.dev_deps,
+            build_deps: f.build_deps,
             cfg,
             edition: f
                 .edition
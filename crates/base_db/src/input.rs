@@ -168,7 +168,13 @@ pub struct CrateData {
     /// `Dependency` matters), this name should only be used for UI.
     pub display_name: Option<CrateDisplayName>,
     pub cfg_options: CfgOptions,
+    /// The cfg options that could be set for the crate, like all the features
+    /// declared in its manifest, whether they are enabled or not.
+    pub potential_cfg_options: CfgOptions,
     pub env: Env,
+    /// Whether the crate is a test, bench or example target, which are built
+    /// with the dev-dependencies of their package.
+    pub is_dev_target: bool,
     pub dependencies: Vec<Dependency>,
     pub proc_macro: Vec<ProcMacro>,
 }
@@ -188,6 +194,18 @@ pub struct Env {
 pub struct Dependency {
     pub crate_id: CrateId,
     pub name: CrateName,
+    pub kind: DependencyKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DependencyKind {
+    Normal,
+    /// A dependency which is only available when building the tests, like
+    /// Cargo's `[dev-dependencies]`.
+    Dev,
+    /// A dependency which is only available when building the build script,
+    /// like Cargo's `[build-dependencies]`.
+    Build,
 }
 
 impl CrateGraph {
@@ -197,7 +215,9 @@ impl CrateGraph {
         edition: Edition,
         display_name: Option<CrateDisplayName>,
         cfg_options: CfgOptions,
+        potential_cfg_options: CfgOptions,
         env: Env,
+        is_dev_target: bool,
         proc_macro: Vec<(SmolStr, Arc<dyn tt::TokenExpander>)>,
     ) -> CrateId {
         let proc_macro =
@@ -208,7 +228,9 @@ impl CrateGraph {
             edition,
             display_name,
             cfg_options,
+            potential_cfg_options,
             env,
+            is_dev_target,
             proc_macro,
             dependencies: Vec::new(),
         };
@@ -223,6 +245,34 @@ impl CrateGraph {
         from: CrateId,
         name: CrateName,
         to: CrateId,
+    ) -> Result<(), CyclicDependenciesError> {
+        self.add_dep_with_kind(from, name, to, DependencyKind::Normal)
+    }
+
+    pub fn add_dev_dep(
+        &mut self,
+        from: CrateId,
+        name: CrateName,
+        to: CrateId,
+    ) -> Result<(), CyclicDependenciesError> {
+        self.add_dep_with_kind(from, name, to, DependencyKind::Dev)
+    }
+
+    pub fn add_build_dep(
+        &mut self,
+        from: CrateId,
+        name: CrateName,
+        to: CrateId,
+    ) -> Result<(), CyclicDependenciesError> {
+        self.add_dep_with_kind(from, name, to, DependencyKind::Build)
+    }
+
+    fn add_dep_with_kind(
+        &mut self,
+        from: CrateId,
+        name: CrateName,
+        to: CrateId,
+        kind: DependencyKind,
     ) -> Result<(), CyclicDependenciesError> {
         if self.dfs_find(from, to, &mut FxHashSet::default()) {
             return Err(CyclicDependenciesError {
@@ -230,7 +280,7 @@ impl CrateGraph {
                 to: (to, self[to].display_name.clone()),
             });
         }
-        self.arena.get_mut(&from).unwrap().add_dep(name, to);
+        self.arena.get_mut(&from).unwrap().add_dep(name, to, kind);
         Ok(())
     }
 
@@ -337,11 +387,11 @@ impl CrateGraph {
         match (cfg_if, std) {
             (Some(cfg_if), Some(std)) => {
                 self.arena.get_mut(&cfg_if).unwrap().dependencies.clear();
-                self.arena
-                    .get_mut(&std)
-                    .unwrap()
-                    .dependencies
-                    .push(Dependency { crate_id: cfg_if, name: CrateName::new("cfg_if").unwrap() });
+                self.arena.get_mut(&std).unwrap().dependencies.push(Dependency {
+                    crate_id: cfg_if,
+                    name: CrateName::new("cfg_if").unwrap(),
+                    kind: DependencyKind::Normal,
+                });
                 true
             }
             _ => false,
@@ -367,8 +417,8 @@ impl CrateId {
 }
 
 impl CrateData {
-    fn add_dep(&mut self, name: CrateName, crate_id: CrateId) {
-        self.dependencies.push(Dependency { name, crate_id })
+    fn add_dep(&mut self, name: CrateName, crate_id: CrateId, kind: DependencyKind) {
+        self.dependencies.push(Dependency { name, crate_id, kind })
     }
 }
 
@@ -408,6 +458,10 @@ impl Env {
     pub fn get(&self, env: &str) -> Option<String> {
         self.entries.get(env).cloned()
     }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
 }
 
 #[derive(Debug)]
@@ -441,7 +495,10 @@ impl fmt::Display for CyclicDependenciesError {
 
 #[cfg(test)]
 mod tests {
-    use super::{CfgOptions, CrateGraph, CrateName, Dependency, Edition::Edition2018, Env, FileId};
+    use super::{
+        CfgOptions, CrateGraph, CrateName, Dependency, DependencyKind, Edition::Edition2018, Env,
+        FileId,
+    };
 
     #[test]
     fn detect_cyclic_dependency_indirect() {
@@ -451,7 +508,9 @@ mod tests {
             Edition2018,
             None,
             CfgOptions::default(),
+            CfgOptions::default(),
             Env::default(),
+            false,
             Default::default(),
         );
         let crate2 = graph.add_crate_root(
@@ -459,7 +518,9 @@ mod tests {
             Edition2018,
             None,
             CfgOptions::default(),
+            CfgOptions::default(),
             Env::default(),
+            false,
             Default::default(),
         );
         let crate3 = graph.add_crate_root(
@@ -467,7 +528,9 @@ mod tests {
             Edition2018,
             None,
             CfgOptions::default(),
+            CfgOptions::default(),
             Env::default(),
+            false,
             Default::default(),
         );
         assert!(graph.add_dep(crate1, CrateName::new("crate2").unwrap(), crate2).is_ok());
@@ -483,7 +546,9 @@ mod tests {
             Edition2018,
             None,
             CfgOptions::default(),
+            CfgOptions::default(),
             Env::default(),
+            false,
             Default::default(),
         );
         let crate2 = graph.add_crate_root(
@@ -491,7 +556,9 @@ mod tests {
             Edition2018,
             None,
             CfgOptions::default(),
+            CfgOptions::default(),
             Env::default(),
+            false,
             Default::default(),
         );
         assert!(graph.add_dep(crate1, CrateName::new("crate2").unwrap(), crate2).is_ok());
@@ -506,7 +573,9 @@ mod tests {
             Edition2018,
             None,
             CfgOptions::default(),
+            CfgOptions::default(),
             Env::default(),
+            false,
             Default::default(),
         );
         let crate2 = graph.add_crate_root(
@@ -514,7 +583,9 @@ mod tests {
             Edition2018,
             None,
             CfgOptions::default(),
+            CfgOptions::default(),
             Env::default(),
+            false,
             Default::default(),
         );
         let crate3 = graph.add_crate_root(
@@ -522,7 +593,9 @@ mod tests {
             Edition2018,
             None,
             CfgOptions::default(),
+            CfgOptions::default(),
             Env::default(),
+            false,
             Default::default(),
         );
         assert!(graph.add_dep(crate1, CrateName::new("crate2").unwrap(), crate2).is_ok());
@@ -537,7 +610,9 @@ mod tests {
             Edition2018,
             None,
             CfgOptions::default(),
+            CfgOptions::default(),
             Env::default(),
+            false,
             Default::default(),
         );
         let crate2 = graph.add_crate_root(
@@ -545,7 +620,9 @@ mod tests {
             Edition2018,
             None,
             CfgOptions::default(),
+            CfgOptions::default(),
             Env::default(),
+            false,
             Default::default(),
         );
         assert!(graph
@@ -555,7 +632,8 @@ mod tests {
             graph[crate1].dependencies,
             vec![Dependency {
                 crate_id: crate2,
-                name: CrateName::new("crate_name_with_dashes").unwrap()
+                name: CrateName::new("crate_name_with_dashes").unwrap(),
+                kind: DependencyKind::Normal,
             }]
         );
     }
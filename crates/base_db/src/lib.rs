@@ -13,8 +13,8 @@ pub use crate::{
     cancellation::Canceled,
     change::Change,
     input::{
-        CrateData, CrateDisplayName, CrateGraph, CrateId, CrateName, Dependency, Edition, Env,
-        ProcMacroId, SourceRoot, SourceRootId,
+        CrateData, CrateDisplayName, CrateGraph, CrateId, CrateName, Dependency, DependencyKind,
+        Edition, Env, ProcMacroId, SourceRoot, SourceRootId,
     },
 };
 pub use salsa;
@@ -41,6 +41,18 @@ impl CfgOptions {
         self.enabled.insert(CfgAtom::KeyValue { key, value });
     }
 
+    /// Returns the values of the key-value options with the key `cfg_key`,
+    /// like the names of the features for `feature`.
+    pub fn get_cfg_values<'a>(
+        &'a self,
+        cfg_key: &'a str,
+    ) -> impl Iterator<Item = &'a SmolStr> + 'a {
+        self.enabled.iter().filter_map(move |atom| match atom {
+            CfgAtom::KeyValue { key, value } if key == cfg_key => Some(value),
+            _ => None,
+        })
+    }
+
     pub fn apply_diff(&mut self, diff: CfgDiff) {
         for atom in diff.enable {
             self.enabled.insert(atom);
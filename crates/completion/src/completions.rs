@@ -12,24 +12,66 @@ pub(crate) mod unqualified_path;
 pub(crate) mod postfix;
 pub(crate) mod macro_in_item_position;
 pub(crate) mod trait_impl;
+pub(crate) mod inherent_impl;
+pub(crate) mod trait_object;
 pub(crate) mod mod_;
+pub(crate) mod dependency;
+pub(crate) mod use_alias;
+pub(crate) mod use_group;
+pub(crate) mod visibility;
+pub(crate) mod type_bound;
+pub(crate) mod where_clause;
+pub(crate) mod assoc_type_arg;
+pub(crate) mod const_arg;
+pub(crate) mod closure;
+pub(crate) mod term_search;
+pub(crate) mod wrap_variant;
+pub(crate) mod into;
+pub(crate) mod struct_literal;
+pub(crate) mod lifetime;
+pub(crate) mod label;
+pub(crate) mod format_string;
+pub(crate) mod env_var;
+pub(crate) mod include_path;
+pub(crate) mod doc_test;
+pub(crate) mod intra_doc_link;
+pub(crate) mod code_block_attr;
+pub(crate) mod extern_block;
+pub(crate) mod abi;
+pub(crate) mod flyimport;
 
 use hir::{ModPath, ScopeDef, Type};
+use ide_db::{base_db::SourceDatabaseExt, helpers::insert_use::ImportScope};
+use syntax::SyntaxNode;
 
 use crate::{
-    item::Builder,
+    item::{Builder, ImportToAdd},
     render::{
-        const_::render_const, enum_variant::render_enum_variant, function::render_fn,
-        macro_::render_macro, render_field, render_resolution, render_tuple_field,
-        type_alias::render_type_alias, RenderContext,
+        const_::render_const,
+        enum_variant::render_enum_variant,
+        function::{render_fn, render_fn_with_substs, render_fn_with_turbofish},
+        macro_::render_macro,
+        render_field, render_resolution, render_tuple_field,
+        type_alias::render_type_alias,
+        RenderContext,
     },
-    CompletionContext, CompletionItem,
+    CompletionContext, CompletionItem, CompletionKind, CompletionRanker,
 };
 
+/// Rendering a completion is not free, so in gigantic scopes (think of enums
+/// with thousands of variants) the items past this limit are only counted.
+const MAX_RENDERED_ITEMS: usize = 1000;
+
 /// Represents an in-progress set of completions being built.
 #[derive(Debug, Default)]
 pub struct Completions {
     buf: Vec<CompletionItem>,
+    /// The number of items which weren't rendered because of `MAX_RENDERED_ITEMS`.
+    skipped: usize,
+    /// The number of rendered items starting with what is typed.
+    matching: usize,
+    /// What is typed, looked up once the first item is added.
+    typed: Option<String>,
 }
 
 impl Into<Vec<CompletionItem>> for Completions {
@@ -47,6 +89,41 @@ impl Builder {
 }
 
 impl Completions {
+    pub(crate) fn items(&self) -> &[CompletionItem] {
+        &self.buf
+    }
+
+    pub(crate) fn rerank(&mut self, ranker: &dyn CompletionRanker) {
+        ranker.rerank(&mut self.buf);
+    }
+
+    /// Attributes the items added since the first `start` ones to `provider`.
+    pub(crate) fn set_provider(&mut self, start: usize, provider: &'static str) {
+        self.buf[start..].iter_mut().for_each(|it| it.set_provider(provider));
+    }
+
+    /// Drops the items added since the first `start` ones which don't match
+    /// `f`.
+    pub(crate) fn retain_from(&mut self, start: usize, mut f: impl FnMut(&CompletionItem) -> bool) {
+        let mut idx = 0;
+        self.buf.retain(|it| {
+            idx += 1;
+            idx <= start || f(it)
+        });
+    }
+
+    /// Finishes the items, dropping what `resolve_completion` can fill in later
+    /// if the editor asked for that.
+    pub(crate) fn finish_items(&mut self, ctx: &CompletionContext) {
+        for item in self.buf.iter_mut() {
+            if ctx.config.resolve_lazily {
+                item.defer_resolution(ctx.position);
+            } else {
+                item.add_import_edit();
+            }
+        }
+    }
+
     pub(crate) fn add(&mut self, item: CompletionItem) {
         self.buf.push(item.into())
     }
@@ -59,12 +136,57 @@ impl Completions {
         items.into_iter().for_each(|item| self.add(item.into()))
     }
 
+    /// Adds an entry telling how many items were left out, if any. The entry
+    /// matches whatever is typed, and inserting it leaves the text as is.
+    pub(crate) fn add_skipped_items_placeholder(&mut self, ctx: &CompletionContext) {
+        if self.skipped == 0 {
+            return;
+        }
+        let typed = &ctx.db.file_text(ctx.position.file_id)[ctx.source_range()];
+        let label = format!("{} more items, keep typing…", self.skipped);
+        let item = CompletionItem::new(CompletionKind::Magic, ctx.source_range(), label)
+            .lookup_by(typed)
+            .insert_text(typed)
+            .build();
+        self.buf.push(item);
+    }
+
+    /// Whether the limit of rendered items is reached for the item called
+    /// `name`, in which case it is counted as skipped. The items starting with
+    /// what is typed have a limit of their own, so that typing more of a name
+    /// brings it up however far into the scope it is.
+    fn is_full(&mut self, ctx: &CompletionContext, name: &str) -> bool {
+        let typed = self.typed.get_or_insert_with(|| {
+            ctx.db.file_text(ctx.position.file_id)[ctx.source_range()].to_string()
+        });
+        let matches_typed = match name.get(..typed.len()) {
+            Some(it) => it.eq_ignore_ascii_case(typed),
+            None => false,
+        };
+        if matches_typed {
+            if self.matching < MAX_RENDERED_ITEMS {
+                self.matching += 1;
+                return false;
+            }
+        } else if self.buf.len() < MAX_RENDERED_ITEMS {
+            return false;
+        }
+        self.skipped += 1;
+        true
+    }
+
     pub(crate) fn add_field(&mut self, ctx: &CompletionContext, field: hir::Field, ty: &Type) {
+        if self.is_full(ctx, &field.name(ctx.db).to_string()) {
+            return;
+        }
         let item = render_field(RenderContext::new(ctx), field, ty);
         self.add(item);
     }
 
     pub(crate) fn add_tuple_field(&mut self, ctx: &CompletionContext, field: usize, ty: &Type) {
+        if self.is_full(ctx, &field.to_string()) {
+            return;
+        }
         let item = render_tuple_field(RenderContext::new(ctx), field, ty);
         self.add(item);
     }
@@ -75,9 +197,17 @@ impl Completions {
         local_name: String,
         resolution: &ScopeDef,
     ) {
-        if let Some(item) = render_resolution(RenderContext::new(ctx), local_name, resolution) {
+        if self.is_full(ctx, &local_name) {
+            return;
+        }
+        if let Some(item) =
+            render_resolution(RenderContext::new(ctx), local_name.clone(), resolution)
+        {
             self.add(item);
         }
+        if let ScopeDef::ModuleDef(hir::ModuleDef::Function(func)) = resolution {
+            self.add_turbofish_call(ctx, Some(local_name), *func);
+        }
     }
 
     pub(crate) fn add_macro(
@@ -90,6 +220,9 @@ impl Completions {
             Some(it) => it,
             None => return,
         };
+        if self.is_full(ctx, &name) {
+            return;
+        }
         if let Some(item) = render_macro(RenderContext::new(ctx), None, name, macro_) {
             self.add(item);
         }
@@ -101,39 +234,167 @@ impl Completions {
         func: hir::Function,
         local_name: Option<String>,
     ) {
-        let item = render_fn(RenderContext::new(ctx), None, local_name, func);
-        self.add(item)
+        let name = local_name.clone().unwrap_or_else(|| func.name(ctx.db).to_string());
+        if self.is_full(ctx, &name) {
+            return;
+        }
+        if let Some(item) = render_fn(RenderContext::new(ctx), None, local_name.clone(), func) {
+            self.add(item)
+        }
+        self.add_turbofish_call(ctx, local_name, func);
+    }
+
+    /// Adds the call of a generic function with a turbofish, next to its plain
+    /// call, when its type parameters can't be inferred from the arguments.
+    fn add_turbofish_call(
+        &mut self,
+        ctx: &CompletionContext,
+        local_name: Option<String>,
+        func: hir::Function,
+    ) {
+        if !ctx.config.turbofish_snippets {
+            return;
+        }
+        let name = local_name.clone().unwrap_or_else(|| func.name(ctx.db).to_string());
+        if self.is_full(ctx, &name) {
+            return;
+        }
+        if let Some(item) = render_fn_with_turbofish(RenderContext::new(ctx), local_name, func) {
+            self.add(item)
+        }
+    }
+
+    pub(crate) fn add_function_with_substs(
+        &mut self,
+        ctx: &CompletionContext,
+        func: hir::Function,
+        substs: Vec<(String, String)>,
+    ) {
+        if self.is_full(ctx, &func.name(ctx.db).to_string()) {
+            return;
+        }
+        if let Some(item) = render_fn_with_substs(RenderContext::new(ctx), func, substs) {
+            self.add(item)
+        }
     }
 
     pub(crate) fn add_const(&mut self, ctx: &CompletionContext, constant: hir::Const) {
-        if let Some(item) = render_const(RenderContext::new(ctx), constant) {
+        if self.is_full(ctx, &name_of(constant.name(ctx.db))) {
+            return;
+        }
+        if let Some(item) = render_const(RenderContext::new(ctx), None, constant) {
+            self.add(item);
+        }
+    }
+
+    pub(crate) fn add_const_with_import(
+        &mut self,
+        ctx: &CompletionContext,
+        constant: hir::Const,
+        import_to_add: ImportToAdd,
+    ) {
+        if self.is_full(ctx, &name_of(constant.name(ctx.db))) {
+            return;
+        }
+        if let Some(item) = render_const(RenderContext::new(ctx), Some(import_to_add), constant) {
             self.add(item);
         }
     }
 
     pub(crate) fn add_type_alias(&mut self, ctx: &CompletionContext, type_alias: hir::TypeAlias) {
+        if self.is_full(ctx, &type_alias.name(ctx.db).to_string()) {
+            return;
+        }
         if let Some(item) = render_type_alias(RenderContext::new(ctx), type_alias) {
             self.add(item)
         }
     }
 
+    /// Adds an associated item found through the bounds of a type parameter,
+    /// noting which trait of the bounds it comes from.
+    pub(crate) fn add_assoc_item_of_bound(
+        &mut self,
+        ctx: &CompletionContext,
+        item: hir::AssocItem,
+        trait_: hir::Trait,
+    ) {
+        if self.is_full(ctx, &name_of(item.name(ctx.db))) {
+            return;
+        }
+        let render_ctx = RenderContext::new(ctx);
+        let rendered = match item {
+            hir::AssocItem::Function(func) => render_fn(render_ctx, None, None, func),
+            hir::AssocItem::Const(ct) => render_const(render_ctx, None, ct),
+            hir::AssocItem::TypeAlias(ty) => render_type_alias(render_ctx, ty),
+        };
+        if let Some(mut rendered) = rendered {
+            rendered.add_origin_to_detail(&trait_.name(ctx.db).to_string());
+            self.add(rendered);
+        }
+    }
+
     pub(crate) fn add_qualified_enum_variant(
         &mut self,
         ctx: &CompletionContext,
         variant: hir::EnumVariant,
         path: ModPath,
     ) {
+        if self.is_full(ctx, &variant.name(ctx.db).to_string()) {
+            return;
+        }
         let item = render_enum_variant(RenderContext::new(ctx), None, None, variant, Some(path));
         self.add(item);
     }
 
+    pub(crate) fn add_enum_variant_with_import(
+        &mut self,
+        ctx: &CompletionContext,
+        variant: hir::EnumVariant,
+        import_to_add: ImportToAdd,
+    ) {
+        if self.is_full(ctx, &variant.name(ctx.db).to_string()) {
+            return;
+        }
+        let item =
+            render_enum_variant(RenderContext::new(ctx), Some(import_to_add), None, variant, None);
+        self.add(item);
+    }
+
     pub(crate) fn add_enum_variant(
         &mut self,
         ctx: &CompletionContext,
         variant: hir::EnumVariant,
         local_name: Option<String>,
     ) {
+        let name = local_name.clone().unwrap_or_else(|| variant.name(ctx.db).to_string());
+        if self.is_full(ctx, &name) {
+            return;
+        }
         let item = render_enum_variant(RenderContext::new(ctx), None, local_name, variant, None);
         self.add(item);
     }
 }
+
+/// The text of an optional name, empty for items like `const _: () = ();`.
+fn name_of(name: Option<hir::Name>) -> String {
+    name.map(|it| it.to_string()).unwrap_or_default()
+}
+
+/// Imports of `paths` into the scope around `anchor`, or `None` if there is no
+/// place to put them.
+pub(crate) fn imports_for(
+    ctx: &CompletionContext,
+    anchor: &SyntaxNode,
+    paths: &[ModPath],
+) -> Option<Vec<ImportToAdd>> {
+    if paths.is_empty() {
+        return Some(Vec::new());
+    }
+    let import_scope = ImportScope::find_insert_use_container(anchor, &ctx.sema)?;
+    let imports = paths.iter().map(|import_path| ImportToAdd {
+        import_path: import_path.clone(),
+        import_scope: import_scope.clone(),
+        merge_behaviour: ctx.config.merge,
+    });
+    Some(imports.collect())
+}
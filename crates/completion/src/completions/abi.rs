@@ -0,0 +1,136 @@
+//! Completes the ABI strings of `extern "<|>"` functions and blocks.
+
+use syntax::{TextRange, TextSize};
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+struct AbiCompletion {
+    label: &'static str,
+    description: &'static str,
+    /// The `target_arch`es supporting the ABI, empty if all of them do.
+    target_archs: &'static [&'static str],
+}
+
+const fn abi(
+    label: &'static str,
+    description: &'static str,
+    target_archs: &'static [&'static str],
+) -> AbiCompletion {
+    AbiCompletion { label, description, target_archs }
+}
+
+const X86: &[&str] = &["x86", "x86_64"];
+
+const ABIS: &[AbiCompletion] = &[
+    abi("C", "The default ABI of C code on the target", &[]),
+    abi("C-unwind", "The C ABI, allowing unwinding across the boundary", &[]),
+    abi("Rust", "The default ABI of Rust functions", &[]),
+    abi("aapcs", "The ARM procedure call standard", &["arm"]),
+    abi("cdecl", "The default ABI of C code on x86", X86),
+    abi("efiapi", "The ABI of UEFI functions", &[]),
+    abi("fastcall", "The Windows fastcall ABI", X86),
+    abi("stdcall", "The ABI of the Win32 API on x86", X86),
+    abi("system", "The ABI of the system libraries, `stdcall` on 32-bit Windows", &[]),
+    abi("sysv64", "The System V ABI of x86_64", &["x86_64"]),
+    abi("thiscall", "The ABI of C++ member functions with MSVC", X86),
+    abi("vectorcall", "The Windows vectorcall ABI", X86),
+    abi("win64", "The Windows ABI of x86_64", &["x86_64"]),
+];
+
+pub(crate) fn complete_abi(acc: &mut Completions, ctx: &CompletionContext) {
+    if !ctx.is_abi_string {
+        return;
+    }
+    let string = match &ctx.string_literal_under_caret {
+        Some(it) => it,
+        None => return,
+    };
+    let content_start = match string.text().find('"') {
+        Some(it) => string.text_range().start() + TextSize::of(&string.text()[..=it]),
+        None => return,
+    };
+    let source_range = TextRange::new(content_start, ctx.position.offset);
+    // Without a known target, all the ABIs are offered.
+    let target_archs = ctx.krate.map(|it| it.cfg_values(ctx.db, "target_arch")).unwrap_or_default();
+    let is_supported = |abi: &AbiCompletion| {
+        abi.target_archs.is_empty()
+            || target_archs.is_empty()
+            || target_archs.iter().any(|arch| abi.target_archs.contains(&arch.as_str()))
+    };
+    for abi in ABIS.iter().filter(|it| is_supported(it)) {
+        CompletionItem::new(CompletionKind::Magic, source_range, abi.label)
+            .kind(CompletionItemKind::Keyword)
+            .detail(abi.description)
+            .add_to(acc);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Magic);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_abis() {
+        check(
+            r#"
+extern "<|>" fn foo() {}
+"#,
+            expect![[r#"
+                kw C          The default ABI of C code on the target
+                kw C-unwind   The C ABI, allowing unwinding across the boundary
+                kw Rust       The default ABI of Rust functions
+                kw aapcs      The ARM procedure call standard
+                kw cdecl      The default ABI of C code on x86
+                kw efiapi     The ABI of UEFI functions
+                kw fastcall   The Windows fastcall ABI
+                kw stdcall    The ABI of the Win32 API on x86
+                kw system     The ABI of the system libraries, `stdcall` on 32-bit Windows
+                kw sysv64     The System V ABI of x86_64
+                kw thiscall   The ABI of C++ member functions with MSVC
+                kw vectorcall The Windows vectorcall ABI
+                kw win64      The Windows ABI of x86_64
+            "#]],
+        );
+    }
+
+    #[test]
+    fn filters_abis_by_target_arch() {
+        check(
+            r#"
+//- /lib.rs crate:foo cfg:target_arch=arm
+extern "<|>" {}
+"#,
+            expect![[r#"
+                kw C        The default ABI of C code on the target
+                kw C-unwind The C ABI, allowing unwinding across the boundary
+                kw Rust     The default ABI of Rust functions
+                kw aapcs    The ARM procedure call standard
+                kw efiapi   The ABI of UEFI functions
+                kw system   The ABI of the system libraries, `stdcall` on 32-bit Windows
+            "#]],
+        );
+    }
+
+    #[test]
+    fn replaces_the_typed_abi() {
+        check_edit(
+            "system",
+            r#"
+extern "sys<|>" {}
+"#,
+            r#"
+extern "system" {}
+"#,
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! Completes the bindings of associated types in the generic arguments of a
+//! trait, like `Item = $0` in `impl Iterator<<|>>`.
+
+use rustc_hash::FxHashSet;
+use syntax::{ast, AstNode};
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_assoc_type_arg(acc: &mut Completions, ctx: &CompletionContext) {
+    let (trait_, cap) = match (ctx.generic_arg_trait, ctx.config.snippet_cap) {
+        (Some(trait_), Some(cap)) => (trait_, cap),
+        _ => return,
+    };
+    let bound: FxHashSet<String> = ctx
+        .token
+        .parent()
+        .ancestors()
+        .find_map(ast::GenericArgList::cast)
+        .into_iter()
+        .flat_map(|it| it.generic_args())
+        .filter_map(|arg| match arg {
+            ast::GenericArg::AssocTypeArg(it) => it.name_ref(),
+            _ => None,
+        })
+        .map(|it| it.syntax().text().to_string())
+        .collect();
+
+    for item in trait_.items(ctx.db) {
+        let alias = match item {
+            hir::AssocItem::TypeAlias(it) => it,
+            _ => continue,
+        };
+        let name = alias.name(ctx.db).to_string();
+        if bound.contains(&name) {
+            continue;
+        }
+        CompletionItem::new(CompletionKind::Magic, ctx.source_range(), format!("{} = …", name))
+            .kind(CompletionItemKind::TypeAlias)
+            .lookup_by(name.clone())
+            .insert_snippet(cap, format!("{} = $0", name))
+            .add_to(acc);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Magic);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_assoc_type_bindings() {
+        check(
+            r#"
+trait Iterator { type Item; fn next(&mut self); }
+fn foo() -> impl Iterator<<|>> {}
+"#,
+            expect![[r#"
+                ta Item = …
+            "#]],
+        );
+        check(
+            r#"
+trait Future { type Output; }
+fn foo(x: Box<dyn Future<Ou<|>>>) {}
+"#,
+            expect![[r#"
+                ta Output = …
+            "#]],
+        );
+    }
+
+    #[test]
+    fn skips_bound_assoc_types_and_non_traits() {
+        check(
+            r#"
+trait Tr { type A; type B; }
+fn foo() -> impl Tr<A = u32, <|>> {}
+"#,
+            expect![[r#"
+                ta B = …
+            "#]],
+        );
+        check(
+            r#"
+struct S<T>(T);
+fn foo() -> S<<|>> {}
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn inserts_assoc_type_binding() {
+        check_edit(
+            "Item",
+            r#"
+trait Iterator { type Item; }
+fn foo() -> impl Iterator<I<|>> {}
+"#,
+            r#"
+trait Iterator { type Item; }
+fn foo() -> impl Iterator<Item = $0> {}
+"#,
+        );
+    }
+}
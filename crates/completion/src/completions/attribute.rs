@@ -3,13 +3,22 @@
 //! This module uses a bit of static metadata to provide completions
 //! for built-in attributes.
 
+use std::iter;
+
+use hir::Documentation;
+use ide_db::helpers::FamousDefs;
+use itertools::Itertools;
+use once_cell::sync::Lazy;
 use rustc_hash::FxHashSet;
-use syntax::{ast, AstNode, SyntaxKind};
+use syntax::{
+    ast::{self, AttrsOwner},
+    AstNode, SyntaxKind, SyntaxToken, TextRange, TextSize,
+};
 
 use crate::{
     context::CompletionContext,
-    generated_lint_completions::{CLIPPY_LINTS, FEATURES},
-    item::{CompletionItem, CompletionItemKind, CompletionKind},
+    generated_lint_completions::{CLIPPY_LINTS, CLIPPY_LINT_GROUPS, FEATURES},
+    item::{CompletionItem, CompletionItemKind, CompletionKind, CompletionScore},
     Completions,
 };
 
@@ -18,21 +27,22 @@ pub(crate) fn complete_attribute(acc: &mut Completions, ctx: &CompletionContext)
         return None;
     }
 
+    if let Some(string) = &ctx.string_literal_under_caret {
+        if let Some(key) = &ctx.cfg_value_key {
+            complete_cfg_value(acc, ctx, key, string);
+        } else if let Some(attribute) = &ctx.attribute_under_caret {
+            if matches!(attribute.path(), Some(path) if path.to_string() == "must_use") {
+                complete_must_use_reason(acc, ctx, string);
+            }
+        }
+        // `#[doc = "<|>"]`, `#[path = r"<|>"]`, etc.
+        return None;
+    }
+
     let attribute = ctx.attribute_under_caret.as_ref()?;
     match (attribute.path(), attribute.token_tree()) {
-        (Some(path), Some(token_tree)) if path.to_string() == "derive" => {
-            complete_derive(acc, ctx, token_tree)
-        }
-        (Some(path), Some(token_tree)) if path.to_string() == "feature" => {
-            complete_lint(acc, ctx, token_tree, FEATURES);
-        }
-        (Some(path), Some(token_tree))
-            if ["allow", "warn", "deny", "forbid"]
-                .iter()
-                .any(|lint_level| lint_level == &path.to_string()) =>
-        {
-            complete_lint(acc, ctx, token_tree.clone(), DEFAULT_LINT_COMPLETIONS);
-            complete_lint(acc, ctx, token_tree, CLIPPY_LINTS);
+        (Some(path), Some(token_tree)) => {
+            complete_attr_input(acc, ctx, attribute, &path.to_string(), token_tree)
         }
         (_, Some(_token_tree)) => {}
         _ => complete_attribute_start(acc, ctx, attribute),
@@ -40,8 +50,189 @@ pub(crate) fn complete_attribute(acc: &mut Completions, ctx: &CompletionContext)
     Some(())
 }
 
+/// Completes the input of the attribute named `name`, which is `attribute`
+/// itself or one of the attributes in its `cfg_attr`.
+fn complete_attr_input(
+    acc: &mut Completions,
+    ctx: &CompletionContext,
+    attribute: &ast::Attr,
+    name: &str,
+    token_tree: ast::TokenTree,
+) {
+    match name {
+        "derive" => complete_derive(acc, ctx, token_tree),
+        "doc" => complete_doc_arg(acc, ctx, attribute, token_tree),
+        "feature" => complete_feature(acc, ctx, token_tree),
+        "allow" | "warn" | "deny" | "forbid" | "expect" => {
+            complete_lint(acc, ctx, token_tree.clone(), &DEFAULT_LINT_TABLE, DEFAULT_LINT_GROUPS);
+            complete_lint(acc, ctx, token_tree.clone(), &RUSTDOC_LINT_TABLE, &[]);
+            complete_lint(acc, ctx, token_tree, &CLIPPY_LINT_TABLE, CLIPPY_LINT_GROUPS);
+        }
+        "cfg" => complete_cfg_predicate(acc, ctx, token_tree),
+        "cfg_attr" => complete_cfg_attr(acc, ctx, attribute, token_tree),
+        "repr" => complete_repr(acc, ctx, attribute, token_tree),
+        _ => {
+            if let Some(attr_args) = ATTR_ARGS.iter().find(|it| it.attr == name) {
+                complete_attr_args(acc, ctx, token_tree, attr_args.args, attr_args.exclusive)
+            }
+        }
+    }
+}
+
+/// Completes `#[cfg_attr(predicate, attr)]`: the predicate like `#[cfg]`, and
+/// the attributes after it like the attributes of the annotated item.
+fn complete_cfg_attr(
+    acc: &mut Completions,
+    ctx: &CompletionContext,
+    attribute: &ast::Attr,
+    input: ast::TokenTree,
+) {
+    let offset = ctx.position.offset;
+    let first_comma = input
+        .syntax()
+        .children_with_tokens()
+        .filter_map(|it| it.into_token())
+        .find(|it| it.kind() == SyntaxKind::COMMA);
+    match first_comma {
+        Some(comma) if comma.text_range().end() <= offset => {}
+        _ => return complete_cfg_predicate(acc, ctx, input),
+    }
+
+    let nested = input.syntax().children().filter_map(ast::TokenTree::cast).find(|it| {
+        it.syntax().text_range().start() < offset && offset < it.syntax().text_range().end()
+    });
+    if let Some(nested) = nested {
+        let name = nested
+            .syntax()
+            .prev_sibling_or_token()
+            .and_then(|it| it.into_token())
+            .filter(|it| it.kind() == SyntaxKind::IDENT);
+        if let Some(name) = name {
+            complete_attr_input(acc, ctx, attribute, name.text(), nested);
+        }
+        return;
+    }
+    if matches!(token_before_typed(&input, offset), Some(it) if it.kind() == SyntaxKind::COMMA) {
+        complete_attribute_start(acc, ctx, attribute);
+    }
+}
+
+/// Completes the value of a cfg predicate, like `#[cfg(target_os = "<|>")]`
+/// or `cfg!(feature = "<|>")`.
+///
+/// Features come from the declared features of the current crate, the other
+/// values from the known ones and the active ones in the crate graph.
+fn complete_cfg_value(
+    acc: &mut Completions,
+    ctx: &CompletionContext,
+    key: &str,
+    string: &SyntaxToken,
+) {
+    let krate = match ctx.krate {
+        Some(it) => it,
+        None => return,
+    };
+    let source_range = match string_content_range(ctx, string) {
+        Some(it) => it,
+        None => return,
+    };
+    if key == "feature" {
+        for feature in krate.cargo_features(ctx.db) {
+            CompletionItem::new(CompletionKind::Attribute, source_range, feature.as_str())
+                .kind(CompletionItemKind::Attribute)
+                .detail("feature")
+                .add_to(acc);
+        }
+        return;
+    }
+
+    let active = krate.cfg_values(ctx.db, key);
+    let known = CFG_VALUES.iter().find(|(it, _)| *it == key).map_or(&[][..], |(_, values)| values);
+    let mut values: Vec<&str> = known.to_vec();
+    values.extend(active.iter().map(|it| it.as_str()).filter(|it| !known.contains(it)));
+    for value in values {
+        let mut item = CompletionItem::new(CompletionKind::Attribute, source_range, value)
+            .kind(CompletionItemKind::Attribute);
+        if active.iter().any(|it| it == value) {
+            item = item.detail("active");
+        }
+        item.add_to(acc);
+    }
+}
+
+/// Completes the reasons std gives in `#[must_use = "<|>"]`.
+fn complete_must_use_reason(acc: &mut Completions, ctx: &CompletionContext, string: &SyntaxToken) {
+    let source_range = match string_content_range(ctx, string) {
+        Some(it) => it,
+        None => return,
+    };
+    for (reason, description) in MUST_USE_REASONS {
+        CompletionItem::new(CompletionKind::Attribute, source_range, *reason)
+            .kind(CompletionItemKind::Attribute)
+            .detail(*description)
+            .add_to(acc);
+    }
+}
+
+/// The range from the opening quote of `string` up to the caret.
+fn string_content_range(ctx: &CompletionContext, string: &SyntaxToken) -> Option<TextRange> {
+    let quote = string.text().find('"')?;
+    let content_start = string.text_range().start() + TextSize::of(&string.text()[..=quote]);
+    Some(TextRange::new(content_start, ctx.position.offset))
+}
+
+/// Completes the keys of `#[cfg(<|>)]`, also when nested in `all`, `any` or
+/// `not`.
+fn complete_cfg_predicate(acc: &mut Completions, ctx: &CompletionContext, input: ast::TokenTree) {
+    if !is_cfg_key_position(ctx, &input) {
+        return;
+    }
+    for arg in CFG_KEYS {
+        add_attr_arg(acc, ctx, arg);
+    }
+}
+
+/// Checks that the caret is on a key of the predicate in `input`, and not on a
+/// value or inside a list other than `all`, `any` or `not`.
+fn is_cfg_key_position(ctx: &CompletionContext, input: &ast::TokenTree) -> bool {
+    let offset = ctx.position.offset;
+    let innermost = input
+        .syntax()
+        .descendants()
+        .filter_map(ast::TokenTree::cast)
+        .filter(|it| it.syntax().text_range().contains_inclusive(offset))
+        .last();
+    let innermost = match innermost {
+        Some(it) => it,
+        None => return false,
+    };
+    if innermost.syntax() != input.syntax() {
+        let combinator = innermost
+            .syntax()
+            .prev_sibling_or_token()
+            .and_then(|it| it.into_token())
+            .filter(|it| it.kind() == SyntaxKind::IDENT);
+        if !matches!(combinator, Some(it) if ["all", "any", "not"].contains(&it.text().as_str())) {
+            return false;
+        }
+    }
+    !matches!(token_before_typed(&innermost, offset), Some(it) if it.kind() == SyntaxKind::EQ)
+}
+
+/// Returns the token before the identifier being typed at `offset` in `input`,
+/// skipping whitespace.
+fn token_before_typed(input: &ast::TokenTree, offset: TextSize) -> Option<SyntaxToken> {
+    let typed = input.syntax().token_at_offset(offset).find(|it| it.kind() == SyntaxKind::IDENT);
+    let prev = match typed {
+        Some(it) => it.prev_token(),
+        None => input.syntax().token_at_offset(offset).left_biased(),
+    };
+    iter::successors(prev, |it| it.prev_token()).find(|it| it.kind() != SyntaxKind::WHITESPACE)
+}
+
 fn complete_attribute_start(acc: &mut Completions, ctx: &CompletionContext, attribute: &ast::Attr) {
-    for attr_completion in ATTRIBUTES {
+    let target = attr_target(attribute);
+    for attr_completion in ATTRIBUTES.iter().filter(|it| it.target.accepts(target)) {
         let mut item = CompletionItem::new(
             CompletionKind::Attribute,
             ctx.source_range(),
@@ -64,6 +255,83 @@ fn complete_attribute_start(acc: &mut Completions, ctx: &CompletionContext, attr
             acc.add(item.build());
         }
     }
+
+    for (helper, derive) in derive_helpers(ctx, attribute) {
+        CompletionItem::new(CompletionKind::Attribute, ctx.source_range(), helper)
+            .kind(CompletionItemKind::Attribute)
+            .detail(format!("helper attribute of {}", derive))
+            .add_to(acc);
+    }
+}
+
+/// Returns the helper attributes of the derives on the type `attribute` is
+/// on, or on the type of the field or variant it is on, along with the derive
+/// declaring them.
+fn derive_helpers(ctx: &CompletionContext, attribute: &ast::Attr) -> Vec<(String, String)> {
+    let owner = match attribute.syntax().parent() {
+        Some(it) => it,
+        None => return Vec::new(),
+    };
+    if !matches!(
+        owner.kind(),
+        SyntaxKind::STRUCT
+            | SyntaxKind::ENUM
+            | SyntaxKind::UNION
+            | SyntaxKind::VARIANT
+            | SyntaxKind::RECORD_FIELD
+            | SyntaxKind::TUPLE_FIELD
+    ) {
+        return Vec::new();
+    }
+    let adt = match owner.ancestors().find_map(ast::AdtDef::cast) {
+        Some(it) => it,
+        None => return Vec::new(),
+    };
+    let mut result = Vec::new();
+    for derive_input in adt
+        .attrs()
+        .filter(|attr| matches!(attr.path(), Some(path) if path.to_string() == "derive"))
+        .filter_map(|attr| attr.token_tree())
+    {
+        for derive in parse_comma_sep_input(derive_input).unwrap_or_default() {
+            let (mac, name) = match resolve_derive(ctx, &derive) {
+                Some(it) => it,
+                None => continue,
+            };
+            for helper in mac.derive_helpers(ctx.db, &name) {
+                result.push((helper.to_string(), derive.clone()));
+            }
+        }
+    }
+    result
+}
+
+/// Resolves the derive macro `path`, which is either in scope or a macro of a
+/// dependency, like `serde::Serialize`.
+fn resolve_derive(ctx: &CompletionContext, path: &str) -> Option<(hir::MacroDef, hir::Name)> {
+    let segments: Vec<&str> = path.split("::").collect();
+    let (scope, name) = match segments.as_slice() {
+        [name] => {
+            let mut scope = Vec::new();
+            ctx.scope.process_all_names(&mut |name, def| scope.push((name, def)));
+            (scope, name)
+        }
+        [krate, name] => {
+            let dependency = ctx
+                .krate?
+                .dependencies(ctx.db)
+                .into_iter()
+                .find(|it| it.name.to_string() == *krate)?;
+            (dependency.krate.root_module(ctx.db).scope(ctx.db, None), name)
+        }
+        _ => return None,
+    };
+    scope.into_iter().find_map(|(it, def)| match def {
+        hir::ScopeDef::MacroDef(mac) if mac.is_derive_macro() && it.to_string() == *name => {
+            Some((mac, it))
+        }
+        _ => None,
+    })
 }
 
 struct AttrCompletion {
@@ -71,12 +339,65 @@ struct AttrCompletion {
     lookup: Option<&'static str>,
     snippet: Option<&'static str>,
     prefer_inner: bool,
+    target: AttrTarget,
 }
 
 impl AttrCompletion {
     const fn prefer_inner(self) -> AttrCompletion {
         AttrCompletion { prefer_inner: true, ..self }
     }
+
+    const fn only_on(self, target: AttrTarget) -> AttrCompletion {
+        AttrCompletion { target, ..self }
+    }
+}
+
+/// The kind of item an attribute is valid on, for the attributes that are
+/// only meaningful in a few places.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AttrTarget {
+    Any,
+    ExternBlock,
+    /// A function or a static declared in an `extern` block.
+    ForeignItem,
+    /// A function or a static with a body, that can be exported.
+    ExportableItem,
+}
+
+impl AttrTarget {
+    /// `None` is an attribute that is not attached to anything yet, where
+    /// everything is offered.
+    fn accepts(self, target: Option<AttrTarget>) -> bool {
+        match (self, target) {
+            (AttrTarget::Any, _) | (_, None) => true,
+            (_, Some(target)) => self == target,
+        }
+    }
+}
+
+fn attr_target(attribute: &ast::Attr) -> Option<AttrTarget> {
+    if attribute.kind() == ast::AttrKind::Inner {
+        return Some(AttrTarget::Any);
+    }
+    let owner = attribute.syntax().parent()?;
+    let target = match owner.kind() {
+        SyntaxKind::EXTERN_BLOCK => AttrTarget::ExternBlock,
+        SyntaxKind::FN | SyntaxKind::STATIC => {
+            if owner.parent().and_then(ast::ExternItemList::cast).is_some() {
+                AttrTarget::ForeignItem
+            } else {
+                AttrTarget::ExportableItem
+            }
+        }
+        // A dangling outer attribute, the item is yet to be written.
+        SyntaxKind::SOURCE_FILE
+        | SyntaxKind::ITEM_LIST
+        | SyntaxKind::EXTERN_ITEM_LIST
+        | SyntaxKind::ASSOC_ITEM_LIST
+        | SyntaxKind::ERROR => return None,
+        _ => AttrTarget::Any,
+    };
+    Some(target)
 }
 
 const fn attr(
@@ -84,7 +405,7 @@ const fn attr(
     lookup: Option<&'static str>,
     snippet: Option<&'static str>,
 ) -> AttrCompletion {
-    AttrCompletion { label, lookup, snippet, prefer_inner: false }
+    AttrCompletion { label, lookup, snippet, prefer_inner: false, target: AttrTarget::Any }
 }
 
 const ATTRIBUTES: &[AttrCompletion] = &[
@@ -101,12 +422,14 @@ const ATTRIBUTES: &[AttrCompletion] = &[
     attr("global_allocator", None, None).prefer_inner(),
     attr(r#"ignore = "…""#, Some("ignore"), Some(r#"ignore = "${0:reason}""#)),
     attr("inline(…)", Some("inline"), Some("inline(${0:lint})")),
-    attr(r#"link_name = "…""#, Some("link_name"), Some(r#"link_name = "${0:symbol_name}""#)),
-    attr("link", None, None),
+    attr(r#"link_name = "…""#, Some("link_name"), Some(r#"link_name = "${0:symbol_name}""#))
+        .only_on(AttrTarget::ForeignItem),
+    attr("link(…)", Some("link"), Some(r#"link(name = "${0:name}")"#))
+        .only_on(AttrTarget::ExternBlock),
     attr("macro_export", None, None),
     attr("macro_use", None, None),
     attr(r#"must_use = "…""#, Some("must_use"), Some(r#"must_use = "${0:reason}""#)),
-    attr("no_mangle", None, None),
+    attr("no_mangle", None, None).only_on(AttrTarget::ExportableItem),
     attr("no_std", None, None).prefer_inner(),
     attr("non_exhaustive", None, None),
     attr("panic_handler", None, None).prefer_inner(),
@@ -140,41 +463,414 @@ const ATTRIBUTES: &[AttrCompletion] = &[
 
 fn complete_derive(acc: &mut Completions, ctx: &CompletionContext, derive_input: ast::TokenTree) {
     if let Ok(existing_derives) = parse_comma_sep_input(derive_input) {
+        let target = derive_target(ctx);
         for derive_completion in DEFAULT_DERIVE_COMPLETIONS
-            .into_iter()
+            .iter()
             .filter(|completion| !existing_derives.contains(completion.label))
         {
-            let mut label = derive_completion.label.to_owned();
-            for dependency in derive_completion
+            let derivable = match &target {
+                Some(target) => is_derivable(ctx, derive_completion, target),
+                None => true,
+            };
+            if !derivable && !DEMOTED_DERIVES.contains(&derive_completion.label) {
+                continue;
+            }
+            let missing_dependencies: Vec<_> = derive_completion
                 .dependencies
-                .into_iter()
+                .iter()
                 .filter(|&&dependency| !existing_derives.contains(dependency))
-            {
-                label.push_str(", ");
-                label.push_str(dependency);
+                .collect();
+            let label = iter::once(&derive_completion.label)
+                .chain(missing_dependencies.iter().copied())
+                .join(", ");
+            let mut item =
+                CompletionItem::new(CompletionKind::Attribute, ctx.source_range(), label)
+                    .kind(CompletionItemKind::Attribute);
+            if !missing_dependencies.is_empty() {
+                item = item.detail(format!("requires {}", missing_dependencies.iter().join(" + ")));
             }
-            CompletionItem::new(CompletionKind::Attribute, ctx.source_range(), label)
-                .kind(CompletionItemKind::Attribute)
-                .add_to(acc)
+            if !derivable {
+                item = item.set_score(CompletionScore::Unlikely);
+            }
+            item.add_to(acc)
         }
 
-        for custom_derive_name in get_derive_names_in_scope(ctx).difference(&existing_derives) {
-            CompletionItem::new(CompletionKind::Attribute, ctx.source_range(), custom_derive_name)
-                .kind(CompletionItemKind::Attribute)
-                .add_to(acc)
+        for (custom_derive_name, krate) in custom_derives(ctx) {
+            if existing_derives.contains(&custom_derive_name) {
+                continue;
+            }
+            let mut item = CompletionItem::new(
+                CompletionKind::Attribute,
+                ctx.source_range(),
+                custom_derive_name,
+            )
+            .kind(CompletionItemKind::Attribute);
+            if let Some(krate) = krate {
+                item = item.detail(krate);
+            }
+            item.add_to(acc)
+        }
+    }
+}
+
+/// Derives which are demoted instead of hidden when the fields don't implement
+/// them. They mostly fail because of float fields, which a wrapper type with
+/// the impls can fix.
+const DEMOTED_DERIVES: &[&str] = &["Eq", "Ord"];
+
+/// The type `#[derive]` is applied to, with the types of all of its fields.
+fn derive_target(ctx: &CompletionContext) -> Option<(hir::Adt, Vec<hir::Type>)> {
+    let attr = ctx.token.ancestors().find_map(ast::Attr::cast)?;
+    let adt = match ast::AdtDef::cast(attr.syntax().parent()?)? {
+        ast::AdtDef::Struct(it) => hir::Adt::Struct(ctx.sema.to_def(&it)?),
+        ast::AdtDef::Enum(it) => hir::Adt::Enum(ctx.sema.to_def(&it)?),
+        ast::AdtDef::Union(it) => hir::Adt::Union(ctx.sema.to_def(&it)?),
+    };
+    let fields = match adt {
+        hir::Adt::Struct(it) => it.fields(ctx.db),
+        hir::Adt::Union(it) => it.fields(ctx.db),
+        hir::Adt::Enum(it) => {
+            it.variants(ctx.db).into_iter().flat_map(|variant| variant.fields(ctx.db)).collect()
+        }
+    };
+    let field_tys = fields.into_iter().map(|field| field.signature_ty(ctx.db)).collect();
+    Some((adt, field_tys))
+}
+
+/// Whether the derive compiles for `target`, as far as we can tell: all the
+/// fields have to implement the derived trait. Fields mentioning type
+/// parameters are fine, as the derive bounds the parameters accordingly.
+fn is_derivable(
+    ctx: &CompletionContext,
+    derive: &DeriveCompletion,
+    (adt, field_tys): &(hir::Adt, Vec<hir::Type>),
+) -> bool {
+    if derive.label == "Default" && matches!(adt, hir::Adt::Enum(_)) {
+        return false;
+    }
+    let trait_ = match (derive.trait_)(&FamousDefs(&ctx.sema, ctx.krate)) {
+        Some(it) => it,
+        None => return true,
+    };
+    field_tys.iter().all(|ty| {
+        ty.contains_unknown() || ty.contains_type_params() || ty.impls_trait(ctx.db, trait_, &[])
+    })
+}
+
+fn complete_doc_arg(
+    acc: &mut Completions,
+    ctx: &CompletionContext,
+    attribute: &ast::Attr,
+    doc_input: ast::TokenTree,
+) {
+    let doc_args =
+        if attribute.kind() == ast::AttrKind::Inner { CRATE_DOC_ARGS } else { ITEM_DOC_ARGS };
+    complete_attr_args(acc, ctx, doc_input, doc_args, false);
+}
+
+fn complete_attr_args(
+    acc: &mut Completions,
+    ctx: &CompletionContext,
+    input: ast::TokenTree,
+    args: &[AttrArgCompletion],
+    exclusive: bool,
+) {
+    if is_in_nested_list(ctx, &input) {
+        return;
+    }
+    let existing_args = match parse_comma_sep_input(input) {
+        Ok(it) => it,
+        Err(()) => return,
+    };
+    // The argument being typed is among the existing ones.
+    if exclusive && existing_args.len() > 1 {
+        return;
+    }
+    for arg in args.iter().filter(|it| !existing_args.iter().any(|arg| arg.starts_with(it.lookup)))
+    {
+        add_attr_arg(acc, ctx, arg);
+    }
+}
+
+/// Completes `#[repr(<|>)]` with the representations the annotated type can
+/// have, leaving out the ones conflicting with the given representations.
+fn complete_repr(
+    acc: &mut Completions,
+    ctx: &CompletionContext,
+    attribute: &ast::Attr,
+    input: ast::TokenTree,
+) {
+    if is_in_nested_list(ctx, &input) {
+        return;
+    }
+    let typed = input
+        .syntax()
+        .token_at_offset(ctx.position.offset)
+        .find(|it| it.kind() == SyntaxKind::IDENT);
+    let mut existing_reprs = match parse_comma_sep_input(input) {
+        Ok(it) => it,
+        Err(()) => return,
+    };
+    // The representation being typed doesn't conflict with the others.
+    if let Some(typed) = typed {
+        existing_reprs.remove(typed.text().as_str());
+    }
+    let owner = attribute.syntax().parent().map(|it| it.kind());
+    let is_int = |repr: &str| REPR_INTS.contains(&repr);
+    let applies = |repr: &str| match owner {
+        Some(SyntaxKind::STRUCT) => !is_int(repr),
+        Some(SyntaxKind::UNION) => matches!(repr, "C" | "packed" | "align"),
+        Some(SyntaxKind::ENUM) => !matches!(repr, "packed" | "align"),
+        _ => true,
+    };
+    // `transparent` can't be combined with anything, and a type has at most
+    // one integer representation and one of `packed` and `align`.
+    let conflicts = |repr: &str, existing: &str| {
+        repr == existing
+            || repr == "transparent"
+            || existing == "transparent"
+            || (is_int(repr) && is_int(existing))
+            || (matches!(repr, "packed" | "align") && matches!(existing, "packed" | "align"))
+    };
+    for arg in REPR_ARGS.iter().filter(|it| applies(it.lookup)) {
+        if existing_reprs.iter().any(|existing| conflicts(arg.lookup, existing)) {
+            continue;
         }
+        add_attr_arg(acc, ctx, arg);
+    }
+}
+
+/// Only the keys themselves are completed, not in nested lists like `doc(cfg(<|>))`.
+fn is_in_nested_list(ctx: &CompletionContext, input: &ast::TokenTree) -> bool {
+    let offset = ctx.position.offset;
+    input
+        .syntax()
+        .descendants()
+        .skip(1)
+        .filter_map(ast::TokenTree::cast)
+        .any(|it| it.syntax().text_range().contains_inclusive(offset))
+}
+
+fn add_attr_arg(acc: &mut Completions, ctx: &CompletionContext, arg: &AttrArgCompletion) {
+    let mut item = CompletionItem::new(CompletionKind::Attribute, ctx.source_range(), arg.label)
+        .kind(CompletionItemKind::Attribute)
+        .lookup_by(arg.lookup)
+        .detail(arg.description);
+    if let (Some(snippet), Some(cap)) = (arg.snippet, ctx.config.snippet_cap) {
+        item = item.insert_snippet(cap, snippet);
     }
+    item.add_to(acc);
+}
+
+/// The arguments of a built-in attribute taking a list, like `#[inline(always)]`.
+struct AttrArgs {
+    attr: &'static str,
+    args: &'static [AttrArgCompletion],
+    /// The attribute takes a single one of the arguments.
+    exclusive: bool,
+}
+
+const ATTR_ARGS: &[AttrArgs] = &[
+    AttrArgs { attr: "deprecated", args: DEPRECATED_ARGS, exclusive: false },
+    AttrArgs { attr: "inline", args: INLINE_ARGS, exclusive: true },
+    AttrArgs { attr: "link", args: LINK_ARGS, exclusive: false },
+    AttrArgs { attr: "proc_macro_derive", args: PROC_MACRO_DERIVE_ARGS, exclusive: false },
+    AttrArgs { attr: "should_panic", args: SHOULD_PANIC_ARGS, exclusive: true },
+];
+
+struct AttrArgCompletion {
+    label: &'static str,
+    lookup: &'static str,
+    snippet: Option<&'static str>,
+    description: &'static str,
 }
 
+#[rustfmt::skip]
+const ITEM_DOC_ARGS: &[AttrArgCompletion] = &[
+    AttrArgCompletion { label: "hidden", lookup: "hidden", snippet: None, description: "hides the item from the documentation" },
+    AttrArgCompletion { label: r#"alias = "…""#, lookup: "alias", snippet: Some(r#"alias = "${0:name}""#), description: "adds an alias for the item in the documentation search" },
+    AttrArgCompletion { label: "cfg(…)", lookup: "cfg", snippet: Some("cfg(${0:predicate})"), description: "documents the configurations the item is available on" },
+    AttrArgCompletion { label: "inline", lookup: "inline", snippet: None, description: "documents the re-exported item inline" },
+    AttrArgCompletion { label: "no_inline", lookup: "no_inline", snippet: None, description: "documents the re-export as a link to the item" },
+];
+
+#[rustfmt::skip]
+const CRATE_DOC_ARGS: &[AttrArgCompletion] = &[
+    AttrArgCompletion { label: r#"html_root_url = "…""#, lookup: "html_root_url", snippet: Some(r#"html_root_url = "${0:url}""#), description: "the base URL for links to this crate from other crates" },
+    AttrArgCompletion { label: r#"html_logo_url = "…""#, lookup: "html_logo_url", snippet: Some(r#"html_logo_url = "${0:url}""#), description: "the logo shown in the documentation" },
+    AttrArgCompletion { label: r#"html_favicon_url = "…""#, lookup: "html_favicon_url", snippet: Some(r#"html_favicon_url = "${0:url}""#), description: "the favicon of the documentation" },
+    AttrArgCompletion { label: r#"html_playground_url = "…""#, lookup: "html_playground_url", snippet: Some(r#"html_playground_url = "${0:url}""#), description: "the playground to run the examples on" },
+    AttrArgCompletion { label: r#"issue_tracker_base_url = "…""#, lookup: "issue_tracker_base_url", snippet: Some(r#"issue_tracker_base_url = "${0:url}""#), description: "the base URL of the issues of unstable features" },
+    AttrArgCompletion { label: "html_no_source", lookup: "html_no_source", snippet: None, description: "does not include the source code in the documentation" },
+    AttrArgCompletion { label: "test(…)", lookup: "test", snippet: Some("test(${0:attr})"), description: "controls how the documentation tests are run" },
+];
+
+#[rustfmt::skip]
+const LINK_ARGS: &[AttrArgCompletion] = &[
+    AttrArgCompletion { label: r#"name = "…""#, lookup: "name", snippet: Some(r#"name = "${0:name}""#), description: "the name of the native library to link" },
+    AttrArgCompletion { label: r#"kind = "static""#, lookup: "kind", snippet: None, description: "links the library statically" },
+    AttrArgCompletion { label: r#"kind = "dylib""#, lookup: "kind", snippet: None, description: "links the library dynamically" },
+    AttrArgCompletion { label: r#"wasm_import_module = "…""#, lookup: "wasm_import_module", snippet: Some(r#"wasm_import_module = "${0:module}""#), description: "the WebAssembly module to import the items from" },
+];
+
+#[rustfmt::skip]
+const DEPRECATED_ARGS: &[AttrArgCompletion] = &[
+    AttrArgCompletion { label: r#"since = "…""#, lookup: "since", snippet: Some(r#"since = "${0:version}""#), description: "the version the item is deprecated since" },
+    AttrArgCompletion { label: r#"note = "…""#, lookup: "note", snippet: Some(r#"note = "${0:reason}""#), description: "the reason for the deprecation, and what to use instead" },
+];
+
+#[rustfmt::skip]
+const INLINE_ARGS: &[AttrArgCompletion] = &[
+    AttrArgCompletion { label: "always", lookup: "always", snippet: None, description: "inlines the function at every call site" },
+    AttrArgCompletion { label: "never", lookup: "never", snippet: None, description: "never inlines the function" },
+];
+
+#[rustfmt::skip]
+const PROC_MACRO_DERIVE_ARGS: &[AttrArgCompletion] = &[
+    AttrArgCompletion { label: "attributes(…)", lookup: "attributes", snippet: Some("attributes(${0:attr})"), description: "declares the helper attributes of the derive" },
+];
+
+#[rustfmt::skip]
+const REPR_ARGS: &[AttrArgCompletion] = &[
+    AttrArgCompletion { label: "C", lookup: "C", snippet: None, description: "lays the type out like C does" },
+    AttrArgCompletion { label: "transparent", lookup: "transparent", snippet: None, description: "lays the type out like its single non-zero-sized field" },
+    AttrArgCompletion { label: "packed", lookup: "packed", snippet: None, description: "removes the padding between the fields" },
+    AttrArgCompletion { label: "align(…)", lookup: "align", snippet: Some("align(${0:8})"), description: "raises the alignment of the type" },
+    AttrArgCompletion { label: "u8", lookup: "u8", snippet: None, description: "stores the discriminant of the enum as `u8`" },
+    AttrArgCompletion { label: "u16", lookup: "u16", snippet: None, description: "stores the discriminant of the enum as `u16`" },
+    AttrArgCompletion { label: "u32", lookup: "u32", snippet: None, description: "stores the discriminant of the enum as `u32`" },
+    AttrArgCompletion { label: "u64", lookup: "u64", snippet: None, description: "stores the discriminant of the enum as `u64`" },
+    AttrArgCompletion { label: "usize", lookup: "usize", snippet: None, description: "stores the discriminant of the enum as `usize`" },
+    AttrArgCompletion { label: "i8", lookup: "i8", snippet: None, description: "stores the discriminant of the enum as `i8`" },
+    AttrArgCompletion { label: "i16", lookup: "i16", snippet: None, description: "stores the discriminant of the enum as `i16`" },
+    AttrArgCompletion { label: "i32", lookup: "i32", snippet: None, description: "stores the discriminant of the enum as `i32`" },
+    AttrArgCompletion { label: "i64", lookup: "i64", snippet: None, description: "stores the discriminant of the enum as `i64`" },
+    AttrArgCompletion { label: "isize", lookup: "isize", snippet: None, description: "stores the discriminant of the enum as `isize`" },
+];
+
+const REPR_INTS: &[&str] =
+    &["u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "isize"];
+
+#[rustfmt::skip]
+const CFG_KEYS: &[AttrArgCompletion] = &[
+    AttrArgCompletion { label: "all(…)", lookup: "all", snippet: Some("all(${0:predicate})"), description: "holds if all the predicates hold" },
+    AttrArgCompletion { label: "any(…)", lookup: "any", snippet: Some("any(${0:predicate})"), description: "holds if any of the predicates holds" },
+    AttrArgCompletion { label: "not(…)", lookup: "not", snippet: Some("not(${0:predicate})"), description: "holds if the predicate does not hold" },
+    AttrArgCompletion { label: r#"target_arch = "…""#, lookup: "target_arch", snippet: Some(r#"target_arch = "${0:x86_64}""#), description: "the CPU architecture of the target" },
+    AttrArgCompletion { label: r#"target_os = "…""#, lookup: "target_os", snippet: Some(r#"target_os = "${0:linux}""#), description: "the operating system of the target" },
+    AttrArgCompletion { label: r#"target_family = "…""#, lookup: "target_family", snippet: Some(r#"target_family = "${0:unix}""#), description: "the family of the operating system of the target" },
+    AttrArgCompletion { label: r#"target_env = "…""#, lookup: "target_env", snippet: Some(r#"target_env = "${0:gnu}""#), description: "the ABI or libc used by the target" },
+    AttrArgCompletion { label: r#"target_endian = "…""#, lookup: "target_endian", snippet: Some(r#"target_endian = "${0:little}""#), description: "the endianness of the target CPU" },
+    AttrArgCompletion { label: r#"target_pointer_width = "…""#, lookup: "target_pointer_width", snippet: Some(r#"target_pointer_width = "${0:64}""#), description: "the pointer width of the target in bits" },
+    AttrArgCompletion { label: r#"target_vendor = "…""#, lookup: "target_vendor", snippet: Some(r#"target_vendor = "${0:unknown}""#), description: "the vendor of the target" },
+    AttrArgCompletion { label: r#"target_feature = "…""#, lookup: "target_feature", snippet: Some(r#"target_feature = "${0:sse2}""#), description: "a CPU feature enabled for the target" },
+    AttrArgCompletion { label: r#"feature = "…""#, lookup: "feature", snippet: Some(r#"feature = "${0:name}""#), description: "a Cargo feature enabled for the crate" },
+    AttrArgCompletion { label: "unix", lookup: "unix", snippet: None, description: "the target family is `unix`" },
+    AttrArgCompletion { label: "windows", lookup: "windows", snippet: None, description: "the target family is `windows`" },
+    AttrArgCompletion { label: "test", lookup: "test", snippet: None, description: "the crate is compiled as a test harness" },
+    AttrArgCompletion { label: "debug_assertions", lookup: "debug_assertions", snippet: None, description: "debug assertions are enabled" },
+    AttrArgCompletion { label: "proc_macro", lookup: "proc_macro", snippet: None, description: "the crate is compiled as a procedural macro" },
+    AttrArgCompletion { label: "doc", lookup: "doc", snippet: None, description: "the crate is being documented by rustdoc" },
+];
+
+/// The known values of the cfg predicates taking one.
+const CFG_VALUES: &[(&str, &[&str])] = &[
+    (
+        "target_arch",
+        &[
+            "aarch64",
+            "arm",
+            "mips",
+            "mips64",
+            "powerpc",
+            "powerpc64",
+            "riscv32",
+            "riscv64",
+            "s390x",
+            "sparc64",
+            "wasm32",
+            "x86",
+            "x86_64",
+        ],
+    ),
+    (
+        "target_os",
+        &[
+            "android",
+            "dragonfly",
+            "emscripten",
+            "freebsd",
+            "fuchsia",
+            "haiku",
+            "illumos",
+            "ios",
+            "linux",
+            "macos",
+            "netbsd",
+            "none",
+            "openbsd",
+            "redox",
+            "solaris",
+            "wasi",
+            "windows",
+        ],
+    ),
+    ("target_family", &["unix", "windows"]),
+    ("target_env", &["gnu", "msvc", "musl", "sgx"]),
+    ("target_endian", &["big", "little"]),
+    ("target_pointer_width", &["16", "32", "64"]),
+    ("target_vendor", &["apple", "fortanix", "pc", "unknown"]),
+    (
+        "target_feature",
+        &[
+            "aes",
+            "avx",
+            "avx2",
+            "bmi1",
+            "bmi2",
+            "crt-static",
+            "fma",
+            "neon",
+            "pclmulqdq",
+            "popcnt",
+            "rdrand",
+            "sse",
+            "sse2",
+            "sse3",
+            "sse4.1",
+            "sse4.2",
+            "ssse3",
+        ],
+    ),
+];
+
+/// The reasons of `#[must_use]` in std, with what they are used on.
+const MUST_USE_REASONS: &[(&str, &str)] = &[
+    (
+        "this returns the result of the operation, without modifying the original",
+        "on methods returning a new value",
+    ),
+    ("iterators are lazy and do nothing unless consumed", "on iterators"),
+    ("futures do nothing unless you `.await` or poll them", "on futures"),
+    ("this `Result` may be an `Err` variant, which should be handled", "on results"),
+];
+
+#[rustfmt::skip]
+const SHOULD_PANIC_ARGS: &[AttrArgCompletion] = &[
+    AttrArgCompletion { label: r#"expected = "…""#, lookup: "expected", snippet: Some(r#"expected = "${0:message}""#), description: "requires the panic message to contain the text" },
+];
+
 fn complete_lint(
     acc: &mut Completions,
     ctx: &CompletionContext,
     derive_input: ast::TokenTree,
-    lints_completions: &[LintCompletion],
+    lints: &LintTable,
+    groups: &[LintGroupCompletion],
 ) {
+    let prefix = typed_lint_prefix(ctx, &derive_input);
     if let Ok(existing_lints) = parse_comma_sep_input(derive_input) {
-        for lint_completion in lints_completions
-            .into_iter()
+        for lint_completion in lints
+            .with_prefix(&prefix)
             .filter(|completion| !existing_lints.contains(completion.label))
         {
             CompletionItem::new(
@@ -184,11 +880,120 @@ fn complete_lint(
             )
             .kind(CompletionItemKind::Attribute)
             .detail(lint_completion.description)
+            .documentation(lint_documentation(lint_completion))
+            .set_deprecated(lint_completion.default_level == "none")
             .add_to(acc)
         }
+        for group in groups.iter().filter(|it| {
+            it.label.starts_with(prefix.as_str()) && !existing_lints.contains(it.label)
+        }) {
+            let lints = group.lints.iter().map(|it| format!("`{}`", it)).join(", ");
+            CompletionItem::new(CompletionKind::Attribute, ctx.source_range(), group.label)
+                .kind(CompletionItemKind::Attribute)
+                .detail(group.description)
+                .documentation(Documentation::new(&format!("Lint group of {}.", lints)))
+                .add_to(acc)
+        }
+    }
+}
+
+/// Documents the default level and the groups of the lint, before its description.
+fn lint_documentation(lint: &LintCompletion) -> Documentation {
+    let mut docs = match lint.default_level {
+        "none" => "Deprecated.".to_string(),
+        level => format!("Default level: `{}`.", level),
+    };
+    let groups = DEFAULT_LINT_GROUPS
+        .iter()
+        .chain(CLIPPY_LINT_GROUPS)
+        .filter(|group| group.lints.contains(&lint.label))
+        .map(|group| format!("`{}`", group.label))
+        .join(", ");
+    if !groups.is_empty() {
+        docs.push_str(&format!(" Lint groups: {}.", groups));
+    }
+    docs.push_str("\n\n");
+    docs.push_str(lint.description);
+    Documentation::new(&docs)
+}
+
+fn complete_feature(acc: &mut Completions, ctx: &CompletionContext, input: ast::TokenTree) {
+    let prefix = typed_lint_prefix(ctx, &input);
+    let existing_features = match parse_comma_sep_input(input) {
+        Ok(it) => it,
+        Err(()) => return,
+    };
+    for feature in
+        FEATURE_TABLE.with_prefix(&prefix).filter(|it| !existing_features.contains(it.label))
+    {
+        let mut item =
+            CompletionItem::new(CompletionKind::Attribute, ctx.source_range(), feature.label)
+                .kind(CompletionItemKind::Attribute)
+                .documentation(Documentation::new(feature.description));
+        if let Some(tracking_issue) = feature.tracking_issue {
+            item = item.detail(tracking_issue);
+        }
+        item.add_to(acc);
     }
 }
 
+/// The part of the lint name before the cursor, like `clippy::nee` in
+/// `#[allow(dead_code, clippy::nee<|>)]`.
+fn typed_lint_prefix(ctx: &CompletionContext, token_tree: &ast::TokenTree) -> String {
+    let range = token_tree.syntax().text_range();
+    let offset = ctx.position.offset;
+    if !range.contains(offset) {
+        return String::new();
+    }
+    let before_cursor = token_tree.syntax().text().slice(TextRange::up_to(offset - range.start()));
+    let before_cursor = before_cursor.to_string();
+    let start = before_cursor.rfind(['(', ',']).map_or(0, |it| it + 1);
+    before_cursor[start..].chars().filter(|it| !it.is_whitespace()).collect()
+}
+
+/// Lints sorted by label, so that only the ones matching the typed prefix
+/// have to be looked at.
+struct LintTable<T: 'static = LintCompletion> {
+    lints: Vec<&'static T>,
+}
+
+trait Labeled {
+    fn label(&self) -> &'static str;
+}
+
+impl Labeled for LintCompletion {
+    fn label(&self) -> &'static str {
+        self.label
+    }
+}
+
+impl Labeled for FeatureCompletion {
+    fn label(&self) -> &'static str {
+        self.label
+    }
+}
+
+impl<T: Labeled> LintTable<T> {
+    fn new(lints: &'static [T]) -> LintTable<T> {
+        let mut lints: Vec<_> = lints.iter().collect();
+        lints.sort_by_key(|it| it.label());
+        LintTable { lints }
+    }
+
+    /// The lints whose label starts with `prefix`. They follow each other in
+    /// the sorted table, so both ends of the range are binary searched for.
+    fn with_prefix(&self, prefix: &str) -> impl Iterator<Item = &'static T> + '_ {
+        let start = self.lints.partition_point(|it| it.label() < prefix);
+        let len = self.lints[start..].partition_point(|it| it.label().starts_with(prefix));
+        self.lints[start..start + len].iter().copied()
+    }
+}
+
+static FEATURE_TABLE: Lazy<LintTable<FeatureCompletion>> = Lazy::new(|| LintTable::new(FEATURES));
+static DEFAULT_LINT_TABLE: Lazy<LintTable> = Lazy::new(|| LintTable::new(DEFAULT_LINT_COMPLETIONS));
+static RUSTDOC_LINT_TABLE: Lazy<LintTable> = Lazy::new(|| LintTable::new(RUSTDOC_LINT_COMPLETIONS));
+static CLIPPY_LINT_TABLE: Lazy<LintTable> = Lazy::new(|| LintTable::new(CLIPPY_LINTS));
+
 fn parse_comma_sep_input(derive_input: ast::TokenTree) -> Result<FxHashSet<String>, ()> {
     match (derive_input.left_delimiter_token(), derive_input.right_delimiter_token()) {
         (Some(left_paren), Some(right_paren))
@@ -224,167 +1029,229 @@ fn parse_comma_sep_input(derive_input: ast::TokenTree) -> Result<FxHashSet<Strin
     }
 }
 
-fn get_derive_names_in_scope(ctx: &CompletionContext) -> FxHashSet<String> {
-    let mut result = FxHashSet::default();
+/// Returns the derive macros in scope and the ones exported by the
+/// dependencies, the latter by their path, along with the defining crate.
+/// The standard derives are left out, as they are completed separately.
+fn custom_derives(ctx: &CompletionContext) -> Vec<(String, Option<String>)> {
+    let is_custom = |name: &str| !DEFAULT_DERIVE_COMPLETIONS.iter().any(|it| it.label == name);
+    let crate_name = |mac: hir::MacroDef| {
+        mac.module(ctx.db)?.krate().display_name(ctx.db).map(|it| it.to_string())
+    };
+    // Proc macros unknown to the build system share a dummy definition, so the
+    // name is needed to tell them apart.
+    let mut in_scope = FxHashSet::default();
+    let mut result = Vec::new();
     ctx.scope.process_all_names(&mut |name, scope_def| {
         if let hir::ScopeDef::MacroDef(mac) = scope_def {
-            if mac.is_derive_macro() {
-                result.insert(name.to_string());
+            if mac.is_derive_macro() && is_custom(&name.to_string()) {
+                in_scope.insert((mac, name.to_string()));
+                result.push((name.to_string(), crate_name(mac)));
             }
         }
     });
+    let dependencies = ctx.krate.map(|krate| krate.dependencies(ctx.db)).unwrap_or_default();
+    for dependency in dependencies {
+        for (name, def) in dependency.krate.root_module(ctx.db).scope(ctx.db, None) {
+            if let hir::ScopeDef::MacroDef(mac) = def {
+                let name = name.to_string();
+                if mac.is_derive_macro() && is_custom(&name) && in_scope.insert((mac, name.clone()))
+                {
+                    result.push((format!("{}::{}", dependency.name, name), crate_name(mac)));
+                }
+            }
+        }
+    }
     result
 }
 
 struct DeriveCompletion {
     label: &'static str,
     dependencies: &'static [&'static str],
+    /// The derived trait, which the fields have to implement.
+    trait_: fn(&FamousDefs) -> Option<hir::Trait>,
 }
 
 /// Standard Rust derives and the information about their dependencies
 /// (the dependencies are needed so that the main derive don't break the compilation when added)
 #[rustfmt::skip]
 const DEFAULT_DERIVE_COMPLETIONS: &[DeriveCompletion] = &[
-    DeriveCompletion { label: "Clone", dependencies: &[] },
-    DeriveCompletion { label: "Copy", dependencies: &["Clone"] },
-    DeriveCompletion { label: "Debug", dependencies: &[] },
-    DeriveCompletion { label: "Default", dependencies: &[] },
-    DeriveCompletion { label: "Hash", dependencies: &[] },
-    DeriveCompletion { label: "PartialEq", dependencies: &[] },
-    DeriveCompletion { label: "Eq", dependencies: &["PartialEq"] },
-    DeriveCompletion { label: "PartialOrd", dependencies: &["PartialEq"] },
-    DeriveCompletion { label: "Ord", dependencies: &["PartialOrd", "Eq", "PartialEq"] },
+    DeriveCompletion { label: "Clone", dependencies: &[], trait_: |it| it.core_clone_Clone() },
+    DeriveCompletion { label: "Copy", dependencies: &["Clone"], trait_: |it| it.core_marker_Copy() },
+    DeriveCompletion { label: "Debug", dependencies: &[], trait_: |it| it.core_fmt_Debug() },
+    DeriveCompletion { label: "Default", dependencies: &[], trait_: |it| it.core_default_Default() },
+    DeriveCompletion { label: "Hash", dependencies: &[], trait_: |it| it.core_hash_Hash() },
+    DeriveCompletion { label: "PartialEq", dependencies: &[], trait_: |it| it.core_cmp_PartialEq() },
+    DeriveCompletion { label: "Eq", dependencies: &["PartialEq"], trait_: |it| it.core_cmp_Eq() },
+    DeriveCompletion { label: "PartialOrd", dependencies: &["PartialEq"], trait_: |it| it.core_cmp_PartialOrd() },
+    DeriveCompletion { label: "Ord", dependencies: &["PartialOrd", "Eq", "PartialEq"], trait_: |it| it.core_cmp_Ord() },
 ];
 
 pub(crate) struct LintCompletion {
     pub(crate) label: &'static str,
     pub(crate) description: &'static str,
+    /// `allow`, `warn` or `deny`, or `none` for deprecated lints.
+    pub(crate) default_level: &'static str,
+}
+
+pub(crate) struct LintGroupCompletion {
+    pub(crate) label: &'static str,
+    pub(crate) description: &'static str,
+    pub(crate) lints: &'static [&'static str],
+}
+
+/// An unstable feature, with its chapter of the Unstable Book as the description.
+pub(crate) struct FeatureCompletion {
+    pub(crate) label: &'static str,
+    pub(crate) description: &'static str,
+    pub(crate) tracking_issue: Option<&'static str>,
 }
 
 #[rustfmt::skip]
 const DEFAULT_LINT_COMPLETIONS: &[LintCompletion] = &[
-    LintCompletion { label: "absolute_paths_not_starting_with_crate", description: r#"fully qualified paths that start with a module name instead of `crate`, `self`, or an extern crate name"# },
-    LintCompletion { label: "anonymous_parameters", description: r#"detects anonymous parameters"# },
-    LintCompletion { label: "box_pointers", description: r#"use of owned (Box type) heap memory"# },
-    LintCompletion { label: "deprecated_in_future", description: r#"detects use of items that will be deprecated in a future version"# },
-    LintCompletion { label: "elided_lifetimes_in_paths", description: r#"hidden lifetime parameters in types are deprecated"# },
-    LintCompletion { label: "explicit_outlives_requirements", description: r#"outlives requirements can be inferred"# },
-    LintCompletion { label: "indirect_structural_match", description: r#"pattern with const indirectly referencing non-structural-match type"# },
-    LintCompletion { label: "keyword_idents", description: r#"detects edition keywords being used as an identifier"# },
-    LintCompletion { label: "macro_use_extern_crate", description: r#"the `#[macro_use]` attribute is now deprecated in favor of using macros via the module system"# },
-    LintCompletion { label: "meta_variable_misuse", description: r#"possible meta-variable misuse at macro definition"# },
-    LintCompletion { label: "missing_copy_implementations", description: r#"detects potentially-forgotten implementations of `Copy`"# },
-    LintCompletion { label: "missing_crate_level_docs", description: r#"detects crates with no crate-level documentation"# },
-    LintCompletion { label: "missing_debug_implementations", description: r#"detects missing implementations of Debug"# },
-    LintCompletion { label: "missing_docs", description: r#"detects missing documentation for public members"# },
-    LintCompletion { label: "missing_doc_code_examples", description: r#"detects publicly-exported items without code samples in their documentation"# },
-    LintCompletion { label: "non_ascii_idents", description: r#"detects non-ASCII identifiers"# },
-    LintCompletion { label: "private_doc_tests", description: r#"detects code samples in docs of private items not documented by rustdoc"# },
-    LintCompletion { label: "single_use_lifetimes", description: r#"detects lifetime parameters that are only used once"# },
-    LintCompletion { label: "trivial_casts", description: r#"detects trivial casts which could be removed"# },
-    LintCompletion { label: "trivial_numeric_casts", description: r#"detects trivial casts of numeric types which could be removed"# },
-    LintCompletion { label: "unaligned_references", description: r#"detects unaligned references to fields of packed structs"# },
-    LintCompletion { label: "unreachable_pub", description: r#"`pub` items not reachable from crate root"# },
-    LintCompletion { label: "unsafe_code", description: r#"usage of `unsafe` code"# },
-    LintCompletion { label: "unsafe_op_in_unsafe_fn", description: r#"unsafe operations in unsafe functions without an explicit unsafe block are deprecated"# },
-    LintCompletion { label: "unstable_features", description: r#"enabling unstable features (deprecated. do not use)"# },
-    LintCompletion { label: "unused_crate_dependencies", description: r#"crate dependencies that are never used"# },
-    LintCompletion { label: "unused_extern_crates", description: r#"extern crates that are never used"# },
-    LintCompletion { label: "unused_import_braces", description: r#"unnecessary braces around an imported item"# },
-    LintCompletion { label: "unused_lifetimes", description: r#"detects lifetime parameters that are never used"# },
-    LintCompletion { label: "unused_qualifications", description: r#"detects unnecessarily qualified names"# },
-    LintCompletion { label: "unused_results", description: r#"unused result of an expression in a statement"# },
-    LintCompletion { label: "variant_size_differences", description: r#"detects enums with widely varying variant sizes"# },
-    LintCompletion { label: "array_into_iter", description: r#"detects calling `into_iter` on arrays"# },
-    LintCompletion { label: "asm_sub_register", description: r#"using only a subset of a register for inline asm inputs"# },
-    LintCompletion { label: "bare_trait_objects", description: r#"suggest using `dyn Trait` for trait objects"# },
-    LintCompletion { label: "bindings_with_variant_name", description: r#"detects pattern bindings with the same name as one of the matched variants"# },
-    LintCompletion { label: "cenum_impl_drop_cast", description: r#"a C-like enum implementing Drop is cast"# },
-    LintCompletion { label: "clashing_extern_declarations", description: r#"detects when an extern fn has been declared with the same name but different types"# },
-    LintCompletion { label: "coherence_leak_check", description: r#"distinct impls distinguished only by the leak-check code"# },
-    LintCompletion { label: "confusable_idents", description: r#"detects visually confusable pairs between identifiers"# },
-    LintCompletion { label: "dead_code", description: r#"detect unused, unexported items"# },
-    LintCompletion { label: "deprecated", description: r#"detects use of deprecated items"# },
-    LintCompletion { label: "ellipsis_inclusive_range_patterns", description: r#"`...` range patterns are deprecated"# },
-    LintCompletion { label: "exported_private_dependencies", description: r#"public interface leaks type from a private dependency"# },
-    LintCompletion { label: "illegal_floating_point_literal_pattern", description: r#"floating-point literals cannot be used in patterns"# },
-    LintCompletion { label: "improper_ctypes", description: r#"proper use of libc types in foreign modules"# },
-    LintCompletion { label: "improper_ctypes_definitions", description: r#"proper use of libc types in foreign item definitions"# },
-    LintCompletion { label: "incomplete_features", description: r#"incomplete features that may function improperly in some or all cases"# },
-    LintCompletion { label: "inline_no_sanitize", description: r#"detects incompatible use of `#[inline(always)]` and `#[no_sanitize(...)]`"# },
-    LintCompletion { label: "intra_doc_link_resolution_failure", description: r#"failures in resolving intra-doc link targets"# },
-    LintCompletion { label: "invalid_codeblock_attributes", description: r#"codeblock attribute looks a lot like a known one"# },
-    LintCompletion { label: "invalid_value", description: r#"an invalid value is being created (such as a NULL reference)"# },
-    LintCompletion { label: "irrefutable_let_patterns", description: r#"detects irrefutable patterns in if-let and while-let statements"# },
-    LintCompletion { label: "late_bound_lifetime_arguments", description: r#"detects generic lifetime arguments in path segments with late bound lifetime parameters"# },
-    LintCompletion { label: "mixed_script_confusables", description: r#"detects Unicode scripts whose mixed script confusables codepoints are solely used"# },
-    LintCompletion { label: "mutable_borrow_reservation_conflict", description: r#"reservation of a two-phased borrow conflicts with other shared borrows"# },
-    LintCompletion { label: "non_camel_case_types", description: r#"types, variants, traits and type parameters should have camel case names"# },
-    LintCompletion { label: "non_shorthand_field_patterns", description: r#"using `Struct { x: x }` instead of `Struct { x }` in a pattern"# },
-    LintCompletion { label: "non_snake_case", description: r#"variables, methods, functions, lifetime parameters and modules should have snake case names"# },
-    LintCompletion { label: "non_upper_case_globals", description: r#"static constants should have uppercase identifiers"# },
-    LintCompletion { label: "no_mangle_generic_items", description: r#"generic items must be mangled"# },
-    LintCompletion { label: "overlapping_patterns", description: r#"detects overlapping patterns"# },
-    LintCompletion { label: "path_statements", description: r#"path statements with no effect"# },
-    LintCompletion { label: "private_in_public", description: r#"detect private items in public interfaces not caught by the old implementation"# },
-    LintCompletion { label: "proc_macro_derive_resolution_fallback", description: r#"detects proc macro derives using inaccessible names from parent modules"# },
-    LintCompletion { label: "redundant_semicolons", description: r#"detects unnecessary trailing semicolons"# },
-    LintCompletion { label: "renamed_and_removed_lints", description: r#"lints that have been renamed or removed"# },
-    LintCompletion { label: "safe_packed_borrows", description: r#"safe borrows of fields of packed structs were erroneously allowed"# },
-    LintCompletion { label: "stable_features", description: r#"stable features found in `#[feature]` directive"# },
-    LintCompletion { label: "trivial_bounds", description: r#"these bounds don't depend on an type parameters"# },
-    LintCompletion { label: "type_alias_bounds", description: r#"bounds in type aliases are not enforced"# },
-    LintCompletion { label: "tyvar_behind_raw_pointer", description: r#"raw pointer to an inference variable"# },
-    LintCompletion { label: "uncommon_codepoints", description: r#"detects uncommon Unicode codepoints in identifiers"# },
-    LintCompletion { label: "unconditional_recursion", description: r#"functions that cannot return without calling themselves"# },
-    LintCompletion { label: "unknown_lints", description: r#"unrecognized lint attribute"# },
-    LintCompletion { label: "unnameable_test_items", description: r#"detects an item that cannot be named being marked as `#[test_case]`"# },
-    LintCompletion { label: "unreachable_code", description: r#"detects unreachable code paths"# },
-    LintCompletion { label: "unreachable_patterns", description: r#"detects unreachable patterns"# },
-    LintCompletion { label: "unstable_name_collisions", description: r#"detects name collision with an existing but unstable method"# },
-    LintCompletion { label: "unused_allocation", description: r#"detects unnecessary allocations that can be eliminated"# },
-    LintCompletion { label: "unused_assignments", description: r#"detect assignments that will never be read"# },
-    LintCompletion { label: "unused_attributes", description: r#"detects attributes that were not used by the compiler"# },
-    LintCompletion { label: "unused_braces", description: r#"unnecessary braces around an expression"# },
-    LintCompletion { label: "unused_comparisons", description: r#"comparisons made useless by limits of the types involved"# },
-    LintCompletion { label: "unused_doc_comments", description: r#"detects doc comments that aren't used by rustdoc"# },
-    LintCompletion { label: "unused_features", description: r#"unused features found in crate-level `#[feature]` directives"# },
-    LintCompletion { label: "unused_imports", description: r#"imports that are never used"# },
-    LintCompletion { label: "unused_labels", description: r#"detects labels that are never used"# },
-    LintCompletion { label: "unused_macros", description: r#"detects macros that were not used"# },
-    LintCompletion { label: "unused_must_use", description: r#"unused result of a type flagged as `#[must_use]`"# },
-    LintCompletion { label: "unused_mut", description: r#"detect mut variables which don't need to be mutable"# },
-    LintCompletion { label: "unused_parens", description: r#"`if`, `match`, `while` and `return` do not need parentheses"# },
-    LintCompletion { label: "unused_unsafe", description: r#"unnecessary use of an `unsafe` block"# },
-    LintCompletion { label: "unused_variables", description: r#"detect variables which are not used in any way"# },
-    LintCompletion { label: "warnings", description: r#"mass-change the level for lints which produce warnings"# },
-    LintCompletion { label: "where_clauses_object_safety", description: r#"checks the object safety of where clauses"# },
-    LintCompletion { label: "while_true", description: r#"suggest using `loop { }` instead of `while true { }`"# },
-    LintCompletion { label: "ambiguous_associated_items", description: r#"ambiguous associated items"# },
-    LintCompletion { label: "arithmetic_overflow", description: r#"arithmetic operation overflows"# },
-    LintCompletion { label: "conflicting_repr_hints", description: r#"conflicts between `#[repr(..)]` hints that were previously accepted and used in practice"# },
-    LintCompletion { label: "const_err", description: r#"constant evaluation detected erroneous expression"# },
-    LintCompletion { label: "ill_formed_attribute_input", description: r#"ill-formed attribute inputs that were previously accepted and used in practice"# },
-    LintCompletion { label: "incomplete_include", description: r#"trailing content in included file"# },
-    LintCompletion { label: "invalid_type_param_default", description: r#"type parameter default erroneously allowed in invalid location"# },
-    LintCompletion { label: "macro_expanded_macro_exports_accessed_by_absolute_paths", description: r#"macro-expanded `macro_export` macros from the current crate cannot be referred to by absolute paths"# },
-    LintCompletion { label: "missing_fragment_specifier", description: r#"detects missing fragment specifiers in unused `macro_rules!` patterns"# },
-    LintCompletion { label: "mutable_transmutes", description: r#"mutating transmuted &mut T from &T may cause undefined behavior"# },
-    LintCompletion { label: "no_mangle_const_items", description: r#"const items will not have their symbols exported"# },
-    LintCompletion { label: "order_dependent_trait_objects", description: r#"trait-object types were treated as different depending on marker-trait order"# },
-    LintCompletion { label: "overflowing_literals", description: r#"literal out of range for its type"# },
-    LintCompletion { label: "patterns_in_fns_without_body", description: r#"patterns in functions without body were erroneously allowed"# },
-    LintCompletion { label: "pub_use_of_private_extern_crate", description: r#"detect public re-exports of private extern crates"# },
-    LintCompletion { label: "soft_unstable", description: r#"a feature gate that doesn't break dependent crates"# },
-    LintCompletion { label: "unconditional_panic", description: r#"operation will cause a panic at runtime"# },
-    LintCompletion { label: "unknown_crate_types", description: r#"unknown crate type found in `#[crate_type]` directive"# },
+    LintCompletion { label: "absolute_paths_not_starting_with_crate", description: r#"fully qualified paths that start with a module name instead of `crate`, `self`, or an extern crate name"#, default_level: "allow" },
+    LintCompletion { label: "anonymous_parameters", description: r#"detects anonymous parameters"#, default_level: "allow" },
+    LintCompletion { label: "box_pointers", description: r#"use of owned (Box type) heap memory"#, default_level: "allow" },
+    LintCompletion { label: "deprecated_in_future", description: r#"detects use of items that will be deprecated in a future version"#, default_level: "allow" },
+    LintCompletion { label: "elided_lifetimes_in_paths", description: r#"hidden lifetime parameters in types are deprecated"#, default_level: "allow" },
+    LintCompletion { label: "explicit_outlives_requirements", description: r#"outlives requirements can be inferred"#, default_level: "allow" },
+    LintCompletion { label: "indirect_structural_match", description: r#"pattern with const indirectly referencing non-structural-match type"#, default_level: "allow" },
+    LintCompletion { label: "keyword_idents", description: r#"detects edition keywords being used as an identifier"#, default_level: "allow" },
+    LintCompletion { label: "macro_use_extern_crate", description: r#"the `#[macro_use]` attribute is now deprecated in favor of using macros via the module system"#, default_level: "allow" },
+    LintCompletion { label: "meta_variable_misuse", description: r#"possible meta-variable misuse at macro definition"#, default_level: "allow" },
+    LintCompletion { label: "missing_copy_implementations", description: r#"detects potentially-forgotten implementations of `Copy`"#, default_level: "allow" },
+    LintCompletion { label: "missing_debug_implementations", description: r#"detects missing implementations of Debug"#, default_level: "allow" },
+    LintCompletion { label: "missing_docs", description: r#"detects missing documentation for public members"#, default_level: "allow" },
+    LintCompletion { label: "non_ascii_idents", description: r#"detects non-ASCII identifiers"#, default_level: "allow" },
+    LintCompletion { label: "single_use_lifetimes", description: r#"detects lifetime parameters that are only used once"#, default_level: "allow" },
+    LintCompletion { label: "trivial_casts", description: r#"detects trivial casts which could be removed"#, default_level: "allow" },
+    LintCompletion { label: "trivial_numeric_casts", description: r#"detects trivial casts of numeric types which could be removed"#, default_level: "allow" },
+    LintCompletion { label: "unaligned_references", description: r#"detects unaligned references to fields of packed structs"#, default_level: "allow" },
+    LintCompletion { label: "unreachable_pub", description: r#"`pub` items not reachable from crate root"#, default_level: "allow" },
+    LintCompletion { label: "unsafe_code", description: r#"usage of `unsafe` code"#, default_level: "allow" },
+    LintCompletion { label: "unsafe_op_in_unsafe_fn", description: r#"unsafe operations in unsafe functions without an explicit unsafe block are deprecated"#, default_level: "allow" },
+    LintCompletion { label: "unstable_features", description: r#"enabling unstable features (deprecated. do not use)"#, default_level: "allow" },
+    LintCompletion { label: "unused_crate_dependencies", description: r#"crate dependencies that are never used"#, default_level: "allow" },
+    LintCompletion { label: "unused_extern_crates", description: r#"extern crates that are never used"#, default_level: "allow" },
+    LintCompletion { label: "unused_import_braces", description: r#"unnecessary braces around an imported item"#, default_level: "allow" },
+    LintCompletion { label: "unused_lifetimes", description: r#"detects lifetime parameters that are never used"#, default_level: "allow" },
+    LintCompletion { label: "unused_qualifications", description: r#"detects unnecessarily qualified names"#, default_level: "allow" },
+    LintCompletion { label: "unused_results", description: r#"unused result of an expression in a statement"#, default_level: "allow" },
+    LintCompletion { label: "variant_size_differences", description: r#"detects enums with widely varying variant sizes"#, default_level: "allow" },
+    LintCompletion { label: "array_into_iter", description: r#"detects calling `into_iter` on arrays"#, default_level: "warn" },
+    LintCompletion { label: "asm_sub_register", description: r#"using only a subset of a register for inline asm inputs"#, default_level: "warn" },
+    LintCompletion { label: "bare_trait_objects", description: r#"suggest using `dyn Trait` for trait objects"#, default_level: "warn" },
+    LintCompletion { label: "bindings_with_variant_name", description: r#"detects pattern bindings with the same name as one of the matched variants"#, default_level: "warn" },
+    LintCompletion { label: "cenum_impl_drop_cast", description: r#"a C-like enum implementing Drop is cast"#, default_level: "warn" },
+    LintCompletion { label: "clashing_extern_declarations", description: r#"detects when an extern fn has been declared with the same name but different types"#, default_level: "warn" },
+    LintCompletion { label: "coherence_leak_check", description: r#"distinct impls distinguished only by the leak-check code"#, default_level: "warn" },
+    LintCompletion { label: "confusable_idents", description: r#"detects visually confusable pairs between identifiers"#, default_level: "warn" },
+    LintCompletion { label: "dead_code", description: r#"detect unused, unexported items"#, default_level: "warn" },
+    LintCompletion { label: "deprecated", description: r#"detects use of deprecated items"#, default_level: "warn" },
+    LintCompletion { label: "ellipsis_inclusive_range_patterns", description: r#"`...` range patterns are deprecated"#, default_level: "warn" },
+    LintCompletion { label: "exported_private_dependencies", description: r#"public interface leaks type from a private dependency"#, default_level: "warn" },
+    LintCompletion { label: "illegal_floating_point_literal_pattern", description: r#"floating-point literals cannot be used in patterns"#, default_level: "warn" },
+    LintCompletion { label: "improper_ctypes", description: r#"proper use of libc types in foreign modules"#, default_level: "warn" },
+    LintCompletion { label: "improper_ctypes_definitions", description: r#"proper use of libc types in foreign item definitions"#, default_level: "warn" },
+    LintCompletion { label: "incomplete_features", description: r#"incomplete features that may function improperly in some or all cases"#, default_level: "warn" },
+    LintCompletion { label: "inline_no_sanitize", description: r#"detects incompatible use of `#[inline(always)]` and `#[no_sanitize(...)]`"#, default_level: "warn" },
+    LintCompletion { label: "invalid_value", description: r#"an invalid value is being created (such as a NULL reference)"#, default_level: "warn" },
+    LintCompletion { label: "irrefutable_let_patterns", description: r#"detects irrefutable patterns in if-let and while-let statements"#, default_level: "warn" },
+    LintCompletion { label: "late_bound_lifetime_arguments", description: r#"detects generic lifetime arguments in path segments with late bound lifetime parameters"#, default_level: "warn" },
+    LintCompletion { label: "mixed_script_confusables", description: r#"detects Unicode scripts whose mixed script confusables codepoints are solely used"#, default_level: "warn" },
+    LintCompletion { label: "mutable_borrow_reservation_conflict", description: r#"reservation of a two-phased borrow conflicts with other shared borrows"#, default_level: "warn" },
+    LintCompletion { label: "non_camel_case_types", description: r#"types, variants, traits and type parameters should have camel case names"#, default_level: "warn" },
+    LintCompletion { label: "non_shorthand_field_patterns", description: r#"using `Struct { x: x }` instead of `Struct { x }` in a pattern"#, default_level: "warn" },
+    LintCompletion { label: "non_snake_case", description: r#"variables, methods, functions, lifetime parameters and modules should have snake case names"#, default_level: "warn" },
+    LintCompletion { label: "non_upper_case_globals", description: r#"static constants should have uppercase identifiers"#, default_level: "warn" },
+    LintCompletion { label: "no_mangle_generic_items", description: r#"generic items must be mangled"#, default_level: "warn" },
+    LintCompletion { label: "overlapping_patterns", description: r#"detects overlapping patterns"#, default_level: "warn" },
+    LintCompletion { label: "path_statements", description: r#"path statements with no effect"#, default_level: "warn" },
+    LintCompletion { label: "private_in_public", description: r#"detect private items in public interfaces not caught by the old implementation"#, default_level: "warn" },
+    LintCompletion { label: "proc_macro_derive_resolution_fallback", description: r#"detects proc macro derives using inaccessible names from parent modules"#, default_level: "warn" },
+    LintCompletion { label: "redundant_semicolons", description: r#"detects unnecessary trailing semicolons"#, default_level: "warn" },
+    LintCompletion { label: "renamed_and_removed_lints", description: r#"lints that have been renamed or removed"#, default_level: "warn" },
+    LintCompletion { label: "safe_packed_borrows", description: r#"safe borrows of fields of packed structs were erroneously allowed"#, default_level: "warn" },
+    LintCompletion { label: "stable_features", description: r#"stable features found in `#[feature]` directive"#, default_level: "warn" },
+    LintCompletion { label: "trivial_bounds", description: r#"these bounds don't depend on an type parameters"#, default_level: "warn" },
+    LintCompletion { label: "type_alias_bounds", description: r#"bounds in type aliases are not enforced"#, default_level: "warn" },
+    LintCompletion { label: "tyvar_behind_raw_pointer", description: r#"raw pointer to an inference variable"#, default_level: "warn" },
+    LintCompletion { label: "uncommon_codepoints", description: r#"detects uncommon Unicode codepoints in identifiers"#, default_level: "warn" },
+    LintCompletion { label: "unconditional_recursion", description: r#"functions that cannot return without calling themselves"#, default_level: "warn" },
+    LintCompletion { label: "unknown_lints", description: r#"unrecognized lint attribute"#, default_level: "warn" },
+    LintCompletion { label: "unnameable_test_items", description: r#"detects an item that cannot be named being marked as `#[test_case]`"#, default_level: "warn" },
+    LintCompletion { label: "unreachable_code", description: r#"detects unreachable code paths"#, default_level: "warn" },
+    LintCompletion { label: "unreachable_patterns", description: r#"detects unreachable patterns"#, default_level: "warn" },
+    LintCompletion { label: "unstable_name_collisions", description: r#"detects name collision with an existing but unstable method"#, default_level: "warn" },
+    LintCompletion { label: "unused_allocation", description: r#"detects unnecessary allocations that can be eliminated"#, default_level: "warn" },
+    LintCompletion { label: "unused_assignments", description: r#"detect assignments that will never be read"#, default_level: "warn" },
+    LintCompletion { label: "unused_attributes", description: r#"detects attributes that were not used by the compiler"#, default_level: "warn" },
+    LintCompletion { label: "unused_braces", description: r#"unnecessary braces around an expression"#, default_level: "warn" },
+    LintCompletion { label: "unused_comparisons", description: r#"comparisons made useless by limits of the types involved"#, default_level: "warn" },
+    LintCompletion { label: "unused_doc_comments", description: r#"detects doc comments that aren't used by rustdoc"#, default_level: "warn" },
+    LintCompletion { label: "unused_features", description: r#"unused features found in crate-level `#[feature]` directives"#, default_level: "warn" },
+    LintCompletion { label: "unused_imports", description: r#"imports that are never used"#, default_level: "warn" },
+    LintCompletion { label: "unused_labels", description: r#"detects labels that are never used"#, default_level: "warn" },
+    LintCompletion { label: "unused_macros", description: r#"detects macros that were not used"#, default_level: "warn" },
+    LintCompletion { label: "unused_must_use", description: r#"unused result of a type flagged as `#[must_use]`"#, default_level: "warn" },
+    LintCompletion { label: "unused_mut", description: r#"detect mut variables which don't need to be mutable"#, default_level: "warn" },
+    LintCompletion { label: "unused_parens", description: r#"`if`, `match`, `while` and `return` do not need parentheses"#, default_level: "warn" },
+    LintCompletion { label: "unused_unsafe", description: r#"unnecessary use of an `unsafe` block"#, default_level: "warn" },
+    LintCompletion { label: "unused_variables", description: r#"detect variables which are not used in any way"#, default_level: "warn" },
+    LintCompletion { label: "warnings", description: r#"mass-change the level for lints which produce warnings"#, default_level: "warn" },
+    LintCompletion { label: "where_clauses_object_safety", description: r#"checks the object safety of where clauses"#, default_level: "warn" },
+    LintCompletion { label: "while_true", description: r#"suggest using `loop { }` instead of `while true { }`"#, default_level: "warn" },
+    LintCompletion { label: "ambiguous_associated_items", description: r#"ambiguous associated items"#, default_level: "deny" },
+    LintCompletion { label: "arithmetic_overflow", description: r#"arithmetic operation overflows"#, default_level: "deny" },
+    LintCompletion { label: "conflicting_repr_hints", description: r#"conflicts between `#[repr(..)]` hints that were previously accepted and used in practice"#, default_level: "deny" },
+    LintCompletion { label: "const_err", description: r#"constant evaluation detected erroneous expression"#, default_level: "deny" },
+    LintCompletion { label: "ill_formed_attribute_input", description: r#"ill-formed attribute inputs that were previously accepted and used in practice"#, default_level: "deny" },
+    LintCompletion { label: "incomplete_include", description: r#"trailing content in included file"#, default_level: "deny" },
+    LintCompletion { label: "invalid_type_param_default", description: r#"type parameter default erroneously allowed in invalid location"#, default_level: "deny" },
+    LintCompletion { label: "macro_expanded_macro_exports_accessed_by_absolute_paths", description: r#"macro-expanded `macro_export` macros from the current crate cannot be referred to by absolute paths"#, default_level: "deny" },
+    LintCompletion { label: "missing_fragment_specifier", description: r#"detects missing fragment specifiers in unused `macro_rules!` patterns"#, default_level: "deny" },
+    LintCompletion { label: "mutable_transmutes", description: r#"mutating transmuted &mut T from &T may cause undefined behavior"#, default_level: "deny" },
+    LintCompletion { label: "no_mangle_const_items", description: r#"const items will not have their symbols exported"#, default_level: "deny" },
+    LintCompletion { label: "order_dependent_trait_objects", description: r#"trait-object types were treated as different depending on marker-trait order"#, default_level: "deny" },
+    LintCompletion { label: "overflowing_literals", description: r#"literal out of range for its type"#, default_level: "deny" },
+    LintCompletion { label: "patterns_in_fns_without_body", description: r#"patterns in functions without body were erroneously allowed"#, default_level: "deny" },
+    LintCompletion { label: "pub_use_of_private_extern_crate", description: r#"detect public re-exports of private extern crates"#, default_level: "deny" },
+    LintCompletion { label: "soft_unstable", description: r#"a feature gate that doesn't break dependent crates"#, default_level: "deny" },
+    LintCompletion { label: "unconditional_panic", description: r#"operation will cause a panic at runtime"#, default_level: "deny" },
+    LintCompletion { label: "unknown_crate_types", description: r#"unknown crate type found in `#[crate_type]` directive"#, default_level: "deny" },
+];
+
+/// The lints of rustdoc, which are only emitted when documenting the crate.
+#[rustfmt::skip]
+const RUSTDOC_LINT_COMPLETIONS: &[LintCompletion] = &[
+    LintCompletion { label: "broken_intra_doc_links", description: r#"failures in resolving intra-doc link targets"#, default_level: "warn" },
+    LintCompletion { label: "intra_doc_link_resolution_failure", description: r#"failures in resolving intra-doc link targets"#, default_level: "warn" },
+    LintCompletion { label: "invalid_codeblock_attributes", description: r#"codeblock attribute looks a lot like a known one"#, default_level: "warn" },
+    LintCompletion { label: "invalid_html_tags", description: r#"detects invalid HTML tags in doc comments"#, default_level: "allow" },
+    LintCompletion { label: "missing_crate_level_docs", description: r#"detects crates with no crate-level documentation"#, default_level: "allow" },
+    LintCompletion { label: "missing_doc_code_examples", description: r#"detects publicly-exported items without code samples in their documentation"#, default_level: "allow" },
+    LintCompletion { label: "non_autolinks", description: r#"detects URLs that could be written using only angle brackets"#, default_level: "warn" },
+    LintCompletion { label: "private_doc_tests", description: r#"detects code samples in docs of private items not documented by rustdoc"#, default_level: "allow" },
+    LintCompletion { label: "private_intra_doc_links", description: r#"linking from a public item to a private one"#, default_level: "warn" },
+];
+
+#[rustfmt::skip]
+const DEFAULT_LINT_GROUPS: &[LintGroupCompletion] = &[
+    LintGroupCompletion { label: "future_incompatible", description: r#"lints that detect code that will become an error in a future release"#, lints: &["ambiguous_associated_items", "cenum_impl_drop_cast", "coherence_leak_check", "conflicting_repr_hints", "ill_formed_attribute_input", "illegal_floating_point_literal_pattern", "indirect_structural_match", "invalid_type_param_default", "late_bound_lifetime_arguments", "macro_expanded_macro_exports_accessed_by_absolute_paths", "missing_fragment_specifier", "mutable_borrow_reservation_conflict", "order_dependent_trait_objects", "patterns_in_fns_without_body", "private_in_public", "proc_macro_derive_resolution_fallback", "pub_use_of_private_extern_crate", "safe_packed_borrows", "soft_unstable", "unstable_name_collisions", "where_clauses_object_safety"] },
+    LintGroupCompletion { label: "nonstandard_style", description: r#"violations of the standard naming conventions"#, lints: &["non_camel_case_types", "non_snake_case", "non_upper_case_globals"] },
+    LintGroupCompletion { label: "rust_2018_compatibility", description: r#"code that will not compile in the 2018 edition"#, lints: &["keyword_idents", "anonymous_parameters", "absolute_paths_not_starting_with_crate", "tyvar_behind_raw_pointer"] },
+    LintGroupCompletion { label: "rust_2018_idioms", description: r#"code that is not idiomatic in the 2018 edition"#, lints: &["bare_trait_objects", "unused_extern_crates", "ellipsis_inclusive_range_patterns", "elided_lifetimes_in_paths", "explicit_outlives_requirements"] },
+    LintGroupCompletion { label: "unused", description: r#"code that is declared or written but never used"#, lints: &["unused_imports", "unused_variables", "unused_assignments", "dead_code", "unused_mut", "unreachable_code", "unreachable_patterns", "overlapping_patterns", "unused_must_use", "unused_unsafe", "path_statements", "unused_attributes", "unused_macros", "unused_allocation", "unused_doc_comments", "unused_extern_crates", "unused_features", "unused_labels", "unused_parens", "unused_braces", "redundant_semicolons"] },
+    LintGroupCompletion { label: "rustdoc", description: r#"the lints of rustdoc"#, lints: &["broken_intra_doc_links", "private_intra_doc_links", "invalid_codeblock_attributes", "missing_doc_code_examples", "private_doc_tests", "invalid_html_tags", "non_autolinks"] },
 ];
 
 #[cfg(test)]
 mod tests {
     use expect_test::{expect, Expect};
 
-    use crate::{test_utils::completion_list, CompletionKind};
+    use crate::{
+        test_utils::{check_edit, completion_list, get_all_items},
+        CompletionConfig, CompletionKind, CompletionScore,
+    };
 
     fn check(ra_fixture: &str, expect: Expect) {
         let actual = completion_list(ra_fixture, CompletionKind::Attribute);
@@ -400,14 +1267,14 @@ struct Test {}
         "#,
             expect![[r#"
                 at Clone
-                at Copy, Clone
+                at Copy, Clone      requires Clone
                 at Debug
                 at Default
-                at Eq, PartialEq
+                at Eq, PartialEq    requires PartialEq
                 at Hash
-                at Ord, PartialOrd, Eq, PartialEq
+                at Ord, PartialOrd, Eq, PartialEq requires PartialOrd + Eq + PartialEq
                 at PartialEq
-                at PartialOrd, PartialEq
+                at PartialOrd, PartialEq requires PartialEq
             "#]],
         );
     }
@@ -432,17 +1299,228 @@ struct Test {}
 "#,
             expect![[r#"
                 at Clone
-                at Copy, Clone
+                at Copy, Clone      requires Clone
                 at Debug
                 at Default
                 at Eq
                 at Hash
-                at Ord, PartialOrd, Eq
+                at Ord, PartialOrd, Eq requires PartialOrd + Eq
                 at PartialOrd
             "#]],
         )
     }
 
+    #[test]
+    fn derive_completion_checks_the_fields() {
+        let core = r#"
+//- /libcore.rs crate:core
+pub mod clone { pub trait Clone {} }
+pub mod marker { pub trait Copy: crate::clone::Clone {} }
+pub mod fmt { pub trait Debug {} }
+pub mod default { pub trait Default {} }
+pub mod hash { pub trait Hash {} }
+pub mod cmp {
+    pub trait PartialEq {}
+    pub trait Eq: PartialEq {}
+    pub trait PartialOrd: PartialEq {}
+    pub trait Ord: Eq + PartialOrd {}
+}
+mod impls {
+    use crate::{clone::*, cmp::*, default::*, fmt::*, hash::*, marker::*};
+    pub struct String;
+    impl Clone for String {} impl Debug for String {} impl Default for String {}
+    impl Hash for String {} impl PartialEq for String {} impl Eq for String {}
+    impl PartialOrd for String {} impl Ord for String {}
+    impl Clone for f64 {} impl Copy for f64 {} impl Debug for f64 {} impl Default for f64 {}
+    impl PartialEq for f64 {} impl PartialOrd for f64 {}
+}
+pub use impls::String;
+"#;
+        check(
+            &format!(
+                r#"
+//- /main.rs crate:main deps:core
+#[derive(<|>)]
+struct Test<T> {{ name: core::String, weight: f64, param: T }}
+{}"#,
+                core
+            ),
+            expect![[r#"
+                at Clone
+                at Debug
+                at Default
+                at Eq, PartialEq    requires PartialEq
+                at Ord, PartialOrd, Eq, PartialEq requires PartialOrd + Eq + PartialEq
+                at PartialEq
+                at PartialOrd, PartialEq requires PartialEq
+            "#]],
+        );
+        check(
+            &format!(
+                r#"
+//- /main.rs crate:main deps:core
+#[derive(<|>)]
+enum Test {{ Weight(f64), Nothing }}
+{}"#,
+                core
+            ),
+            expect![[r#"
+                at Clone
+                at Copy, Clone      requires Clone
+                at Debug
+                at Eq, PartialEq    requires PartialEq
+                at Ord, PartialOrd, Eq, PartialEq requires PartialOrd + Eq + PartialEq
+                at PartialEq
+                at PartialOrd, PartialEq requires PartialEq
+            "#]],
+        );
+
+        let items = get_all_items(
+            CompletionConfig::default(),
+            &format!(
+                "//- /main.rs crate:main deps:core\n#[derive(<|>)]\nstruct Test(f64);\n{}",
+                core
+            ),
+        );
+        let score = |label: &str| items.iter().find(|it| it.label() == label).unwrap().score();
+        assert_eq!(score("Eq, PartialEq"), Some(CompletionScore::Unlikely));
+        assert_eq!(score("PartialEq"), None);
+    }
+
+    #[test]
+    fn completes_derives_of_dependencies() {
+        check(
+            r#"
+//- /main.rs crate:main deps:serde_derive
+use serde_derive::Deserialize;
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy, Default, <|>)]
+struct Test;
+//- /lib.rs crate:serde_derive
+#[proc_macro_derive(Serialize)]
+pub fn serialize(input: TokenStream) -> TokenStream { input }
+#[proc_macro_derive(Deserialize)]
+pub fn deserialize(input: TokenStream) -> TokenStream { input }
+"#,
+            expect![[r#"
+                at Deserialize      serde_derive
+                at Hash
+                at serde_derive::Serialize serde_derive
+            "#]],
+        );
+        check(
+            r#"
+//- /main.rs crate:main deps:serde_derive
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy, Default, Hash, serde_derive::Serialize, <|>)]
+struct Test;
+//- /lib.rs crate:serde_derive
+#[proc_macro_derive(Serialize)]
+pub fn serialize(input: TokenStream) -> TokenStream { input }
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn completes_helpers_of_renamed_derives() {
+        let actual = completion_list(
+            r#"
+//- /main.rs crate:main deps:serde_derive
+use serde_derive::Serialize as Ser;
+#[derive(Ser)]
+struct S { #[<|>] field: u32 }
+//- /lib.rs crate:serde_derive
+#[proc_macro_derive(Serialize, attributes(serde))]
+pub fn serialize(input: TokenStream) -> TokenStream { input }
+"#,
+            CompletionKind::Attribute,
+        );
+        assert!(actual.contains("at serde            helper attribute of Ser"), "{}", actual);
+    }
+
+    #[test]
+    fn completes_derive_helpers() {
+        let helpers = |ra_fixture: &str| {
+            completion_list(ra_fixture, CompletionKind::Attribute)
+                .lines()
+                .filter(|it| it.contains("helper"))
+                .map(|it| format!("{}\n", it.trim()))
+                .collect::<String>()
+        };
+        let serde = r#"
+//- /lib.rs crate:serde_derive
+#[proc_macro_derive(Serialize, attributes(serde, serialize))]
+pub fn serialize(input: TokenStream) -> TokenStream { input }
+"#;
+        expect![[r#"
+            at serde            helper attribute of Serialize
+            at serialize        helper attribute of Serialize
+        "#]]
+        .assert_eq(&helpers(&format!(
+            r#"
+//- /main.rs crate:main deps:serde_derive
+use serde_derive::Serialize;
+#[derive(Serialize)]
+struct S {{ #[<|>] field: u32 }}
+{}"#,
+            serde
+        )));
+        expect![[r#"
+            at serde            helper attribute of serde_derive::Serialize
+            at serialize        helper attribute of serde_derive::Serialize
+        "#]]
+        .assert_eq(&helpers(&format!(
+            r#"
+//- /main.rs crate:main deps:serde_derive
+#[<|>]
+#[derive(Debug, serde_derive::Serialize)]
+enum E {{ V }}
+{}"#,
+            serde
+        )));
+        expect![[""]].assert_eq(&helpers(&format!(
+            r#"
+//- /main.rs crate:main deps:serde_derive
+#[derive(Debug)]
+struct S {{ #[<|>] field: u32 }}
+{}"#,
+            serde
+        )));
+    }
+
+    #[test]
+    fn completes_doc_args_on_items() {
+        check(
+            r#"
+#[doc(hidden, <|>)]
+struct Test {}
+"#,
+            expect![[r#"
+                at alias = "…" adds an alias for the item in the documentation search
+                at cfg(…)      documents the configurations the item is available on
+                at inline      documents the re-exported item inline
+                at no_inline   documents the re-export as a link to the item
+            "#]],
+        );
+        check(r#"#[doc(cfg(<|>))] struct Test {}"#, expect![[""]]);
+    }
+
+    #[test]
+    fn completes_doc_args_at_crate_level() {
+        check(
+            r#"#![doc(<|>)]"#,
+            expect![[r#"
+            at html_favicon_url = "…" the favicon of the documentation
+            at html_logo_url = "…" the logo shown in the documentation
+            at html_no_source   does not include the source code in the documentation
+            at html_playground_url = "…" the playground to run the examples on
+            at html_root_url = "…" the base URL for links to this crate from other crates
+            at issue_tracker_base_url = "…" the base URL of the issues of unstable features
+            at test(…)          controls how the documentation tests are run
+        "#]],
+        );
+        check_edit("html_root_url", r#"#![doc(<|>)]"#, r#"#![doc(html_root_url = "${0:url}")]"#);
+    }
+
     #[test]
     fn test_attribute_completion() {
         check(
@@ -458,7 +1536,7 @@ struct Test {}
                 at forbid(…)
                 at ignore = "…"
                 at inline(…)
-                at link
+                at link(…)
                 at link_name = "…"
                 at macro_export
                 at macro_use
@@ -479,9 +1557,176 @@ struct Test {}
         )
     }
 
+    #[test]
+    fn ffi_attributes_are_restricted_to_their_items() {
+        let ffi_attrs = |ra_fixture: &str| {
+            completion_list(ra_fixture, CompletionKind::Attribute)
+                .lines()
+                .filter(|it| it.contains("link") || it.contains("no_mangle"))
+                .map(|it| format!("{}\n", it.trim()))
+                .collect::<String>()
+        };
+        expect![[r#"
+            at link(…)
+        "#]]
+        .assert_eq(&ffi_attrs(r#"#[<|>] extern "C" {}"#));
+        expect![[r#"
+            at link_name = "…"
+        "#]]
+        .assert_eq(&ffi_attrs(r#"extern "C" { #[<|>] fn foo(); }"#));
+        expect![[r#"
+            at no_mangle
+        "#]]
+        .assert_eq(&ffi_attrs(r#"#[<|>] pub extern "C" fn foo() {}"#));
+        expect![[""]].assert_eq(&ffi_attrs(r#"#[<|>] struct S;"#));
+    }
+
+    #[test]
+    fn completes_link_args() {
+        check(
+            r#"#[link(name = "foo", <|>)] extern "C" {}"#,
+            expect![[r#"
+            at kind = "dylib"   links the library dynamically
+            at kind = "static"  links the library statically
+            at wasm_import_module = "…" the WebAssembly module to import the items from
+        "#]],
+        );
+        check_edit(
+            "wasm_import_module",
+            r#"#[link(<|>)] extern "C" {}"#,
+            r#"#[link(wasm_import_module = "${0:module}")] extern "C" {}"#,
+        );
+    }
+
+    #[test]
+    fn completes_must_use_reasons() {
+        check(
+            r#"#[must_use = "<|>"] fn f() -> u32 { 0 }"#,
+            expect![[r#"
+                at futures do nothing unless you `.await` or poll them on futures
+                at iterators are lazy and do nothing unless consumed on iterators
+                at this `Result` may be an `Err` variant, which should be handled on results
+                at this returns the result of the operation, without modifying the original on methods returning a new value
+            "#]],
+        );
+        check_edit(
+            "iterators are lazy and do nothing unless consumed",
+            r#"#[must_use = "it<|>"] struct Iter;"#,
+            r#"#[must_use = "iterators are lazy and do nothing unless consumed"] struct Iter;"#,
+        );
+        check(r#"#[deprecated = "<|>"] fn f() {}"#, expect![[""]]);
+    }
+
+    #[test]
+    fn completes_args_of_builtin_attrs() {
+        check(
+            r#"#[deprecated(since = "1.0", <|>)] fn f() {}"#,
+            expect![[r#"
+                at note = "…" the reason for the deprecation, and what to use instead
+            "#]],
+        );
+        check(
+            r#"#[inline(<|>)] fn f() {}"#,
+            expect![[r#"
+                at always inlines the function at every call site
+                at never  never inlines the function
+            "#]],
+        );
+        check(r#"#[inline(always, <|>)] fn f() {}"#, expect![[""]]);
+        check(r#"#[repr(align(<|>))] struct S;"#, expect![[""]]);
+        check_edit(
+            "align",
+            r#"#[repr(C, <|>)] struct S;"#,
+            r#"#[repr(C, align(${0:8}))] struct S;"#,
+        );
+    }
+
+    #[test]
+    fn completes_reprs_for_the_annotated_type() {
+        check(
+            r#"#[repr(<|>)] struct S;"#,
+            expect![[r#"
+                at C           lays the type out like C does
+                at align(…)    raises the alignment of the type
+                at packed      removes the padding between the fields
+                at transparent lays the type out like its single non-zero-sized field
+            "#]],
+        );
+        check(
+            r#"#[repr(<|>)] union U { a: u8 }"#,
+            expect![[r#"
+                at C        lays the type out like C does
+                at align(…) raises the alignment of the type
+                at packed   removes the padding between the fields
+            "#]],
+        );
+        check(
+            r#"#[repr(C, <|>)] enum E { A }"#,
+            expect![[r#"
+                at i16   stores the discriminant of the enum as `i16`
+                at i32   stores the discriminant of the enum as `i32`
+                at i64   stores the discriminant of the enum as `i64`
+                at i8    stores the discriminant of the enum as `i8`
+                at isize stores the discriminant of the enum as `isize`
+                at u16   stores the discriminant of the enum as `u16`
+                at u32   stores the discriminant of the enum as `u32`
+                at u64   stores the discriminant of the enum as `u64`
+                at u8    stores the discriminant of the enum as `u8`
+                at usize stores the discriminant of the enum as `usize`
+            "#]],
+        );
+        check(r#"#[repr(transparent, <|>)] struct S(u8);"#, expect![[""]]);
+    }
+
+    #[test]
+    fn completes_unstable_features() {
+        check(
+            r#"#![feature(non_ascii_idents, trace<|>)]"#,
+            expect![[r#"
+                at trace_macros https://github.com/rust-lang/rust/issues/29598
+            "#]],
+        );
+        check(
+            r#"#![feature(non_ascii_i<|>)]"#,
+            expect![[r#"
+                at non_ascii_idents https://github.com/rust-lang/rust/issues/55467
+            "#]],
+        );
+        check(r#"#![feature(non_ascii_idents, non_ascii_i<|>)]"#, expect![[""]]);
+    }
+
     #[test]
     fn test_attribute_completion_inside_nested_attr() {
-        check(r#"#[cfg(<|>)]"#, expect![[]])
+        check(
+            r#"#[cfg(<|>)]"#,
+            expect![[r#"
+                at all(…)           holds if all the predicates hold
+                at any(…)           holds if any of the predicates holds
+                at debug_assertions debug assertions are enabled
+                at doc              the crate is being documented by rustdoc
+                at feature = "…"    a Cargo feature enabled for the crate
+                at not(…)           holds if the predicate does not hold
+                at proc_macro       the crate is compiled as a procedural macro
+                at target_arch = "…" the CPU architecture of the target
+                at target_endian = "…" the endianness of the target CPU
+                at target_env = "…" the ABI or libc used by the target
+                at target_family = "…" the family of the operating system of the target
+                at target_feature = "…" a CPU feature enabled for the target
+                at target_os = "…"  the operating system of the target
+                at target_pointer_width = "…" the pointer width of the target in bits
+                at target_vendor = "…" the vendor of the target
+                at test             the crate is compiled as a test harness
+                at unix             the target family is `unix`
+                at windows          the target family is `windows`
+            "#]],
+        )
+    }
+
+    #[test]
+    fn no_attribute_completion_in_attribute_value() {
+        check(r#"#[doc = "fo<|>"] fn f() {}"#, expect![[]]);
+        check(r##"#[doc = r#"fo<|>"#] fn f() {}"##, expect![[]]);
+        check(r#"#[path = b"fo<|>"] mod f;"#, expect![[]]);
     }
 
     #[test]
@@ -501,12 +1746,9 @@ struct Test {}
                 at global_allocator
                 at ignore = "…"
                 at inline(…)
-                at link
-                at link_name = "…"
                 at macro_export
                 at macro_use
                 at must_use = "…"
-                at no_mangle
                 at no_std
                 at non_exhaustive
                 at panic_handler
@@ -525,4 +1767,309 @@ struct Test {}
             "#]],
         );
     }
+
+    #[test]
+    fn completes_lints_matching_typed_prefix() {
+        check(
+            r#"#[allow(unused_qualifications, unused_i<|>)] struct Test;"#,
+            expect![[r#"
+            at unused_import_braces unnecessary braces around an imported item
+            at unused_imports   imports that are never used
+        "#]],
+        );
+    }
+
+    #[test]
+    fn completes_clippy_lints_matching_typed_prefix() {
+        check(
+            r#"#[deny(clippy::needless_bo<|>)] fn f() {}"#,
+            expect![[r#"
+            at clippy::needless_bool Checks for expressions of the form `if c { true } else {\nfalse }` (or vice versa) and suggests using the condition directly.
+            at clippy::needless_borrow Checks for address of operations (`&`) that are going to\nbe dereferenced immediately by the compiler.
+            at clippy::needless_borrowed_reference Checks for useless borrowed references.
+        "#]],
+        );
+    }
+
+    #[test]
+    fn completes_lint_groups_and_rustdoc_lints() {
+        check(
+            r#"#[warn(clippy::pe<|>)] fn f() {}"#,
+            expect![[r#"
+                at clippy::pedantic lints which are rather strict or might have false positives
+                at clippy::perf     code that can be written to run faster
+            "#]],
+        );
+        check(
+            r#"#[expect(rust_2018_idioms, rust_2018<|>)] fn f() {}"#,
+            expect![[r#"
+                at rust_2018_compatibility code that will not compile in the 2018 edition
+            "#]],
+        );
+        check(
+            r#"#![deny(broken_intra<|>)]"#,
+            expect![[r#"
+                at broken_intra_doc_links failures in resolving intra-doc link targets
+            "#]],
+        );
+    }
+
+    #[test]
+    fn lint_documentation_lists_level_and_groups() {
+        let lint = super::CLIPPY_LINTS.iter().find(|it| it.label == "clippy::needless_bool");
+        let docs = super::lint_documentation(lint.unwrap());
+        let first_line = docs.as_str().lines().next().unwrap();
+        assert_eq!(
+            first_line,
+            "Default level: `warn`. Lint groups: `clippy::all`, `clippy::complexity`."
+        );
+        let lint = super::DEFAULT_LINT_COMPLETIONS.iter().find(|it| it.label == "unused_mut");
+        let docs = super::lint_documentation(lint.unwrap());
+        assert_eq!(
+            docs.as_str(),
+            "Default level: `warn`. Lint groups: `unused`.\n\ndetect mut variables which don't need to be mutable"
+        );
+    }
+
+    #[test]
+    fn lint_table_lookup_by_prefix() {
+        let labels: Vec<_> =
+            super::FEATURE_TABLE.with_prefix("non_ascii_").map(|it| it.label).collect();
+        assert_eq!(labels, ["non_ascii_idents"]);
+        let labels: Vec<_> = super::CLIPPY_LINT_TABLE
+            .with_prefix("clippy::needless_bo")
+            .map(|it| it.label)
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                "clippy::needless_bool",
+                "clippy::needless_borrow",
+                "clippy::needless_borrowed_reference"
+            ]
+        );
+        assert_eq!(super::CLIPPY_LINT_TABLE.with_prefix("clippy::xyz").count(), 0);
+        assert_eq!(super::CLIPPY_LINT_TABLE.with_prefix("").count(), super::CLIPPY_LINTS.len());
+    }
+
+    #[test]
+    fn completes_declared_features_in_cfg() {
+        check(
+            r#"
+//- /lib.rs crate:foo cfg:feature=std,feature=serde
+#[cfg(all(unix, feature = "<|>"))]
+fn f() {}
+"#,
+            expect![[r#"
+                at serde feature
+                at std   feature
+            "#]],
+        );
+        check(
+            r#"
+//- /lib.rs crate:foo cfg:feature=std
+#[cfg_attr(feature = "s<|>", derive(Debug))]
+struct S;
+"#,
+            expect![[r#"
+                at std feature
+            "#]],
+        );
+        check(
+            r#"
+//- /lib.rs crate:foo cfg:feature=std
+fn f() -> bool { cfg!(feature = "<|>") }
+"#,
+            expect![[r#"
+                at std feature
+            "#]],
+        );
+        check(
+            r#"
+//- /lib.rs crate:foo cfg:feature=std
+#[doc(alias = "<|>")]
+fn f() {}
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn completes_cfg_keys() {
+        check(
+            r#"#[cfg(all(unix, t<|>))] fn f() {}"#,
+            expect![[r#"
+                at all(…)           holds if all the predicates hold
+                at any(…)           holds if any of the predicates holds
+                at debug_assertions debug assertions are enabled
+                at doc              the crate is being documented by rustdoc
+                at feature = "…"    a Cargo feature enabled for the crate
+                at not(…)           holds if the predicate does not hold
+                at proc_macro       the crate is compiled as a procedural macro
+                at target_arch = "…" the CPU architecture of the target
+                at target_endian = "…" the endianness of the target CPU
+                at target_env = "…" the ABI or libc used by the target
+                at target_family = "…" the family of the operating system of the target
+                at target_feature = "…" a CPU feature enabled for the target
+                at target_os = "…"  the operating system of the target
+                at target_pointer_width = "…" the pointer width of the target in bits
+                at target_vendor = "…" the vendor of the target
+                at test             the crate is compiled as a test harness
+                at unix             the target family is `unix`
+                at windows          the target family is `windows`
+            "#]],
+        );
+        check(r#"#[cfg(foo(<|>))] fn f() {}"#, expect![[""]]);
+        check(r#"#[cfg(target_os = <|>)] fn f() {}"#, expect![[""]]);
+    }
+
+    #[test]
+    fn completes_cfg_values() {
+        check(
+            r#"
+//- /lib.rs crate:foo cfg:target_os=linux,target_os=hermit
+#[cfg(any(windows, target_os = "<|>"))]
+fn f() {}
+"#,
+            expect![[r#"
+                at android
+                at dragonfly
+                at emscripten
+                at freebsd
+                at fuchsia
+                at haiku
+                at hermit     active
+                at illumos
+                at ios
+                at linux      active
+                at macos
+                at netbsd
+                at none
+                at openbsd
+                at redox
+                at solaris
+                at wasi
+                at windows
+            "#]],
+        );
+        check(
+            r#"
+//- /lib.rs crate:foo
+fn f() -> bool { cfg!(target_endian = "<|>") }
+"#,
+            expect![[r#"
+                at big
+                at little
+            "#]],
+        );
+        check(
+            r#"
+//- /lib.rs crate:foo
+#[cfg(target_foo = "<|>")]
+fn f() {}
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn inserts_cfg_key_snippets() {
+        check_edit(
+            "target_os",
+            r#"#[cfg(not(<|>))] fn f() {}"#,
+            r#"#[cfg(not(target_os = "${0:linux}"))] fn f() {}"#,
+        );
+    }
+
+    #[test]
+    fn completes_cfg_attr_predicate() {
+        check(
+            r#"#[cfg_attr(not(w<|>), derive(Debug))] struct S;"#,
+            expect![[r#"
+                at all(…)           holds if all the predicates hold
+                at any(…)           holds if any of the predicates holds
+                at debug_assertions debug assertions are enabled
+                at doc              the crate is being documented by rustdoc
+                at feature = "…"    a Cargo feature enabled for the crate
+                at not(…)           holds if the predicate does not hold
+                at proc_macro       the crate is compiled as a procedural macro
+                at target_arch = "…" the CPU architecture of the target
+                at target_endian = "…" the endianness of the target CPU
+                at target_env = "…" the ABI or libc used by the target
+                at target_family = "…" the family of the operating system of the target
+                at target_feature = "…" a CPU feature enabled for the target
+                at target_os = "…"  the operating system of the target
+                at target_pointer_width = "…" the pointer width of the target in bits
+                at target_vendor = "…" the vendor of the target
+                at test             the crate is compiled as a test harness
+                at unix             the target family is `unix`
+                at windows          the target family is `windows`
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_cfg_attr_attributes() {
+        check(
+            r#"#[cfg_attr(unix, <|>)] struct S;"#,
+            expect![[r#"
+                at allow(…)
+                at cfg(…)
+                at cfg_attr(…)
+                at deny(…)
+                at deprecated = "…"
+                at derive(…)
+                at doc = "…"
+                at forbid(…)
+                at ignore = "…"
+                at inline(…)
+                at macro_export
+                at macro_use
+                at must_use = "…"
+                at non_exhaustive
+                at path = "…"
+                at proc_macro
+                at proc_macro_attribute
+                at proc_macro_derive(…)
+                at repr(…)
+                at should_panic(…)
+                at target_feature = "…"
+                at test
+                at used
+                at warn(…)
+            "#]],
+        );
+        check(
+            r#"
+#[cfg_attr(unix, allow(dead_code), derive(Clone, <|>))]
+struct S;
+"#,
+            expect![[r#"
+                at Copy
+                at Debug
+                at Default
+                at Eq, PartialEq    requires PartialEq
+                at Hash
+                at Ord, PartialOrd, Eq, PartialEq requires PartialOrd + Eq + PartialEq
+                at PartialEq
+                at PartialOrd, PartialEq requires PartialEq
+            "#]],
+        );
+    }
+
+    #[test]
+    fn replaces_typed_feature_prefix() {
+        check_edit(
+            "serde",
+            r#"
+//- /lib.rs crate:foo cfg:feature=serde
+#[cfg(feature = "se<|>")]
+fn f() {}
+"#,
+            r#"
+#[cfg(feature = "serde")]
+fn f() {}
+"#,
+        );
+    }
 }
@@ -0,0 +1,328 @@
+//! Completes a closure for the arguments whose parameter is a closure, like
+//! `|acc, x| $0` in `iter.fold(0, <|>)`.
+//!
+//! The `Fn` traits are bounds of the parameter, which are not lowered for type
+//! parameters yet, so they are read from the declaration of the function.
+
+use hir::{HasSource, ModuleDef, PathResolution};
+use stdx::to_lower_snake_case;
+use syntax::{
+    ast::{self, GenericParamsOwner, NameOwner, TypeBoundsOwner},
+    AstNode,
+    SyntaxKind::*,
+    SyntaxNode, T,
+};
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_closure(acc: &mut Completions, ctx: &CompletionContext) {
+    if !ctx.is_trivial_path || ctx.is_path_type {
+        return;
+    }
+    let cap = match ctx.config.snippet_cap {
+        Some(it) => it,
+        None => return,
+    };
+    let names = match closure_params(ctx) {
+        Some(it) => it,
+        None => return,
+    };
+    let label = format!("|{}| …", names.join(", "));
+    CompletionItem::new(CompletionKind::Snippet, ctx.source_range(), label)
+        .kind(CompletionItemKind::Snippet)
+        .lookup_by("||")
+        .insert_snippet(cap, format!("|{}| $0", names.join(", ")))
+        .add_to(acc);
+}
+
+/// The names of the parameters of the closure the argument at the caret is
+/// expected to be. The path at the caret has to be the argument itself, and
+/// not part of a larger expression in it, like the body of `|x| <|>`.
+fn closure_params(ctx: &CompletionContext) -> Option<Vec<String>> {
+    let mut token = ctx.token.clone();
+    while token.kind() == WHITESPACE {
+        token = token.prev_token()?;
+    }
+    let arg_list = match token.kind() {
+        T!['('] | T![,] => Some(token.parent()),
+        _ => token
+            .parent()
+            .ancestors()
+            .find(|it| !matches!(it.kind(), NAME_REF | PATH_SEGMENT | PATH | PATH_EXPR)),
+    }
+    .and_then(ast::ArgList::cast)?;
+    let mut idx = arg_list
+        .syntax()
+        .children_with_tokens()
+        .filter(|it| it.kind() == T![,])
+        .take_while(|it| it.text_range().end() <= ctx.position.offset)
+        .count();
+    let call = arg_list.syntax().parent()?;
+    let func = called_function(ctx, &call, &mut idx)?;
+    let fn_ = func.source(ctx.db).value;
+    let param_ty = fn_.param_list()?.params().nth(idx)?.ty()?;
+
+    let bounds = match param_ty {
+        ast::Type::ImplTraitType(it) => it.type_bound_list()?.bounds().collect(),
+        ast::Type::RefType(it) => match it.ty()? {
+            ast::Type::DynTraitType(it) => it.type_bound_list()?.bounds().collect(),
+            _ => return None,
+        },
+        ast::Type::PathType(it) => type_param_bounds(&fn_, &it.path()?.syntax().to_string()),
+        _ => return None,
+    };
+    let fn_bound = bounds.iter().find_map(|bound| {
+        let segment = match bound.ty()? {
+            ast::Type::PathType(it) => it.path()?.segment()?,
+            _ => return None,
+        };
+        let name = segment.name_ref()?.to_string();
+        if !matches!(name.as_str(), "Fn" | "FnMut" | "FnOnce") {
+            return None;
+        }
+        Some(segment)
+    })?;
+    let ret_ty = fn_bound.ret_type().and_then(|it| it.ty()).map(|it| it.syntax().to_string());
+    let names =
+        fn_bound.param_list()?.params().map(|it| param_name(it.ty(), ret_ty.as_deref())).collect();
+    Some(dedup_names(names))
+}
+
+/// The function called by `call`, adjusting `idx` to skip the `self` argument of
+/// methods called as functions.
+fn called_function(
+    ctx: &CompletionContext,
+    call: &SyntaxNode,
+    idx: &mut usize,
+) -> Option<hir::Function> {
+    if let Some(call) = ast::MethodCallExpr::cast(call.clone()) {
+        return ctx.sema.resolve_method_call(&call);
+    }
+    let call = ast::CallExpr::cast(call.clone())?;
+    let path = match call.expr()? {
+        ast::Expr::PathExpr(it) => it.path()?,
+        _ => return None,
+    };
+    let func = match ctx.sema.resolve_path(&path)? {
+        PathResolution::Def(ModuleDef::Function(it))
+        | PathResolution::AssocItem(hir::AssocItem::Function(it)) => it,
+        _ => return None,
+    };
+    if func.self_param(ctx.db).is_some() {
+        *idx = idx.checked_sub(1)?;
+    }
+    Some(func)
+}
+
+/// The bounds of the type parameter `name` of `fn_`, both inline and in the
+/// where clause.
+fn type_param_bounds(fn_: &ast::Fn, name: &str) -> Vec<ast::TypeBound> {
+    let inline = fn_
+        .generic_param_list()
+        .into_iter()
+        .flat_map(|it| it.type_params())
+        .filter(|it| it.name().map(|it| it.text().to_string()).as_deref() == Some(name))
+        .filter_map(|it| it.type_bound_list());
+    let in_where_clause = fn_
+        .where_clause()
+        .into_iter()
+        .flat_map(|it| it.predicates())
+        .filter(|it| it.ty().map(|it| it.syntax().to_string()).as_deref() == Some(name))
+        .filter_map(|it| it.type_bound_list());
+    inline.chain(in_where_clause).flat_map(|it| it.bounds()).collect()
+}
+
+/// A name for a closure parameter of type `ty`: `acc` for the value threaded
+/// through, like the one of `fold`, and the name of the type otherwise.
+fn param_name(ty: Option<ast::Type>, ret_ty: Option<&str>) -> String {
+    let mut ty = match ty {
+        Some(it) => it,
+        None => return "x".to_string(),
+    };
+    while let ast::Type::RefType(it) = &ty {
+        ty = match it.ty() {
+            Some(it) => it,
+            None => return "x".to_string(),
+        };
+    }
+    if Some(ty.syntax().to_string().as_str()) == ret_ty {
+        return "acc".to_string();
+    }
+    let name = match &ty {
+        ast::Type::PathType(it) => {
+            it.path().and_then(|it| it.segment()).and_then(|it| it.name_ref())
+        }
+        _ => None,
+    };
+    match name.map(|it| it.to_string()) {
+        // Type parameters, builtin types and `Self::Item` say nothing about the
+        // value.
+        Some(name) if name.len() > 1 && name != "Item" && starts_with_uppercase(&name) => {
+            to_lower_snake_case(&name)
+        }
+        _ => "x".to_string(),
+    }
+}
+
+fn starts_with_uppercase(name: &str) -> bool {
+    name.chars().next().filter(|it| it.is_uppercase()).is_some()
+}
+
+/// Parameters with the same name are named `a`, `b`, ... instead, like the
+/// ones of comparators.
+fn dedup_names(names: Vec<String>) -> Vec<String> {
+    let is_duplicate = |name: &String| names.iter().filter(|it| *it == name).count() > 1;
+    let mut letters = (b'a'..=b'z').map(|it| (it as char).to_string());
+    names
+        .iter()
+        .map(|name| {
+            if is_duplicate(name) {
+                letters.next().unwrap_or_else(|| name.clone())
+            } else {
+                name.clone()
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Snippet);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_closures_for_fn_bounds() {
+        check(
+            r#"
+trait Iterator {
+    type Item;
+    fn map<B, F: FnMut(Self::Item) -> B>(self, f: F) {}
+    fn fold<B, F>(self, init: B, f: F) -> B where F: FnMut(B, Self::Item) -> B {}
+}
+fn main(it: impl Iterator) { it.fold(0, <|>) }
+"#,
+            expect![[r#"
+                sn pd
+                sn ppd
+                sn |acc, x| …
+            "#]],
+        );
+        check(
+            r#"
+struct Formatter;
+fn with(f: impl FnOnce(&mut Formatter, usize)) {}
+fn main() { with(<|>) }
+"#,
+            expect![[r#"
+                sn pd
+                sn ppd
+                sn |formatter, x| …
+            "#]],
+        );
+    }
+
+    #[test]
+    fn names_parameters_of_the_same_type_with_letters() {
+        check(
+            r#"
+struct S;
+impl S { fn sort_by(&mut self, compare: &dyn Fn(&u32, &u32) -> bool) {} }
+fn main(s: S) { s.sort_by(<|>) }
+"#,
+            expect![[r#"
+                sn pd
+                sn ppd
+                sn |a, b| …
+            "#]],
+        );
+        check(
+            r#"
+struct S;
+impl S { fn sort_by(&mut self, compare: &dyn Fn(&u32, &u32) -> bool) {} }
+fn main(s: S) { S::sort_by(&mut s, <|>) }
+"#,
+            expect![[r#"
+                sn pd
+                sn ppd
+                sn |a, b| …
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_closure_for_other_params() {
+        check(
+            r#"
+fn map<F: Fn(u32)>(x: u32, f: F) {}
+fn main() { map(<|>) }
+"#,
+            expect![[r#"
+                sn pd
+                sn ppd
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_closure_inside_the_argument() {
+        check(
+            r#"
+fn map<F: Fn(u32)>(f: F) {}
+fn main() { map(|x| <|>) }
+"#,
+            expect![[r#"
+                sn pd
+                sn ppd
+            "#]],
+        );
+        check(
+            r#"
+fn map<F: Fn(u32)>(f: F) {}
+fn main() { map(|x| x + f<|>) }
+"#,
+            expect![[r#"
+                sn pd
+                sn ppd
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_closure_for_typed_argument() {
+        check(
+            r#"
+fn map<F: Fn(u32)>(f: F) {}
+fn main() { map(f<|>) }
+"#,
+            expect![[r#"
+                sn pd
+                sn ppd
+                sn |x| …
+            "#]],
+        );
+    }
+
+    #[test]
+    fn inserts_closure() {
+        check_edit(
+            "||",
+            r#"
+fn map<F: Fn(u32)>(f: F) {}
+fn main() { map(<|>) }
+"#,
+            r#"
+fn map<F: Fn(u32)>(f: F) {}
+fn main() { map(|x| $0) }
+"#,
+        );
+    }
+}
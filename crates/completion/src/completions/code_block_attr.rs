@@ -0,0 +1,119 @@
+//! Completes the attributes of doc test code blocks, like ```` /// ```no_<|> ````.
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+/// The code block attributes rustdoc understands, as `(label, description)`.
+const CODE_BLOCK_ATTRS: &[(&str, &str)] = &[
+    ("compile_fail", "The code must fail to compile"),
+    ("edition2015", "Compile the code with the 2015 edition"),
+    ("edition2018", "Compile the code with the 2018 edition"),
+    ("ignore", "Don't compile or run the code"),
+    ("no_run", "Compile the code, but don't run it"),
+    ("rust", "The code block contains Rust code"),
+    ("should_panic", "The code must panic when run"),
+    ("test_harness", "Compile the code as a test, without wrapping it into `fn main`"),
+];
+
+pub(crate) fn complete_code_block_attr(acc: &mut Completions, ctx: &CompletionContext) {
+    let attrs = match &ctx.code_block_attrs {
+        Some(it) => it,
+        None => return,
+    };
+    let has_edition = attrs.existing.iter().any(|it| it.starts_with("edition"));
+    for &(label, description) in CODE_BLOCK_ATTRS {
+        if attrs.existing.iter().any(|it| it == label)
+            || (has_edition && label.starts_with("edition"))
+        {
+            continue;
+        }
+        CompletionItem::new(CompletionKind::Attribute, ctx.source_range(), label)
+            .kind(CompletionItemKind::Attribute)
+            .detail(description)
+            .add_to(acc);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Attribute);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_code_block_attrs() {
+        check(
+            r#"
+/// ```<|>
+/// foo();
+/// ```
+fn foo() {}
+"#,
+            expect![[r#"
+                at compile_fail The code must fail to compile
+                at edition2015  Compile the code with the 2015 edition
+                at edition2018  Compile the code with the 2018 edition
+                at ignore       Don't compile or run the code
+                at no_run       Compile the code, but don't run it
+                at rust         The code block contains Rust code
+                at should_panic The code must panic when run
+                at test_harness Compile the code as a test, without wrapping it into `fn main`
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_combinations_of_attrs() {
+        check(
+            r#"
+/// ```rust,edition2018,<|>
+/// ```
+fn foo() {}
+"#,
+            expect![[r#"
+                at compile_fail The code must fail to compile
+                at ignore       Don't compile or run the code
+                at no_run       Compile the code, but don't run it
+                at should_panic The code must panic when run
+                at test_harness Compile the code as a test, without wrapping it into `fn main`
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_completion_on_closing_fences() {
+        check(
+            r#"
+/// ```
+/// foo();
+/// ```<|>
+fn foo() {}
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn replaces_the_typed_attr() {
+        check_edit(
+            "no_run",
+            r#"
+/// ```rust,no<|>
+/// ```
+fn foo() {}
+"#,
+            r#"
+/// ```rust,no_run
+/// ```
+fn foo() {}
+"#,
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! Completes the arguments of const generic parameters, like in
+//! `ArrayVec<u8, <|>>`: the constants and const parameters in scope with the
+//! type of the parameter, and the associated constants of `Self`, which have to
+//! be wrapped in a block.
+
+use hir::{HirDisplay, ScopeDef};
+use syntax::{ast::NameOwner, AstNode};
+
+use crate::{
+    completions::unqualified_path::const_params_in_scope, CompletionContext, CompletionItem,
+    CompletionItemKind, CompletionKind, Completions,
+};
+
+pub(crate) fn complete_const_arg(acc: &mut Completions, ctx: &CompletionContext) {
+    let expected = match &ctx.const_arg_ty {
+        Some(it) if !it.is_unknown() => it,
+        _ => return,
+    };
+    let has_expected_type = |ty: &hir::Type| !ty.is_unknown() && ty.could_unify_with(expected);
+
+    let mut self_ty = None;
+    ctx.scope.process_all_names(&mut |_, def| match def {
+        ScopeDef::ModuleDef(hir::ModuleDef::Const(it)) if has_expected_type(&it.ty(ctx.db)) => {
+            acc.add_const(ctx, it)
+        }
+        ScopeDef::ImplSelfType(it) => self_ty = Some(it.target_ty(ctx.db)),
+        ScopeDef::AdtSelfType(it) => self_ty = Some(it.ty(ctx.db)),
+        _ => {}
+    });
+
+    let const_params = const_params_in_scope(&ctx.token.parent()).into_iter().filter(|it| match it
+        .ty()
+        .and_then(|ty| ctx.sema.resolve_type(&ty))
+    {
+        Some(ty) => has_expected_type(&ty),
+        None => false,
+    });
+    for const_param in const_params {
+        if let Some(name) = const_param.name() {
+            CompletionItem::new(
+                CompletionKind::Reference,
+                ctx.source_range(),
+                name.text().to_string(),
+            )
+            .kind(CompletionItemKind::Const)
+            .detail(const_param.syntax().text().to_string())
+            .add_to(acc);
+        }
+    }
+
+    if let (Some(self_ty), Some(krate)) = (self_ty, ctx.krate) {
+        let traits_in_scope = ctx.scope.traits_in_scope();
+        self_ty.iterate_path_candidates(ctx.db, krate, &traits_in_scope, None, |_, item| {
+            let const_ = match item {
+                hir::AssocItem::Const(it) => it,
+                _ => return None,
+            };
+            let name = match const_.name(ctx.db) {
+                Some(it) if has_expected_type(&const_.ty(ctx.db)) => it,
+                _ => return None,
+            };
+            let label = format!("{{ Self::{} }}", name);
+            CompletionItem::new(CompletionKind::Reference, ctx.source_range(), label)
+                .kind(CompletionItemKind::Const)
+                .lookup_by(name.to_string())
+                .detail(expected.display(ctx.db).to_string())
+                .add_to(acc);
+            None::<()>
+        });
+    }
+
+    // Any other expression has to be wrapped in a block too.
+    if let Some(cap) = ctx.config.snippet_cap {
+        CompletionItem::new(CompletionKind::Snippet, ctx.source_range(), "{ }")
+            .kind(CompletionItemKind::Snippet)
+            .insert_snippet(cap, "{ $0 }")
+            .add_to(acc);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Reference);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_consts_of_the_param_type() {
+        check(
+            r#"
+struct ArrayVec<T, const CAP: usize>([T; CAP]);
+const SMALL: usize = 4;
+const NAME: &str = "";
+struct S;
+fn foo<const N: usize, const B: bool>() {
+    let v: ArrayVec<u8, <|>>;
+}
+"#,
+            expect![[r#"
+                ct N     const N: usize
+                ct SMALL const SMALL: usize = 4;
+            "#]],
+        );
+    }
+
+    #[test]
+    fn compares_the_types_and_not_how_they_are_written() {
+        check(
+            r#"
+type Len = usize;
+struct ArrayVec<T: Copy, const CAP: Len>([T; CAP]);
+const SMALL: usize = 4;
+const BIG: u64 = 1024;
+fn foo<const N: Len>() {
+    let v: ArrayVec<u8, <|>>;
+}
+"#,
+            expect![[r#"
+                ct N     const N: Len
+                ct SMALL const SMALL: usize = 4;
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_assoc_consts_of_self_in_blocks() {
+        check(
+            r#"
+struct ArrayVec<T, const CAP: usize>([T; CAP]);
+struct Buf;
+impl Buf {
+    const CAP: usize = 16;
+    const NAME: &'static str = "";
+    fn new() -> ArrayVec<u8, <|>> {}
+}
+"#,
+            expect![[r#"
+                ct { Self::CAP } usize
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_as_usual_for_type_params() {
+        check(
+            r#"
+struct ArrayVec<T, const CAP: usize>([T; CAP]);
+const SMALL: usize = 4;
+struct S;
+fn foo() { let v: ArrayVec<<|>, 4>; }
+"#,
+            expect![[r#"
+                st ArrayVec<…>
+                st S
+                ct SMALL
+                fn foo()       fn foo()
+            "#]],
+        );
+    }
+
+    #[test]
+    fn inserts_block_snippet() {
+        check_edit(
+            "{ }",
+            r#"
+struct ArrayVec<T, const CAP: usize>([T; CAP]);
+fn foo() { let v: ArrayVec<u8, <|>>; }
+"#,
+            r#"
+struct ArrayVec<T, const CAP: usize>([T; CAP]);
+fn foo() { let v: ArrayVec<u8, { $0 }>; }
+"#,
+        );
+    }
+}
@@ -0,0 +1,237 @@
+//! Completes the crate names of the dependencies at the start of a use path,
+//! like `use se<|>` or `::se<|>`, and in `extern crate se<|>`.
+//!
+//! The names are the ones the dependencies are known by in the crate graph, so
+//! Cargo renames are honored. The detail shows the version of the dependency
+//! and whether it is a dev- or build-dependency. Dev-dependencies are only
+//! offered in test code and build-dependencies only in the build script.
+
+use hir::Crate;
+use ide_db::base_db::SourceDatabaseExt;
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_dependency(acc: &mut Completions, ctx: &CompletionContext) {
+    let in_use_path = ctx.use_item_syntax.is_some() && ctx.is_trivial_path;
+    if !in_use_path && !ctx.is_global_path {
+        return;
+    }
+    for (name, detail) in dependencies(ctx) {
+        let mut item = CompletionItem::new(CompletionKind::Reference, ctx.source_range(), name)
+            .kind(CompletionItemKind::Module);
+        if let Some(detail) = detail {
+            item = item.detail(detail);
+        }
+        item.add_to(acc);
+    }
+}
+
+/// Completes `extern crate <|>`, also with an alias for the crate.
+pub(crate) fn complete_extern_crate(acc: &mut Completions, ctx: &CompletionContext) {
+    if !ctx.is_extern_crate_name {
+        return;
+    }
+    for (name, detail) in dependencies(ctx) {
+        let mut item =
+            CompletionItem::new(CompletionKind::Reference, ctx.source_range(), name.as_str())
+                .kind(CompletionItemKind::Module);
+        if let Some(detail) = &detail {
+            item = item.detail(detail);
+        }
+        item.add_to(acc);
+
+        if let Some(cap) = ctx.config.snippet_cap {
+            let mut item = CompletionItem::new(
+                CompletionKind::Snippet,
+                ctx.source_range(),
+                format!("{} as …", name),
+            )
+            .kind(CompletionItemKind::Snippet)
+            .insert_snippet(cap, format!("{} as ${{0:{}}}", name, name));
+            if let Some(detail) = detail {
+                item = item.detail(detail);
+            }
+            item.add_to(acc);
+        }
+    }
+}
+
+/// Returns the names of the dependencies available in the current code, with
+/// their version and kind as the detail.
+fn dependencies(ctx: &CompletionContext) -> Vec<(String, Option<String>)> {
+    let krate = match ctx.krate {
+        Some(it) => it,
+        None => return Vec::new(),
+    };
+    let in_build_script = is_build_script(ctx, krate);
+    krate
+        .dependencies(ctx.db)
+        .into_iter()
+        .filter(|dep| !dep.is_dev || ctx.in_test_code)
+        .filter(|dep| !dep.is_build || in_build_script)
+        .map(|dep| {
+            let kind = if dep.is_dev {
+                Some("dev-dependency")
+            } else if dep.is_build {
+                Some("build-dependency")
+            } else {
+                None
+            };
+            let detail = match (dep.krate.version(ctx.db), kind) {
+                (Some(version), Some(kind)) => Some(format!("{} ({})", version, kind)),
+                (Some(version), None) => Some(version),
+                (None, Some(kind)) => Some(kind.to_string()),
+                (None, None) => None,
+            };
+            (dep.name.to_string(), detail)
+        })
+        .collect()
+}
+
+fn is_build_script(ctx: &CompletionContext, krate: Crate) -> bool {
+    let root_file = krate.root_file(ctx.db);
+    let source_root = ctx.db.source_root(ctx.db.file_source_root(root_file));
+    match source_root.path_for_file(&root_file) {
+        Some(path) => path.name_and_extension() == Some(("build", Some("rs"))),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Reference);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_dependencies_in_use_paths() {
+        check(
+            r#"
+//- /main.rs crate:main deps:serde,log
+use <|>
+//- /serde/lib.rs crate:serde env:CARGO_PKG_VERSION=1.0.118
+//- /log/lib.rs crate:log
+"#,
+            expect![[r#"
+                md log
+                md serde 1.0.118
+            "#]],
+        );
+        check(
+            r#"
+//- /main.rs crate:main deps:serde
+fn main() { ::<|> }
+//- /serde/lib.rs crate:serde
+"#,
+            expect![[r#"
+                md serde
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_dev_dependencies_in_tests() {
+        check(
+            r#"
+//- /main.rs crate:main dev-deps:quickcheck
+use <|>
+//- /quickcheck/lib.rs crate:quickcheck
+"#,
+            expect![[""]],
+        );
+        check(
+            r#"
+//- /main.rs crate:main dev-deps:quickcheck cfg:test
+#[cfg(test)]
+mod tests {
+    use quick<|>;
+}
+//- /quickcheck/lib.rs crate:quickcheck env:CARGO_PKG_VERSION=0.9.2
+"#,
+            expect![[r#"
+                md quickcheck 0.9.2 (dev-dependency)
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_build_dependencies_in_build_scripts() {
+        check(
+            r#"
+//- /main.rs crate:main build-deps:cc
+use <|>
+//- /cc/lib.rs crate:cc
+"#,
+            expect![[""]],
+        );
+        check(
+            r#"
+//- /build.rs crate:build build-deps:cc
+use <|>
+//- /cc/lib.rs crate:cc env:CARGO_PKG_VERSION=1.0.66
+"#,
+            expect![[r#"
+                md cc 1.0.66 (build-dependency)
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_dependencies_in_other_paths() {
+        check(
+            r#"
+//- /main.rs crate:main deps:serde
+use serde::<|>
+//- /serde/lib.rs crate:serde
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn completes_extern_crate_names() {
+        check(
+            r#"
+//- /main.rs crate:main deps:serde,log
+extern crate <|>
+//- /serde/lib.rs crate:serde env:CARGO_PKG_VERSION=1.0.118
+//- /log/lib.rs crate:log
+"#,
+            expect![[r#"
+                md log
+                md serde 1.0.118
+            "#]],
+        );
+        check(
+            r#"
+//- /main.rs crate:main deps:serde
+extern crate serde as s<|>;
+//- /serde/lib.rs crate:serde
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn inserts_extern_crate_alias() {
+        check_edit(
+            "serde as …",
+            r#"
+//- /main.rs crate:main deps:serde
+extern crate s<|>;
+//- /serde/lib.rs crate:serde
+"#,
+            r#"
+extern crate serde as ${0:serde};
+"#,
+        );
+    }
+}
@@ -0,0 +1,210 @@
+//! Completes paths inside of doc tests.
+//!
+//! rustdoc compiles every doc test as a separate crate, so items of the
+//! documented crate don't come into scope by themselves and have to be
+//! reached via the crate root, e.g. `crate::foo::<|>` or `my_crate::foo::<|>`.
+
+use hir::{ModuleDef, ScopeDef, Visibility};
+use syntax::{
+    algo::find_node_at_offset,
+    ast::{self, PathSegmentKind},
+    AstNode,
+};
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_doc_test(acc: &mut Completions, ctx: &CompletionContext) -> Option<()> {
+    let doc_test = ctx.doc_test.as_ref()?;
+    let krate = ctx.krate?;
+    let name_ref = find_node_at_offset::<ast::NameRef>(doc_test.file.syntax(), doc_test.offset)?;
+    let path = ast::PathSegment::cast(name_ref.syntax().parent()?)?.parent_path();
+    let crate_name = krate.display_name(ctx.db).map(|it| it.to_string());
+
+    let qualifier = match qualifier_segments(&path) {
+        Some(it) => it,
+        None => {
+            // Nothing from the documented crate is in scope, suggest the crate itself.
+            if let Some(crate_name) = crate_name {
+                CompletionItem::new(CompletionKind::Reference, ctx.source_range(), crate_name)
+                    .kind(CompletionItemKind::Module)
+                    .add_to(acc);
+            }
+            return None;
+        }
+    };
+
+    let (first, rest) = qualifier.split_first()?;
+    let is_crate_root = match first.kind()? {
+        PathSegmentKind::CrateKw => true,
+        PathSegmentKind::Name(name) => Some(name.text().to_string()) == crate_name,
+        _ => false,
+    };
+    if !is_crate_root {
+        return None;
+    }
+
+    let mut module = krate.root_module(ctx.db);
+    for segment in rest {
+        let name = segment.name_ref()?;
+        module = module.children(ctx.db).find(|child| {
+            child.name(ctx.db).map_or(false, |it| it.to_string() == name.text().as_str())
+        })?;
+    }
+
+    for (name, def) in module.scope(ctx.db, None) {
+        let is_public = match &def {
+            ScopeDef::ModuleDef(ModuleDef::BuiltinType(_)) => false,
+            ScopeDef::ModuleDef(module_def) => {
+                module.visibility_of(ctx.db, module_def) == Some(Visibility::Public)
+            }
+            ScopeDef::MacroDef(_) => true,
+            _ => false,
+        };
+        if is_public {
+            acc.add_resolution(ctx, name.to_string(), &def);
+        }
+    }
+    Some(())
+}
+
+/// Returns the segments of the path qualifier, starting from the root, also
+/// looking through the enclosing use trees.
+fn qualifier_segments(path: &ast::Path) -> Option<Vec<ast::PathSegment>> {
+    let mut qualifier = path.qualifier().or_else(|| {
+        let use_tree_list = path.syntax().ancestors().find_map(ast::UseTreeList::cast)?;
+        use_tree_list.syntax().parent().and_then(ast::UseTree::cast)?.path()
+    });
+    let mut segments = Vec::new();
+    while let Some(path) = qualifier {
+        segments.push(path.segment()?);
+        qualifier = path.qualifier().or_else(|| {
+            let use_tree = path.syntax().parent().and_then(ast::UseTree::cast)?;
+            let use_tree_list = use_tree.syntax().parent().and_then(ast::UseTreeList::cast)?;
+            use_tree_list.syntax().parent().and_then(ast::UseTree::cast)?.path()
+        });
+    }
+    segments.reverse();
+    if segments.is_empty() {
+        None
+    } else {
+        Some(segments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Reference);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_crate_paths_in_doc_tests() {
+        check(
+            r#"
+//- /lib.rs crate:my_crate
+/// ```
+/// let x = crate::<|>
+/// ```
+pub fn foo() {}
+pub struct Bar;
+struct Private;
+pub mod baz {}
+"#,
+            expect![[r#"
+                st Bar
+                md baz
+                fn foo() pub fn foo()
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_paths_through_crate_name_and_modules() {
+        check(
+            r#"
+//- /lib.rs crate:my_crate
+pub mod baz {
+    pub struct Quux;
+    fn private() {}
+}
+/// ```rust
+/// # use my_crate::baz::Quux;
+/// use my_crate::baz::<|>
+/// ```
+pub fn foo() {}
+"#,
+            expect![[r#"
+                st Quux
+            "#]],
+        );
+    }
+
+    #[test]
+    fn suggests_crate_name_for_unqualified_paths() {
+        check(
+            r#"
+//- /lib.rs crate:my_crate
+/// ```
+/// use my<|>
+/// ```
+pub fn foo() {}
+"#,
+            expect![[r#"
+                md my_crate
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_completion_outside_of_rust_code_blocks() {
+        check(
+            r#"
+//- /lib.rs crate:my_crate
+/// crate::<|>
+/// ```text
+/// crate::
+/// ```
+pub fn foo() {}
+"#,
+            expect![[""]],
+        );
+        check(
+            r#"
+//- /lib.rs crate:my_crate
+/// ```text
+/// crate::<|>
+/// ```
+pub fn foo() {}
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn replaces_typed_identifier_in_doc_test() {
+        check_edit(
+            "Bar",
+            r#"
+//- /lib.rs crate:my_crate
+//! ```
+//! let x = my_crate::B<|>;
+//! ```
+pub struct Bar;
+"#,
+            r#"
+//! ```
+//! let x = my_crate::Bar;
+//! ```
+pub struct Bar;
+"#,
+        );
+    }
+}
@@ -1,7 +1,8 @@
 //! Completes references after dot (fields and method calls).
 
-use hir::{HasVisibility, Type};
+use hir::{BuiltinInt, BuiltinType, HasVisibility, Type};
 use rustc_hash::FxHashSet;
+use syntax::ast;
 use test_utils::mark;
 
 use crate::{context::CompletionContext, Completions};
@@ -18,12 +19,52 @@ pub(crate) fn complete_dot(acc: &mut Completions, ctx: &CompletionContext) {
         _ => return,
     };
 
+    if let Some(receivers) = numeric_literal_receivers(ctx, dot_receiver, &receiver_ty) {
+        complete_methods(acc, ctx, &receivers);
+        return;
+    }
+
     if ctx.is_call {
         mark::hit!(test_no_struct_field_completion_for_method_call);
     } else {
         complete_fields(acc, ctx, &receiver_ty);
     }
-    complete_methods(acc, ctx, &receiver_ty);
+    complete_methods(acc, ctx, &[receiver_ty]);
+}
+
+/// An unsuffixed numeric literal takes its type from the context and only
+/// falls back to `i32` or `f64`, so the methods of the expected numeric types
+/// are offered as well. `1.<|>` is lexed as the float literal `1.`, but the
+/// user is accessing a method of the integer `1`.
+fn numeric_literal_receivers(
+    ctx: &CompletionContext,
+    receiver: &ast::Expr,
+    receiver_ty: &Type,
+) -> Option<Vec<Type>> {
+    let literal = match receiver {
+        ast::Expr::Literal(it) => it,
+        _ => return None,
+    };
+    let (is_int, default) = match literal.kind() {
+        ast::LiteralKind::IntNumber(it) if it.suffix().is_none() => (true, receiver_ty.clone()),
+        ast::LiteralKind::FloatNumber(it) if it.suffix().is_none() => {
+            if ctx.dot_receiver_is_ambiguous_float_literal {
+                mark::hit!(completes_int_methods_on_ambiguous_float_literal);
+                let i32 = BuiltinType::Int(BuiltinInt::I32);
+                (true, Type::from_builtin(ctx.db, ctx.scope.module()?, i32))
+            } else {
+                (false, receiver_ty.clone())
+            }
+        }
+        _ => return None,
+    };
+    let mut receivers: Vec<Type> = ctx
+        .expected_types()
+        .filter(|ty| if is_int { ty.is_integral() } else { ty.is_float() })
+        .collect();
+    receivers.push(default);
+    receivers.dedup();
+    Some(receivers)
 }
 
 fn complete_fields(acc: &mut Completions, ctx: &CompletionContext, receiver: &Type) {
@@ -43,19 +84,27 @@ fn complete_fields(acc: &mut Completions, ctx: &CompletionContext, receiver: &Ty
     }
 }
 
-fn complete_methods(acc: &mut Completions, ctx: &CompletionContext, receiver: &Type) {
+fn complete_methods(acc: &mut Completions, ctx: &CompletionContext, receivers: &[Type]) {
     if let Some(krate) = ctx.krate {
         let mut seen_methods = FxHashSet::default();
         let traits_in_scope = ctx.scope.traits_in_scope();
-        receiver.iterate_method_candidates(ctx.db, krate, &traits_in_scope, None, |_ty, func| {
-            if func.self_param(ctx.db).is_some()
-                && ctx.scope.module().map_or(true, |m| func.is_visible_from(ctx.db, m))
-                && seen_methods.insert(func.name(ctx.db))
-            {
-                acc.add_function(ctx, func, None);
-            }
-            None::<()>
-        });
+        for receiver in receivers {
+            receiver.iterate_method_candidates(
+                ctx.db,
+                krate,
+                &traits_in_scope,
+                None,
+                |_ty, func| {
+                    if func.self_param(ctx.db).is_some()
+                        && ctx.scope.module().map_or(true, |m| func.is_visible_from(ctx.db, m))
+                        && seen_methods.insert(func.name(ctx.db))
+                    {
+                        acc.add_function(ctx, func, None);
+                    }
+                    None::<()>
+                },
+            );
+        }
     }
 }
 
@@ -233,6 +282,23 @@ fn foo(a: &A) { a.<|> }
         );
     }
 
+    #[test]
+    fn completes_methods_of_type_param_bounds() {
+        check(
+            r"
+trait Io { fn flush(&self); }
+trait Read: Io { fn read(&self); }
+trait Seek { fn seek(&self); }
+fn foo<T: Read>(t: T) where T: Seek { t.<|> }
+",
+            expect![[r#"
+                me flush() fn flush(&self)
+                me read()  fn read(&self)
+                me seek()  fn seek(&self)
+            "#]],
+        );
+    }
+
     #[test]
     fn completes_trait_method_from_other_module() {
         check(
@@ -428,4 +494,81 @@ fn main() { make_s!().f<|>; }
             "#]],
         )
     }
+
+    #[test]
+    fn completes_methods_of_expected_numeric_types() {
+        check(
+            r#"
+#[lang = "i32"]
+impl i32 { fn to_i64(self) -> i64 { 0 } }
+#[lang = "u8"]
+impl u8 { fn to_ascii_uppercase(&self) -> u8 { 0 } }
+fn main() { let x: u8 = 1.to<|>; }
+"#,
+            expect![[r#"
+                me to_ascii_uppercase() fn to_ascii_uppercase(&self) -> u8
+                me to_i64()         fn to_i64(self) -> i64
+            "#]],
+        );
+        check(
+            r#"
+#[lang = "f64"]
+impl f64 { fn floor(self) -> f64 { 0.0 } }
+#[lang = "f32"]
+impl f32 { fn ceil(self) -> f32 { 0.0 } }
+fn take(x: f32) {}
+fn main() { take(1.0.<|>); }
+"#,
+            expect![[r#"
+                me ceil()  fn ceil(self) -> f32
+                me floor() fn floor(self) -> f64
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_int_methods_on_ambiguous_float_literal() {
+        mark::check!(completes_int_methods_on_ambiguous_float_literal);
+        check(
+            r#"
+#[lang = "i32"]
+impl i32 { fn to_i64(self) -> i64 { 0 } }
+#[lang = "f64"]
+impl f64 { fn floor(self) -> f64 { 0.0 } }
+fn main() { 1.<|> }
+"#,
+            expect![[r#"
+                me to_i64() fn to_i64(self) -> i64
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_after_nested_tuple_index() {
+        check(
+            r#"
+struct S;
+impl S { fn foo(&self) {} }
+fn main() {
+    let t = ((S, (S,)), 1);
+    t.0.1.0.<|>
+}
+"#,
+            expect![[r#"
+                me foo() fn foo(&self)
+            "#]],
+        );
+        check(
+            r#"
+struct S;
+fn main() {
+    let t = ((((S,),),), 1);
+    t.0.0.0.<|>
+}
+"#,
+            expect![[r#"
+                fd 0 S
+            "#]],
+        );
+    }
 }
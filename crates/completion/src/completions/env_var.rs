@@ -0,0 +1,139 @@
+//! Completes the keys of `env!("<|>")` and `option_env!("<|>")`.
+//!
+//! Besides the variables Cargo sets when compiling a crate, the variables
+//! recorded for the crate (like `OUT_DIR` from a build script) are offered,
+//! with their value as the detail.
+
+use syntax::{TextRange, TextSize};
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+/// The variables Cargo sets for the compiled crate, as `(name, description)`.
+const CARGO_ENV_VARS: &[(&str, &str)] = &[
+    ("CARGO", "Path to the cargo binary performing the build"),
+    ("CARGO_BIN_NAME", "The name of the binary being compiled"),
+    ("CARGO_CRATE_NAME", "The name of the crate being compiled"),
+    ("CARGO_MANIFEST_DIR", "The directory containing the manifest of the package"),
+    ("CARGO_PKG_AUTHORS", "Colon separated list of the package authors"),
+    ("CARGO_PKG_DESCRIPTION", "The description of the package"),
+    ("CARGO_PKG_HOMEPAGE", "The home page of the package"),
+    ("CARGO_PKG_LICENSE", "The license of the package"),
+    ("CARGO_PKG_LICENSE_FILE", "The license file of the package"),
+    ("CARGO_PKG_NAME", "The name of the package"),
+    ("CARGO_PKG_REPOSITORY", "The repository of the package"),
+    ("CARGO_PKG_VERSION", "The full version of the package"),
+    ("CARGO_PKG_VERSION_MAJOR", "The major version of the package"),
+    ("CARGO_PKG_VERSION_MINOR", "The minor version of the package"),
+    ("CARGO_PKG_VERSION_PATCH", "The patch version of the package"),
+    ("CARGO_PKG_VERSION_PRE", "The pre-release version of the package"),
+    ("OUT_DIR", "The output directory of the build script"),
+];
+
+pub(crate) fn complete_env_var(acc: &mut Completions, ctx: &CompletionContext) {
+    if !ctx.is_env_var_key {
+        return;
+    }
+    let string = match &ctx.string_literal_under_caret {
+        Some(it) => it,
+        None => return,
+    };
+    let content_start = match string.text().find('"') {
+        Some(it) => string.text_range().start() + TextSize::of(&string.text()[..=it]),
+        None => return,
+    };
+    let source_range = TextRange::new(content_start, ctx.position.offset);
+    let recorded = ctx.krate.map(|krate| krate.env_vars(ctx.db)).unwrap_or_default();
+    let value_of = |name: &str| recorded.iter().find(|(it, _)| it == name).map(|(_, v)| v.clone());
+
+    for &(name, description) in CARGO_ENV_VARS {
+        CompletionItem::new(CompletionKind::Magic, source_range, name)
+            .kind(CompletionItemKind::Const)
+            .detail(value_of(name).unwrap_or_else(|| description.to_string()))
+            .add_to(acc);
+    }
+    for (name, value) in &recorded {
+        if CARGO_ENV_VARS.iter().any(|(it, _)| it == name) {
+            continue;
+        }
+        CompletionItem::new(CompletionKind::Magic, source_range, name.as_str())
+            .kind(CompletionItemKind::Const)
+            .detail(value.as_str())
+            .add_to(acc);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Magic);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_cargo_and_recorded_env_vars() {
+        check(
+            r#"
+//- /lib.rs crate:foo env:OUT_DIR=target/out,RUST_LOG=debug
+fn main() {
+    let dir = env!("<|>");
+}
+"#,
+            expect![[r#"
+                ct CARGO            Path to the cargo binary performing the build
+                ct CARGO_BIN_NAME   The name of the binary being compiled
+                ct CARGO_CRATE_NAME The name of the crate being compiled
+                ct CARGO_MANIFEST_DIR The directory containing the manifest of the package
+                ct CARGO_PKG_AUTHORS Colon separated list of the package authors
+                ct CARGO_PKG_DESCRIPTION The description of the package
+                ct CARGO_PKG_HOMEPAGE The home page of the package
+                ct CARGO_PKG_LICENSE The license of the package
+                ct CARGO_PKG_LICENSE_FILE The license file of the package
+                ct CARGO_PKG_NAME   The name of the package
+                ct CARGO_PKG_REPOSITORY The repository of the package
+                ct CARGO_PKG_VERSION The full version of the package
+                ct CARGO_PKG_VERSION_MAJOR The major version of the package
+                ct CARGO_PKG_VERSION_MINOR The minor version of the package
+                ct CARGO_PKG_VERSION_PATCH The patch version of the package
+                ct CARGO_PKG_VERSION_PRE The pre-release version of the package
+                ct OUT_DIR          target/out
+                ct RUST_LOG         debug
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_completion_in_other_strings() {
+        check(
+            r#"
+fn main() {
+    let s = concat!("<|>");
+}
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn replaces_the_typed_key() {
+        check_edit(
+            "CARGO_PKG_NAME",
+            r#"
+fn main() {
+    let name = option_env!("CARGO_<|>");
+}
+"#,
+            r#"
+fn main() {
+    let name = option_env!("CARGO_PKG_NAME");
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,241 @@
+//! Completes names of foreign items in `extern` blocks, using the declarations
+//! of other `extern` blocks in the workspace.
+
+use ide_db::symbol_index::{self, FileSymbol};
+use rustc_hash::FxHashSet;
+use syntax::{
+    ast::{self, GenericParamsOwner, NameOwner},
+    AstNode, SyntaxKind,
+};
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_extern_item(acc: &mut Completions, ctx: &CompletionContext) {
+    let current_item = match &ctx.extern_item_under_caret {
+        Some(it) => it,
+        None => return,
+    };
+    let symbol_kind = match current_item {
+        ast::ExternItem::Fn(_) => SyntaxKind::FN,
+        ast::ExternItem::Static(_) => SyntaxKind::STATIC,
+        _ => return,
+    };
+
+    let mut seen: FxHashSet<String> = current_item
+        .syntax()
+        .parent()
+        .and_then(ast::ExternItemList::cast)
+        .into_iter()
+        .flat_map(|list| list.extern_items())
+        .filter(|item| item != current_item)
+        .filter_map(|item| item.name())
+        .map(|name| name.text().to_string())
+        .collect();
+
+    let prefix = match ctx.original_token.kind() {
+        SyntaxKind::IDENT => ctx.original_token.text().to_string(),
+        _ => String::new(),
+    };
+    let symbols = symbol_index::world_symbols(ctx.db, symbol_index::Query::new(prefix));
+    for symbol in symbols.into_iter().filter(|it| it.kind == symbol_kind) {
+        // Don't suggest the item that is being typed.
+        if symbol.file_id == ctx.position.file_id && symbol.range.contains(ctx.position.offset) {
+            continue;
+        }
+        let item = match foreign_item(ctx, &symbol) {
+            Some(it) => it,
+            None => continue,
+        };
+        if !seen.insert(symbol.name.to_string()) {
+            continue;
+        }
+        let (signature, kind) = match &item {
+            ast::ExternItem::Fn(it) => (fn_signature(it), CompletionItemKind::Function),
+            ast::ExternItem::Static(it) => (static_signature(it), CompletionItemKind::Static),
+            _ => continue,
+        };
+        let signature = match signature {
+            Some(it) => it,
+            None => continue,
+        };
+        CompletionItem::new(CompletionKind::Magic, ctx.source_range(), symbol.name.to_string())
+            .kind(kind)
+            .detail(item_detail(&item))
+            .insert_text(signature)
+            .add_to(acc);
+    }
+}
+
+/// Returns the foreign item the symbol points to, if the symbol is declared in
+/// an `extern` block.
+fn foreign_item(ctx: &CompletionContext, symbol: &FileSymbol) -> Option<ast::ExternItem> {
+    let file = ctx.sema.parse(symbol.file_id);
+    let node = symbol.ptr.to_node(file.syntax());
+    let item = ast::ExternItem::cast(node)?;
+    ast::ExternItemList::cast(item.syntax().parent()?)?;
+    Some(item)
+}
+
+/// `fn foo(x: c_int) -> c_int` is completed as `foo(x: c_int) -> c_int;`.
+fn fn_signature(func: &ast::Fn) -> Option<String> {
+    let mut res = func.name()?.text().to_string();
+    if let Some(generic_params) = func.generic_param_list() {
+        res.push_str(&generic_params.syntax().text().to_string());
+    }
+    res.push_str(&func.param_list()?.syntax().text().to_string());
+    if let Some(ret_type) = func.ret_type() {
+        res.push(' ');
+        res.push_str(&ret_type.syntax().text().to_string());
+    }
+    res.push(';');
+    Some(res)
+}
+
+/// `static mut FOO: c_int` is completed as `mut FOO: c_int;`.
+fn static_signature(static_: &ast::Static) -> Option<String> {
+    let mut res = String::new();
+    if static_.mut_token().is_some() {
+        res.push_str("mut ");
+    }
+    res.push_str(&static_.name()?.text());
+    res.push_str(": ");
+    res.push_str(&static_.ty()?.syntax().text().to_string());
+    res.push(';');
+    Some(res)
+}
+
+/// The declaration without attributes and doc comments, like `fn foo(x: c_int) -> c_int;`.
+fn item_detail(item: &ast::ExternItem) -> String {
+    let syntax = item.syntax();
+    let start = syntax
+        .children_with_tokens()
+        .find(|it| {
+            !matches!(it.kind(), SyntaxKind::ATTR | SyntaxKind::COMMENT) && !it.kind().is_trivia()
+        })
+        .map_or(syntax.text_range().start(), |it| it.text_range().start());
+    let text = syntax.text().to_string();
+    let offset: usize = (start - syntax.text_range().start()).into();
+    text[offset..].split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Magic);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_foreign_fns_from_other_extern_blocks() {
+        check(
+            r#"
+//- /lib.rs
+mod ffi;
+extern "C" {
+    fn <|>
+}
+//- /ffi.rs
+extern "C" {
+    /// Frees the memory.
+    pub fn free(ptr: *mut u8);
+    pub fn strlen(s: *const u8) -> usize;
+    pub static errno: i32;
+}
+fn not_foreign() {}
+"#,
+            expect![[r#"
+                fn free   pub fn free(ptr: *mut u8);
+                fn strlen pub fn strlen(s: *const u8) -> usize;
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_foreign_statics() {
+        check(
+            r#"
+extern "C" {
+    static mut environ: *const *const u8;
+    fn strlen(s: *const u8) -> usize;
+}
+mod m {
+    extern "C" {
+        static <|>
+    }
+}
+"#,
+            expect![[r#"
+                sc environ static mut environ: *const *const u8;
+            "#]],
+        );
+    }
+
+    #[test]
+    fn skips_items_declared_in_the_same_block() {
+        check(
+            r#"
+extern "C" {
+    fn abs(x: i32) -> i32;
+}
+extern "C" {
+    fn abs(x: i32) -> i32;
+    fn a<|>
+}
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn inserts_signature() {
+        check_edit(
+            "strlen",
+            r#"
+extern "C" {
+    fn strlen(s: *const u8) -> usize;
+}
+mod m {
+    extern "C" {
+        fn st<|>
+    }
+}
+"#,
+            r#"
+extern "C" {
+    fn strlen(s: *const u8) -> usize;
+}
+mod m {
+    extern "C" {
+        fn strlen(s: *const u8) -> usize;
+    }
+}
+"#,
+        );
+        check_edit(
+            "environ",
+            r#"
+extern "C" {
+    static mut environ: *const *const u8;
+}
+extern "C" {
+    static <|>
+}
+"#,
+            r#"
+extern "C" {
+    static mut environ: *const *const u8;
+}
+extern "C" {
+    static mut environ: *const *const u8;
+}
+"#,
+        );
+    }
+}
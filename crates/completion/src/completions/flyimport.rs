@@ -0,0 +1,349 @@
+//! Completes items which are not in scope yet, importing them on the fly.
+//!
+//! As the user types an identifier, all importable items of the workspace and
+//! its dependencies are fuzzy-searched for it, and the chosen one is inserted
+//! together with a `use` for it.
+
+use std::time::{Duration, Instant};
+
+use either::Either;
+use hir::ScopeDef;
+use ide_db::{helpers::insert_use::ImportScope, imports_locator};
+use syntax::AstNode;
+use test_utils::mark;
+
+use crate::{
+    completions::unqualified_path::{is_implementable_type, is_unqualified_path, is_valid_bound},
+    render::{render_resolution_with_import, RenderContext},
+    CompletionContext, Completions,
+};
+
+/// How long we're willing to look for import candidates before giving up on
+/// the rest of them.
+const FUZZY_COMPLETION_TIME_BUDGET: Duration = Duration::from_millis(100);
+
+/// The symbol search has to look past the candidates that are filtered out,
+/// like the ones that are in scope already.
+const SEARCH_LIMIT_FACTOR: usize = 3;
+
+pub(crate) fn complete_flyimport(acc: &mut Completions, ctx: &CompletionContext) {
+    if !ctx.config.enable_autoimport_completions
+        || !is_unqualified_path(ctx)
+        || ctx.is_pat_binding_or_const
+    {
+        return;
+    }
+    fuzzy_completion(acc, ctx).unwrap_or_default()
+}
+
+fn fuzzy_completion(acc: &mut Completions, ctx: &CompletionContext) -> Option<()> {
+    let _p = profile::span("fuzzy_completion");
+    let anchor = ctx.name_ref_syntax.as_ref()?;
+    let potential_import_name = anchor.text().to_string();
+    if potential_import_name.chars().count() < ctx.config.autoimport_min_prefix_length {
+        mark::hit!(flyimport_needs_min_prefix);
+        return None;
+    }
+    let current_module = ctx.scope.module()?;
+    let import_scope = ImportScope::find_insert_use_container(anchor.syntax(), &ctx.sema)?;
+    let max_candidates = ctx.config.autoimport_max_candidates;
+    let deadline = Instant::now() + FUZZY_COMPLETION_TIME_BUDGET;
+
+    let possible_imports = imports_locator::find_similar_imports(
+        &ctx.sema,
+        ctx.krate?,
+        &potential_import_name,
+        max_candidates.saturating_mul(SEARCH_LIMIT_FACTOR),
+        true,
+    )
+    // The candidates are found lazily, so stopping here also stops the search.
+    .take_while(|_| Instant::now() < deadline)
+    .filter_map(|import_candidate| {
+        Some(match import_candidate {
+            Either::Left(module_def) => {
+                (current_module.find_use_path(ctx.db, module_def)?, ScopeDef::ModuleDef(module_def))
+            }
+            Either::Right(macro_def) => {
+                (current_module.find_use_path(ctx.db, macro_def)?, ScopeDef::MacroDef(macro_def))
+            }
+        })
+    })
+    .filter(|(mod_path, _)| mod_path.len() > 1)
+    .filter(|(_, definition)| !ctx.expects_trait() || is_valid_bound(definition))
+    .filter(|(_, definition)| !ctx.is_impl_self_ty_path || is_implementable_type(definition))
+    .filter(|(_, definition)| ctx.in_test_code || !ctx.is_test_only(definition))
+    .take(max_candidates)
+    .filter_map(|(import_path, definition)| {
+        render_resolution_with_import(
+            RenderContext::new(ctx),
+            import_path.clone(),
+            import_scope.clone(),
+            ctx.config.merge,
+            &definition,
+        )
+    });
+
+    acc.add_all(possible_imports);
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+    use test_utils::mark;
+
+    use crate::{
+        test_utils::{check_edit, completion_list, completion_list_with_config},
+        CompletionConfig, CompletionKind,
+    };
+
+    #[test]
+    fn function_fuzzy_completion() {
+        check_edit(
+            "stdin",
+            r#"
+//- /lib.rs crate:dep
+pub mod io {
+    pub fn stdin() {}
+};
+
+//- /main.rs crate:main deps:dep
+fn main() {
+    stdi<|>
+}
+"#,
+            r#"
+use dep::io::stdin;
+
+fn main() {
+    stdin()$0
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn macro_fuzzy_completion() {
+        check_edit(
+            "macro_with_curlies!",
+            r#"
+//- /lib.rs crate:dep
+/// Please call me as macro_with_curlies! {}
+#[macro_export]
+macro_rules! macro_with_curlies {
+    () => {}
+}
+
+//- /main.rs crate:main deps:dep
+fn main() {
+    curli<|>
+}
+"#,
+            r#"
+use dep::macro_with_curlies;
+
+fn main() {
+    macro_with_curlies! {$0}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn struct_fuzzy_completion() {
+        check_edit(
+            "ThirdStruct",
+            r#"
+//- /lib.rs crate:dep
+pub struct FirstStruct;
+pub mod some_module {
+    pub struct SecondStruct;
+    pub struct ThirdStruct;
+}
+
+//- /main.rs crate:main deps:dep
+use dep::{FirstStruct, some_module::SecondStruct};
+
+fn main() {
+    this<|>
+}
+"#,
+            r#"
+use dep::{FirstStruct, some_module::{SecondStruct, ThirdStruct}};
+
+fn main() {
+    ThirdStruct
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn fuzzy_completion_imports_the_shortest_reexport() {
+        check_edit(
+            "FacadeStruct",
+            r#"
+//- /lib.rs crate:dep
+pub mod deeply {
+    pub mod nested {
+        pub struct FacadeStruct;
+    }
+}
+pub use deeply::nested::FacadeStruct;
+
+//- /main.rs crate:main deps:dep
+fn main() {
+    Facade<|>
+}
+"#,
+            r#"
+use dep::FacadeStruct;
+
+fn main() {
+    FacadeStruct
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn fuzzy_completion_imports_through_facade_crates() {
+        let items = completion_list(
+            r#"
+//- /core.rs crate:core
+pub mod iter {
+    pub mod adapters {
+        pub struct Peekable;
+    }
+    pub use self::adapters::Peekable;
+}
+
+//- /std.rs crate:std deps:core
+pub use core::iter;
+
+//- /main.rs crate:main deps:std,core
+fn main() {
+    Peek<|>
+}
+"#,
+            CompletionKind::Reference,
+        );
+        expect![[r#"
+            md core
+            fn main()           fn main()
+            md std
+            st std::iter::Peekable
+        "#]]
+        .assert_eq(&items);
+    }
+
+    #[test]
+    fn flyimport_can_be_disabled() {
+        let config =
+            CompletionConfig { enable_autoimport_completions: false, ..Default::default() };
+        let items = completion_list_with_config(
+            config,
+            r#"
+//- /lib.rs crate:dep
+pub struct ThirdStruct;
+
+//- /main.rs crate:main deps:dep
+fn main() {
+    Thir<|>
+}
+"#,
+            CompletionKind::Reference,
+        );
+        assert!(!items.contains("ThirdStruct"), "{}", items);
+    }
+
+    #[test]
+    fn flyimport_needs_min_prefix() {
+        mark::check!(flyimport_needs_min_prefix);
+        let fixture = |prefix: &str| {
+            format!(
+                r#"
+//- /lib.rs crate:dep
+pub struct Thing;
+
+//- /main.rs crate:main deps:dep
+fn main() {{
+    {}<|>
+}}
+"#,
+                prefix
+            )
+        };
+        let config = CompletionConfig { autoimport_min_prefix_length: 3, ..Default::default() };
+        let items =
+            completion_list_with_config(config.clone(), &fixture("Th"), CompletionKind::Reference);
+        assert!(!items.contains("dep::Thing"), "{}", items);
+        let items = completion_list_with_config(config, &fixture("Thi"), CompletionKind::Reference);
+        expect![[r#"
+            md dep
+            st dep::Thing
+            fn main()     fn main()
+        "#]]
+        .assert_eq(&items);
+    }
+
+    #[test]
+    fn flyimport_offers_at_most_max_candidates() {
+        let config = CompletionConfig { autoimport_max_candidates: 2, ..Default::default() };
+        let items = completion_list_with_config(
+            config,
+            r#"
+//- /lib.rs crate:dep
+pub mod a { pub struct Frob; }
+pub mod b { pub struct Frob; }
+pub mod c { pub struct Frob; }
+
+//- /main.rs crate:main deps:dep
+fn main() {
+    Fro<|>
+}
+"#,
+            CompletionKind::Reference,
+        );
+        assert_eq!(items.matches("Frob").count(), 2, "{}", items);
+    }
+
+    #[test]
+    fn flyimport_offers_only_traits_for_impl_trait() {
+        let items = completion_list(
+            r#"
+//- /lib.rs crate:dep
+pub mod fmt {
+    pub trait Display {}
+    pub struct DisplayAdapter;
+}
+
+//- /main.rs crate:main deps:dep
+struct S;
+impl Displ<|> for S {}
+"#,
+            CompletionKind::Reference,
+        );
+        assert!(items.contains("dep::fmt::Display"), "{}", items);
+        assert!(!items.contains("DisplayAdapter"), "{}", items);
+    }
+
+    #[test]
+    fn flyimport_offers_only_traits_for_supertraits() {
+        let items = completion_list(
+            r#"
+//- /lib.rs crate:dep
+pub mod fmt {
+    pub trait Display {}
+    pub struct DisplayAdapter;
+}
+
+//- /main.rs crate:main deps:dep
+trait Pretty: Displ<|> {}
+"#,
+            CompletionKind::Reference,
+        );
+        assert!(items.contains("dep::fmt::Display"), "{}", items);
+        assert!(!items.contains("DisplayAdapter"), "{}", items);
+    }
+}
@@ -1,17 +1,24 @@
 //! See `complete_fn_param`.
 
+use hir::{Adt, AssocItem, ModuleDef, ScopeDef};
+use ide_db::symbol_index::{self, Query};
 use rustc_hash::FxHashMap;
 use syntax::{
-    ast::{self, ModuleItemOwner},
-    match_ast, AstNode,
+    ast::{self, ModuleItemOwner, NameOwner},
+    match_ast, AstNode, SyntaxKind,
 };
+use test_utils::mark;
 
-use crate::{CompletionContext, CompletionItem, CompletionKind, Completions};
+use crate::{
+    name_suggestion, CompletionContext, CompletionItem, CompletionItemKind, CompletionKind,
+    Completions,
+};
 
 /// Complete repeated parameters, both name and type. For example, if all
 /// functions in a file have a `spam: &mut Spam` parameter, a completion with
 /// `spam: &mut Spam` insert text/label and `spam` lookup string will be
-/// suggested.
+/// suggested. Parameters of similarly named functions from the rest of the
+/// crate are suggested as well, so are the `self` parameters in impls.
 pub(crate) fn complete_fn_param(acc: &mut Completions, ctx: &CompletionContext) {
     if !ctx.is_param {
         return;
@@ -50,23 +57,206 @@ pub(crate) fn complete_fn_param(acc: &mut Completions, ctx: &CompletionContext)
         };
     }
 
-    params
+    if let Some(me) = &me {
+        similarly_named_fns(ctx, me).into_iter().for_each(&mut process_fn);
+        if ctx.param_type.is_none() {
+            complete_self_param(acc, ctx, me);
+        }
+    }
+
+    if let Some(ty) = &ctx.param_type {
+        // The type is already there, so only the names are completed: the ones
+        // other parameters of the same type have, and the ones the type suggests.
+        let ty_text = ty.syntax().text().to_string();
+        let mut names: Vec<String> = params
+            .values()
+            .filter(|param| param.ty().map_or(false, |it| it.syntax().text() == ty_text.as_str()))
+            .filter_map(|param| Some(param.pat()?.syntax().text().to_string()))
+            .collect();
+        names.extend(name_suggestion::for_type(ty));
+        names.sort();
+        names.dedup();
+        for name in names {
+            CompletionItem::new(CompletionKind::Magic, ctx.source_range(), name)
+                .kind(CompletionItemKind::Binding)
+                .add_to(acc);
+        }
+        return;
+    }
+
+    let mut params: Vec<(String, String)> = params
         .into_iter()
         .filter_map(|(label, param)| {
             let lookup = param.pat()?.syntax().text().to_string();
             Some((label, lookup))
         })
-        .for_each(|(label, lookup)| {
-            CompletionItem::new(CompletionKind::Magic, ctx.source_range(), label)
-                .kind(crate::CompletionItemKind::Binding)
-                .lookup_by(lookup)
-                .add_to(acc)
+        .collect();
+    for (label, lookup) in params_from_signature_types(ctx) {
+        if !params.iter().any(|(it, _)| *it == label) {
+            params.push((label, lookup));
+        }
+    }
+    params.into_iter().for_each(|(label, lookup)| {
+        CompletionItem::new(CompletionKind::Magic, ctx.source_range(), label)
+            .kind(CompletionItemKind::Binding)
+            .lookup_by(lookup)
+            .add_to(acc)
+    });
+}
+
+/// How many parameters `params_from_signature_types` suggests.
+const SIGNATURE_TYPE_PARAMS_LIMIT: usize = 5;
+
+/// How many functions `params_from_signature_types` looks at, so that the
+/// signatures of a huge crate aren't all lowered on every keystroke.
+const SIGNATURE_TYPE_COUNTED_FNS_LIMIT: usize = 1000;
+
+/// Parameters of the types in scope which the functions of the current crate
+/// take the most, named after them, like `request: HttpRequest`.
+fn params_from_signature_types(ctx: &CompletionContext) -> Vec<(String, String)> {
+    let krate = match ctx.krate {
+        Some(it) => it,
+        None => return Vec::new(),
+    };
+    let db = ctx.db;
+    let mut counts: FxHashMap<Adt, usize> = FxHashMap::default();
+    let mut modules = vec![krate.root_module(db)];
+    let mut counted_fns = 0;
+    'modules: while let Some(module) = modules.pop() {
+        modules.extend(module.children(db));
+        let fns = module.declarations(db).into_iter().filter_map(|def| match def {
+            ModuleDef::Function(it) => Some(it),
+            _ => None,
         });
+        let methods =
+            module.impl_defs(db).into_iter().flat_map(|it| it.items(db)).filter_map(|item| {
+                match item {
+                    AssocItem::Function(it) => Some(it),
+                    _ => None,
+                }
+            });
+        for func in fns.chain(methods) {
+            if counted_fns == SIGNATURE_TYPE_COUNTED_FNS_LIMIT {
+                mark::hit!(limits_counted_signatures);
+                break 'modules;
+            }
+            counted_fns += 1;
+            for param in func.assoc_fn_params(db) {
+                if let Some(adt) = param.ty().as_adt() {
+                    *counts.entry(adt).or_default() += 1;
+                }
+            }
+        }
+    }
+
+    let mut res = Vec::new();
+    ctx.scope.process_all_names(&mut |name, def| {
+        let adt = match def {
+            ScopeDef::ModuleDef(ModuleDef::Adt(it)) => it,
+            _ => return,
+        };
+        let count = match counts.get(&adt) {
+            Some(&it) => it,
+            None => return,
+        };
+        // Without its arguments, a generic type would be left incomplete, and
+        // one-letter types, like the ones of examples, make poor names.
+        if adt.module(db).krate() != krate
+            || adt.ty(db).type_arguments().next().is_some()
+            || name.to_string().len() < 2
+        {
+            return;
+        }
+        if let Some(param_name) = name_suggestion::names_from_type_name(&name.to_string()).first() {
+            res.push((count, format!("{}: {}", param_name, name), param_name.clone()));
+        }
+    });
+    res.sort_by(|(count, label, _), (other_count, other_label, _)| {
+        other_count.cmp(count).then_with(|| label.cmp(other_label))
+    });
+    res.into_iter()
+        .take(SIGNATURE_TYPE_PARAMS_LIMIT)
+        .map(|(_, label, lookup)| (label, lookup))
+        .collect()
+}
+
+/// How many functions from the rest of the crate are looked at, per word of
+/// the function name.
+const SIMILARLY_NAMED_FNS_LIMIT: usize = 32;
+
+/// Finds functions in the current crate sharing a word with the name of `me`,
+/// like `parse_item` for `parse_expr`.
+fn similarly_named_fns(ctx: &CompletionContext, me: &ast::Fn) -> Vec<ast::Fn> {
+    let krate = match ctx.krate {
+        Some(it) => it,
+        None => return Vec::new(),
+    };
+    let name = match me.name() {
+        Some(it) => it.text().to_string(),
+        None => return Vec::new(),
+    };
+    let mut res = Vec::new();
+    for word in name.split('_').filter(|it| it.len() >= 3) {
+        // The query matches fuzzily, so the limit is only applied once the
+        // symbols are filtered down to the ones having the whole word.
+        let query = Query::new(word.to_string());
+        let symbols = symbol_index::crate_symbols(ctx.db, krate.into(), query)
+            .into_iter()
+            .filter(|symbol| symbol.kind == SyntaxKind::FN)
+            .filter(|symbol| symbol.name.split('_').any(|it| it == word))
+            // Functions of the current file are handled by `complete_fn_param` itself.
+            .filter(|symbol| symbol.file_id != ctx.position.file_id)
+            .take(SIMILARLY_NAMED_FNS_LIMIT);
+        for symbol in symbols {
+            let file = ctx.sema.parse(symbol.file_id);
+            if let Some(func) = ast::Fn::cast(symbol.ptr.to_node(file.syntax())) {
+                res.push(func);
+            }
+        }
+    }
+    res
+}
+
+/// Completes `self` parameters as the first parameter of methods. Trait impls
+/// are skipped, as there the trait dictates whether there's a `self`.
+fn complete_self_param(acc: &mut Completions, ctx: &CompletionContext, me: &ast::Fn) {
+    let container = match me.syntax().parent().and_then(ast::AssocItemList::cast) {
+        Some(it) => it.syntax().parent(),
+        None => return,
+    };
+    let can_have_self = container.map_or(false, |it| {
+        match_ast! {
+            match it {
+                ast::Impl(it) => it.trait_().is_none(),
+                ast::Trait(_it) => true,
+                _ => false,
+            }
+        }
+    });
+    if !can_have_self {
+        return;
+    }
+    let param_list = match me.param_list() {
+        Some(it) => it,
+        None => return,
+    };
+    let is_first_param = param_list.self_param().is_none()
+        && !param_list.params().any(|it| it.syntax().text_range().end() < ctx.position.offset);
+    if !is_first_param {
+        return;
+    }
+    for self_param in &["self", "&self", "&mut self", "self: Arc<Self>"] {
+        CompletionItem::new(CompletionKind::Magic, ctx.source_range(), *self_param)
+            .kind(CompletionItemKind::Binding)
+            .lookup_by("self")
+            .add_to(acc);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use expect_test::{expect, Expect};
+    use test_utils::mark;
 
     use crate::{test_utils::completion_list, CompletionKind};
 
@@ -132,4 +322,194 @@ fn outer(text: String) {
             "#]],
         )
     }
+
+    #[test]
+    fn completes_params_of_similarly_named_fns_from_other_files() {
+        check(
+            r#"
+//- /lib.rs
+mod grammar;
+fn parse_expr(<|>) {}
+//- /grammar.rs
+fn parse_item(p: &mut Parser, kind: ItemKind) {}
+fn lower_expr(expr: ast::Expr) {}
+fn unrelated(x: u32) {}
+"#,
+            expect![[r#"
+                bn expr: ast::Expr
+                bn kind: ItemKind
+                bn p: &mut Parser
+            "#]],
+        );
+    }
+
+    #[test]
+    fn limits_similarly_named_fns_after_matching_the_word() {
+        let fuzzy_matches: String =
+            (0..40).map(|i| format!("fn ex_pr_{}(x{}: u32) {{}}\n", i, i)).collect();
+        let actual = completion_list(
+            &format!(
+                "//- /lib.rs\nmod grammar;\nfn parse_expr(<|>) {{}}\n//- /grammar.rs\n{}{}",
+                fuzzy_matches, "fn lower_expr(expr: ast::Expr) {}\n"
+            ),
+            CompletionKind::Magic,
+        );
+        expect![[r#"
+            bn expr: ast::Expr
+        "#]]
+        .assert_eq(&actual);
+    }
+
+    #[test]
+    fn completes_self_params_in_impls() {
+        check(
+            r#"
+struct S;
+impl S {
+    fn foo(<|>) {}
+}
+"#,
+            expect![[r#"
+                bn &mut self
+                bn &self
+                bn self
+                bn self: Arc<Self>
+            "#]],
+        );
+        check(
+            r#"
+trait T {
+    fn foo(&self, x: u32);
+    fn bar(x: u32, <|>);
+}
+"#,
+            expect![[r#"
+                bn x: u32
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_names_for_typed_params() {
+        check(
+            r#"
+fn foo(cfg: &AnalysisConfig) {}
+fn bar(c<|>: &AnalysisConfig, reader: BufReader<File>) {}
+"#,
+            expect![[r#"
+                bn analysis_config
+                bn cfg
+                bn config
+            "#]],
+        );
+        check(
+            r#"
+fn foo(<|>: &mut [u8]) {}
+"#,
+            expect![[r#"
+                bn buf
+            "#]],
+        );
+        check(
+            r#"
+fn foo(<|>: Option<Box<dyn Write>>) {}
+"#,
+            expect![[r#"
+                bn writer
+            "#]],
+        );
+        check(
+            r#"
+struct S;
+impl S {
+    fn foo(<|>: impl Iterator<Item = u32>) {}
+}
+"#,
+            expect![[r#"
+                bn iter
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_abbreviated_names_for_typed_params() {
+        check(
+            r#"
+fn handle(<|>: HttpRequest) {}
+"#,
+            expect![[r#"
+                bn http_request
+                bn req
+                bn request
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_params_of_types_from_other_signatures() {
+        check(
+            r#"
+//- /lib.rs
+mod routes;
+struct HttpRequest;
+struct Response;
+struct Unused;
+struct Wrapper<T>(T);
+mod m { pub struct Hidden; }
+fn handle(<|>) {}
+//- /routes.rs
+use crate::{m::Hidden, HttpRequest, Response, Wrapper};
+fn get(req: HttpRequest, hidden: Hidden) {}
+fn post(response: Response, wrapper: Wrapper<u32>) {}
+"#,
+            expect![[r#"
+                bn request: HttpRequest
+                bn response: Response
+            "#]],
+        );
+    }
+
+    #[test]
+    fn limits_params_of_types_from_other_signatures() {
+        check(
+            r#"
+//- /lib.rs
+mod routes;
+struct Alpha;
+struct Beta;
+struct Gamma;
+struct Delta;
+struct Epsilon;
+struct Zeta;
+fn handle(<|>) {}
+//- /routes.rs
+use crate::*;
+fn one(a: Alpha, b: Beta, g: Gamma, d: Delta, e: Epsilon, z: Zeta) {}
+fn two(a: Alpha, b: Beta, g: Gamma, d: Delta, z: Zeta) {}
+"#,
+            expect![[r#"
+                bn alpha: Alpha
+                bn beta: Beta
+                bn delta: Delta
+                bn gamma: Gamma
+                bn zeta: Zeta
+            "#]],
+        );
+    }
+
+    #[test]
+    fn limits_signatures_counted_for_param_types() {
+        mark::check!(limits_counted_signatures);
+        let fns: String = (0..1000).map(|it| format!("fn f{}(x: Filler) {{}}\n", it)).collect();
+        check(
+            &format!(
+                "//- /lib.rs\nmod late;\nstruct Filler;\nstruct Rare;\nfn handle(<|>) {{}}\n{}\n//- /late.rs\nuse crate::*;\nfn g(r: Rare) {{}}",
+                fns
+            ),
+            expect![[r#"
+                bn filler: Filler
+                bn x: Filler
+            "#]],
+        );
+    }
 }
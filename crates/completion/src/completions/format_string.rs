@@ -0,0 +1,204 @@
+//! Completes the placeholders of format strings, like `println!("{<|>}")`.
+//!
+//! Before a `:` the argument is completed with the locals which can be
+//! captured, after it the format spec.
+
+use hir::HirDisplay;
+use syntax::{TextRange, TextSize};
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+/// Spec fragments as `(label, snippet, detail)`.
+const FORMAT_SPECS: &[(&str, &str, &str)] = &[
+    (":?", ":?", "Debug"),
+    (":#?", ":#?", "pretty-printed Debug"),
+    (":>8", ":>${1:8}", "right-aligned with a width"),
+    (":.2", ":.${1:2}", "with a precision"),
+];
+
+pub(crate) fn complete_format_string(acc: &mut Completions, ctx: &CompletionContext) {
+    if !ctx.is_format_string {
+        return;
+    }
+    let string = match &ctx.string_literal_under_caret {
+        Some(it) => it,
+        None => return,
+    };
+    let text = string.text().as_str();
+    let offset = ctx.position.offset - string.text_range().start();
+    let content_start = match text.find('"') {
+        Some(it) => it + 1,
+        None => return,
+    };
+    let text_before = &text[content_start..offset.into()];
+    let placeholder_start = match open_placeholder(text_before) {
+        Some(it) => content_start + it,
+        None => return,
+    };
+    let to_range = |start: usize| {
+        TextRange::new(
+            string.text_range().start() + TextSize::from(start as u32),
+            ctx.position.offset,
+        )
+    };
+
+    let placeholder = &text[placeholder_start..offset.into()];
+    if let Some(colon) = placeholder.find(':') {
+        let source_range = to_range(placeholder_start + colon);
+        for &(label, snippet, detail) in FORMAT_SPECS {
+            let item = CompletionItem::new(CompletionKind::Magic, source_range, label)
+                .kind(CompletionItemKind::Snippet)
+                .detail(detail);
+            let item = match ctx.config.snippet_cap {
+                Some(cap) => item.insert_snippet(cap, snippet),
+                None => item,
+            };
+            item.add_to(acc);
+        }
+    } else if placeholder.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        let source_range = to_range(placeholder_start);
+        for (name, local) in &ctx.locals {
+            CompletionItem::new(CompletionKind::Reference, source_range, name.as_str())
+                .kind(CompletionItemKind::Binding)
+                .detail(local.ty(ctx.db).display(ctx.db).to_string())
+                .add_to(acc);
+        }
+    }
+}
+
+/// Returns the index right after the `{` of the placeholder left open at the
+/// end of `text`, skipping the escaped `{{` and `}}`.
+fn open_placeholder(text: &str) -> Option<usize> {
+    let mut open = None;
+    let mut chars = text.char_indices().peekable();
+    while let Some((idx, c)) = chars.next() {
+        match c {
+            '{' if open.is_none() && matches!(chars.peek(), Some((_, '{'))) => {
+                chars.next();
+            }
+            '{' => open = Some(idx + 1),
+            '}' if open.is_some() => open = None,
+            '}' if matches!(chars.peek(), Some((_, '}'))) => {
+                chars.next();
+            }
+            _ => {}
+        }
+    }
+    open
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
+
+    const FORMAT_MACROS: &str = r#"
+macro_rules! println {
+    ($($arg:tt)*) => ({
+        $crate::io::_print($crate::format_args_nl!($($arg)*));
+    })
+}
+#[rustc_builtin_macro]
+macro_rules! format_args_nl {
+    ($fmt:expr) => {{ /* compiler built-in */ }};
+    ($fmt:expr, $($args:tt)*) => {{ /* compiler built-in */ }};
+}
+"#;
+
+    fn check(ra_fixture: &str, kind: CompletionKind, expect: Expect) {
+        let actual = completion_list(&format!("{}{}", FORMAT_MACROS, ra_fixture), kind);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_captured_locals() {
+        check(
+            r#"
+fn main() {
+    let name = "world";
+    let count = 92;
+    println!("hello {na<|>}");
+}
+"#,
+            CompletionKind::Reference,
+            expect![[r#"
+                bn count i32
+                bn name  &str
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_format_specs() {
+        check(
+            r#"
+fn main() {
+    let name = "world";
+    println!("hello {name:<|>}");
+}
+"#,
+            CompletionKind::Magic,
+            expect![[r#"
+                sn :#? pretty-printed Debug
+                sn :.2 with a precision
+                sn :>8 right-aligned with a width
+                sn :?  Debug
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_completions_outside_of_placeholders() {
+        check(
+            r#"
+fn main() {
+    let name = "world";
+    println!("{{na<|>}} {}", name);
+}
+"#,
+            CompletionKind::Reference,
+            expect![[""]],
+        );
+        check(
+            r#"
+fn main() {
+    let name = "world";
+    let s = "{na<|>}";
+}
+"#,
+            CompletionKind::Reference,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn replaces_the_typed_spec() {
+        check_edit(
+            ":>8",
+            &format!(
+                "{}{}",
+                FORMAT_MACROS,
+                r#"
+fn main() {
+    let x = 1.0;
+    println!("{x:<|>}");
+}
+"#
+            ),
+            &format!(
+                "{}{}",
+                FORMAT_MACROS,
+                r#"
+fn main() {
+    let x = 1.0;
+    println!("{x:>${1:8}}");
+}
+"#
+            ),
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! Completes the paths of `include!("<|>")`, `include_str!` and `include_bytes!`.
+//!
+//! The paths are relative to the including file, so the files and directories
+//! of the source root next to the directory typed so far are offered.
+
+use std::collections::BTreeSet;
+
+use ide_db::base_db::{FileId, VfsPath};
+use syntax::{TextRange, TextSize};
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_include_path(acc: &mut Completions, ctx: &CompletionContext) {
+    if !ctx.is_include_path {
+        return;
+    }
+    let source_root = ctx.source_root();
+    let current_dir = match source_root.path_for_file(&ctx.position.file_id) {
+        Some(it) => it.parent(),
+        None => return,
+    };
+    if let Some(dir) = current_dir {
+        complete_relative_path(acc, ctx, dir, |_, _| true);
+    }
+}
+
+/// Completes the names in the directory typed so far into the string literal
+/// under the caret, resolved against `base_dir`. Only files passing `accept`,
+/// and the directories containing them, are offered.
+pub(super) fn complete_relative_path(
+    acc: &mut Completions,
+    ctx: &CompletionContext,
+    base_dir: VfsPath,
+    accept: impl Fn(FileId, &VfsPath) -> bool,
+) -> Option<()> {
+    let string = ctx.string_literal_under_caret.as_ref()?;
+    let string_start = string.text_range().start();
+    let content_start = string_start + TextSize::of(&string.text()[..=string.text().find('"')?]);
+    let typed = &string.text()
+        [(content_start - string_start).into()..(ctx.position.offset - string_start).into()];
+    let name_start = typed.rfind('/').map_or(0, |it| it + 1);
+    let dir = resolve_dir(base_dir, &typed[..name_start])?;
+
+    let source_root = ctx.source_root();
+    let mut entries = BTreeSet::new();
+    for file in source_root.iter().filter(|&it| it != ctx.position.file_id) {
+        let path = match source_root.path_for_file(&file) {
+            Some(it) => it,
+            None => continue,
+        };
+        if !accept(file, path) {
+            continue;
+        }
+        if let Some(entry) = entry_in_dir(&dir, path) {
+            entries.insert(entry);
+        }
+    }
+
+    let source_range =
+        TextRange::new(content_start + TextSize::of(&typed[..name_start]), ctx.position.offset);
+    for (name, is_dir) in entries {
+        let label = if is_dir { format!("{}/", name) } else { name };
+        CompletionItem::new(CompletionKind::Magic, source_range, label)
+            .kind(CompletionItemKind::Module)
+            .add_to(acc);
+    }
+    Some(())
+}
+
+/// Resolves `relative`, like `../data/`, against the directory `dir`.
+fn resolve_dir(mut dir: VfsPath, relative: &str) -> Option<VfsPath> {
+    for component in relative.split('/') {
+        match component {
+            "" | "." => {}
+            ".." => {
+                if !dir.pop() {
+                    return None;
+                }
+            }
+            _ => dir = dir.join(component)?,
+        }
+    }
+    Some(dir)
+}
+
+/// Returns the name of the file or directory in `dir` which contains `path`,
+/// and whether it is a directory.
+fn entry_in_dir(dir: &VfsPath, path: &VfsPath) -> Option<(String, bool)> {
+    let mut entry = path.clone();
+    loop {
+        let parent = entry.parent()?;
+        if &parent == dir {
+            break;
+        }
+        if !parent.starts_with(dir) {
+            return None;
+        }
+        entry = parent;
+    }
+    let name = match entry.name_and_extension()? {
+        (name, Some(ext)) => format!("{}.{}", name, ext),
+        (name, None) => name.to_string(),
+    };
+    Some((name, &entry != path))
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Magic);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_files_and_dirs_next_to_the_file() {
+        check(
+            r#"
+//- /lib.rs
+const GRAMMAR: &str = include_str!("<|>");
+//- /grammar.ron
+//- /data/table.bin
+//- /data/nested/deep.txt
+"#,
+            expect![[r#"
+                md data/
+                md grammar.ron
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_paths_relative_to_the_typed_dir() {
+        check(
+            r#"
+//- /lib.rs
+mod foo;
+//- /foo/mod.rs
+const TABLE: &[u8] = include_bytes!("../data/t<|>");
+//- /data/table.bin
+//- /data/nested/deep.txt
+//- /foo/other.txt
+"#,
+            expect![[r#"
+                md nested/
+                md table.bin
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_completion_in_other_strings() {
+        check(
+            r#"
+//- /lib.rs
+const S: &str = concat!("<|>");
+//- /grammar.ron
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn replaces_the_typed_name() {
+        check_edit(
+            "generated.rs",
+            r#"
+//- /lib.rs
+include!("gen<|>");
+//- /generated.rs
+"#,
+            r#"
+include!("generated.rs");
+"#,
+        );
+    }
+}
@@ -0,0 +1,257 @@
+//! Completes stubs of conventional methods in inherent impls, like `new` for
+//! structs or `is_*` predicates for enum variants.
+
+use hir::{Adt, HasSource};
+use syntax::ast::{self, edit::IndentLevel, AstNode, NameOwner};
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_inherent_impl(acc: &mut Completions, ctx: &CompletionContext) {
+    if !ctx.has_impl_parent || ctx.inside_impl_trait_block || !ctx.is_trivial_path {
+        return;
+    }
+    let cap = match ctx.config.snippet_cap {
+        Some(it) => it,
+        None => return,
+    };
+    let impl_def = match &ctx.impl_def {
+        Some(it) => it,
+        None => return,
+    };
+    let adt = match ctx.sema.to_def(impl_def).and_then(|it| it.target_ty(ctx.db).as_adt()) {
+        Some(it) => it,
+        None => return,
+    };
+    let existing_fns: Vec<String> = impl_def
+        .assoc_item_list()
+        .into_iter()
+        .flat_map(|it| it.assoc_items())
+        .filter_map(|item| match item {
+            ast::AssocItem::Fn(it) => it.name(),
+            _ => None,
+        })
+        .map(|it| it.text().to_string())
+        .collect();
+
+    let indent = IndentLevel::from_node(impl_def.syntax()) + 1;
+    for stub in method_stubs(ctx, adt) {
+        if existing_fns.contains(&stub.name) {
+            continue;
+        }
+        CompletionItem::new(CompletionKind::Magic, ctx.source_range(), stub.label)
+            .kind(CompletionItemKind::Method)
+            .lookup_by(stub.name)
+            .insert_snippet(cap, stub.snippet.replace('\n', &format!("\n{}", indent)))
+            .add_to(acc);
+    }
+}
+
+struct MethodStub {
+    name: String,
+    label: String,
+    snippet: String,
+}
+
+fn method_stubs(ctx: &CompletionContext, adt: Adt) -> Vec<MethodStub> {
+    match adt {
+        Adt::Struct(it) => {
+            let strukt = it.source(ctx.db).value;
+            new_stub(strukt.field_list()).into_iter().collect()
+        }
+        Adt::Enum(it) => it
+            .source(ctx.db)
+            .value
+            .variant_list()
+            .into_iter()
+            .flat_map(|it| it.variants())
+            .filter_map(|variant| is_variant_stub(&variant))
+            .collect(),
+        Adt::Union(_) => Vec::new(),
+    }
+}
+
+/// `pub fn new(a: A, b: B) -> Self { Self { a, b } }`
+fn new_stub(field_list: Option<ast::FieldList>) -> Option<MethodStub> {
+    let (params, body) = match field_list {
+        Some(ast::FieldList::RecordFieldList(it)) => {
+            let fields: Vec<_> = it
+                .fields()
+                .filter_map(|field| Some((field.name()?.to_string(), field.ty()?.to_string())))
+                .collect();
+            let params: Vec<_> =
+                fields.iter().map(|(name, ty)| format!("{}: {}", name, ty)).collect();
+            let names: Vec<_> = fields.iter().map(|(name, _)| name.as_str()).collect();
+            (params.join(", "), format!("Self {{ {} }}", names.join(", ")))
+        }
+        Some(ast::FieldList::TupleFieldList(it)) => {
+            let tys: Vec<_> =
+                it.fields().filter_map(|field| Some(field.ty()?.to_string())).collect();
+            let params: Vec<_> =
+                tys.iter().enumerate().map(|(idx, ty)| format!("arg{}: {}", idx, ty)).collect();
+            let args: Vec<_> = (0..tys.len()).map(|idx| format!("arg{}", idx)).collect();
+            (params.join(", "), format!("Self({})", args.join(", ")))
+        }
+        None => (String::new(), "Self".to_string()),
+    };
+    Some(MethodStub {
+        name: "new".to_string(),
+        label: format!("pub fn new({}) -> Self", if params.is_empty() { "" } else { "…" }),
+        snippet: format!(
+            "pub fn new({}) -> Self {{\n    ${{0:{}}}\n}}",
+            params,
+            escape_placeholder(&body)
+        ),
+    })
+}
+
+/// `pub fn is_foo(&self) -> bool { matches!(self, Self::Foo) }`
+fn is_variant_stub(variant: &ast::Variant) -> Option<MethodStub> {
+    let variant_name = variant.name()?.to_string();
+    let pattern = match variant.field_list() {
+        Some(ast::FieldList::RecordFieldList(_)) => format!("Self::{} {{ .. }}", variant_name),
+        Some(ast::FieldList::TupleFieldList(_)) => format!("Self::{}(..)", variant_name),
+        None => format!("Self::{}", variant_name),
+    };
+    let name = format!("is_{}", stdx::to_lower_snake_case(&variant_name));
+    Some(MethodStub {
+        label: format!("pub fn {}(&self) -> bool", name),
+        snippet: format!(
+            "pub fn {}(&self) -> bool {{\n    ${{0:{}}}\n}}",
+            name,
+            escape_placeholder(&format!("matches!(self, {})", pattern))
+        ),
+        name,
+    })
+}
+
+/// Escapes the characters that end or start a snippet construct, so that
+/// `text` can be used as the default value of a placeholder.
+fn escape_placeholder(text: &str) -> String {
+    let mut buf = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '$' | '}') {
+            buf.push('\\');
+        }
+        buf.push(c);
+    }
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Magic);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_new_for_structs() {
+        check_edit(
+            "new",
+            r#"
+struct Point { x: i32, y: i32 }
+impl Point {
+    <|>
+}
+"#,
+            r#"
+struct Point { x: i32, y: i32 }
+impl Point {
+    pub fn new(x: i32, y: i32) -> Self {
+        ${0:Self { x, y \}}
+    }
+}
+"#,
+        );
+        check_edit(
+            "new",
+            r#"
+struct Meters(f64);
+impl Meters {
+    <|>
+}
+"#,
+            r#"
+struct Meters(f64);
+impl Meters {
+    pub fn new(arg0: f64) -> Self {
+        ${0:Self(arg0)}
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn completes_variant_predicates_for_enums() {
+        check(
+            r#"
+enum Shape { Circle { r: f64 }, Square(f64), Empty }
+impl Shape {
+    fn is_empty(&self) -> bool { true }
+    <|>
+}
+"#,
+            expect![[r#"
+                me pub fn is_circle(&self) -> bool
+                me pub fn is_square(&self) -> bool
+            "#]],
+        );
+    }
+
+    #[test]
+    fn indents_stubs_and_escapes_placeholders() {
+        check_edit(
+            "is_circle",
+            r#"
+mod shapes {
+    enum Shape { Circle { r: f64 } }
+    impl Shape {
+        <|>
+    }
+}
+"#,
+            r#"
+mod shapes {
+    enum Shape { Circle { r: f64 } }
+    impl Shape {
+        pub fn is_circle(&self) -> bool {
+            ${0:matches!(self, Self::Circle { .. \})}
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_stubs_in_trait_impls_and_existing_methods() {
+        check(
+            r#"
+struct S;
+trait T {}
+impl T for S {
+    <|>
+}
+"#,
+            expect![[""]],
+        );
+        check(
+            r#"
+struct S;
+impl S {
+    fn new() -> Self { S }
+    <|>
+}
+"#,
+            expect![[""]],
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! Completes the locals which convert into the expected type, like
+//! `name.into()` for a `&str` local where a `String` is expected.
+//!
+//! The conversion is looked up as an implementation of `From` for the expected
+//! type, which is how `Into` is implemented. These items are ranked below the
+//! locals of the expected type itself.
+
+use hir::HirDisplay;
+use ide_db::helpers::FamousDefs;
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_into(acc: &mut Completions, ctx: &CompletionContext) {
+    if !ctx.is_trivial_path
+        || ctx.function_syntax.is_none()
+        || ctx.is_path_type
+        || ctx.is_pat_binding_or_const
+    {
+        return;
+    }
+    let expected = match ctx.expected_types().find(|it| !it.is_unknown()) {
+        Some(it) => it,
+        None => return,
+    };
+    let from_trait = match FamousDefs(&ctx.sema, ctx.krate).core_convert_From() {
+        Some(it) => it,
+        None => return,
+    };
+
+    for (name, local) in ctx.locals.iter() {
+        let ty = local.ty(ctx.db);
+        if ty.is_unknown()
+            || ty == expected
+            || !expected.impls_trait(ctx.db, from_trait, std::slice::from_ref(&ty))
+        {
+            continue;
+        }
+        CompletionItem::new(CompletionKind::Magic, ctx.source_range(), format!("{}.into()", name))
+            .kind(CompletionItemKind::Binding)
+            .detail(format!("{} into {}", ty.display(ctx.db), expected.display(ctx.db)))
+            .add_to(acc);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list, with_core},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(&with_core(ra_fixture), CompletionKind::Magic);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_locals_convertible_into_expected_type() {
+        check(
+            r#"
+struct Name;
+struct Id;
+struct Other;
+impl From<Id> for Name { fn from(id: Id) -> Name { Name } }
+fn greet(name: Name) {}
+fn main(id: Id, other: Other, name: Name) { greet(<|>) }
+"#,
+            expect![[r#"
+                bn id.into() Id into Name
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_conversions_without_expected_type() {
+        check(
+            r#"
+struct Name;
+struct Id;
+impl From<Id> for Name { fn from(id: Id) -> Name { Name } }
+fn main(id: Id) { let name = <|>; }
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn inserts_into_call() {
+        check_edit(
+            "id.into()",
+            &with_core(
+                r#"
+struct Name;
+struct Id;
+impl From<Id> for Name { fn from(id: Id) -> Name { Name } }
+fn main(id: Id) { let name: Name = <|>; }
+"#,
+            ),
+            r#"
+struct Name;
+struct Id;
+impl From<Id> for Name { fn from(id: Id) -> Name { Name } }
+fn main(id: Id) { let name: Name = id.into(); }
+"#,
+        );
+    }
+}
@@ -0,0 +1,299 @@
+//! Completes the paths of intra-doc links, like `/// See [Foo::ba<|>]` or
+//! `/// [the parser](crate::pa<|>)`.
+//!
+//! Links are resolved from the scope of the documented item, so besides the
+//! names in scope, paths starting with `crate`, `self`, `super` and `Self` are
+//! completed. The items of the enclosing impl and of the crate root, which
+//! aren't in scope by their name, are offered as `Self::item` and
+//! `crate::Item` right away.
+
+use hir::{Adt, ModuleDef, PathResolution, ScopeDef};
+use rustc_hash::FxHashSet;
+use syntax::ast;
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_intra_doc_link(acc: &mut Completions, ctx: &CompletionContext) {
+    let link = match &ctx.intra_doc_link {
+        Some(it) => it,
+        None => return,
+    };
+    let qualifier = match &link.qualifier {
+        Some(it) => it,
+        None => {
+            complete_unqualified(acc, ctx);
+            return;
+        }
+    };
+    let resolution = match ast::Path::parse(qualifier) {
+        Ok(path) => ctx.scope.speculative_resolve(&path),
+        Err(()) => None,
+    };
+    let context_module = ctx.scope.module();
+    let ty = match resolution {
+        Some(PathResolution::Def(ModuleDef::Module(module))) => {
+            for (name, def) in module.scope(ctx.db, context_module) {
+                add_def(acc, ctx, name.to_string(), def);
+            }
+            return;
+        }
+        Some(PathResolution::Def(ModuleDef::Trait(trait_))) => {
+            for item in trait_.items(ctx.db) {
+                add_assoc_item(acc, ctx, item);
+            }
+            return;
+        }
+        Some(PathResolution::Def(ModuleDef::Adt(adt))) => {
+            if let Adt::Enum(enum_) = adt {
+                for variant in enum_.variants(ctx.db) {
+                    acc.add_enum_variant(ctx, variant, None);
+                }
+            }
+            adt.ty(ctx.db)
+        }
+        Some(PathResolution::Def(ModuleDef::TypeAlias(alias))) => alias.ty_with_params(ctx.db),
+        Some(PathResolution::SelfType(impl_def)) => impl_def.target_ty(ctx.db),
+        _ => return,
+    };
+    if let Some(krate) = ctx.krate {
+        ty.iterate_assoc_items(ctx.db, krate, |item| {
+            add_assoc_item(acc, ctx, item);
+            None::<()>
+        });
+    }
+}
+
+fn complete_unqualified(acc: &mut Completions, ctx: &CompletionContext) {
+    ctx.scope.process_all_names(&mut |name, def| match def {
+        ScopeDef::ModuleDef(_) | ScopeDef::MacroDef(_) => add_def(acc, ctx, name.to_string(), def),
+        ScopeDef::AdtSelfType(_) | ScopeDef::ImplSelfType(_) => {
+            acc.add_resolution(ctx, name.to_string(), &def)
+        }
+        ScopeDef::GenericParam(_) | ScopeDef::Local(_) | ScopeDef::Unknown => {}
+    });
+    complete_prefixed(acc, ctx);
+    let module = ctx.scope.module();
+    let has_parent = module.and_then(|it| it.parent(ctx.db)).is_some();
+    for keyword in ["crate", "self", "super"].iter().filter(|&&it| it != "super" || has_parent) {
+        CompletionItem::new(CompletionKind::Keyword, ctx.source_range(), *keyword)
+            .kind(CompletionItemKind::Keyword)
+            .add_to(acc);
+    }
+}
+
+fn complete_prefixed(acc: &mut Completions, ctx: &CompletionContext) {
+    let mut in_scope = FxHashSet::default();
+    let mut impl_def = None;
+    ctx.scope.process_all_names(&mut |name, def| {
+        if let ScopeDef::ImplSelfType(it) = def {
+            impl_def = Some(it);
+        }
+        in_scope.insert(name);
+    });
+    if let Some(impl_def) = impl_def {
+        for item in impl_def.items(ctx.db) {
+            let (name, def) = match item {
+                hir::AssocItem::Function(it) => (Some(it.name(ctx.db)), ModuleDef::Function(it)),
+                hir::AssocItem::Const(it) => (it.name(ctx.db), ModuleDef::Const(it)),
+                hir::AssocItem::TypeAlias(it) => (Some(it.name(ctx.db)), ModuleDef::TypeAlias(it)),
+            };
+            if let Some(name) = name {
+                add_def(acc, ctx, format!("Self::{}", name), ScopeDef::ModuleDef(def));
+            }
+        }
+    }
+    let module = match ctx.scope.module() {
+        Some(it) => it,
+        None => return,
+    };
+    let root = module.krate().root_module(ctx.db);
+    if root == module {
+        return;
+    }
+    for (name, def) in root.scope(ctx.db, Some(module)) {
+        if !in_scope.contains(&name) {
+            add_def(acc, ctx, format!("crate::{}", name), def);
+        }
+    }
+}
+
+fn add_def(acc: &mut Completions, ctx: &CompletionContext, name: String, def: ScopeDef) {
+    match def {
+        // Links to macros are written with the bang, `[vec!]`.
+        ScopeDef::MacroDef(_) => {
+            CompletionItem::new(CompletionKind::Reference, ctx.source_range(), format!("{}!", name))
+                .kind(CompletionItemKind::Macro)
+                .add_to(acc)
+        }
+        ScopeDef::ModuleDef(_) => acc.add_resolution(ctx, name, &def),
+        _ => {}
+    }
+}
+
+fn add_assoc_item(acc: &mut Completions, ctx: &CompletionContext, item: hir::AssocItem) {
+    match item {
+        hir::AssocItem::Function(func) => acc.add_function(ctx, func, None),
+        hir::AssocItem::Const(ct) => acc.add_const(ctx, ct),
+        hir::AssocItem::TypeAlias(ty) => acc.add_type_alias(ctx, ty),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Reference);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_names_in_scope() {
+        check(
+            r#"
+mod parser {}
+struct Foo;
+macro_rules! m { () => {} }
+/// See [F<|>]
+fn foo() {}
+"#,
+            expect![[r#"
+                st Foo
+                fn foo    fn foo()
+                ma m!
+                md parser
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_qualified_paths() {
+        check(
+            r#"
+mod parser {
+    pub struct Parser;
+    pub fn parse() {}
+}
+/// Uses [the parser](crate::parser::<|>).
+fn foo() {}
+"#,
+            expect![[r#"
+                st Parser
+                fn parse  pub fn parse()
+            "#]],
+        );
+        check(
+            r#"
+enum E { A, B }
+impl E { fn new() -> E { E::A } }
+/// [`E::<|>`]
+fn foo() {}
+"#,
+            expect![[r#"
+                ev A   ()
+                ev B   ()
+                fn new fn new() -> E
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_self_items() {
+        check(
+            r#"
+struct S;
+impl S {
+    /// Like [`Self::<|>`], but faster.
+    fn fast() {}
+    fn slow() {}
+    const C: u32 = 0;
+}
+"#,
+            expect![[r#"
+                ct C    const C: u32 = 0;
+                fn fast fn fast()
+                fn slow fn slow()
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_prefixed_items_out_of_scope() {
+        check(
+            r#"
+struct S;
+impl S {
+    /// Like [f<|>], but faster.
+    fn fast() {}
+    const C: u32 = 0;
+}
+"#,
+            expect![[r#"
+                st S
+                tp Self
+                ct Self::C
+                fn Self::fast fn fast()
+            "#]],
+        );
+        check(
+            r#"
+struct Root;
+mod m {
+    struct Inner;
+    /// See [R<|>]
+    fn foo() {}
+}
+"#,
+            expect![[r#"
+                st Inner
+                st crate::Root
+                md crate::m
+                fn foo         fn foo()
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_completion_outside_of_links() {
+        check(
+            r#"
+struct Foo;
+/// See F<|>
+fn foo() {}
+"#,
+            expect![[""]],
+        );
+        check(
+            r#"
+struct Foo;
+/// ```text
+/// [F<|>
+/// ```
+fn foo() {}
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn inserts_paths_without_parens() {
+        check_edit(
+            "parse",
+            r#"
+mod parser { pub fn parse(input: &str) {} }
+/// See [parser::pa<|>].
+fn foo() {}
+"#,
+            r#"
+mod parser { pub fn parse(input: &str) {} }
+/// See [parser::parse].
+fn foo() {}
+"#,
+        );
+    }
+}
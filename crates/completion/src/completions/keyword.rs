@@ -1,5 +1,6 @@
 //! Completes keywords.
 
+use hir::{ModuleDef, PathResolution};
 use syntax::{ast, SyntaxKind};
 use test_utils::mark;
 
@@ -10,20 +11,25 @@ pub(crate) fn complete_use_tree_keyword(acc: &mut Completions, ctx: &CompletionC
     let source_range = ctx.source_range();
 
     if ctx.use_item_syntax.is_some() {
+        let listed = ctx.listed_use_trees();
         if ctx.path_qual.is_none() {
             CompletionItem::new(CompletionKind::Keyword, source_range, "crate::")
                 .kind(CompletionItemKind::Keyword)
                 .insert_text("crate::")
                 .add_to(acc);
+        } else if !listed.contains("*") {
+            CompletionItem::new(CompletionKind::Keyword, source_range, "*")
+                .kind(CompletionItemKind::Keyword)
+                .detail("imports all the public items")
+                .add_to(acc);
+        }
+        if !listed.contains("self") {
+            CompletionItem::new(CompletionKind::Keyword, source_range, "self")
+                .kind(CompletionItemKind::Keyword)
+                .add_to(acc);
         }
-        CompletionItem::new(CompletionKind::Keyword, source_range, "self")
-            .kind(CompletionItemKind::Keyword)
-            .add_to(acc);
-        CompletionItem::new(CompletionKind::Keyword, source_range, "super::")
-            .kind(CompletionItemKind::Keyword)
-            .insert_text("super::")
-            .add_to(acc);
     }
+    complete_super_keyword(acc, ctx);
 
     // Suggest .await syntax for types that implement Future trait
     if let Some(receiver) = &ctx.dot_receiver {
@@ -44,6 +50,10 @@ pub(crate) fn complete_expr_keyword(acc: &mut Completions, ctx: &CompletionConte
         mark::hit!(no_keyword_completion_in_comments);
         return;
     }
+    if ctx.string_literal_under_caret.is_some() {
+        mark::hit!(no_keyword_completion_in_strings);
+        return;
+    }
     if ctx.record_lit_syntax.is_some() {
         mark::hit!(no_keyword_completion_in_record_lit);
         return;
@@ -55,31 +65,74 @@ pub(crate) fn complete_expr_keyword(acc: &mut Completions, ctx: &CompletionConte
         return;
     }
     if ctx.unsafe_is_prev {
-        if ctx.has_item_list_or_source_file_parent || ctx.block_expr_parent {
-            add_keyword(ctx, acc, "fn", "fn $0() {}")
+        if ctx.has_item_list_or_source_file_parent
+            || has_trait_or_impl_parent
+            || ctx.block_expr_parent
+        {
+            add_keyword(ctx, acc, "fn", "fn ${1:name}($2) {\n    $0\n}")
         }
 
         if (ctx.has_item_list_or_source_file_parent) || ctx.block_expr_parent {
-            add_keyword(ctx, acc, "trait", "trait $0 {}");
-            add_keyword(ctx, acc, "impl", "impl $0 {}");
+            add_keyword(ctx, acc, "trait", "trait ${1:Name} {\n    $0\n}");
+            add_keyword(ctx, acc, "impl", "impl ${1:Type} {\n    $0\n}");
         }
 
         return;
     }
-    if ctx.has_item_list_or_source_file_parent || has_trait_or_impl_parent || ctx.block_expr_parent
-    {
+    if ctx.default_is_prev_in_impl {
         add_keyword(ctx, acc, "fn", "fn $0() {}");
+        add_keyword(ctx, acc, "const", "const ");
+        add_keyword(ctx, acc, "type", "type ");
+        add_keyword(ctx, acc, "async", "async ");
+        add_keyword(ctx, acc, "unsafe", "unsafe ");
+        return;
+    }
+    if ctx.has_extern_block_parent {
+        add_keyword(ctx, acc, "fn", "fn $0();");
+        add_keyword(ctx, acc, "static", "static ");
+        add_keyword(ctx, acc, "type", "type $0;");
+        return;
+    }
+    if has_trait_or_impl_parent {
+        add_keyword(ctx, acc, "fn", "fn $0() {}");
+        add_keyword(ctx, acc, "const", "const ");
+        add_keyword(ctx, acc, "type", "type ");
+        add_keyword(ctx, acc, "async", "async ");
+        add_keyword(ctx, acc, "unsafe", "unsafe ");
+        // Items of trait impls take the visibility of the trait.
+        if ctx.has_impl_parent && !ctx.inside_impl_trait_block {
+            complete_visibility_keywords(acc, ctx);
+        }
+        if ctx.has_impl_parent
+            && ["specialization", "min_specialization"]
+                .iter()
+                .any(|feature| is_feature_enabled(ctx, feature))
+        {
+            add_keyword(ctx, acc, "default", "default ");
+        }
+        return;
+    }
+    if ctx.has_item_list_or_source_file_parent || ctx.block_expr_parent {
+        add_keyword(ctx, acc, "fn", "fn ${1:name}($2) {\n    $0\n}");
     }
     if (ctx.has_item_list_or_source_file_parent) || ctx.block_expr_parent {
         add_keyword(ctx, acc, "use", "use ");
-        add_keyword(ctx, acc, "impl", "impl $0 {}");
-        add_keyword(ctx, acc, "trait", "trait $0 {}");
+        add_keyword(ctx, acc, "impl", "impl ${1:Type} {\n    $0\n}");
+        add_keyword(ctx, acc, "trait", "trait ${1:Name} {\n    $0\n}");
     }
 
     if ctx.has_item_list_or_source_file_parent {
-        add_keyword(ctx, acc, "enum", "enum $0 {}");
-        add_keyword(ctx, acc, "struct", "struct $0");
-        add_keyword(ctx, acc, "union", "union $0 {}");
+        add_keyword(ctx, acc, "enum", "enum ${1:Name} {\n    $0\n}");
+        add_keyword(ctx, acc, "struct", "struct ${1:Name} {\n    $0\n}");
+        add_keyword(ctx, acc, "union", "union ${1:Name} {\n    $0\n}");
+        if is_feature_enabled(ctx, "decl_macro") {
+            add_keyword(ctx, acc, "macro", "macro ${1:name}($2) {\n    $0\n}");
+        }
+    }
+
+    if ctx.is_expr && ctx.amp_is_prev {
+        add_keyword(ctx, acc, "raw const", "raw const ");
+        add_keyword(ctx, acc, "raw mut", "raw mut ");
     }
 
     if ctx.is_expr {
@@ -99,27 +152,22 @@ pub(crate) fn complete_expr_keyword(acc: &mut Completions, ctx: &CompletionConte
         add_keyword(ctx, acc, "else if", "else if $0 {}");
     }
     if (ctx.has_item_list_or_source_file_parent) || ctx.block_expr_parent {
-        add_keyword(ctx, acc, "mod", "mod $0 {}");
+        add_keyword(ctx, acc, "mod", "mod ${1:name} {\n    $0\n}");
     }
     if ctx.bind_pat_parent || ctx.ref_pat_parent {
         add_keyword(ctx, acc, "mut", "mut ");
     }
-    if ctx.has_item_list_or_source_file_parent || has_trait_or_impl_parent || ctx.block_expr_parent
-    {
-        add_keyword(ctx, acc, "const", "const ");
-        add_keyword(ctx, acc, "type", "type ");
+    if ctx.has_item_list_or_source_file_parent || ctx.block_expr_parent {
+        add_keyword(ctx, acc, "const", "const ${1:NAME}: ${2:Type} = $0;");
+        add_keyword(ctx, acc, "type", "type ${1:Name} = $0;");
     }
     if (ctx.has_item_list_or_source_file_parent) || ctx.block_expr_parent {
-        add_keyword(ctx, acc, "static", "static ");
+        add_keyword(ctx, acc, "static", "static ${1:NAME}: ${2:Type} = $0;");
     };
     if (ctx.has_item_list_or_source_file_parent) || ctx.block_expr_parent {
         add_keyword(ctx, acc, "extern", "extern ");
     }
-    if ctx.has_item_list_or_source_file_parent
-        || has_trait_or_impl_parent
-        || ctx.block_expr_parent
-        || ctx.is_match_arm
-    {
+    if ctx.has_item_list_or_source_file_parent || ctx.block_expr_parent || ctx.is_match_arm {
         add_keyword(ctx, acc, "unsafe", "unsafe ");
     }
     if ctx.in_loop_body {
@@ -131,12 +179,14 @@ pub(crate) fn complete_expr_keyword(acc: &mut Completions, ctx: &CompletionConte
             add_keyword(ctx, acc, "break", "break");
         }
     }
-    if ctx.has_item_list_or_source_file_parent || ctx.has_impl_parent | ctx.has_field_list_parent {
-        add_keyword(ctx, acc, "pub(crate)", "pub(crate) ");
-        add_keyword(ctx, acc, "pub", "pub ");
+    if ctx.can_be_trait_object {
+        add_keyword(ctx, acc, "dyn", "dyn ");
+    }
+    if ctx.has_item_list_or_source_file_parent || ctx.has_field_list_parent {
+        complete_visibility_keywords(acc, ctx);
     }
 
-    if !ctx.is_trivial_path {
+    if !ctx.is_trivial_path || ctx.is_path_type {
         return;
     }
     let fn_def = match &ctx.function_syntax {
@@ -146,6 +196,26 @@ pub(crate) fn complete_expr_keyword(acc: &mut Completions, ctx: &CompletionConte
     acc.add_all(complete_return(ctx, &fn_def, ctx.can_be_stmt));
 }
 
+/// `pub(super)` is only offered when there is a parent module to go to, and
+/// `pub(in …)` only when the path can be left for the snippet to fill in.
+fn complete_visibility_keywords(acc: &mut Completions, ctx: &CompletionContext) {
+    add_keyword(ctx, acc, "pub(crate)", "pub(crate) ");
+    if ctx.scope.module().and_then(|it| it.parent(ctx.db)).is_some() {
+        add_keyword(ctx, acc, "pub(super)", "pub(super) ");
+    }
+    if ctx.config.snippet_cap.is_some() {
+        add_keyword(ctx, acc, "pub(in …)", "pub(in $0) ");
+    }
+    add_keyword(ctx, acc, "pub", "pub ");
+}
+
+fn is_feature_enabled(ctx: &CompletionContext, feature: &str) -> bool {
+    match ctx.krate {
+        Some(krate) => krate.features(ctx.db).iter().any(|it| it == feature),
+        None => false,
+    }
+}
+
 fn keyword(ctx: &CompletionContext, kw: &str, snippet: &str) -> CompletionItem {
     let res = CompletionItem::new(CompletionKind::Keyword, ctx.source_range(), kw)
         .kind(CompletionItemKind::Keyword);
@@ -157,6 +227,47 @@ fn keyword(ctx: &CompletionContext, kw: &str, snippet: &str) -> CompletionItem {
     .build()
 }
 
+/// `super` can start a path in `use` items and follow other `super`s in any
+/// path, as long as there is a parent module left to go to.
+fn complete_super_keyword(acc: &mut Completions, ctx: &CompletionContext) {
+    let module = match &ctx.path_qual {
+        None if ctx.use_item_syntax.is_some() => ctx.scope.module(),
+        Some(qual) if is_super_chain(qual) => match ctx.sema.resolve_path(qual) {
+            Some(PathResolution::Def(ModuleDef::Module(it))) => Some(it),
+            _ => None,
+        },
+        _ => None,
+    };
+    let parent = match module.and_then(|it| it.parent(ctx.db)) {
+        Some(it) => it,
+        None => return,
+    };
+    let mut path = vec!["crate".to_string()];
+    path.extend(
+        parent
+            .path_to_root(ctx.db)
+            .iter()
+            .rev()
+            .filter_map(|it| Some(it.name(ctx.db)?.to_string())),
+    );
+    CompletionItem::new(CompletionKind::Keyword, ctx.source_range(), "super::")
+        .kind(CompletionItemKind::Keyword)
+        .insert_text("super::")
+        .detail(path.join("::"))
+        .add_to(acc);
+}
+
+fn is_super_chain(path: &ast::Path) -> bool {
+    let mut path = Some(path.clone());
+    while let Some(it) = path {
+        if !matches!(it.segment().and_then(|it| it.kind()), Some(ast::PathSegmentKind::SuperKw)) {
+            return false;
+        }
+        path = it.qualifier();
+    }
+    true
+}
+
 fn add_keyword(ctx: &CompletionContext, acc: &mut Completions, kw: &str, snippet: &str) {
     acc.add(keyword(ctx, kw, snippet));
 }
@@ -180,8 +291,8 @@ mod tests {
     use expect_test::{expect, Expect};
 
     use crate::{
-        test_utils::{check_edit, completion_list},
-        CompletionKind,
+        test_utils::{check_edit, completion_list, completion_list_with_config},
+        CompletionConfig, CompletionKind,
     };
     use test_utils::mark;
 
@@ -197,23 +308,54 @@ mod tests {
             expect![[r#"
                 kw crate::
                 kw self
-                kw super::
             "#]],
         );
 
         check(
             r"use a::<|>",
             expect![[r#"
+                kw *    imports all the public items
                 kw self
-                kw super::
             "#]],
         );
 
         check(
             r"use a::{b, <|>}",
             expect![[r#"
+                kw *    imports all the public items
                 kw self
-                kw super::
+            "#]],
+        );
+        check(r"use a::{self, *, <|>}", expect![[""]]);
+    }
+
+    #[test]
+    fn completes_super_while_there_is_a_parent_module() {
+        check(
+            r#"
+mod a { mod b { mod c { fn f() { super::super::<|> } } } }
+"#,
+            expect![[r#"
+                kw super:: crate
+            "#]],
+        );
+        check(
+            r#"
+mod a { mod b { mod c { use super::super::super::<|>; } } }
+"#,
+            expect![[r#"
+                kw *    imports all the public items
+                kw self
+            "#]],
+        );
+        check(
+            r#"
+mod a { mod b { mod c { use <|>; } } }
+"#,
+            expect![[r#"
+                kw crate::
+                kw self
+                kw super:: crate::a::b
             "#]],
         );
     }
@@ -231,6 +373,7 @@ mod tests {
                 kw mod
                 kw pub
                 kw pub(crate)
+                kw pub(in …)
                 kw static
                 kw struct
                 kw trait
@@ -352,6 +495,7 @@ fn quux() -> i32 {
         check(
             r"trait My { <|> }",
             expect![[r#"
+                kw async
                 kw const
                 kw fn
                 kw type
@@ -365,16 +509,55 @@ fn quux() -> i32 {
         check(
             r"impl My { <|> }",
             expect![[r#"
+                kw async
                 kw const
                 kw fn
                 kw pub
                 kw pub(crate)
+                kw pub(in …)
+                kw type
+                kw unsafe
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_keywords_in_trait_impl_def() {
+        check(
+            r"impl Tr for My { <|> }",
+            expect![[r#"
+                kw async
+                kw const
+                kw fn
                 kw type
                 kw unsafe
             "#]],
         );
     }
 
+    #[test]
+    fn test_keywords_after_unsafe_in_trait_def() {
+        check(
+            r"trait My { unsafe <|> }",
+            expect![[r#"
+                kw fn
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_keywords_in_extern_block() {
+        check(
+            r#"extern "C" { <|> }"#,
+            expect![[r#"
+                kw fn
+                kw static
+                kw type
+            "#]],
+        );
+        check_edit("fn", r#"extern "C" { <|> }"#, r#"extern "C" { fn $0(); }"#);
+    }
+
     #[test]
     fn test_keywords_in_loop() {
         check(
@@ -465,6 +648,49 @@ fn quux() -> i32 {
         );
     }
 
+    #[test]
+    fn no_return_in_type_position() {
+        check(r"fn foo() { let x: <|> }", expect![[""]]);
+    }
+
+    #[test]
+    fn completes_dyn_in_trait_object_positions() {
+        check(
+            r"fn foo(x: &<|>) {}",
+            expect![[r#"
+                kw dyn
+            "#]],
+        );
+        check(
+            r"fn foo(x: &mut <|>) {}",
+            expect![[r#"
+                kw dyn
+            "#]],
+        );
+        check(
+            r"struct Box<T>(T); fn foo(x: Box<<|>>) {}",
+            expect![[r#"
+                kw dyn
+            "#]],
+        );
+        check_edit("dyn", r"fn foo(x: &<|>) {}", r"fn foo(x: &dyn ) {}");
+        check(r"struct Vec<T>(T); fn foo(x: Vec<<|>>) {}", expect![[""]]);
+        check(
+            r"fn foo() { let x = &<|> }",
+            expect![[r#"
+                kw if
+                kw if let
+                kw loop
+                kw match
+                kw mut
+                kw raw const
+                kw raw mut
+                kw return
+                kw while
+            "#]],
+        );
+    }
+
     #[test]
     fn no_keyword_completion_in_comments() {
         mark::check!(no_keyword_completion_in_comments);
@@ -493,6 +719,32 @@ Some multi-line comment<|>
         );
     }
 
+    #[test]
+    fn no_keyword_completion_in_strings() {
+        mark::check!(no_keyword_completion_in_strings);
+        check(
+            r##"
+macro_rules! m { ($($tt:tt)*) => { $($tt)* } }
+fn test() { m!(r#"fo<|>"#); }
+"##,
+            expect![[""]],
+        );
+        check(
+            r#"
+macro_rules! m { ($($tt:tt)*) => { $($tt)* } }
+fn test() { m!(b"fo<|>"); }
+"#,
+            expect![[""]],
+        );
+        check(
+            r#"
+macro_rules! m { ($($tt:tt)*) => { $($tt)* } }
+fn test() { m!(br"fo<|>"); }
+"#,
+            expect![[""]],
+        );
+    }
+
     #[test]
     fn test_completion_await_impls_future() {
         check(
@@ -564,10 +816,43 @@ struct Foo {
             expect![[r#"
                 kw pub
                 kw pub(crate)
+                kw pub(in …)
             "#]],
         )
     }
 
+    #[test]
+    fn completes_visibilities_of_nested_modules() {
+        check(
+            r#"
+mod m {
+    struct Foo {
+        <|>
+    }
+}
+"#,
+            expect![[r#"
+                kw pub
+                kw pub(crate)
+                kw pub(in …)
+                kw pub(super)
+            "#]],
+        );
+        check_edit("pub(in …)", r#"mod m { <|> }"#, r#"mod m { pub(in $0)  }"#);
+    }
+
+    #[test]
+    fn no_pub_in_without_snippets() {
+        let config = CompletionConfig { snippet_cap: None, ..CompletionConfig::default() };
+        let actual =
+            completion_list_with_config(config, r#"struct Foo { <|> }"#, CompletionKind::Keyword);
+        expect![[r#"
+            kw pub
+            kw pub(crate)
+        "#]]
+        .assert_eq(&actual);
+    }
+
     #[test]
     fn skip_struct_initializer() {
         mark::check!(no_keyword_completion_in_record_lit);
@@ -609,4 +894,73 @@ fn foo() {
             "#]],
         );
     }
+
+    #[test]
+    fn item_keywords_complete_whole_items() {
+        check_edit("struct", r#"st<|>"#, "struct ${1:Name} {\n    $0\n}");
+        check_edit("fn", r#"mod m { f<|> }"#, "mod m { fn ${1:name}($2) {\n    $0\n} }");
+        check_edit("const", r#"co<|>"#, "const ${1:NAME}: ${2:Type} = $0;");
+        check_edit("type", r#"fn main() { ty<|> }"#, "fn main() { type ${1:Name} = $0; }");
+    }
+
+    #[test]
+    fn weak_keywords_are_contextual() {
+        let weak_keywords = |ra_fixture: &str| {
+            completion_list(ra_fixture, CompletionKind::Keyword)
+                .lines()
+                .filter(|it| ["union", "macro", "raw"].iter().any(|kw| it.contains(kw)))
+                .map(|it| format!("{}\n", it.trim()))
+                .collect::<String>()
+        };
+        expect![[r#"
+            kw union
+        "#]]
+        .assert_eq(&weak_keywords(r"mod m { u<|> }"));
+        expect![[r#"
+            kw macro
+            kw union
+        "#]]
+        .assert_eq(&weak_keywords("#![feature(decl_macro)]\nm<|>"));
+        expect![[r#"
+            kw raw const
+            kw raw mut
+        "#]]
+        .assert_eq(&weak_keywords(r"fn f() { let x = &r<|> }"));
+        expect![[""]].assert_eq(&weak_keywords(r"fn f() { let x = r<|> }"));
+        expect![[""]].assert_eq(&weak_keywords(r"fn f(x: &r<|>) {}"));
+        check_edit("raw const", r"fn f() { let x = &r<|> }", r"fn f() { let x = &raw const  }");
+    }
+
+    #[test]
+    fn default_keyword_requires_specialization() {
+        let default_keyword = |ra_fixture: &str| {
+            completion_list(ra_fixture, CompletionKind::Keyword)
+                .lines()
+                .filter(|it| it.contains("default"))
+                .map(|it| format!("{}\n", it.trim()))
+                .collect::<String>()
+        };
+        expect![[""]].assert_eq(&default_keyword(r"trait T {} impl T for () { d<|> }"));
+        expect![[r#"
+            kw default
+        "#]]
+        .assert_eq(&default_keyword(
+            "#![feature(specialization)]\ntrait T {} impl T for () { d<|> }",
+        ));
+        expect![[r#"
+            kw default
+        "#]]
+        .assert_eq(&default_keyword("#![feature(min_specialization)]\nstruct S; impl S { d<|> }"));
+        expect![[""]].assert_eq(&default_keyword("#![feature(specialization)]\ntrait T { d<|> }"));
+        check(
+            "#![feature(specialization)]\ntrait T {} impl T for () { default <|> }",
+            expect![[r#"
+                kw async
+                kw const
+                kw fn
+                kw type
+                kw unsafe
+            "#]],
+        );
+    }
 }
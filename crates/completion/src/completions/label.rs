@@ -0,0 +1,159 @@
+//! Completes loop labels, after `break` and `continue` as well as where a
+//! loop is labeled.
+
+use syntax::{
+    ast::{self, LoopBodyOwner},
+    match_ast, AstNode, SyntaxKind, SyntaxNode,
+};
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_label(acc: &mut Completions, ctx: &CompletionContext) {
+    let lifetime = match &ctx.lifetime_token {
+        Some(it) => it,
+        None => return,
+    };
+    if ctx.is_label_ref {
+        for label in enclosing_loops(&lifetime.parent()).filter_map(|(_, label)| label) {
+            add_label(acc, ctx, label);
+        }
+    } else if ctx.is_label_def {
+        complete_fresh_label(acc, ctx, &lifetime.parent());
+    }
+}
+
+/// Offers `'outer` for a loop with no loop around it and `'inner` for one
+/// nested in another, numbered if the name is taken already.
+fn complete_fresh_label(acc: &mut Completions, ctx: &CompletionContext, label: &SyntaxNode) {
+    let labeled_loop = match label.parent() {
+        Some(it) => it,
+        None => return,
+    };
+    let outer_loops: Vec<_> = match labeled_loop.parent() {
+        Some(parent) => enclosing_loops(&parent).collect(),
+        None => return,
+    };
+    let base = if outer_loops.is_empty() { "'outer" } else { "'inner" };
+    let taken = |name: &str| outer_loops.iter().any(|(_, label)| label.as_deref() == Some(name));
+    let fresh = std::iter::once(base.to_string())
+        .chain((2..).map(|idx| format!("{}{}", base, idx)))
+        .find(|name| !taken(name));
+    if let Some(name) = fresh {
+        add_label(acc, ctx, name);
+    }
+}
+
+/// The loops around `node` with their labels, innermost first. Labels are not
+/// visible in closures and items, so the search stops at them.
+fn enclosing_loops(node: &SyntaxNode) -> impl Iterator<Item = (SyntaxNode, Option<String>)> {
+    node.ancestors()
+        .take_while(|it| it.kind() != SyntaxKind::CLOSURE_EXPR && !ast::Item::can_cast(it.kind()))
+        .filter_map(|node| {
+            let label = match_ast! {
+                match node {
+                    ast::LoopExpr(it) => it.label(),
+                    ast::WhileExpr(it) => it.label(),
+                    ast::ForExpr(it) => it.label(),
+                    _ => return None,
+                }
+            };
+            let label = label.and_then(|it| it.lifetime_token()).map(|it| it.text().to_string());
+            Some((node, label))
+        })
+}
+
+fn add_label(acc: &mut Completions, ctx: &CompletionContext, name: String) {
+    CompletionItem::new(CompletionKind::Reference, ctx.source_range(), name)
+        .kind(CompletionItemKind::Label)
+        .add_to(acc);
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Reference);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_labels_of_enclosing_loops() {
+        check(
+            r#"
+fn foo() {
+    'outer: for _ in 0..10 {
+        'unrelated: loop {}
+        'inner: while true {
+            loop { break '<|> }
+        }
+    }
+}
+"#,
+            expect![[r#"
+                lb 'inner
+                lb 'outer
+            "#]],
+        );
+        check_edit(
+            "'outer",
+            r#"
+fn foo() {
+    'outer: loop { loop { continue '<|> } }
+}
+"#,
+            r#"
+fn foo() {
+    'outer: loop { loop { continue 'outer } }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn labels_are_not_visible_in_closures() {
+        check(
+            r#"
+fn foo() {
+    'outer: loop {
+        let f = || 'inner: loop { break '<|> };
+    }
+}
+"#,
+            expect![[r#"
+                lb 'inner
+            "#]],
+        );
+    }
+
+    #[test]
+    fn suggests_a_name_for_a_new_label() {
+        check(
+            r#"
+fn foo() {
+    '<|>: loop {}
+}
+"#,
+            expect![[r#"
+                lb 'outer
+            "#]],
+        );
+        check(
+            r#"
+fn foo() {
+    'inner: loop {
+        '<|>: loop {}
+    }
+}
+"#,
+            expect![[r#"
+                lb 'inner2
+            "#]],
+        );
+    }
+}
@@ -0,0 +1,228 @@
+//! Completes lifetimes, both where they are used and where `for<>` binders
+//! introduce them.
+
+use rustc_hash::FxHashSet;
+use syntax::{ast, AstNode, SyntaxKind, SyntaxNode, SyntaxToken};
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_lifetime(acc: &mut Completions, ctx: &CompletionContext) {
+    let lifetime = match &ctx.lifetime_token {
+        Some(it) => it,
+        None => return,
+    };
+    let parent = lifetime.parent();
+    match parent.kind() {
+        SyntaxKind::LIFETIME_PARAM => {
+            let param_lifetime =
+                ast::LifetimeParam::cast(parent).and_then(|it| it.lifetime_token());
+            match param_lifetime {
+                // In `'b: 'a`, the lifetimes after the colon are bounds.
+                Some(param_lifetime) if param_lifetime != *lifetime => {
+                    for name in lifetimes_in_scope(&lifetime.parent(), Some(lifetime)) {
+                        if name != param_lifetime.text().as_str() {
+                            add_lifetime(acc, ctx, name);
+                        }
+                    }
+                    add_lifetime(acc, ctx, "'static".to_string());
+                }
+                _ => complete_fresh_lifetime(acc, ctx, lifetime),
+            }
+        }
+        SyntaxKind::REF_TYPE
+        | SyntaxKind::LIFETIME_ARG
+        | SyntaxKind::TYPE_BOUND
+        | SyntaxKind::WHERE_PRED
+        | SyntaxKind::SELF_PARAM => {
+            for name in lifetimes_in_scope(&lifetime.parent(), Some(lifetime)) {
+                add_lifetime(acc, ctx, name);
+            }
+            add_lifetime(acc, ctx, "'static".to_string());
+        }
+        _ => (),
+    }
+}
+
+/// Offers a lifetime name that isn't taken yet, as the parameter being
+/// declared has no name worth completing.
+fn complete_fresh_lifetime(acc: &mut Completions, ctx: &CompletionContext, lifetime: &SyntaxToken) {
+    let taken = lifetimes_in_scope(&lifetime.parent(), Some(lifetime));
+    let fresh =
+        (b'a'..=b'z').map(|it| format!("'{}", it as char)).find(|name| !taken.contains(name));
+    if let Some(name) = fresh {
+        add_lifetime(acc, ctx, name);
+    }
+}
+
+/// Lifetimes bound by the items and `for<>` binders enclosing `node`,
+/// innermost first, other than the `lifetime` being declared. Items nested in
+/// function bodies don't see the generics around them, so the search stops at
+/// the first item outside of an impl or a trait.
+pub(super) fn lifetimes_in_scope(node: &SyntaxNode, lifetime: Option<&SyntaxToken>) -> Vec<String> {
+    let mut seen = FxHashSet::default();
+    let mut res = Vec::new();
+    for node in node.ancestors() {
+        if let Some(param_list) = node.children().find_map(ast::GenericParamList::cast) {
+            for param in param_list.lifetime_params() {
+                match param.lifetime_token() {
+                    Some(it) if Some(&it) != lifetime => {
+                        let name = it.text().to_string();
+                        if seen.insert(name.clone()) {
+                            res.push(name);
+                        }
+                    }
+                    _ => (),
+                }
+            }
+        }
+        if ast::Item::can_cast(node.kind()) && !is_assoc_item(&node) {
+            break;
+        }
+    }
+    res
+}
+
+pub(super) fn is_assoc_item(item: &SyntaxNode) -> bool {
+    item.parent().filter(|it| it.kind() == SyntaxKind::ASSOC_ITEM_LIST).is_some()
+}
+
+pub(super) fn add_lifetime(acc: &mut Completions, ctx: &CompletionContext, name: String) {
+    CompletionItem::new(CompletionKind::Reference, ctx.source_range(), name)
+        .kind(CompletionItemKind::TypeParam)
+        .add_to(acc);
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Reference);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_lifetimes_of_enclosing_items() {
+        check(
+            r#"
+struct S<'s>(&'s str);
+impl<'i> S<'i> {
+    fn foo<'f>(&self, x: &'<|> str) {}
+}
+"#,
+            expect![[r#"
+                tp 'f
+                tp 'i
+                tp 'static
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_lifetimes_bound_by_hrtb() {
+        check(
+            r#"
+fn foo<'x, F>(f: F) where F: for<'a> Fn(&'<|> str) {}
+"#,
+            expect![[r#"
+                tp 'a
+                tp 'static
+                tp 'x
+            "#]],
+        );
+        check(
+            r#"
+fn foo<F>(f: F) where for<'a> F: Fn(&'a str) -> &'<|> str {}
+"#,
+            expect![[r#"
+                tp 'a
+                tp 'static
+            "#]],
+        );
+        check_edit(
+            "'a",
+            r#"
+fn foo<F>(f: F) where F: for<'a> Fn(&'<|> str) {}
+"#,
+            r#"
+fn foo<F>(f: F) where F: for<'a> Fn(&'a str) {}
+"#,
+        );
+    }
+
+    #[test]
+    fn completes_fresh_lifetime_in_binder() {
+        check(
+            r#"
+fn foo<'a, F>(f: F) where F: for<'<|>> Fn(&'a str) {}
+"#,
+            expect![[r#"
+                tp 'b
+            "#]],
+        );
+        check_edit(
+            "'a",
+            r#"
+fn foo<F>(f: F) where F: for<'<|>> Fn(&str) {}
+"#,
+            r#"
+fn foo<F>(f: F) where F: for<'a> Fn(&str) {}
+"#,
+        );
+    }
+
+    #[test]
+    fn nested_items_do_not_see_outer_lifetimes() {
+        check(
+            r#"
+fn outer<'o>() {
+    fn inner<'i>(x: &'<|> str) {}
+}
+"#,
+            expect![[r#"
+                tp 'i
+                tp 'static
+            "#]],
+        );
+    }
+
+    #[test]
+    fn only_labels_after_break() {
+        check(
+            r#"
+fn foo<'a>() {
+    'outer: loop { break '<|> }
+}
+"#,
+            expect![[r#"
+                lb 'outer
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_bounds_of_lifetime_params() {
+        check(
+            r#"
+fn foo<'a, 'b: '<|>>() {}
+"#,
+            expect![[r#"
+                tp 'a
+                tp 'static
+            "#]],
+        );
+        check(
+            r#"
+struct S<'a, '<|>>(&'a ());
+"#,
+            expect![[r#"
+                tp 'b
+            "#]],
+        );
+    }
+}
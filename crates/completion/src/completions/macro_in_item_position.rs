@@ -1,15 +1,22 @@
 //! Completes macro invocations used in item position.
 
-use crate::{CompletionContext, Completions};
+use rustc_hash::FxHashSet;
+
+use crate::{
+    completions::unqualified_path::complete_exported_macros, CompletionContext, Completions,
+};
 
 pub(crate) fn complete_macro_in_item_position(acc: &mut Completions, ctx: &CompletionContext) {
     // Show only macros in top level.
     if ctx.is_new_item {
+        let mut macros_in_scope = FxHashSet::default();
         ctx.scope.process_all_names(&mut |name, res| {
             if let hir::ScopeDef::MacroDef(mac) = res {
+                macros_in_scope.insert(name.to_string());
                 acc.add_macro(ctx, Some(name.to_string()), mac);
             }
-        })
+        });
+        complete_exported_macros(acc, ctx, &macros_in_scope);
     }
 }
 
@@ -38,4 +45,19 @@ fn foo() {}
             "#]],
         )
     }
+
+    #[test]
+    fn completes_exported_macros_defined_later() {
+        check(
+            r#"
+mod m { <|> }
+#[macro_export]
+macro_rules! exported { () => {} }
+"#,
+            expect![[r##"
+                ma exported!(…) #[macro_export]
+                macro_rules! exported
+            "##]],
+        )
+    }
 }
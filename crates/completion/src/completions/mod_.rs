@@ -4,6 +4,7 @@ use hir::{Module, ModuleSource};
 use ide_db::base_db::{SourceDatabaseExt, VfsPath};
 use ide_db::RootDatabase;
 use rustc_hash::FxHashSet;
+use syntax::SyntaxKind::MODULE;
 
 use crate::{CompletionItem, CompletionItemKind};
 
@@ -83,6 +84,30 @@ pub(crate) fn complete_mod(acc: &mut Completions, ctx: &CompletionContext) -> Op
     Some(())
 }
 
+/// Complete the file of a module declared with an explicit path, i.e. `#[path = "<|>"] mod foo;`
+pub(crate) fn complete_mod_path_attr(acc: &mut Completions, ctx: &CompletionContext) -> Option<()> {
+    if !ctx.is_mod_path_attr {
+        return None;
+    }
+    let string = ctx.string_literal_under_caret.as_ref()?;
+    let mod_declaration = string.parent().ancestors().find(|it| it.kind() == MODULE)?;
+    let current_module = ctx.sema.scope(&mod_declaration).module()?;
+    let source_root = ctx.source_root();
+    let module_file_path = source_root.path_for_file(&ctx.position.file_id)?;
+    // Outside of inline modules, the path is relative to the directory of the current file.
+    let base_directory =
+        if module_chain_to_containing_module_file(current_module, ctx.db).is_empty() {
+            module_file_path.parent()?
+        } else {
+            directory_to_look_for_submodules(current_module, ctx.db, module_file_path)?
+        };
+    // Only offer the files which aren't part of the module tree yet.
+    super::include_path::complete_relative_path(acc, ctx, base_directory, |file, path| {
+        matches!(path.name_and_extension(), Some((_, Some("rs"))))
+            && ctx.sema.to_module_def(file).is_none()
+    })
+}
+
 fn directory_to_look_for_submodules(
     module: Module,
     db: &RootDatabase,
@@ -317,4 +342,43 @@ mod tests {
             expect![[r#""#]],
         );
     }
+
+    #[test]
+    fn path_attr_completion() {
+        check(
+            r#"
+            //- /lib.rs
+            mod linked;
+            #[path = "<|>"]
+            mod foo;
+            //- /linked.rs
+            //- /generated/foo_impl.rs
+            fn foo() {}
+            //- /unlinked.rs
+            //- /data.txt
+        "#,
+            expect![[r#"
+                md generated/
+                md unlinked.rs
+            "#]],
+        );
+    }
+
+    #[test]
+    fn path_attr_completion_in_inline_module() {
+        check(
+            r#"
+            //- /lib.rs
+            mod inline {
+                #[path = "imp/<|>"]
+                mod foo;
+            }
+            //- /inline/imp/unix.rs
+            //- /imp/windows.rs
+        "#,
+            expect![[r#"
+                md unix.rs
+            "#]],
+        );
+    }
 }
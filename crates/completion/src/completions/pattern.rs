@@ -1,6 +1,15 @@
 //! Completes constats and paths in patterns.
 
-use crate::{CompletionContext, Completions};
+use hir::{HasVisibility, PathResolution, StructKind};
+use syntax::{
+    ast::{self, edit::IndentLevel},
+    AstNode,
+};
+
+use crate::{
+    completions::unqualified_path::is_pattern_item, name_suggestion, CompletionContext,
+    CompletionItem, CompletionItemKind, CompletionKind, CompletionScore, Completions,
+};
 
 /// Completes constats and paths in patterns.
 pub(crate) fn complete_pattern(acc: &mut Completions, ctx: &CompletionContext) {
@@ -11,44 +20,235 @@ pub(crate) fn complete_pattern(acc: &mut Completions, ctx: &CompletionContext) {
         return;
     }
 
-    // FIXME: ideally, we should look at the type we are matching against and
-    // suggest variants + auto-imports
+    // FIXME: suggest variants of the matched type that are not in scope, with
+    // auto-imports
     ctx.scope.process_all_names(&mut |name, res| {
         let add_resolution = match &res {
-            hir::ScopeDef::ModuleDef(def) => {
-                if ctx.is_irrefutable_let_pat_binding {
-                    matches!(def, hir::ModuleDef::Adt(hir::Adt::Struct(_)))
-                } else {
-                    matches!(
-                        def,
-                        hir::ModuleDef::Adt(hir::Adt::Enum(..))
-                            | hir::ModuleDef::Adt(hir::Adt::Struct(..))
-                            | hir::ModuleDef::EnumVariant(..)
-                            | hir::ModuleDef::Const(..)
-                            | hir::ModuleDef::Module(..)
-                    )
-                }
+            hir::ScopeDef::ModuleDef(def) if ctx.is_irrefutable_let_pat_binding => {
+                matches!(def, hir::ModuleDef::Adt(hir::Adt::Struct(_)))
             }
-            hir::ScopeDef::MacroDef(_) => true,
-            _ => false,
+            _ => is_pattern_item(&res),
         };
         if add_resolution {
             acc.add_resolution(ctx, name.to_string(), &res);
         }
     });
+    complete_struct_destructuring(acc, ctx);
+    complete_match_arms(acc, ctx);
+    complete_binding_names(acc, ctx);
+}
+
+/// Offers names for the binding of a `let`, derived from its initializer, like
+/// `config` for `let <|> = get_config();`.
+fn complete_binding_names(acc: &mut Completions, ctx: &CompletionContext) -> Option<()> {
+    if !ctx.is_irrefutable_let_pat_binding {
+        return None;
+    }
+    let let_stmt = ctx.token.parent().ancestors().find_map(ast::LetStmt::cast)?;
+    // Not the names of the parts of a tuple or a struct.
+    if !matches!(let_stmt.pat(), None | Some(ast::Pat::IdentPat(_))) {
+        return None;
+    }
+    // While the pattern is still missing, the initializer ends up in a
+    // statement of its own.
+    let initializer = match let_stmt.pat() {
+        Some(_) => let_stmt.initializer()?,
+        None => let_stmt.syntax().next_sibling().and_then(ast::ExprStmt::cast)?.expr()?,
+    };
+    let mut names: Vec<String> = name_suggestion::for_expr(&initializer).into_iter().collect();
+    if let Some(ty) = ctx.sema.type_of_expr(&initializer) {
+        names.extend(name_suggestion::for_hir_type(ctx.db, &ty));
+    }
+    names.sort();
+    names.dedup();
+    for name in names {
+        CompletionItem::new(CompletionKind::Magic, ctx.source_range(), name)
+            .kind(CompletionItemKind::Binding)
+            .add_to(acc);
+    }
+    Some(())
+}
+
+/// Offers to destructure the matched struct, like `S { foo, bar }`.
+fn complete_struct_destructuring(acc: &mut Completions, ctx: &CompletionContext) -> Option<()> {
+    let strukt = match ctx.expected_type.as_ref()?.as_adt()? {
+        hir::Adt::Struct(it) => it,
+        _ => return None,
+    };
+    let module = ctx.scope.module()?;
+    let path = module.find_use_path(ctx.db, hir::ModuleDef::Adt(strukt.into()))?.to_string();
+    let fields = strukt.fields(ctx.db);
+    let visible: Vec<_> = fields.iter().filter(|it| it.is_visible_from(ctx.db, module)).collect();
+    let pat = match strukt.kind(ctx.db) {
+        StructKind::Record => {
+            let mut names: Vec<_> = visible.iter().map(|it| it.name(ctx.db).to_string()).collect();
+            if visible.len() < fields.len() {
+                names.push("..".to_string());
+            }
+            record_pat(&path, &names)
+        }
+        StructKind::Tuple if visible.len() < fields.len() => format!("{}(..)", path),
+        StructKind::Tuple => format!("{}({})", path, vec!["_"; fields.len()].join(", ")),
+        StructKind::Unit => return None,
+    };
+    CompletionItem::new(CompletionKind::Reference, ctx.source_range(), pat)
+        .kind(CompletionItemKind::Struct)
+        .lookup_by(strukt.name(ctx.db).to_string())
+        .set_score(CompletionScore::TypeMatch)
+        .add_to(acc);
+    Some(())
+}
+
+/// Offers the arms of all the variants not matched yet at once, like
+/// `A(${1:_}) => todo!(),` followed by `B => todo!(),` for `match e { <|> }`.
+fn complete_match_arms(acc: &mut Completions, ctx: &CompletionContext) -> Option<()> {
+    let cap = ctx.config.snippet_cap?;
+    let arm_list = ctx.token.parent().ancestors().find_map(ast::MatchArmList::cast)?;
+    let (current_arms, other_arms): (Vec<ast::MatchArm>, Vec<ast::MatchArm>) = arm_list
+        .arms()
+        .partition(|it| it.syntax().text_range().contains_inclusive(ctx.position.offset));
+    // Only the start of an arm, like `match e { A<|> }`.
+    let is_arm_start = current_arms.iter().all(|it| {
+        it.fat_arrow_token().is_none() && matches!(it.pat(), Some(ast::Pat::IdentPat(_)))
+    });
+    if !is_arm_start {
+        return None;
+    }
+    // The guarded arms don't cover their patterns, and the alternatives of the
+    // or-patterns are looked at one by one.
+    let other_arms: Vec<ast::Pat> = other_arms
+        .iter()
+        .filter(|it| it.guard().is_none())
+        .filter_map(|it| it.pat())
+        .flat_map(alternatives)
+        .collect();
+    let is_catch_all = |pat: &ast::Pat| match pat {
+        ast::Pat::WildcardPat(_) => true,
+        ast::Pat::IdentPat(it) => ctx.sema.resolve_bind_pat_to_const(it).is_none(),
+        _ => false,
+    };
+    if other_arms.iter().any(is_catch_all) {
+        return None;
+    }
+    let match_expr = arm_list.syntax().parent().and_then(ast::MatchExpr::cast)?;
+    let expected = ctx.sema.type_of_expr(&match_expr.expr()?)?;
+    let module = ctx.scope.module()?;
+
+    let pats: Vec<String> = if expected.is_bool() {
+        let matched: Vec<String> = other_arms.iter().map(|it| it.syntax().to_string()).collect();
+        ["true", "false"]
+            .iter()
+            .filter(|it| !matched.iter().any(|matched| matched == *it))
+            .map(|it| it.to_string())
+            .collect()
+    } else {
+        let enum_ = match expected.as_adt()? {
+            hir::Adt::Enum(it) => it,
+            _ => return None,
+        };
+        let matched: Vec<hir::EnumVariant> =
+            other_arms.iter().filter_map(|it| matched_variant(ctx, it)).collect();
+        let mut placeholder = 0;
+        enum_
+            .variants(ctx.db)
+            .into_iter()
+            .filter(|it| !matched.contains(it))
+            .map(|variant| {
+                let path =
+                    module.find_use_path(ctx.db, hir::ModuleDef::EnumVariant(variant))?.to_string();
+                let mut bind = |name: String| {
+                    placeholder += 1;
+                    format!("${{{}:{}}}", placeholder, name)
+                };
+                let fields = variant.fields(ctx.db);
+                let pat = match variant.kind(ctx.db) {
+                    StructKind::Tuple => {
+                        let fields: Vec<_> = fields.iter().map(|_| bind("_".to_string())).collect();
+                        format!("{}({})", path, fields.join(", "))
+                    }
+                    StructKind::Record => {
+                        let fields: Vec<_> = fields
+                            .iter()
+                            .map(|it| {
+                                let name = it.name(ctx.db).to_string();
+                                format!("{}: {}", name, bind(name.clone()))
+                            })
+                            .collect();
+                        record_pat(&path, &fields)
+                    }
+                    StructKind::Unit => path,
+                };
+                Some(pat)
+            })
+            .collect::<Option<_>>()?
+    };
+    if pats.is_empty() {
+        return None;
+    }
+
+    let indent = IndentLevel::from_node(match_expr.syntax()) + 1;
+    let arms: Vec<String> = pats.iter().map(|pat| format!("{} => todo!(),", pat)).collect();
+    CompletionItem::new(CompletionKind::Snippet, ctx.source_range(), "fill match arms")
+        .kind(CompletionItemKind::Snippet)
+        .insert_snippet(cap, format!("{}$0", arms.join(&format!("\n{}", indent))))
+        .add_to(acc);
+    Some(())
+}
+
+/// A record pattern like `S { foo, bar }`, or `S {}` without fields.
+fn record_pat(path: &str, fields: &[String]) -> String {
+    if fields.is_empty() {
+        return format!("{} {{}}", path);
+    }
+    format!("{} {{ {} }}", path, fields.join(", "))
+}
+
+/// The alternatives of `pat`, like `A` and `B` for `A | (B)`.
+fn alternatives(pat: ast::Pat) -> Vec<ast::Pat> {
+    match pat {
+        ast::Pat::OrPat(it) => it.pats().flat_map(alternatives).collect(),
+        ast::Pat::ParenPat(it) => it.pat().map(alternatives).unwrap_or_default(),
+        _ => vec![pat],
+    }
+}
+
+/// The variant matched by the arm with `pat`.
+fn matched_variant(ctx: &CompletionContext, pat: &ast::Pat) -> Option<hir::EnumVariant> {
+    let path = match pat {
+        ast::Pat::IdentPat(it) => match ctx.sema.resolve_bind_pat_to_const(it)? {
+            hir::ModuleDef::EnumVariant(it) => return Some(it),
+            _ => return None,
+        },
+        ast::Pat::PathPat(it) => it.path()?,
+        ast::Pat::TupleStructPat(it) => it.path()?,
+        ast::Pat::RecordPat(it) => it.path()?,
+        _ => return None,
+    };
+    match ctx.sema.resolve_path(&path)? {
+        PathResolution::Def(hir::ModuleDef::EnumVariant(it)) => Some(it),
+        _ => None,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use expect_test::{expect, Expect};
 
-    use crate::{test_utils::completion_list, CompletionKind};
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
 
     fn check(ra_fixture: &str, expect: Expect) {
         let actual = completion_list(ra_fixture, CompletionKind::Reference);
         expect.assert_eq(&actual)
     }
 
+    fn check_snippet(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Snippet);
+        expect.assert_eq(&actual)
+    }
+
     #[test]
     fn completes_enum_variants_and_modules() {
         check(
@@ -114,4 +314,322 @@ fn foo() {
             "#]],
         );
     }
+
+    #[test]
+    fn completes_variants_of_if_let_initializer() {
+        check(
+            r#"
+enum E { A(u32, u32), B { x: u32 }, C }
+
+fn foo(e: E) {
+    if let <|> = e {}
+}
+"#,
+            expect![[r#"
+                en E
+                ev E::A(…) (u32, u32)
+                ev E::B    { x: u32 }
+                ev E::C    ()
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_struct_destructuring_in_while_let() {
+        check(
+            r#"
+struct S { foo: u32, bar: u32 }
+struct T(u32, u32);
+
+fn foo(s: S, t: T) {
+    while let <|> = s {}
+}
+"#,
+            expect![[r#"
+                st S
+                st S { foo, bar }
+                st T
+            "#]],
+        );
+        check(
+            r#"
+struct T(u32, u32);
+
+fn foo(t: T) {
+    if let <|> = t {}
+}
+"#,
+            expect![[r#"
+                st T
+                st T(_, _)
+            "#]],
+        );
+    }
+
+    #[test]
+    fn destructures_structs_without_fields() {
+        check(
+            r#"
+struct S {}
+
+fn foo(s: S) {
+    if let <|> = s {}
+}
+"#,
+            expect![[r#"
+                st S
+                st S {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn elides_private_fields_in_struct_destructuring() {
+        check(
+            r#"
+mod m {
+    pub struct S { pub foo: u32, bar: u32 }
+    pub struct T(pub u32, u32);
+}
+
+fn foo(s: m::S) {
+    if let <|> = s {}
+}
+"#,
+            expect![[r#"
+                md m
+                st m::S { foo, .. }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn inserts_payload_placeholders_for_variants() {
+        check_edit(
+            "Some",
+            r#"
+enum Option<T> { Some(T), None }
+use Option::*;
+
+fn foo(x: Option<u32>) {
+    if let <|> = x {}
+}
+"#,
+            r#"
+enum Option<T> { Some(T), None }
+use Option::*;
+
+fn foo(x: Option<u32>) {
+    if let Some(${1:_})$0 = x {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn fills_missing_match_arms() {
+        check_snippet(
+            r#"
+enum E { A(u32, u32), B { x: u32 }, C }
+fn foo(e: E) {
+    match e {
+        E::C => (),
+        <|>
+    }
+}
+"#,
+            expect![[r#"
+                sn fill match arms
+            "#]],
+        );
+        check_edit(
+            "fill match arms",
+            r#"
+enum E { A(u32, u32), B { x: u32 }, C }
+fn foo(e: E) {
+    match e {
+        E::C => (),
+        <|>
+    }
+}
+"#,
+            r#"
+enum E { A(u32, u32), B { x: u32 }, C }
+fn foo(e: E) {
+    match e {
+        E::C => (),
+        E::A(${1:_}, ${2:_}) => todo!(),
+        E::B { x: ${3:x} } => todo!(),$0
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn fills_match_arms_of_variants_without_fields() {
+        check_edit(
+            "fill match arms",
+            r#"
+enum E { A {}, B }
+fn foo(e: E) {
+    match e {
+        <|>
+    }
+}
+"#,
+            r#"
+enum E { A {}, B }
+fn foo(e: E) {
+    match e {
+        E::A {} => todo!(),
+        E::B => todo!(),$0
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn fills_match_arms_of_bools() {
+        check_edit(
+            "fill match arms",
+            r#"
+fn foo(b: bool) {
+    match b {
+        true => (),
+        <|>
+    }
+}
+"#,
+            r#"
+fn foo(b: bool) {
+    match b {
+        true => (),
+        false => todo!(),$0
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn fills_match_arms_after_variants_in_scope() {
+        check_edit(
+            "fill match arms",
+            r#"
+enum Option<T> { Some(T), None }
+use Option::*;
+fn foo(x: Option<u32>) {
+    match x {
+        None => (),
+        <|>
+    }
+}
+"#,
+            r#"
+enum Option<T> { Some(T), None }
+use Option::*;
+fn foo(x: Option<u32>) {
+    match x {
+        None => (),
+        Some(${1:_}) => todo!(),$0
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn fills_match_arms_after_or_patterns_and_guards() {
+        check_edit(
+            "fill match arms",
+            r#"
+enum E { A, B, C, D }
+fn foo(e: E, cond: bool) {
+    match e {
+        E::A | (E::B) => (),
+        E::C if cond => (),
+        _ if cond => (),
+        <|>
+    }
+}
+"#,
+            r#"
+enum E { A, B, C, D }
+fn foo(e: E, cond: bool) {
+    match e {
+        E::A | (E::B) => (),
+        E::C if cond => (),
+        _ if cond => (),
+        E::C => todo!(),
+        E::D => todo!(),$0
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_match_arms_after_wildcard() {
+        check_snippet(
+            r#"
+enum E { A, B }
+fn foo(e: E) {
+    match e {
+        _ => (),
+        <|>
+    }
+}
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn suggests_binding_names_from_initializer() {
+        let check_names = |ra_fixture: &str, expect: Expect| {
+            let actual = completion_list(ra_fixture, CompletionKind::Magic);
+            expect.assert_eq(&actual)
+        };
+        check_names(
+            r#"
+struct AppConfig;
+fn get_config() -> AppConfig { AppConfig }
+fn foo() {
+    let <|> = get_config();
+}
+"#,
+            expect![[r#"
+                bn app_config
+                bn config
+            "#]],
+        );
+        check_names(
+            r#"
+struct HashMap<K, V>;
+struct Client;
+impl Client { fn entries(&self) -> Option<HashMap<u32, u32>> { None } }
+enum Option<T> { Some(T), None }
+impl<T> Option<T> { fn unwrap(self) -> T { loop {} } }
+fn foo(c: Client) {
+    let <|> = c.entries().unwrap();
+}
+"#,
+            expect![[r#"
+                bn entries
+                bn hash_map
+                bn map
+            "#]],
+        );
+        check_names(
+            r#"
+struct Point { x: u32, y: u32 }
+fn foo(p: Point) {
+    let (a, <|>) = (p.x, p.y);
+}
+"#,
+            expect![[""]],
+        );
+    }
 }
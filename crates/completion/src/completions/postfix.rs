@@ -12,7 +12,8 @@ use text_edit::TextEdit;
 
 use self::format_like::add_format_like_completions;
 use crate::{
-    config::SnippetCap,
+    completions::imports_for,
+    config::{PostfixTemplate, SnippetCap},
     context::CompletionContext,
     item::{Builder, CompletionKind},
     CompletionItem, CompletionItemKind, Completions,
@@ -40,6 +41,7 @@ pub(crate) fn complete_postfix(acc: &mut Completions, ctx: &CompletionContext) {
         Some(it) => it,
         None => return,
     };
+    let builtins_start = acc.items().len();
     let try_enum = TryEnum::from_ty(&ctx.sema, &receiver_ty);
     if let Some(try_enum) = &try_enum {
         match try_enum {
@@ -249,6 +251,38 @@ pub(crate) fn complete_postfix(acc: &mut Completions, ctx: &CompletionContext) {
             add_format_like_completions(acc, ctx, &dot_receiver, cap, &literal_text);
         }
     }
+
+    add_custom_postfix_completions(acc, ctx, &dot_receiver, &receiver_text, cap, builtins_start);
+}
+
+/// Adds the templates from the config, dropping the built-in completions
+/// they replace.
+fn add_custom_postfix_completions(
+    acc: &mut Completions,
+    ctx: &CompletionContext,
+    receiver: &ast::Expr,
+    receiver_text: &str,
+    cap: SnippetCap,
+    builtins_start: usize,
+) {
+    let templates = &ctx.config.postfix_templates;
+    if templates.is_empty() {
+        return;
+    }
+    acc.retain_from(builtins_start, |item| {
+        !templates.iter().any(|template| template.label == item.label())
+    });
+    for template in templates {
+        let imports = match imports_for(ctx, receiver.syntax(), &template.imports) {
+            Some(it) => it,
+            None => continue,
+        };
+        let detail = template.snippet.replace(PostfixTemplate::RECEIVER, "expr");
+        let snippet = template.snippet.replace(PostfixTemplate::RECEIVER, receiver_text);
+        postfix_snippet(ctx, cap, receiver, &template.label, &detail, &snippet)
+            .require_imports(imports)
+            .add_to(acc);
+    }
 }
 
 fn get_receiver_text(receiver: &ast::Expr, receiver_is_ambiguous_float_literal: bool) -> String {
@@ -296,12 +330,16 @@ mod tests {
     use expect_test::{expect, Expect};
 
     use crate::{
-        test_utils::{check_edit, completion_list},
-        CompletionKind,
+        test_utils::{check_edit, check_edit_with_config, completion_list_with_config},
+        CompletionConfig, CompletionKind, PostfixTemplate,
     };
 
     fn check(ra_fixture: &str, expect: Expect) {
-        let actual = completion_list(ra_fixture, CompletionKind::Postfix);
+        check_with_config(CompletionConfig::default(), ra_fixture, expect)
+    }
+
+    fn check_with_config(config: CompletionConfig, ra_fixture: &str, expect: Expect) {
+        let actual = completion_list_with_config(config, ra_fixture, CompletionKind::Postfix);
         expect.assert_eq(&actual)
     }
 
@@ -547,4 +585,100 @@ fn main() {
             r#"fn main() { log::error!("{}", 2+2) }"#,
         );
     }
+
+    fn config_with_templates(templates: &[(&str, &str, &[&str])]) -> CompletionConfig {
+        let postfix_templates = templates
+            .iter()
+            .map(|(label, snippet, imports)| {
+                let imports: Vec<_> = imports.iter().map(|it| it.to_string()).collect();
+                PostfixTemplate::new(label, snippet, &imports).unwrap()
+            })
+            .collect();
+        CompletionConfig { postfix_templates, ..CompletionConfig::default() }
+    }
+
+    #[test]
+    fn custom_templates_replace_builtins_with_the_same_name() {
+        check_with_config(
+            config_with_templates(&[
+                ("wrapok", "Ok(${receiver})", &[]),
+                ("dbg", "log::debug!(\"{:?}\", ${receiver})", &[]),
+            ]),
+            r#"
+fn main() {
+    let bar = 92;
+    bar.<|>
+}
+"#,
+            expect![[r#"
+                sn box    Box::new(expr)
+                sn call   function(expr)
+                sn dbg    log::debug!("{:?}", expr)
+                sn dbgr   dbg!(&expr)
+                sn let    let
+                sn letm   let mut
+                sn match  match expr {}
+                sn ok     Ok(expr)
+                sn ref    &expr
+                sn refm   &mut expr
+                sn some   Some(expr)
+                sn wrapok Ok(expr)
+            "#]],
+        );
+        check_edit_with_config(
+            config_with_templates(&[("wrapok", "Ok(${receiver})$0", &[])]),
+            "wrapok",
+            r#"fn main() { let bar = &92; bar.<|> }"#,
+            r#"fn main() { let bar = &92; Ok(bar)$0 }"#,
+        );
+    }
+
+    #[test]
+    fn custom_templates_add_their_imports() {
+        check_edit_with_config(
+            config_with_templates(&[(
+                "shared",
+                "Arc::new(Mutex::new(${receiver}))",
+                &["std::sync::Arc", "std::sync::Mutex"],
+            )]),
+            "shared",
+            r#"
+use std::collections::HashMap;
+
+fn main() { HashMap::new().<|> }
+"#,
+            r#"
+use std::{collections::HashMap, sync::{Arc, Mutex}};
+
+fn main() { Arc::new(Mutex::new(HashMap::new())) }
+"#,
+        );
+    }
+
+    #[test]
+    fn invalid_custom_templates_are_rejected() {
+        let new = |label, snippet, imports: &[&str]| {
+            let imports: Vec<_> = imports.iter().map(|it| it.to_string()).collect();
+            PostfixTemplate::new(label, snippet, &imports).map(|_| ())
+        };
+        assert_eq!(new("wrapok", "Ok(${receiver})$0", &["crate::Wrapper"]), Ok(()));
+        assert_eq!(
+            new("wrap ok", "Ok(${receiver})", &[]).unwrap_err(),
+            "invalid postfix template name `wrap ok`"
+        );
+        assert_eq!(
+            new("wrapok", "Ok($0)", &[]).unwrap_err(),
+            "postfix template `wrapok` doesn't use ${receiver}"
+        );
+        assert_eq!(
+            new("wrapok", "Ok(${reciever})${receiver}", &[]).unwrap_err(),
+            "postfix template `wrapok` has an unknown placeholder at `${reciever})${receiver}`"
+        );
+        assert_eq!(
+            new("wrapok", "Ok(${receiver})", &["Vec<u8>"]).unwrap_err(),
+            "postfix template `wrapok` has an invalid import `Vec<u8>`"
+        );
+        assert!(new("wrapok", "Ok(${receiver})", &["crate"]).is_err());
+        assert!(new("wrapok", "Ok(${receiver})", &["super::Wrapper"]).is_err());
+    }
 }
@@ -1,11 +1,16 @@
 //! Completion of paths, i.e. `some::prefix::<|>`.
 
-use hir::{Adt, HasVisibility, PathResolution, ScopeDef};
+use hir::{known, Adt, AssocItemContainer, HasVisibility, HirDisplay, PathResolution, ScopeDef};
+use ide_db::helpers::insert_use::ImportScope;
 use rustc_hash::FxHashSet;
-use syntax::AstNode;
+use syntax::{ast, AstNode};
 use test_utils::mark;
 
-use crate::{CompletionContext, Completions};
+use crate::{
+    completions::unqualified_path::{is_implementable_type, is_pattern_item, is_valid_bound},
+    item::ImportToAdd,
+    CompletionContext, Completions,
+};
 
 pub(crate) fn complete_qualified_path(acc: &mut Completions, ctx: &CompletionContext) {
     let path = match &ctx.path_qual {
@@ -13,7 +18,10 @@ pub(crate) fn complete_qualified_path(acc: &mut Completions, ctx: &CompletionCon
         None => return,
     };
 
-    if ctx.attribute_under_caret.is_some() || ctx.mod_declaration_under_caret.is_some() {
+    if ctx.attribute_under_caret.is_some()
+        || ctx.mod_declaration_under_caret.is_some()
+        || ctx.is_visibility_path
+    {
         return;
     }
 
@@ -23,17 +31,39 @@ pub(crate) fn complete_qualified_path(acc: &mut Completions, ctx: &CompletionCon
         Some(res) => res,
         None => return,
     };
+    if ctx.is_impl_trait_path
+        && !matches!(resolution, PathResolution::Def(hir::ModuleDef::Module(_)))
+    {
+        return;
+    }
+
+    // Items of the bounds of a type parameter can be reached through several
+    // bounds, like a supertrait shared by two of them, so deduplicate them.
+    let mut seen = FxHashSet::default();
 
     // Add associated types on type parameters and `Self`.
     resolution.assoc_type_shorthand_candidates(ctx.db, |alias| {
-        acc.add_type_alias(ctx, alias);
+        let item = hir::AssocItem::TypeAlias(alias);
+        if !is_pattern_assoc_item(ctx, item) || !seen.insert(item) {
+            return None;
+        }
+        match (&resolution, item.container(ctx.db)) {
+            (PathResolution::TypeParam(_), AssocItemContainer::Trait(trait_)) => {
+                acc.add_assoc_item_of_bound(ctx, item, trait_)
+            }
+            _ => acc.add_type_alias(ctx, alias),
+        }
         None::<()>
     });
 
     match resolution {
         PathResolution::Def(hir::ModuleDef::Module(module)) => {
             let module_scope = module.scope(ctx.db, context_module);
+            let listed = ctx.listed_use_trees();
             for (name, def) in module_scope {
+                if listed.contains(&name.to_string()) {
+                    continue;
+                }
                 if ctx.use_item_syntax.is_some() {
                     if let ScopeDef::Unknown = def {
                         if let Some(name_ref) = ctx.name_ref_syntax.as_ref() {
@@ -45,12 +75,25 @@ pub(crate) fn complete_qualified_path(acc: &mut Completions, ctx: &CompletionCon
                         }
                     }
                 }
+                if !ctx.in_test_code && ctx.is_test_only(&def) {
+                    continue;
+                }
+                if ctx.is_impl_trait_path && !is_valid_bound(&def) {
+                    continue;
+                }
+                if ctx.is_impl_self_ty_path && !is_implementable_type(&def) {
+                    continue;
+                }
+                if ctx.is_pat_path && !is_pattern_item(&def) {
+                    continue;
+                }
 
                 acc.add_resolution(ctx, name.to_string(), &def);
             }
         }
         PathResolution::Def(def @ hir::ModuleDef::Adt(_))
-        | PathResolution::Def(def @ hir::ModuleDef::TypeAlias(_)) => {
+        | PathResolution::Def(def @ hir::ModuleDef::TypeAlias(_))
+        | PathResolution::Def(def @ hir::ModuleDef::BuiltinType(_)) => {
             if let hir::ModuleDef::Adt(Adt::Enum(e)) = def {
                 for variant in e.variants(ctx.db) {
                     acc.add_enum_variant(ctx, variant, None);
@@ -58,7 +101,11 @@ pub(crate) fn complete_qualified_path(acc: &mut Completions, ctx: &CompletionCon
             }
             let ty = match def {
                 hir::ModuleDef::Adt(adt) => adt.ty(ctx.db),
-                hir::ModuleDef::TypeAlias(a) => a.ty(ctx.db),
+                hir::ModuleDef::TypeAlias(a) => a.ty_with_params(ctx.db),
+                hir::ModuleDef::BuiltinType(builtin) => match context_module {
+                    Some(module) => hir::Type::from_builtin(ctx.db, module, builtin),
+                    None => return,
+                },
                 _ => unreachable!(),
             };
 
@@ -72,21 +119,30 @@ pub(crate) fn complete_qualified_path(acc: &mut Completions, ctx: &CompletionCon
                     if context_module.map_or(false, |m| !item.is_visible_from(ctx.db, m)) {
                         return None;
                     }
+                    if !is_pattern_assoc_item(ctx, item) {
+                        return None;
+                    }
                     match item {
-                        hir::AssocItem::Function(func) => {
-                            acc.add_function(ctx, func, None);
-                        }
+                        hir::AssocItem::Function(func) => match alias_substs(ctx, def, &ty, item) {
+                            Some(substs) => acc.add_function_with_substs(ctx, func, substs),
+                            None => acc.add_function(ctx, func, None),
+                        },
                         hir::AssocItem::Const(ct) => acc.add_const(ctx, ct),
                         hir::AssocItem::TypeAlias(ty) => acc.add_type_alias(ctx, ty),
                     }
                     None::<()>
                 });
 
+                complete_consts_of_unimported_traits(acc, ctx, &ty, krate);
+
                 // Iterate assoc types separately
                 ty.iterate_assoc_items(ctx.db, krate, |item| {
                     if context_module.map_or(false, |m| !item.is_visible_from(ctx.db, m)) {
                         return None;
                     }
+                    if !is_pattern_assoc_item(ctx, item) {
+                        return None;
+                    }
                     match item {
                         hir::AssocItem::Function(_) | hir::AssocItem::Const(_) => {}
                         hir::AssocItem::TypeAlias(ty) => acc.add_type_alias(ctx, ty),
@@ -101,6 +157,9 @@ pub(crate) fn complete_qualified_path(acc: &mut Completions, ctx: &CompletionCon
                 if context_module.map_or(false, |m| !item.is_visible_from(ctx.db, m)) {
                     continue;
                 }
+                if !is_pattern_assoc_item(ctx, item) {
+                    continue;
+                }
                 match item {
                     hir::AssocItem::Function(func) => {
                         acc.add_function(ctx, func, None);
@@ -118,16 +177,41 @@ pub(crate) fn complete_qualified_path(acc: &mut Completions, ctx: &CompletionCon
                     _ => return,
                 };
 
+                // `Self` in a trait also has the items of the trait itself.
+                let enclosing_trait = match resolution {
+                    PathResolution::TypeParam(param) if param.name(ctx.db) == known::SELF_TYPE => {
+                        ctx.token
+                            .parent()
+                            .ancestors()
+                            .find_map(ast::Trait::cast)
+                            .and_then(|it| ctx.sema.to_def(&it))
+                    }
+                    _ => None,
+                };
+                if let Some(trait_) = enclosing_trait {
+                    for item in trait_.items(ctx.db) {
+                        if is_pattern_assoc_item(ctx, item) && seen.insert(item) {
+                            acc.add_assoc_item_of_bound(ctx, item, trait_);
+                        }
+                    }
+                }
+
                 let traits_in_scope = ctx.scope.traits_in_scope();
-                let mut seen = FxHashSet::default();
                 ty.iterate_path_candidates(ctx.db, krate, &traits_in_scope, None, |_ty, item| {
                     if context_module.map_or(false, |m| !item.is_visible_from(ctx.db, m)) {
                         return None;
                     }
+                    if !is_pattern_assoc_item(ctx, item) {
+                        return None;
+                    }
 
-                    // We might iterate candidates of a trait multiple times here, so deduplicate
-                    // them.
                     if seen.insert(item) {
+                        if let (PathResolution::TypeParam(_), AssocItemContainer::Trait(trait_)) =
+                            (&resolution, item.container(ctx.db))
+                        {
+                            acc.add_assoc_item_of_bound(ctx, item, trait_);
+                            return None;
+                        }
                         match item {
                             hir::AssocItem::Function(func) => {
                                 acc.add_function(ctx, func, None);
@@ -144,6 +228,83 @@ pub(crate) fn complete_qualified_path(acc: &mut Completions, ctx: &CompletionCon
     }
 }
 
+/// Of the associated items, only the constants can be matched against.
+fn is_pattern_assoc_item(ctx: &CompletionContext, item: hir::AssocItem) -> bool {
+    !ctx.is_pat_path || matches!(item, hir::AssocItem::Const(_))
+}
+
+/// When going through an alias like `type IntMap<V> = HashMap<u32, V>`, the
+/// types the impl parameters of `item` take, to show in its signature.
+fn alias_substs(
+    ctx: &CompletionContext,
+    def: hir::ModuleDef,
+    ty: &hir::Type,
+    item: hir::AssocItem,
+) -> Option<Vec<(String, String)>> {
+    if !matches!(def, hir::ModuleDef::TypeAlias(_)) {
+        return None;
+    }
+    let impl_def = match item.container(ctx.db) {
+        AssocItemContainer::ImplDef(it) => it,
+        AssocItemContainer::Trait(_) => return None,
+    };
+    let substs: Vec<_> = impl_def
+        .type_params_for(ctx.db, ty)?
+        .into_iter()
+        .filter(|(_, ty)| !ty.is_unknown())
+        .map(|(name, ty)| (name.to_string(), ty.display(ctx.db).to_string()))
+        .filter(|(name, ty)| name != ty)
+        .collect();
+    if substs.is_empty() {
+        return None;
+    }
+    Some(substs)
+}
+
+/// Consts like `u32::MAX` are commonly reached through the type alone, so
+/// offer the ones of traits which are not imported yet together with the
+/// import of the trait.
+fn complete_consts_of_unimported_traits(
+    acc: &mut Completions,
+    ctx: &CompletionContext,
+    ty: &hir::Type,
+    krate: hir::Crate,
+) -> Option<()> {
+    if ctx.use_item_syntax.is_some() {
+        return None;
+    }
+    let current_module = ctx.scope.module()?;
+    let import_scope = ImportScope::find_insert_use_container(&ctx.token.parent(), &ctx.sema)?;
+    let traits_in_scope: FxHashSet<hir::Trait> =
+        ctx.scope.traits_in_scope().into_iter().map(hir::Trait::from).collect();
+
+    ty.iterate_path_candidates_of_implemented_traits(ctx.db, krate, |_ty, item| {
+        let const_ = match item {
+            hir::AssocItem::Const(it) => it,
+            _ => return None,
+        };
+        let trait_ = match item.container(ctx.db) {
+            AssocItemContainer::Trait(it) => it,
+            AssocItemContainer::ImplDef(_) => return None,
+        };
+        if traits_in_scope.contains(&trait_) || !item.is_visible_from(ctx.db, current_module) {
+            return None;
+        }
+        if let Some(import_path) =
+            current_module.find_use_path(ctx.db, hir::ModuleDef::Trait(trait_))
+        {
+            let import_to_add = ImportToAdd {
+                import_path,
+                import_scope: import_scope.clone(),
+                merge_behaviour: ctx.config.merge,
+            };
+            acc.add_const_with_import(ctx, const_, import_to_add);
+        }
+        None::<()>
+    });
+    Some(())
+}
+
 #[cfg(test)]
 mod tests {
     use expect_test::{expect, Expect};
@@ -184,6 +345,20 @@ use self::{foo::*, bar<|>};
         );
     }
 
+    #[test]
+    fn dont_complete_items_listed_in_the_same_braces() {
+        check(
+            r#"
+mod foo { pub struct S; pub struct T; pub mod bar {} }
+use foo::{S, bar::Baz, <|>};
+"#,
+            expect![[r#"
+                st T
+                md bar
+            "#]],
+        );
+    }
+
     #[test]
     fn dont_complete_primitive_in_use() {
         check_builtin(r#"use self::<|>;"#, expect![[""]]);
@@ -503,14 +678,14 @@ trait Sub: Super {
 fn foo<T: Sub>() { T::<|> }
 "#,
             expect![[r#"
-                ct C2           const C2: ();
-                ct CONST        const CONST: u8;
-                ta SubTy        type SubTy;
-                ta Ty           type Ty;
-                fn func()       fn func()
-                me method(…)    fn method(&self)
-                fn subfunc()    fn subfunc()
-                me submethod(…) fn submethod(&self)
+                ct C2           const C2: (); (from Sub)
+                ct CONST        const CONST: u8; (from Super)
+                ta SubTy        type SubTy; (from Sub)
+                ta Ty           type Ty; (from Super)
+                fn func()       fn func() (from Super)
+                me method(…)    fn method(&self) (from Super)
+                fn subfunc()    fn subfunc() (from Sub)
+                me submethod(…) fn submethod(&self) (from Sub)
             "#]],
         );
     }
@@ -752,4 +927,248 @@ fn main() {
             "#]],
         );
     }
+
+    #[test]
+    fn completes_consts_of_unimported_traits() {
+        check(
+            r#"
+mod num {
+    pub trait Bounded { const MAX: Self; }
+    impl Bounded for u32 { const MAX: u32 = 0; }
+    impl Bounded for super::S { const MAX: super::S = super::S; }
+
+    pub trait Bits { const BITS: u32; fn count_ones(self) -> u32; }
+    impl Bits for u32 { const BITS: u32 = 32; fn count_ones(self) -> u32 { 0 } }
+}
+use num::Bits;
+
+struct S;
+
+fn main() { let _ = u32::<|> }
+"#,
+            expect![[r#"
+                ct BITS             const BITS: u32;
+                me count_ones(…)    fn count_ones(self) -> u32
+                ct num::Bounded::MAX const MAX: Self;
+            "#]],
+        );
+    }
+
+    #[test]
+    fn inserts_the_trait_import_of_an_assoc_const() {
+        check_edit(
+            "MAX",
+            r#"
+mod num {
+    pub trait Bounded { const MAX: Self; }
+    impl Bounded for u32 { const MAX: u32 = 0; }
+}
+
+fn main() { let _ = u32::<|> }
+"#,
+            r#"
+use num::Bounded;
+
+mod num {
+    pub trait Bounded { const MAX: Self; }
+    impl Bounded for u32 { const MAX: u32 = 0; }
+}
+
+fn main() { let _ = u32::MAX }
+"#,
+        );
+    }
+
+    #[test]
+    fn substitutes_generic_alias_params_in_signatures() {
+        check(
+            r#"
+struct RandomState;
+struct HashMap<K, V, S = RandomState> {}
+
+impl<K, V> HashMap<K, V, RandomState> {
+    pub fn new() -> HashMap<K, V, RandomState> { }
+    pub fn with_capacity(capacity: usize) -> HashMap<K, V, RandomState> { }
+}
+impl<K, V, S> HashMap<K, V, S> {
+    pub fn get(&self, k: &K) -> Option<&V> { }
+}
+
+type IntMap<V> = HashMap<u32, V>;
+
+fn foo() { IntMap::<|> }
+"#,
+            expect![[r#"
+                me get(…)           pub fn get(&self, k: &u32) -> Option<&V>
+                fn new()            pub fn new() -> HashMap<u32, V, RandomState>
+                fn with_capacity(…) pub fn with_capacity(capacity: usize) -> HashMap<u32, V, RandomState>
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_assoc_items_through_aliases_in_modules() {
+        check(
+            r#"
+struct HashMap<K, V> {}
+impl<K, V> HashMap<K, V> {
+    pub fn get(&self, k: &K) -> Option<&V> { }
+}
+trait Capacity { const DEFAULT_CAPACITY: usize; }
+impl Capacity for HashMap<u32, u8> { const DEFAULT_CAPACITY: usize = 16; }
+
+mod m { pub type Bytes = super::HashMap<u32, u8>; }
+
+fn foo() { m::Bytes::<|> }
+"#,
+            expect![[r#"
+                ct DEFAULT_CAPACITY const DEFAULT_CAPACITY: usize;
+                me get(…)           pub fn get(&self, k: &u32) -> Option<&u8>
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_ty_param_items_of_bounds_out_of_scope() {
+        check(
+            r#"
+mod m {
+    pub trait Super { const SUPER: u8; fn from_super(); }
+    pub trait Sub: Super { type Item; fn from_sub() -> Self; }
+}
+trait Other { fn other(&self); }
+
+fn foo<T, U: Other>() where T: m::Sub { T::<|> }
+"#,
+            expect![[r#"
+                ta Item         type Item; (from Sub)
+                ct SUPER        const SUPER: u8; (from Super)
+                fn from_sub()   fn from_sub() -> Self (from Sub)
+                fn from_super() fn from_super() (from Super)
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_items_of_several_bounds_once() {
+        check(
+            r#"
+trait Io { type Error; }
+trait Read: Io { fn read(&mut self); }
+trait Seek: Io { const START: u64; }
+
+fn foo<T: Read + Seek>() { T::<|> }
+"#,
+            expect![[r#"
+                ta Error   type Error; (from Io)
+                ct START   const START: u64; (from Seek)
+                me read(…) fn read(&mut self) (from Read)
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_own_items_on_self_in_trait() {
+        check(
+            r#"
+trait Super { type Item; fn from_super(); }
+trait Sub: Super {
+    fn from_sub();
+    fn default() { Self::<|> }
+}
+"#,
+            expect![[r#"
+                ta Item         type Item; (from Super)
+                fn default()    fn default() (from Sub)
+                fn from_sub()   fn from_sub() (from Sub)
+                fn from_super() fn from_super() (from Super)
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_only_traits_and_modules_in_impl_trait() {
+        check(
+            r#"
+mod fmt {
+    pub trait Display {}
+    pub struct Formatter;
+    pub mod rt {}
+}
+struct S;
+impl fmt::<|> for S {}
+"#,
+            expect![[r#"
+                tt Display
+                md rt
+            "#]],
+        );
+        check(
+            r#"
+struct S;
+impl S { type Item = (); }
+impl S::<|> for S {}
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn completes_only_pattern_items_in_pattern_paths() {
+        check(
+            r#"
+enum E { A(u32), B }
+impl E {
+    const C: E = E::B;
+    fn new() -> E { E::B }
+}
+fn foo(e: E) {
+    match e { E::<|> }
+}
+"#,
+            expect![[r#"
+                ev A(…) (u32)
+                ev B    ()
+                ct C    const C: E = E::B;
+            "#]],
+        );
+        check(
+            r#"
+mod m {
+    pub fn f() {}
+    pub const X: u32 = 0;
+    pub static Y: u32 = 0;
+    pub struct S;
+    pub trait Tr {}
+    pub type Alias = u32;
+}
+fn foo(x: u32) {
+    match x { m::<|> }
+}
+"#,
+            expect![[r#"
+                st S
+                ct X
+            "#]],
+        );
+    }
+
+    #[test]
+    fn inserts_placeholders_for_qualified_tuple_variants_in_patterns() {
+        check_edit(
+            "A",
+            r#"
+enum E { A(u32), B }
+fn foo(e: E) {
+    match e { E::<|> }
+}
+"#,
+            r#"
+enum E { A(u32), B }
+fn foo(e: E) {
+    match e { E::A(${1:_})$0 }
+}
+"#,
+        );
+    }
 }
@@ -1,6 +1,7 @@
 //! Complete fields in record literals and patterns.
+use hir::HirDisplay;
 use ide_db::helpers::FamousDefs;
-use syntax::ast::Expr;
+use syntax::ast::{self, Expr};
 
 use crate::{
     item::CompletionKind, CompletionContext, CompletionItem, CompletionItemKind, Completions,
@@ -10,12 +11,18 @@ pub(crate) fn complete_record(acc: &mut Completions, ctx: &CompletionContext) ->
     let missing_fields = match (ctx.record_pat_syntax.as_ref(), ctx.record_lit_syntax.as_ref()) {
         (None, None) => return None,
         (Some(_), Some(_)) => unreachable!("A record cannot be both a literal and a pattern"),
-        (Some(record_pat), _) => ctx.sema.record_pattern_missing_fields(record_pat),
+        (Some(record_pat), _) => {
+            let missing_fields = ctx.sema.record_pattern_missing_fields(record_pat);
+            complete_pattern_fill(acc, ctx, record_pat, &missing_fields);
+            missing_fields
+        }
         (_, Some(record_lit)) => {
             let ty = ctx.sema.type_of_expr(&Expr::RecordExpr(record_lit.clone()));
             let default_trait = FamousDefs(&ctx.sema, ctx.krate).core_default_Default();
             let impl_default_trait = default_trait
-                .and_then(|default_trait| ty.map(|ty| ty.impls_trait(ctx.db, default_trait, &[])))
+                .and_then(|default_trait| {
+                    ty.as_ref().map(|ty| ty.impls_trait(ctx.db, default_trait, &[]))
+                })
                 .unwrap_or(false);
 
             let missing_fields = ctx.sema.record_literal_missing_fields(record_lit);
@@ -30,6 +37,39 @@ pub(crate) fn complete_record(acc: &mut Completions, ctx: &CompletionContext) ->
                     .kind(CompletionItemKind::Field)
                     .build(),
                 );
+                // The same spelled with the struct, like `..S::default()`.
+                if let Some(path) = record_lit.path() {
+                    let is_struct = ty
+                        .as_ref()
+                        .and_then(|ty| ty.as_adt())
+                        .filter(|it| matches!(it, hir::Adt::Struct(_)))
+                        .is_some();
+                    if is_struct {
+                        let update = format!("..{}::default()", path);
+                        CompletionItem::new(CompletionKind::Snippet, ctx.source_range(), &update)
+                            .insert_text(update)
+                            .kind(CompletionItemKind::Field)
+                            .add_to(acc);
+                    }
+                }
+            }
+
+            if let Some(cap) = ctx.config.snippet_cap {
+                if missing_fields.len() > 1 {
+                    let fields = missing_fields
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, (field, _))| format!("{}: ${}", field.name(ctx.db), idx + 1))
+                        .collect::<Vec<_>>();
+                    CompletionItem::new(
+                        CompletionKind::Snippet,
+                        ctx.source_range(),
+                        "fill remaining fields",
+                    )
+                    .insert_snippet(cap, fields.join(", "))
+                    .kind(CompletionItemKind::Snippet)
+                    .add_to(acc);
+                }
             }
 
             missing_fields
@@ -37,18 +77,76 @@ pub(crate) fn complete_record(acc: &mut Completions, ctx: &CompletionContext) ->
     };
 
     for (field, ty) in missing_fields {
+        let name = field.name(ctx.db).to_string();
+        // The field itself is completed with the shorthand then, so the
+        // initialization with another value is offered separately.
+        if ctx.record_lit_syntax.is_some()
+            && !ctx.record_field_has_value()
+            && ctx.has_local_for_field(&name, &ty)
+        {
+            if let Some(cap) = ctx.config.snippet_cap {
+                CompletionItem::new(
+                    CompletionKind::Reference,
+                    ctx.source_range(),
+                    format!("{}: …", name),
+                )
+                .kind(CompletionItemKind::Field)
+                .detail(ty.display(ctx.db).to_string())
+                .insert_snippet(cap, format!("{}: $0", name))
+                .add_to(acc);
+            }
+        }
         acc.add_field(ctx, field, &ty);
     }
 
     Some(())
 }
 
+/// Offers to bind all the missing fields of the pattern at once, and to ignore
+/// them with `..`.
+fn complete_pattern_fill(
+    acc: &mut Completions,
+    ctx: &CompletionContext,
+    record_pat: &ast::RecordPat,
+    missing_fields: &[(hir::Field, hir::Type)],
+) {
+    if missing_fields.is_empty() {
+        return;
+    }
+    let has_rest = record_pat.record_pat_field_list().and_then(|it| it.dotdot_token()).is_some();
+    if has_rest {
+        return;
+    }
+    if let Some(cap) = ctx.config.snippet_cap {
+        if missing_fields.len() > 1 {
+            let fields = missing_fields
+                .iter()
+                .enumerate()
+                .map(|(idx, (field, _))| {
+                    let name = field.name(ctx.db);
+                    format!("{}: ${{{}:{}}}", name, idx + 1, name)
+                })
+                .collect::<Vec<_>>();
+            CompletionItem::new(CompletionKind::Snippet, ctx.source_range(), "fill all fields")
+                .insert_snippet(cap, fields.join(", "))
+                .kind(CompletionItemKind::Snippet)
+                .add_to(acc);
+        }
+    }
+    CompletionItem::new(CompletionKind::Snippet, ctx.source_range(), "..")
+        .kind(CompletionItemKind::Snippet)
+        .detail("ignore the remaining fields")
+        .add_to(acc);
+}
+
 #[cfg(test)]
 mod tests {
     use expect_test::{expect, Expect};
-    use ide_db::helpers::FamousDefs;
 
-    use crate::{test_utils::completion_list, CompletionKind};
+    use crate::{
+        test_utils::{check_edit, completion_list, with_core},
+        CompletionKind,
+    };
 
     fn check(ra_fixture: &str, expect: Expect) {
         let actual = completion_list(ra_fixture, CompletionKind::Reference);
@@ -56,10 +154,7 @@ mod tests {
     }
 
     fn check_snippet(ra_fixture: &str, expect: Expect) {
-        let actual = completion_list(
-            &format!("//- /main.rs crate:main deps:core\n{}\n{}", ra_fixture, FamousDefs::FIXTURE),
-            CompletionKind::Snippet,
-        );
+        let actual = completion_list(&with_core(ra_fixture), CompletionKind::Snippet);
         expect.assert_eq(&actual);
     }
 
@@ -95,12 +190,32 @@ fn process(f: S) {
             test_code,
             expect![[r#"
                 fd ..Default::default()
+                fd ..S::default()
                 sn pd
                 sn ppd
             "#]],
         );
     }
 
+    #[test]
+    fn inserts_functional_update_with_struct_name() {
+        check_edit(
+            "..S::default()",
+            &with_core(
+                r#"
+struct S { foo: u32, bar: usize }
+impl core::default::Default for S { fn default() -> Self { loop {} } }
+fn main() { let s = S { foo: 5, <|> }; }
+"#,
+            ),
+            r#"
+struct S { foo: u32, bar: usize }
+impl core::default::Default for S { fn default() -> Self { loop {} } }
+fn main() { let s = S { foo: 5, ..S::default() }; }
+"#,
+        );
+    }
+
     #[test]
     fn test_record_literal_field_without_default() {
         let test_code = r#"
@@ -309,6 +424,164 @@ fn main() {
         );
     }
 
+    #[test]
+    fn fills_remaining_fields() {
+        check_snippet(
+            r#"
+struct S { foo: u32, bar: u32, baz: u32 }
+fn main() {
+    let s = S { bar: 1, <|> };
+}
+"#,
+            expect![[r#"
+                sn fill remaining fields
+                sn pd
+                sn ppd
+            "#]],
+        );
+        check_edit(
+            "fill remaining fields",
+            r#"
+struct S { foo: u32, bar: u32, baz: u32 }
+fn main() {
+    let s = S { bar: 1, <|> };
+}
+"#,
+            r#"
+struct S { foo: u32, bar: u32, baz: u32 }
+fn main() {
+    let s = S { bar: 1, foo: $1, baz: $2 };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_fill_for_one_remaining_field() {
+        check_snippet(
+            r#"
+struct S { foo: u32, bar: u32 }
+fn main() {
+    let s = S { bar: 1, <|> };
+}
+"#,
+            expect![[r#"
+                sn pd
+                sn ppd
+            "#]],
+        );
+    }
+
+    #[test]
+    fn prefers_shorthand_for_locals_of_field_name() {
+        check(
+            r#"
+struct S { foo: u32, bar: u32 }
+fn main(foo: u32) {
+    let s = S { <|> };
+}
+"#,
+            expect![[r#"
+                fd bar    u32
+                fd foo    u32
+                fd foo: … u32
+            "#]],
+        );
+        check_edit(
+            "foo",
+            r#"
+struct S { foo: u32, bar: u32 }
+fn main(foo: u32) {
+    let s = S { <|> };
+}
+"#,
+            r#"
+struct S { foo: u32, bar: u32 }
+fn main(foo: u32) {
+    let s = S { foo };
+}
+"#,
+        );
+        check_edit(
+            "bar",
+            r#"
+struct S { foo: u32, bar: u32 }
+fn main(foo: u32) {
+    let s = S { <|> };
+}
+"#,
+            r#"
+struct S { foo: u32, bar: u32 }
+fn main(foo: u32) {
+    let s = S { bar: $0 };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn keeps_the_value_of_the_field_under_caret() {
+        check_edit(
+            "bar",
+            r#"
+struct S { foo: u32, bar: u32 }
+fn main() {
+    let s = S { ba<|>: 1 };
+}
+"#,
+            r#"
+struct S { foo: u32, bar: u32 }
+fn main() {
+    let s = S { bar: 1 };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn fills_all_fields_of_patterns() {
+        check_snippet(
+            r#"
+struct S { foo: u32, bar: u32, baz: u32 }
+fn process(s: S) {
+    let S { bar, <|> } = s;
+}
+"#,
+            expect![[r#"
+                sn ..              ignore the remaining fields
+                sn fill all fields
+            "#]],
+        );
+        check_edit(
+            "fill all fields",
+            r#"
+struct S { foo: u32, bar: u32, baz: u32 }
+fn process(s: S) {
+    match s { S { <|> } => () }
+}
+"#,
+            r#"
+struct S { foo: u32, bar: u32, baz: u32 }
+fn process(s: S) {
+    match s { S { foo: ${1:foo}, bar: ${2:bar}, baz: ${3:baz} } => () }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_rest_pattern_twice() {
+        check_snippet(
+            r#"
+struct S { foo: u32, bar: u32 }
+fn process(s: S) {
+    let S { <|>, .. } = s;
+}
+"#,
+            expect![[r#""#]],
+        );
+    }
+
     #[test]
     fn completes_functional_update() {
         check(
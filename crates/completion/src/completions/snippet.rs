@@ -1,8 +1,16 @@
 //! This file provides snippet completions, like `pd` => `eprintln!(...)`.
 
+use syntax::{
+    ast::{self, AstToken, VisibilityOwner},
+    AstNode, SyntaxKind, TextRange, TextSize,
+};
+
 use crate::{
-    config::SnippetCap, item::Builder, CompletionContext, CompletionItem, CompletionItemKind,
-    CompletionKind, Completions,
+    completions::imports_for,
+    config::{SnippetCap, SnippetScope},
+    doc_comment::doc_comment_at,
+    item::Builder,
+    CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions,
 };
 
 fn snippet(ctx: &CompletionContext, cap: SnippetCap, label: &str, snippet: &str) -> Builder {
@@ -12,7 +20,7 @@ fn snippet(ctx: &CompletionContext, cap: SnippetCap, label: &str, snippet: &str)
 }
 
 pub(crate) fn complete_expr_snippet(acc: &mut Completions, ctx: &CompletionContext) {
-    if !(ctx.is_trivial_path && ctx.function_syntax.is_some()) {
+    if !(ctx.is_trivial_path && ctx.function_syntax.is_some()) || ctx.is_path_type {
         return;
     }
     let cap = match ctx.config.snippet_cap {
@@ -22,6 +30,7 @@ pub(crate) fn complete_expr_snippet(acc: &mut Completions, ctx: &CompletionConte
 
     snippet(ctx, cap, "pd", "eprintln!(\"$0 = {:?}\", $0);").add_to(acc);
     snippet(ctx, cap, "ppd", "eprintln!(\"$0 = {:#?}\", $0);").add_to(acc);
+    add_custom_snippets(acc, ctx, cap, SnippetScope::Expr);
 }
 
 pub(crate) fn complete_item_snippet(acc: &mut Completions, ctx: &CompletionContext) {
@@ -33,45 +42,182 @@ pub(crate) fn complete_item_snippet(acc: &mut Completions, ctx: &CompletionConte
         None => return,
     };
 
-    snippet(
-        ctx,
-        cap,
-        "tmod (Test module)",
-        "\
+    for attr in &ctx.config.test_attributes {
+        // The standard harness keeps the plain labels, the others are told apart
+        // by their attribute.
+        let (tmod_label, tfn_label) = if attr == "test" {
+            ("tmod (Test module)".to_string(), "tfn (Test function)".to_string())
+        } else {
+            (format!("tmod (#[{}])", attr), format!("tfn (#[{}])", attr))
+        };
+
+        snippet(
+            ctx,
+            cap,
+            &tmod_label,
+            &format!(
+                "\
 #[cfg(test)]
-mod tests {
+mod tests {{
     use super::*;
 
-    #[test]
-    fn ${1:test_name}() {
+    #[{}]
+    fn ${{1:test_name}}() {{
         $0
-    }
-}",
-    )
-    .lookup_by("tmod")
-    .add_to(acc);
-
-    snippet(
-        ctx,
-        cap,
-        "tfn (Test function)",
-        "\
-#[test]
-fn ${1:feature}() {
+    }}
+}}",
+                attr
+            ),
+        )
+        .lookup_by("tmod")
+        .add_to(acc);
+
+        snippet(
+            ctx,
+            cap,
+            &tfn_label,
+            &format!(
+                "\
+#[{}]
+fn ${{1:feature}}() {{
     $0
-}",
-    )
-    .lookup_by("tfn")
-    .add_to(acc);
+}}",
+                attr
+            ),
+        )
+        .lookup_by("tfn")
+        .add_to(acc);
+    }
 
     snippet(ctx, cap, "macro_rules", "macro_rules! $1 {\n\t($2) => {\n\t\t$0\n\t};\n}").add_to(acc);
+    add_custom_snippets(acc, ctx, cap, SnippetScope::Item);
+}
+
+/// Completes the standard sections at the start of a doc comment line, like
+/// `# Examples`, and the whole documentation of a public function on an
+/// empty line.
+pub(crate) fn complete_doc_snippet(acc: &mut Completions, ctx: &CompletionContext) {
+    if !ctx.in_doc_comment {
+        return;
+    }
+    let cap = match ctx.config.snippet_cap {
+        Some(it) => it,
+        None => return,
+    };
+    let comment = match ast::Comment::cast(ctx.original_token.clone()) {
+        Some(it) => it,
+        None => return,
+    };
+    let doc_comment = match doc_comment_at(&ctx.original_token, ctx.position.offset) {
+        Some(it) if !it.in_code_block() => it,
+        _ => return,
+    };
+    let typed = match doc_comment.text_before_cursor() {
+        Some(it) => it.trim_start(),
+        None => return,
+    };
+    // Only a heading, or nothing yet, is replaced: the prose of the comment
+    // must stay as it is.
+    if !(typed.is_empty() || typed.starts_with('#')) {
+        return;
+    }
+    let source_range =
+        TextRange::new(ctx.position.offset - TextSize::of(typed), ctx.position.offset);
+
+    // The following lines of the snippet continue the comment.
+    let indent = match comment.syntax().prev_token() {
+        Some(it) if it.kind() == SyntaxKind::WHITESPACE => {
+            it.text().rsplit('\n').next().unwrap_or_default().to_string()
+        }
+        _ => String::new(),
+    };
+    let doc_lines = |lines: &[&str]| {
+        let prefix = format!("{}{}", indent, comment.prefix());
+        lines
+            .iter()
+            .map(|line| if line.is_empty() { String::new() } else { format!(" {}", line) })
+            .enumerate()
+            .map(|(idx, line)| match idx {
+                0 => line.trim_start().to_string(),
+                _ => format!("\n{}{}", prefix, line),
+            })
+            .collect::<String>()
+    };
+
+    for &(label, lines) in DOC_SECTIONS {
+        CompletionItem::new(CompletionKind::Snippet, source_range, label)
+            .insert_snippet(cap, doc_lines(lines))
+            .kind(CompletionItemKind::Snippet)
+            .add_to(acc);
+    }
+
+    let is_outer = comment.kind().doc == Some(ast::CommentPlacement::Outer);
+    let public_fn = ast::Fn::cast(comment.syntax().parent()).filter(|it| it.visibility().is_some());
+    if let Some(func) = public_fn.filter(|_| typed.is_empty() && is_outer) {
+        CompletionItem::new(CompletionKind::Snippet, source_range, "docs (Documentation)")
+            .insert_snippet(cap, doc_lines(&doc_skeleton(&func)))
+            .kind(CompletionItemKind::Snippet)
+            .lookup_by("docs")
+            .add_to(acc);
+    }
+}
+
+/// The documentation of a function, with a section on safety for unsafe
+/// functions and one on errors for those returning a `Result`.
+fn doc_skeleton(func: &ast::Fn) -> Vec<&'static str> {
+    let mut lines = vec!["${1:Summary.}"];
+    if func.unsafe_token().is_some() {
+        lines.extend(&["", "# Safety", "", "${2:The invariants the caller has to uphold.}"]);
+    }
+    let returns_result = func
+        .ret_type()
+        .and_then(|it| it.ty())
+        .filter(|it| it.syntax().text().to_string().starts_with("Result"))
+        .is_some();
+    if returns_result {
+        lines.extend(&["", "# Errors", "", "${3:When an error is returned.}"]);
+    }
+    lines.extend(&["", "# Examples", "", "```", "$0", "```"]);
+    lines
+}
+
+/// The standard sections of the documentation, as lines of the doc comment.
+const DOC_SECTIONS: &[(&str, &[&str])] = &[
+    ("# Examples", &["# Examples", "", "```", "$0", "```"]),
+    ("# Panics", &["# Panics", "", "$0"]),
+    ("# Errors", &["# Errors", "", "$0"]),
+    ("# Safety", &["# Safety", "", "$0"]),
+];
+
+fn add_custom_snippets(
+    acc: &mut Completions,
+    ctx: &CompletionContext,
+    cap: SnippetCap,
+    scope: SnippetScope,
+) {
+    for custom in ctx.config.snippets.iter().filter(|it| it.scope == scope) {
+        let imports = match imports_for(ctx, &ctx.token.parent(), &custom.imports) {
+            Some(it) => it,
+            None => continue,
+        };
+        let mut item = snippet(ctx, cap, &custom.label, &custom.body).require_imports(imports);
+        if let Some(description) = &custom.description {
+            item = item.detail(description);
+        }
+        item.add_to(acc);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use expect_test::{expect, Expect};
 
-    use crate::{test_utils::completion_list, CompletionKind};
+    use crate::{
+        test_utils::{
+            check_edit, check_edit_with_config, completion_list, completion_list_with_config,
+        },
+        CompletionConfig, CompletionKind, CustomSnippet, SnippetScope,
+    };
 
     fn check(ra_fixture: &str, expect: Expect) {
         let actual = completion_list(ra_fixture, CompletionKind::Snippet);
@@ -111,4 +257,204 @@ mod tests {
             "#]],
         )
     }
+
+    #[test]
+    fn uses_configured_test_attributes() {
+        let config = CompletionConfig {
+            test_attributes: vec!["test".to_string(), "wasm_bindgen_test".to_string()],
+            ..CompletionConfig::default()
+        };
+        let actual =
+            completion_list_with_config(config.clone(), "mod m { <|> }", CompletionKind::Snippet);
+        expect![[r##"
+            sn macro_rules
+            sn tfn (#[wasm_bindgen_test])
+            sn tfn (Test function)
+            sn tmod (#[wasm_bindgen_test])
+            sn tmod (Test module)
+        "##]]
+        .assert_eq(&actual);
+
+        let config =
+            CompletionConfig { test_attributes: vec!["wasm_bindgen_test".to_string()], ..config };
+        check_edit_with_config(
+            config,
+            "tfn",
+            "mod m { <|> }",
+            "mod m { #[wasm_bindgen_test]\nfn ${1:feature}() {\n    $0\n} }",
+        );
+    }
+
+    #[test]
+    fn completes_custom_snippets_in_their_scope() {
+        let arc = CustomSnippet::new(
+            "arc",
+            "Arc::new($0)",
+            Some("Arc::new(..)"),
+            SnippetScope::Expr,
+            &["std::sync::Arc".to_string()],
+        )
+        .unwrap();
+        let main =
+            CustomSnippet::new("main", "fn main() {\n    $0\n}", None, SnippetScope::Item, &[])
+                .unwrap();
+        let config = CompletionConfig { snippets: vec![arc, main], ..CompletionConfig::default() };
+        let actual = completion_list_with_config(
+            config.clone(),
+            "fn foo(x: i32) { <|> }",
+            CompletionKind::Snippet,
+        );
+        expect![[r#"
+            sn arc Arc::new(..)
+            sn pd
+            sn ppd
+        "#]]
+        .assert_eq(&actual);
+        let actual =
+            completion_list_with_config(config.clone(), "mod m { <|> }", CompletionKind::Snippet);
+        expect![[r#"
+            sn macro_rules
+            sn main
+            sn tfn (Test function)
+            sn tmod (Test module)
+        "#]]
+        .assert_eq(&actual);
+
+        check_edit_with_config(
+            config,
+            "arc",
+            r#"
+use std::sync::Mutex;
+
+fn foo() { <|> }
+"#,
+            r#"
+use std::sync::{Arc, Mutex};
+
+fn foo() { Arc::new($0) }
+"#,
+        );
+    }
+
+    #[test]
+    fn invalid_custom_snippets_are_rejected() {
+        let new = |label, body, imports: &[&str]| {
+            let imports: Vec<_> = imports.iter().map(|it| it.to_string()).collect();
+            CustomSnippet::new(label, body, None, SnippetScope::Expr, &imports).map(|_| ())
+        };
+        assert_eq!(new("arc", "Arc::new($0)", &["std::sync::Arc"]), Ok(()));
+        assert_eq!(new(" ", "Arc::new($0)", &[]).unwrap_err(), "snippets need a name");
+        assert_eq!(new("arc", "", &[]).unwrap_err(), "snippet `arc` has an empty body");
+        assert_eq!(
+            new("arc", "Arc::new($0)", &["std::sync::Arc::new()"]).unwrap_err(),
+            "snippet `arc` has an invalid import `std::sync::Arc::new()`"
+        );
+    }
+
+    #[test]
+    fn completes_doc_sections() {
+        check(
+            r#"
+/// Parses the input.
+///
+/// # P<|>
+pub fn parse() {}
+"#,
+            expect![[r#"
+                sn # Errors
+                sn # Examples
+                sn # Panics
+                sn # Safety
+            "#]],
+        );
+        check_edit(
+            "# Examples",
+            r#"
+mod m {
+    //! Utilities.
+    //!
+    //! # Ex<|>
+}
+"#,
+            r#"
+mod m {
+    //! Utilities.
+    //!
+    //! # Examples
+    //!
+    //! ```
+    //! $0
+    //! ```
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_doc_sections_in_code_blocks_or_mid_line() {
+        check(
+            r#"
+/// ```text
+/// # P<|>
+/// ```
+pub fn parse() {}
+"#,
+            expect![[""]],
+        );
+        check(
+            r#"
+/// Returns `x`.<|>
+pub fn f() {}
+"#,
+            expect![[""]],
+        );
+        check(
+            r#"
+/// Returns the value<|>
+pub fn f() {}
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn completes_doc_skeleton_for_public_fns() {
+        check_edit(
+            "docs",
+            r#"
+/// <|>
+pub unsafe fn parse(text: &str) -> Result<(), ()> {}
+"#,
+            r#"
+/// ${1:Summary.}
+///
+/// # Safety
+///
+/// ${2:The invariants the caller has to uphold.}
+///
+/// # Errors
+///
+/// ${3:When an error is returned.}
+///
+/// # Examples
+///
+/// ```
+/// $0
+/// ```
+pub unsafe fn parse(text: &str) -> Result<(), ()> {}
+"#,
+        );
+        check(
+            r#"
+/// <|>
+fn parse() {}
+"#,
+            expect![[r#"
+                sn # Errors
+                sn # Examples
+                sn # Panics
+                sn # Safety
+            "#]],
+        );
+    }
 }
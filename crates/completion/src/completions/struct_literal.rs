@@ -0,0 +1,177 @@
+//! Completes a literal of the expected struct, like `S { foo: $1, bar: $2 }$0`,
+//! and `S { ..Default::default() }` for the structs implementing `Default`.
+//! Both need all the fields to be visible.
+
+use hir::{HasVisibility, StructKind};
+use ide_db::helpers::FamousDefs;
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_struct_literal(acc: &mut Completions, ctx: &CompletionContext) {
+    if !ctx.is_trivial_path
+        || ctx.function_syntax.is_none()
+        || ctx.is_path_type
+        || ctx.is_pat_binding_or_const
+    {
+        return;
+    }
+    let cap = match ctx.config.snippet_cap {
+        Some(it) => it,
+        None => return,
+    };
+    let expected = match ctx.expected_types().find(|it| !it.is_unknown()) {
+        Some(it) => it,
+        None => return,
+    };
+    let strukt = match expected.as_adt() {
+        Some(hir::Adt::Struct(it)) if it.kind(ctx.db) == StructKind::Record => it,
+        _ => return,
+    };
+    let module = match ctx.scope.module() {
+        Some(it) => it,
+        None => return,
+    };
+    let path = match module.find_use_path(ctx.db, hir::ModuleDef::Adt(strukt.into())) {
+        Some(it) => it.to_string(),
+        None => return,
+    };
+
+    let fields = strukt.fields(ctx.db);
+    if !fields.iter().all(|it| it.is_visible_from(ctx.db, module)) {
+        return;
+    }
+    let placeholders = fields
+        .iter()
+        .enumerate()
+        .map(|(idx, field)| format!("{}: ${}", field.name(ctx.db), idx + 1))
+        .collect::<Vec<_>>();
+    CompletionItem::new(CompletionKind::Magic, ctx.source_range(), format!("{} {{ … }}", path))
+        .kind(CompletionItemKind::Struct)
+        .insert_snippet(cap, format!("{} {{ {} }}$0", path, placeholders.join(", ")))
+        .add_to(acc);
+
+    let impls_default = FamousDefs(&ctx.sema, ctx.krate)
+        .core_default_Default()
+        .filter(|it| expected.impls_trait(ctx.db, *it, &[]))
+        .is_some();
+    if impls_default {
+        let literal = format!("{} {{ ..Default::default() }}", path);
+        // The first field is offered to be set, the others can be added before
+        // the `..` as well.
+        let snippet = match fields.first() {
+            Some(field) => {
+                format!("{} {{ ${{1:{}}}: $2, ..Default::default() }}$0", path, field.name(ctx.db))
+            }
+            None => format!("{} {{ ..Default::default() }}$0", path),
+        };
+        CompletionItem::new(CompletionKind::Magic, ctx.source_range(), literal)
+            .kind(CompletionItemKind::Struct)
+            .insert_snippet(cap, snippet)
+            .add_to(acc);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list, with_core},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(&with_core(ra_fixture), CompletionKind::Magic);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_literal_of_expected_struct() {
+        check(
+            r#"
+struct Point { x: u32, y: u32 }
+fn draw(p: Point) {}
+fn main() { draw(<|>) }
+"#,
+            expect![[r#"
+                st Point { … }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_default_literal() {
+        check(
+            r#"
+struct Options { verbose: bool, depth: u32 }
+impl Default for Options { fn default() -> Self { loop {} } }
+fn main() { let options: Options = <|>; }
+"#,
+            expect![[r#"
+                st Options { ..Default::default() }
+                st Options { … }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_literal_with_private_fields() {
+        check(
+            r#"
+mod m { pub struct Secret { inner: u32 } }
+fn main() { let s: m::Secret = <|>; }
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn no_default_literal_with_private_fields() {
+        check(
+            r#"
+mod m {
+    pub struct Secret { inner: u32 }
+    impl Default for Secret { fn default() -> Self { loop {} } }
+}
+fn main() { let s: m::Secret = <|>; }
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn inserts_default_literal_with_a_field_to_set() {
+        check_edit(
+            "Options { ..Default::default() }",
+            &with_core(
+                r#"
+struct Options { verbose: bool, depth: u32 }
+impl Default for Options { fn default() -> Self { loop {} } }
+fn main() { let options: Options = <|>; }
+"#,
+            ),
+            r#"
+struct Options { verbose: bool, depth: u32 }
+impl Default for Options { fn default() -> Self { loop {} } }
+fn main() { let options: Options = Options { ${1:verbose}: $2, ..Default::default() }$0; }
+"#,
+        );
+    }
+
+    #[test]
+    fn inserts_literal_with_placeholders() {
+        check_edit(
+            "Point { … }",
+            &with_core(
+                r#"
+struct Point { x: u32, y: u32 }
+fn main() { let p: Point = <|>; }
+"#,
+            ),
+            r#"
+struct Point { x: u32, y: u32 }
+fn main() { let p: Point = Point { x: $1, y: $2 }$0; }
+"#,
+        );
+    }
+}
@@ -0,0 +1,212 @@
+//! Experimental: completes whole expressions of the expected type, built from
+//! what is in scope. Those are the constructors of the type, like `Foo::new()`,
+//! the calls of the functions returning it, and the fields of the locals which
+//! have it. The arguments of the calls are filled with the locals of their
+//! types.
+//!
+//! The locals of the expected type themselves are left to the completion of
+//! the names in scope, which already prefers them.
+
+use hir::{HasVisibility, HirDisplay, ScopeDef, Type};
+use itertools::Itertools;
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_term_search(acc: &mut Completions, ctx: &CompletionContext) {
+    if !ctx.config.enable_term_search
+        || !ctx.is_trivial_path
+        || ctx.function_syntax.is_none()
+        || ctx.is_path_type
+        || ctx.is_pat_binding_or_const
+    {
+        return;
+    }
+    let (module, krate) = match (ctx.scope.module(), ctx.krate) {
+        (Some(module), Some(krate)) => (module, krate),
+        _ => return,
+    };
+    let expected = match ctx.expected_types().find(|it| !it.is_unknown()) {
+        Some(it) => it,
+        None => return,
+    };
+
+    let adt_path =
+        expected.as_adt().and_then(|it| module.find_use_path(ctx.db, hir::ModuleDef::Adt(it)));
+    if let Some(adt_path) = adt_path {
+        expected.clone().iterate_assoc_items(ctx.db, krate, |item| {
+            let func = match item {
+                hir::AssocItem::Function(it) => it,
+                _ => return None,
+            };
+            if func.self_param(ctx.db).is_none()
+                && func.ret_type(ctx.db) == expected
+                && func.is_visible_from(ctx.db, module)
+            {
+                let callee = format!("{}::{}", adt_path, func.name(ctx.db));
+                add_call(acc, ctx, &expected, callee, func);
+            }
+            None::<()>
+        });
+    }
+
+    ctx.scope.process_all_names(&mut |name, def| {
+        if let ScopeDef::ModuleDef(hir::ModuleDef::Function(func)) = def {
+            // Calls without arguments are the same as the plain completion.
+            if func.ret_type(ctx.db) == expected && !func.assoc_fn_params(ctx.db).is_empty() {
+                add_call(acc, ctx, &expected, name.to_string(), func);
+            }
+        }
+    });
+
+    for (name, local) in ctx.locals.iter() {
+        for (field, ty) in local.ty(ctx.db).fields(ctx.db) {
+            if ty != expected || !field.is_visible_from(ctx.db, module) {
+                continue;
+            }
+            let expr = format!("{}.{}", name, field.name(ctx.db));
+            add_expr(acc, ctx, &expected, expr, CompletionItemKind::Field);
+        }
+    }
+}
+
+/// Adds the call of `func` if all of its arguments can be filled with locals.
+fn add_call(
+    acc: &mut Completions,
+    ctx: &CompletionContext,
+    expected: &Type,
+    callee: String,
+    func: hir::Function,
+) {
+    let args: Option<Vec<String>> =
+        func.assoc_fn_params(ctx.db).iter().map(|param| local_of_type(ctx, param.ty())).collect();
+    if let Some(args) = args {
+        let expr = format!("{}({})", callee, args.iter().join(", "));
+        add_expr(acc, ctx, expected, expr, CompletionItemKind::Function);
+    }
+}
+
+/// A local of type `ty`, or a reference to one if `ty` is a reference.
+fn local_of_type(ctx: &CompletionContext, ty: &Type) -> Option<String> {
+    if let Some((name, _)) = ctx.locals.iter().find(|(_, local)| local.ty(ctx.db) == *ty) {
+        return Some(name.clone());
+    }
+    let derefed_ty = ty.remove_ref()?;
+    let (name, _) = ctx.locals.iter().find(|(_, local)| local.ty(ctx.db) == derefed_ty)?;
+    let mutability = if ty.is_mutable_reference() { "&mut " } else { "&" };
+    Some(format!("{}{}", mutability, name))
+}
+
+fn add_expr(
+    acc: &mut Completions,
+    ctx: &CompletionContext,
+    expected: &Type,
+    expr: String,
+    kind: CompletionItemKind,
+) {
+    CompletionItem::new(CompletionKind::Magic, ctx.source_range(), expr)
+        .kind(kind)
+        .detail(expected.display(ctx.db).to_string())
+        .add_to(acc);
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit_with_config, completion_list_with_config},
+        CompletionConfig, CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let config = CompletionConfig { enable_term_search: true, ..CompletionConfig::default() };
+        let actual = completion_list_with_config(config, ra_fixture, CompletionKind::Magic);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_expressions_of_the_expected_type() {
+        check(
+            r#"
+struct Config { verbose: bool }
+struct Args { config: Config, name: u32 }
+impl Config {
+    fn new() -> Config { Config { verbose: false } }
+    fn from_args(args: &Args) -> Config { todo!() }
+    fn verbose(&self) -> bool { self.verbose }
+}
+fn parse(args: &Args, name: u32) -> Config {}
+fn default_config() -> Config {}
+fn run(config: Config) {}
+fn main(args: Args, id: u32) { run(<|>) }
+"#,
+            expect![[r#"
+                st Config { … }
+                fn Config::from_args(&args) Config
+                fn Config::new()    Config
+                fd args.config      Config
+                fn parse(&args, id) Config
+            "#]],
+        );
+    }
+
+    #[test]
+    fn calls_constructors_through_their_path() {
+        check(
+            r#"
+mod config {
+    pub struct Config;
+    impl Config { pub fn new() -> Config { Config } }
+}
+fn main() { let config: config::Config = <|>; }
+"#,
+            expect![[r#"
+                fn config::Config::new() Config
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_calls_with_missing_arguments() {
+        check(
+            r#"
+struct Config;
+fn parse(name: u32) -> Config {}
+fn main() { let config: Config = <|>; }
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let actual = completion_list_with_config(
+            CompletionConfig::default(),
+            r#"
+struct Config;
+impl Config { fn new() -> Config { Config } }
+fn main() { let config: Config = <|>; }
+"#,
+            CompletionKind::Magic,
+        );
+        expect![[""]].assert_eq(&actual);
+    }
+
+    #[test]
+    fn inserts_the_expression() {
+        check_edit_with_config(
+            CompletionConfig { enable_term_search: true, ..CompletionConfig::default() },
+            "Config::new()",
+            r#"
+struct Config;
+impl Config { fn new() -> Config { Config } }
+fn main() { let config: Config = <|>; }
+"#,
+            r#"
+struct Config;
+impl Config { fn new() -> Config { Config } }
+fn main() { let config: Config = Config::new(); }
+"#,
+        );
+    }
+}
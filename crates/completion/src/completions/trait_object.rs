@@ -0,0 +1,165 @@
+//! Completes implementors of a trait where a trait object like `Box<dyn Trait>`
+//! or `&dyn Trait` is expected, e.g. `Box::new(Circle::new($0))`.
+
+use hir::{Adt, ScopeDef};
+use ide_db::helpers::FamousDefs;
+
+use crate::{
+    item::CompletionScore, CompletionContext, CompletionItem, CompletionItemKind, CompletionKind,
+    Completions,
+};
+
+pub(crate) fn complete_trait_object_implementors(acc: &mut Completions, ctx: &CompletionContext) {
+    if !ctx.is_trivial_path || ctx.is_pat_binding_or_const {
+        return;
+    }
+    let (cap, krate) = match (ctx.config.snippet_cap, ctx.krate) {
+        (Some(cap), Some(krate)) => (cap, krate),
+        _ => return,
+    };
+    let (trait_, wrapper) = match ctx.expected_types().find_map(|ty| trait_object(ctx, &ty)) {
+        Some(it) => it,
+        None => return,
+    };
+
+    let mut implementors = Vec::new();
+    ctx.scope.process_all_names(&mut |name, def| {
+        if let ScopeDef::ModuleDef(hir::ModuleDef::Adt(adt)) = def {
+            implementors.push((name.to_string(), adt));
+        }
+    });
+    for (name, adt) in implementors {
+        let ty = adt.ty(ctx.db);
+        if !ty.impls_trait(ctx.db, trait_, &[]) {
+            continue;
+        }
+        let has_new = ty
+            .iterate_assoc_items(ctx.db, krate, |item| match item {
+                hir::AssocItem::Function(func)
+                    if func.name(ctx.db).to_string() == "new"
+                        && func.self_param(ctx.db).is_none() =>
+                {
+                    Some(())
+                }
+                _ => None,
+            })
+            .is_some();
+        let (value_label, value_snippet) = if has_new {
+            (format!("{}::new(…)", name), format!("{}::new($0)", name))
+        } else {
+            (name.clone(), format!("{}$0", name))
+        };
+        let (label, snippet) = match wrapper {
+            Wrapper::Box => {
+                (format!("Box::new({})", value_label), format!("Box::new({})", value_snippet))
+            }
+            Wrapper::Ref => (format!("&{}", value_label), format!("&{}", value_snippet)),
+        };
+        let kind = match adt {
+            Adt::Struct(_) => CompletionItemKind::Struct,
+            Adt::Union(_) => CompletionItemKind::Struct,
+            Adt::Enum(_) => CompletionItemKind::Enum,
+        };
+        CompletionItem::new(CompletionKind::Magic, ctx.source_range(), label)
+            .kind(kind)
+            .detail(format!("impl {}", trait_.name(ctx.db)))
+            .lookup_by(name)
+            .insert_snippet(cap, snippet)
+            .set_score(CompletionScore::TypeMatch)
+            .add_to(acc);
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Wrapper {
+    Box,
+    Ref,
+}
+
+/// The trait of an expected `Box<dyn Trait>` or `&dyn Trait`.
+fn trait_object(ctx: &CompletionContext, ty: &hir::Type) -> Option<(hir::Trait, Wrapper)> {
+    if let Some(inner) = ty.remove_ref() {
+        return Some((inner.as_dyn_trait()?, Wrapper::Ref));
+    }
+    let box_ = FamousDefs(&ctx.sema, ctx.krate).alloc_boxed_Box()?;
+    match ty.as_adt()? {
+        Adt::Struct(it) if it == box_ => {
+            let trait_ = ty.type_arguments().next()?.as_dyn_trait()?;
+            Some((trait_, Wrapper::Box))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{completion_list, do_completion, with_core},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(&with_core(ra_fixture), CompletionKind::Magic);
+        expect.assert_eq(&actual)
+    }
+
+    const SHAPES: &str = r#"
+use alloc::boxed::Box;
+trait Shape {}
+struct Circle { radius: f64 }
+impl Circle { fn new(radius: f64) -> Circle { Circle { radius } } }
+impl Shape for Circle {}
+struct Square;
+impl Shape for Square {}
+struct NotAShape;
+"#;
+
+    #[test]
+    fn completes_implementors_for_boxed_trait_objects() {
+        check(
+            &format!("{}\nfn main() {{ let shape: Box<dyn Shape> = <|>; }}\n", SHAPES),
+            expect![[r#"
+                st Box::new(Circle::new(…)) impl Shape
+                st Box::new(Square) impl Shape
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_implementors_for_trait_object_refs() {
+        check(
+            &format!("{}\nfn draw(shape: &dyn Shape) {{}}\nfn main() {{ draw(<|>); }}\n", SHAPES),
+            expect![[r#"
+                st &Circle::new(…) impl Shape
+                st &Square         impl Shape
+            "#]],
+        );
+    }
+
+    #[test]
+    fn ignores_other_types_called_box() {
+        check(
+            r#"
+trait Shape {}
+struct Box<T: ?Sized>(T);
+struct Square;
+impl Shape for Square {}
+fn main() { let shape: Box<dyn Shape> = <|>; }
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn inserts_the_constructor_call() {
+        let items = do_completion(
+            &with_core(&format!("{}\nfn main() {{ let shape: Box<dyn Shape> = <|>; }}\n", SHAPES)),
+            CompletionKind::Magic,
+        );
+        let circle = items.iter().find(|it| it.lookup() == "Circle").unwrap();
+        let insert = circle.text_edit().iter().map(|it| it.insert.clone()).collect::<String>();
+        assert_eq!(insert, "Box::new(Circle::new($0))");
+    }
+}
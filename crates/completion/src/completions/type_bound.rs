@@ -0,0 +1,349 @@
+//! Completes special bounds in bound lists, like `?Sized` and `'static`.
+//!
+//! After a `+`, `'static` and the auto traits are preferred, since they are
+//! what is usually added to a bound, like in `Box<dyn Error + Send + 'static>`.
+//! In the supertraits of a trait, its lifetime parameters are completed too.
+
+use syntax::{
+    ast::{self, NameOwner, TypeBoundsOwner},
+    match_ast, AstNode, SyntaxKind, SyntaxNode,
+};
+
+use crate::{
+    completions::lifetime::{add_lifetime, lifetimes_in_scope},
+    CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, CompletionScore,
+    Completions,
+};
+
+pub(crate) fn complete_type_bound(acc: &mut Completions, ctx: &CompletionContext) {
+    let current_bound = match &ctx.type_bound {
+        Some(it) => it,
+        None => return,
+    };
+    let bound_list = match current_bound.syntax().parent().and_then(ast::TypeBoundList::cast) {
+        Some(it) => it,
+        None => return,
+    };
+    let after_question_mark = current_bound.question_mark_token().is_some();
+
+    let existing_bounds = ctx.other_bounds();
+    let has_bound = |bound: &str| existing_bounds.contains(bound);
+
+    if let Some(target) = relaxed_bound_target(&bound_list) {
+        let (is_relaxed, used_by_value) = match &target {
+            RelaxedBound::TypeParam { name, owner } => (
+                is_relaxed_elsewhere(owner, name, &bound_list),
+                Some(name).filter(|name| used_by_value(owner, name)),
+            ),
+            RelaxedBound::AssocType => (false, None),
+        };
+        if !is_relaxed && !has_bound("?Sized") && !has_bound("Sized") {
+            let label = if after_question_mark { "Sized" } else { "?Sized" };
+            let detail = match used_by_value {
+                Some(name) => format!("`{}` is used by value, which requires `Sized`", name),
+                None => "relaxes the implicit `Sized` bound".to_string(),
+            };
+            CompletionItem::new(CompletionKind::Keyword, ctx.source_range(), label)
+                .kind(CompletionItemKind::Trait)
+                .detail(detail)
+                .add_to(acc);
+        }
+    }
+
+    if !after_question_mark && !has_bound("'static") {
+        let mut item = CompletionItem::new(CompletionKind::Keyword, ctx.source_range(), "'static")
+            .kind(CompletionItemKind::Keyword);
+        if ctx.is_additional_bound() {
+            item = item.set_score(CompletionScore::AutoTrait);
+        }
+        item.add_to(acc);
+    }
+
+    // The lifetimes of a trait can bound it, like `trait Foo<'a>: 'a`.
+    if ctx.supertrait_of.is_some() && !after_question_mark {
+        for name in lifetimes_in_scope(&ctx.token.parent(), None) {
+            if !has_bound(&name) {
+                add_lifetime(acc, ctx, name);
+            }
+        }
+    }
+}
+
+enum RelaxedBound {
+    /// A type parameter of `owner`, the item declaring it.
+    TypeParam { name: String, owner: SyntaxNode },
+    /// An associated type of a trait.
+    AssocType,
+}
+
+/// `?Sized` may only relax the bounds of a type parameter, either at its
+/// declaration, in a where clause, or on an associated type in a trait.
+fn relaxed_bound_target(bound_list: &ast::TypeBoundList) -> Option<RelaxedBound> {
+    let parent = bound_list.syntax().parent()?;
+    match parent.kind() {
+        SyntaxKind::TYPE_PARAM => {
+            let param = ast::TypeParam::cast(parent)?;
+            let owner = param.syntax().parent()?.parent()?;
+            Some(RelaxedBound::TypeParam { name: param.name()?.text().to_string(), owner })
+        }
+        SyntaxKind::TYPE_ALIAS => {
+            let is_in_trait = parent
+                .parent()
+                .and_then(|it| it.parent())
+                .filter(|it| it.kind() == SyntaxKind::TRAIT)
+                .is_some();
+            if is_in_trait {
+                Some(RelaxedBound::AssocType)
+            } else {
+                None
+            }
+        }
+        SyntaxKind::WHERE_PRED => {
+            let where_pred = ast::WherePred::cast(parent)?;
+            let name = match where_pred.ty()? {
+                ast::Type::PathType(path_type) => {
+                    let path = path_type.path().filter(|path| path.qualifier().is_none())?;
+                    path.syntax().text().to_string()
+                }
+                _ => return None,
+            };
+            let owner = where_pred.syntax().parent()?.parent()?;
+            let is_type_param =
+                owner.children().find_map(ast::GenericParamList::cast)?.generic_params().any(
+                    |param| match param {
+                        ast::GenericParam::TypeParam(param) => {
+                            param.name().filter(|it| it.text() == name.as_str()).is_some()
+                        }
+                        _ => false,
+                    },
+                );
+            if is_type_param {
+                Some(RelaxedBound::TypeParam { name, owner })
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Whether the type parameter is already relaxed, or bound by `Sized`, in one
+/// of the other bound lists of the item, like `T: ?Sized` in
+/// `fn foo<T: ?Sized>() where T: <|>`.
+fn is_relaxed_elsewhere(owner: &SyntaxNode, name: &str, current: &ast::TypeBoundList) -> bool {
+    let declared = owner
+        .children()
+        .filter_map(ast::GenericParamList::cast)
+        .flat_map(|it| it.type_params())
+        .filter(|param| param.name().filter(|it| it.text() == name).is_some())
+        .filter_map(|param| param.type_bound_list());
+    let in_where_clause = owner
+        .children()
+        .filter_map(ast::WhereClause::cast)
+        .flat_map(|it| it.predicates())
+        .filter(|pred| pred.ty().filter(|ty| ty.syntax().text() == name).is_some())
+        .filter_map(|pred| pred.type_bound_list());
+    declared
+        .chain(in_where_clause)
+        .filter(|it| it != current)
+        .flat_map(|it| it.bounds())
+        .any(|bound| bound.ty().filter(|ty| ty.syntax().text() == "Sized").is_some())
+}
+
+/// Whether the type parameter is used where only sized types may be, like the
+/// type of a function parameter or of a field other than the last one.
+fn used_by_value(owner: &SyntaxNode, name: &str) -> bool {
+    let is_param = |ty: Option<ast::Type>| ty.filter(|it| it.syntax().text() == name).is_some();
+    match_ast! {
+        match owner {
+            ast::Fn(it) => {
+                it.param_list().into_iter().flat_map(|it| it.params()).any(|it| is_param(it.ty()))
+                    || is_param(it.ret_type().and_then(|it| it.ty()))
+            },
+            ast::Struct(it) => {
+                let mut types = field_types(it.field_list());
+                types.pop();
+                types.into_iter().any(|it| is_param(Some(it)))
+            },
+            ast::Enum(it) => it
+                .variant_list()
+                .into_iter()
+                .flat_map(|it| it.variants())
+                .flat_map(|it| field_types(it.field_list()))
+                .any(|it| is_param(Some(it))),
+            ast::Union(it) => field_types(it.record_field_list().map(ast::FieldList::RecordFieldList))
+                .into_iter()
+                .any(|it| is_param(Some(it))),
+            _ => false,
+        }
+    }
+}
+
+fn field_types(field_list: Option<ast::FieldList>) -> Vec<ast::Type> {
+    match field_list {
+        Some(ast::FieldList::RecordFieldList(it)) => it.fields().filter_map(|it| it.ty()).collect(),
+        Some(ast::FieldList::TupleFieldList(it)) => it.fields().filter_map(|it| it.ty()).collect(),
+        None => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list, get_all_items},
+        CompletionConfig, CompletionKind, CompletionScore,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Keyword);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_bounds_on_type_param() {
+        check(
+            r#"fn foo<T: <|>>() {}"#,
+            expect![[r#"
+                kw 'static
+                tt ?Sized  relaxes the implicit `Sized` bound
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_sized_after_question_mark() {
+        check(
+            r#"fn foo<T: ?<|>>() {}"#,
+            expect![[r#"
+                tt Sized relaxes the implicit `Sized` bound
+            "#]],
+        );
+        check_edit("Sized", r#"fn foo<T: ?S<|>>() {}"#, r#"fn foo<T: ?Sized>() {}"#);
+    }
+
+    #[test]
+    fn completes_relaxed_bound_in_where_clause_on_type_param() {
+        check(
+            r#"fn foo<T>() where T: <|> {}"#,
+            expect![[r#"
+                kw 'static
+                tt ?Sized  relaxes the implicit `Sized` bound
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_relaxed_bound_on_projection() {
+        check(
+            r#"
+trait Tr { type Item; }
+fn foo<T: Tr>() where T::Item: <|> {}
+"#,
+            expect![[r#"
+                kw 'static
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_relaxed_bound_on_assoc_type() {
+        check(
+            r#"trait Tr { type Item: <|>; }"#,
+            expect![[r#"
+                kw 'static
+                tt ?Sized  relaxes the implicit `Sized` bound
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_relaxed_bound_in_impl_trait_and_supertraits() {
+        check(
+            r#"fn foo() -> impl <|> {}"#,
+            expect![[r#"
+                kw 'static
+            "#]],
+        );
+        check(
+            r#"trait Tr: <|> {}"#,
+            expect![[r#"
+                kw 'static
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_relaxed_bound_if_relaxed_elsewhere() {
+        check(
+            r#"fn foo<T: ?Sized>() where T: <|> {}"#,
+            expect![[r#"
+                kw 'static
+            "#]],
+        );
+        check(
+            r#"fn foo<T: Sized + <|>>() {}"#,
+            expect![[r#"
+                kw 'static
+            "#]],
+        );
+    }
+
+    #[test]
+    fn marks_relaxed_bound_on_params_used_by_value() {
+        check(
+            r#"fn foo<T: <|>>(x: T) {}"#,
+            expect![[r#"
+                kw 'static
+                tt ?Sized  `T` is used by value, which requires `Sized`
+            "#]],
+        );
+        check(
+            r#"struct S<T: <|>> { a: T, b: u8 }"#,
+            expect![[r#"
+                kw 'static
+                tt ?Sized  `T` is used by value, which requires `Sized`
+            "#]],
+        );
+        check(
+            r#"struct S<T: <|>> { a: u8, b: T }"#,
+            expect![[r#"
+                kw 'static
+                tt ?Sized  relaxes the implicit `Sized` bound
+            "#]],
+        );
+    }
+
+    #[test]
+    fn skips_present_bounds() {
+        check(r#"fn foo<T: ?Sized + 'static + <|>>() {}"#, expect![[""]]);
+    }
+
+    #[test]
+    fn prefers_static_after_plus() {
+        let score_of_static = |ra_fixture: &str| {
+            get_all_items(CompletionConfig::default(), ra_fixture)
+                .into_iter()
+                .find(|it| it.label() == "'static")
+                .and_then(|it| it.score())
+        };
+        assert_eq!(
+            score_of_static(r#"fn foo(x: Box<dyn Fn() + <|>>) {}"#),
+            Some(CompletionScore::AutoTrait)
+        );
+        assert_eq!(score_of_static(r#"fn foo<T: <|>>() {}"#), None);
+    }
+
+    #[test]
+    fn completes_lifetimes_in_supertraits() {
+        let actual =
+            completion_list(r#"trait Foo<'a, 'b>: 'a + <|> {}"#, CompletionKind::Reference);
+        expect![[r#"
+            tp 'b
+        "#]]
+        .assert_eq(&actual);
+        let actual = completion_list(r#"fn foo<'a, T: <|>>() {}"#, CompletionKind::Reference);
+        expect![[""]].assert_eq(&actual);
+    }
+}
@@ -1,26 +1,26 @@
 //! Completion of names from the current scope, e.g. locals and imported items.
 
-use either::Either;
-use hir::{Adt, ModuleDef, ScopeDef, Type};
+use hir::{known, Adt, ModPath, ModuleDef, PathKind, ScopeDef, Type};
 use ide_db::helpers::insert_use::ImportScope;
-use ide_db::imports_locator;
-use syntax::AstNode;
+use rustc_hash::FxHashSet;
+use syntax::{
+    ast::{self, GenericParamsOwner, NameOwner},
+    match_ast, AstNode,
+    SyntaxKind::{MODULE, SOURCE_FILE},
+    SyntaxNode,
+};
 use test_utils::mark;
 
 use crate::{
+    completions::lifetime::is_assoc_item,
+    item::ImportToAdd,
     render::{render_resolution_with_import, RenderContext},
-    CompletionContext, Completions,
+    CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, CompletionScore,
+    Completions,
 };
 
 pub(crate) fn complete_unqualified_path(acc: &mut Completions, ctx: &CompletionContext) {
-    if !(ctx.is_trivial_path || ctx.is_pat_binding_or_const) {
-        return;
-    }
-    if ctx.record_lit_syntax.is_some()
-        || ctx.record_pat_syntax.is_some()
-        || ctx.attribute_under_caret.is_some()
-        || ctx.mod_declaration_under_caret.is_some()
-    {
+    if !is_unqualified_path(ctx) {
         return;
     }
 
@@ -32,6 +32,18 @@ pub(crate) fn complete_unqualified_path(acc: &mut Completions, ctx: &CompletionC
         return;
     }
 
+    complete_const_params(acc, ctx);
+
+    // In use paths the dependencies are completed with their details by
+    // `complete_dependency`.
+    let dependencies: FxHashSet<_> = match (&ctx.use_item_syntax, ctx.krate) {
+        (Some(_), Some(krate)) => {
+            krate.dependencies(ctx.db).into_iter().map(|it| it.name).collect()
+        }
+        _ => FxHashSet::default(),
+    };
+    let other_bounds = ctx.other_bounds();
+    let mut macros_in_scope = FxHashSet::default();
     ctx.scope.process_all_names(&mut |name, res| {
         if ctx.use_item_syntax.is_some() {
             if let (ScopeDef::Unknown, Some(name_ref)) = (&res, &ctx.name_ref_syntax) {
@@ -40,16 +52,191 @@ pub(crate) fn complete_unqualified_path(acc: &mut Completions, ctx: &CompletionC
                     return;
                 }
             }
+            if let ScopeDef::ModuleDef(ModuleDef::Module(module)) = &res {
+                if module.parent(ctx.db).is_none() && dependencies.contains(&name) {
+                    return;
+                }
+            }
+        }
+        if ctx.expects_trait() && !is_valid_bound(&res) {
+            return;
+        }
+        if other_bounds.contains(&name.to_string()) {
+            return;
+        }
+        // A trait can't be its own supertrait.
+        if let (ScopeDef::ModuleDef(ModuleDef::Trait(trait_)), Some(owner)) =
+            (&res, ctx.supertrait_of)
+        {
+            if *trait_ == owner {
+                return;
+            }
+        }
+        if ctx.is_impl_self_ty_path && !is_implementable_type(&res) {
+            return;
+        }
+        if !ctx.in_test_code && ctx.is_test_only(&res) {
+            return;
+        }
+        if let ScopeDef::MacroDef(_) = res {
+            macros_in_scope.insert(name.to_string());
         }
         acc.add_resolution(ctx, name.to_string(), &res)
     });
 
-    if ctx.config.enable_experimental_completions {
-        fuzzy_completion(acc, ctx).unwrap_or_default()
+    if !ctx.expects_trait() && !ctx.is_impl_self_ty_path && ctx.use_item_syntax.is_none() {
+        complete_exported_macros(acc, ctx, &macros_in_scope);
     }
 }
 
+/// `#[macro_export]` macros live in the crate root wherever they are defined,
+/// so they are not in the textual scope of the modules before them. Completes
+/// them there together with an import.
+pub(super) fn complete_exported_macros(
+    acc: &mut Completions,
+    ctx: &CompletionContext,
+    macros_in_scope: &FxHashSet<String>,
+) -> Option<()> {
+    let current_module = ctx.scope.module()?;
+    let import_scope =
+        ImportScope::find_insert_use_container(&ctx.original_token.parent(), &ctx.sema)?;
+    let root = current_module.krate().root_module(ctx.db);
+    if root == current_module {
+        return None;
+    }
+    for (name, def) in root.scope(ctx.db, None) {
+        let mac = match def {
+            ScopeDef::MacroDef(it) => it,
+            _ => continue,
+        };
+        if macros_in_scope.contains(&name.to_string())
+            || (!ctx.in_test_code && ctx.is_test_only(&def))
+        {
+            continue;
+        }
+        let import_path = match current_module.find_use_path(ctx.db, mac) {
+            Some(it) => it,
+            None => continue,
+        };
+        mark::hit!(completes_exported_macro_with_import);
+        acc.add_all(render_resolution_with_import(
+            RenderContext::new(ctx),
+            import_path,
+            import_scope.clone(),
+            ctx.config.merge,
+            &def,
+        ));
+    }
+    Some(())
+}
+
+pub(super) fn is_unqualified_path(ctx: &CompletionContext) -> bool {
+    (ctx.is_trivial_path || ctx.is_pat_binding_or_const)
+        && ctx.record_lit_syntax.is_none()
+        && ctx.record_pat_syntax.is_none()
+        && ctx.attribute_under_caret.is_none()
+        && ctx.mod_declaration_under_caret.is_none()
+        && !ctx.is_visibility_path
+        && !ctx.is_where_predicate_type
+        && ctx.const_arg_param.is_none()
+}
+
+/// Const generic parameters are not lowered yet, so they are taken from the
+/// syntax of the enclosing items instead of the scope.
+fn complete_const_params(acc: &mut Completions, ctx: &CompletionContext) {
+    if ctx.expects_trait() || ctx.is_impl_self_ty_path || ctx.use_item_syntax.is_some() {
+        return;
+    }
+    let const_params = const_params_in_scope(&ctx.token.parent());
+    for const_param in const_params {
+        let name = match const_param.name() {
+            Some(it) => it.text().to_string(),
+            None => continue,
+        };
+        let mut item = CompletionItem::new(CompletionKind::Reference, ctx.source_range(), name)
+            .kind(CompletionItemKind::Const)
+            .detail(const_param.syntax().text().to_string());
+        if ctx.is_path_type {
+            item = item.set_score(CompletionScore::GenericParam);
+        }
+        item.add_to(acc);
+    }
+}
+
+/// The const parameters of the items around `node`, up to the first one which
+/// isn't an associated item, like `lifetimes_in_scope` does for lifetimes.
+pub(super) fn const_params_in_scope(node: &SyntaxNode) -> Vec<ast::ConstParam> {
+    let mut res = Vec::new();
+    for node in node.ancestors() {
+        if let Some(param_list) = generic_param_list(&node) {
+            res.extend(param_list.generic_params().filter_map(|it| match it {
+                ast::GenericParam::ConstParam(it) => Some(it),
+                _ => None,
+            }));
+        }
+        if ast::Item::can_cast(node.kind()) && !is_assoc_item(&node) {
+            break;
+        }
+    }
+    res
+}
+
+fn generic_param_list(node: &SyntaxNode) -> Option<ast::GenericParamList> {
+    match_ast! {
+        match node {
+            ast::Fn(it) => it.generic_param_list(),
+            ast::Struct(it) => it.generic_param_list(),
+            ast::Enum(it) => it.generic_param_list(),
+            ast::Union(it) => it.generic_param_list(),
+            ast::Trait(it) => it.generic_param_list(),
+            ast::Impl(it) => it.generic_param_list(),
+            ast::TypeAlias(it) => it.generic_param_list(),
+            _ => None,
+        }
+    }
+}
+
+/// Only traits (and modules that might contain them) can be used as bounds
+/// or implemented.
+pub(super) fn is_valid_bound(res: &ScopeDef) -> bool {
+    matches!(
+        res,
+        ScopeDef::ModuleDef(ModuleDef::Trait(_)) | ScopeDef::ModuleDef(ModuleDef::Module(_))
+    )
+}
+
+/// Traits are implemented for types that can be named, or for the generic
+/// parameters of the impl. Modules might contain such types.
+pub(super) fn is_implementable_type(res: &ScopeDef) -> bool {
+    matches!(
+        res,
+        ScopeDef::ModuleDef(ModuleDef::Adt(_))
+            | ScopeDef::ModuleDef(ModuleDef::TypeAlias(_))
+            | ScopeDef::ModuleDef(ModuleDef::BuiltinType(_))
+            | ScopeDef::ModuleDef(ModuleDef::Module(_))
+            | ScopeDef::GenericParam(_)
+    )
+}
+
+/// Only these can be matched against, or lead to something which can be.
+pub(super) fn is_pattern_item(res: &ScopeDef) -> bool {
+    matches!(
+        res,
+        ScopeDef::ModuleDef(ModuleDef::Adt(hir::Adt::Enum(_)))
+            | ScopeDef::ModuleDef(ModuleDef::Adt(hir::Adt::Struct(_)))
+            | ScopeDef::ModuleDef(ModuleDef::EnumVariant(_))
+            | ScopeDef::ModuleDef(ModuleDef::Const(_))
+            | ScopeDef::ModuleDef(ModuleDef::Module(_))
+            | ScopeDef::MacroDef(_)
+    )
+}
+
 fn complete_enum_variants(acc: &mut Completions, ctx: &CompletionContext, ty: &Type) {
+    // Patterns can match references to the enum thanks to binding modes.
+    let ty = match ty.remove_ref() {
+        Some(inner) if ctx.is_pat_binding_or_const => inner,
+        _ => ty.clone(),
+    };
     if let Some(Adt::Enum(enum_data)) = ty.as_adt() {
         let variants = enum_data.variants(ctx.db);
 
@@ -61,57 +248,60 @@ fn complete_enum_variants(acc: &mut Completions, ctx: &CompletionContext, ty: &T
             enum_data.module(ctx.db)
         };
 
+        if ctx.is_pat_binding_or_const
+            && ctx.config.qualify_variants_with_self
+            && is_in_impl_of(ctx, enum_data)
+        {
+            mark::hit!(completes_variants_with_self_in_enum_impl);
+            for variant in variants {
+                let path = ModPath::from_segments(
+                    PathKind::Plain,
+                    vec![known::SELF_TYPE, variant.name(ctx.db)],
+                );
+                acc.add_qualified_enum_variant(ctx, variant, path);
+            }
+            return;
+        }
+
+        let import_scope = if ctx.config.import_enum_variants && !ctx.is_pat_binding_or_const {
+            ImportScope::find_insert_use_container(&ctx.token.parent(), &ctx.sema)
+        } else {
+            None
+        };
         for variant in variants {
             if let Some(path) = module.find_use_path(ctx.db, ModuleDef::from(variant)) {
                 // Variants with trivial paths are already added by the existing completion logic,
                 // so we should avoid adding these twice
-                if path.segments.len() > 1 {
-                    acc.add_qualified_enum_variant(ctx, variant, path);
+                if path.segments.len() <= 1 {
+                    continue;
+                }
+                match &import_scope {
+                    Some(import_scope) => {
+                        let import_to_add = ImportToAdd {
+                            import_path: path,
+                            import_scope: import_scope.clone(),
+                            merge_behaviour: ctx.config.merge,
+                        };
+                        acc.add_enum_variant_with_import(ctx, variant, import_to_add);
+                    }
+                    None => acc.add_qualified_enum_variant(ctx, variant, path),
                 }
             }
         }
     }
 }
 
-fn fuzzy_completion(acc: &mut Completions, ctx: &CompletionContext) -> Option<()> {
-    let _p = profile::span("fuzzy_completion");
-    let current_module = ctx.scope.module()?;
-    let anchor = ctx.name_ref_syntax.as_ref()?;
-    let import_scope = ImportScope::find_insert_use_container(anchor.syntax(), &ctx.sema)?;
-
-    let potential_import_name = ctx.token.to_string();
-
-    let possible_imports = imports_locator::find_similar_imports(
-        &ctx.sema,
-        ctx.krate?,
-        &potential_import_name,
-        50,
-        true,
-    )
-    .filter_map(|import_candidate| {
-        Some(match import_candidate {
-            Either::Left(module_def) => {
-                (current_module.find_use_path(ctx.db, module_def)?, ScopeDef::ModuleDef(module_def))
-            }
-            Either::Right(macro_def) => {
-                (current_module.find_use_path(ctx.db, macro_def)?, ScopeDef::MacroDef(macro_def))
-            }
-        })
-    })
-    .filter(|(mod_path, _)| mod_path.len() > 1)
-    .take(20)
-    .filter_map(|(import_path, definition)| {
-        render_resolution_with_import(
-            RenderContext::new(ctx),
-            import_path.clone(),
-            import_scope.clone(),
-            ctx.config.merge,
-            &definition,
-        )
-    });
-
-    acc.add_all(possible_imports);
-    Some(())
+fn is_in_impl_of(ctx: &CompletionContext, enum_: hir::Enum) -> bool {
+    let impl_ = ctx
+        .sema
+        .ancestors_with_macros(ctx.token.parent())
+        .take_while(|it| it.kind() != SOURCE_FILE && it.kind() != MODULE)
+        .find_map(ast::Impl::cast);
+    let impl_ = match impl_.and_then(|it| ctx.sema.to_def(&it)) {
+        Some(it) => it,
+        None => return false,
+    };
+    impl_.target_ty(ctx.db).as_adt() == Some(Adt::Enum(enum_))
 }
 
 #[cfg(test)]
@@ -120,8 +310,10 @@ mod tests {
     use test_utils::mark;
 
     use crate::{
-        test_utils::{check_edit, completion_list},
-        CompletionKind,
+        test_utils::{
+            check_edit, check_edit_with_config, completion_list, completion_list_with_config,
+        },
+        CompletionConfig, CompletionKind,
     };
 
     fn check(ra_fixture: &str, expect: Expect) {
@@ -274,7 +466,23 @@ fn main() {
             r#"fn quux<T>() { <|> }"#,
             expect![[r#"
                 tp T
-                fn quux() fn quux<T>()
+                fn quux()      fn quux<T>()
+                fn quux::<…>() fn quux<T>()
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_const_params_of_outer_items() {
+        check(
+            r#"
+fn outer<const N: usize>() {
+    fn inner<const M: usize>() { <|> }
+}
+"#,
+            expect![[r#"
+                ct M       const M: usize
+                fn outer() fn outer<const N: usize>()
             "#]],
         );
     }
@@ -699,6 +907,61 @@ fn f() -> m::E { V<|> }
         )
     }
 
+    #[test]
+    fn completes_enum_variant_through_the_shortest_reexport() {
+        check(
+            r#"
+//- /lib.rs crate:dep
+pub mod deeply {
+    pub mod nested {
+        pub enum Facade { V }
+    }
+}
+pub use deeply::nested::Facade;
+
+//- /main.rs crate:main deps:dep
+fn f() -> dep::Facade { V<|> }
+"#,
+            expect![[r#"
+                md dep
+                ev dep::Facade::V ()
+                fn f()            fn f() -> dep::Facade
+            "#]],
+        )
+    }
+
+    #[test]
+    fn imports_enum_variants_if_configured() {
+        let config = CompletionConfig { import_enum_variants: true, ..Default::default() };
+        let fixture = r#"
+mod compass { pub enum Direction { North, South } }
+fn turn() -> compass::Direction { N<|> }
+"#;
+        expect![[r#"
+            md compass
+            en compass::Direction
+            ev compass::Direction::North ()
+            ev compass::Direction::South ()
+            fn turn()           fn turn() -> compass::Direction
+        "#]]
+        .assert_eq(&completion_list_with_config(
+            config.clone(),
+            fixture,
+            CompletionKind::Reference,
+        ));
+        check_edit_with_config(
+            config,
+            "North",
+            fixture,
+            r#"
+use compass::Direction::North;
+
+mod compass { pub enum Direction { North, South } }
+fn turn() -> compass::Direction { North }
+"#,
+        );
+    }
+
     #[test]
     fn dont_complete_attr() {
         check(
@@ -729,83 +992,355 @@ impl My<|>
     }
 
     #[test]
-    fn function_fuzzy_completion() {
-        check_edit(
-            "stdin",
+    fn completes_only_traits_and_modules_in_bounds() {
+        check(
             r#"
-//- /lib.rs crate:dep
-pub mod io {
-    pub fn stdin() {}
-};
+trait MyTrait {}
+struct MyStruct {}
+mod my_mod {}
 
-//- /main.rs crate:main deps:dep
-fn main() {
-    stdi<|>
-}
+fn foo<T: My<|>>() {}
+"#,
+            expect![[r#"
+                tt MyTrait
+                md my_mod
+            "#]],
+        )
+    }
+
+    #[test]
+    fn completes_only_traits_and_modules_in_impl_trait() {
+        check(
+            r#"
+trait MyTrait {}
+struct MyStruct {}
+mod my_mod {}
+
+impl My<|> for MyStruct {}
+"#,
+            expect![[r#"
+                tt MyTrait
+                md my_mod
+            "#]],
+        );
+        check(
+            r#"
+trait MyTrait {}
+struct MyStruct {}
+
+impl My<|> {}
+"#,
+            expect![[r#"
+                st MyStruct
+                tt MyTrait
+                tp Self
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_only_types_in_impl_self_ty() {
+        check(
+            r#"
+trait Shape {}
+struct Circle;
+struct Square;
+impl Shape for Square {}
+type Alias = Circle;
+fn area() {}
+const PI: f64 = 3.14;
+macro_rules! m { () => {} }
+mod shapes {}
+
+impl Shape for <|> {}
 "#,
+            expect![[r#"
+                ta Alias
+                st Circle
+                st Square implements Shape
+                md shapes
+            "#]],
+        );
+    }
+
+    #[test]
+    fn skips_bounds_already_in_the_list() {
+        check(
             r#"
-use dep::io::stdin;
+trait Debug {}
+trait Clone {}
+fn foo<T: Debug + <|> + Clone>() {}
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn completes_supertraits_without_the_trait_itself() {
+        check(
+            r#"
+trait Debug {}
+trait Clone {}
+struct S;
+trait Foo: Clone + <|> {}
+"#,
+            expect![[r#"
+                tt Debug
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_only_traits_and_modules_after_dyn() {
+        check(
+            r#"
+trait MyTrait {}
+struct MyStruct {}
+
+fn foo(x: &dyn My<|>) {}
+"#,
+            expect![[r#"
+                tt MyTrait
+            "#]],
+        )
+    }
+
+    #[test]
+    fn completes_glob_imports_in_blocks() {
+        check(
+            r#"
+mod m {
+    pub struct Glob;
+    pub fn glob_fn() {}
+    fn private_fn() {}
+}
+enum E { V1, V2 }
 
 fn main() {
-    stdin()$0
+    use m::*;
+    use E::*;
+    <|>
 }
 "#,
+            expect![[r#"
+                en E
+                st Glob
+                ev V1        ()
+                ev V2        ()
+                fn glob_fn() pub fn glob_fn()
+                md m
+                fn main()    fn main()
+            "#]],
         );
     }
 
     #[test]
-    fn macro_fuzzy_completion() {
-        check_edit(
-            "macro_with_curlies!",
+    fn test_only_items_are_hidden_outside_tests() {
+        check(
             r#"
-//- /lib.rs crate:dep
-/// Please call me as macro_with_curlies! {}
-#[macro_export]
-macro_rules! macro_with_curlies {
-    () => {}
+//- /lib.rs crate:main cfg:test
+#[cfg(test)]
+mod test_utils {
+    pub fn fixture() {}
 }
+#[cfg(test)]
+fn test_helper() {}
+fn helper() {}
 
-//- /main.rs crate:main deps:dep
 fn main() {
-    curli<|>
+    <|>
 }
 "#,
+            expect![[r#"
+                fn helper() fn helper()
+                fn main()   fn main()
+            "#]],
+        );
+        check(
             r#"
-use dep::macro_with_curlies;
+//- /lib.rs crate:main cfg:test
+#[cfg(test)]
+mod test_utils {
+    pub fn fixture() {}
+}
+#[cfg(test)]
+fn test_helper() {}
+fn helper() {}
 
-fn main() {
-    macro_with_curlies! {$0}
+#[cfg(test)]
+fn it_works() {
+    <|>
 }
 "#,
+            expect![[r#"
+                fn helper()      fn helper()
+                fn it_works()    fn it_works()
+                fn test_helper() fn test_helper()
+                md test_utils
+            "#]],
         );
     }
 
     #[test]
-    fn struct_fuzzy_completion() {
+    fn dev_dependencies_are_only_imported_in_tests() {
+        let fixture = |body: &str| {
+            format!(
+                r#"
+//- /dep.rs crate:dev_dep
+pub struct DevStruct;
+
+//- /lib.rs crate:main dev-deps:dev_dep cfg:test
+{}
+"#,
+                body
+            )
+        };
+        let items = completion_list(&fixture("fn main() { DevStr<|> }"), CompletionKind::Reference);
+        assert!(!items.contains("DevStruct"), "{}", items);
+
         check_edit(
-            "ThirdStruct",
+            "DevStruct",
+            &fixture("#[cfg(test)]\nmod tests {\n    fn t() { DevStr<|> }\n}"),
             r#"
-//- /lib.rs crate:dep
-pub struct FirstStruct;
-pub mod some_module {
-    pub struct SecondStruct;
-    pub struct ThirdStruct;
+#[cfg(test)]
+mod tests {
+    use dev_dep::DevStruct;
+
+    fn t() { DevStruct }
 }
+"#,
+        );
+    }
 
-//- /main.rs crate:main deps:dep
-use dep::{FirstStruct, some_module::SecondStruct};
+    #[test]
+    fn dev_dependencies_are_imported_in_test_targets() {
+        check_edit(
+            "DevStruct",
+            r#"
+//- /dep.rs crate:dev_dep
+pub struct DevStruct;
 
-fn main() {
-    this<|>
+//- /tests/it.rs crate:it dev-deps:dev_dep
+fn helper() { DevStr<|> }
+"#,
+            r#"
+use dev_dep::DevStruct;
+
+fn helper() { DevStruct }
+"#,
+        );
+    }
+
+    #[test]
+    fn completes_exported_macro_with_import() {
+        mark::check!(completes_exported_macro_with_import);
+        check_edit(
+            "exported!",
+            r#"
+mod m {
+    fn f() {
+        <|>
+    }
+}
+mod n {
+    #[macro_export]
+    macro_rules! exported { () => {} }
 }
 "#,
             r#"
-use dep::{FirstStruct, some_module::{SecondStruct, ThirdStruct}};
+mod m {
+    use crate::exported;
 
-fn main() {
-    ThirdStruct
+    fn f() {
+        exported!($0)
+    }
+}
+mod n {
+    #[macro_export]
+    macro_rules! exported { () => {} }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn exported_macros_in_the_crate_root_are_completed_once() {
+        check(
+            r#"
+fn f() { <|> }
+#[macro_export]
+macro_rules! exported { () => {} }
+"#,
+            expect![[r##"
+                ma exported!(…) #[macro_export]
+                macro_rules! exported
+                fn f()          fn f()
+            "##]],
+        );
+    }
+
+    #[test]
+    fn completes_variants_with_self_in_enum_impl() {
+        mark::check!(completes_variants_with_self_in_enum_impl);
+        check(
+            r#"
+enum E { Unit, Tuple(u32), Record { x: u32 } }
+impl E {
+    fn f(&self) {
+        match self {
+            R<|>
+        }
+    }
+}
+"#,
+            expect![[r#"
+                en E
+                ev Self::Record   { x: u32 }
+                ev Self::Tuple(…) (u32)
+                ev Self::Unit     ()
+            "#]],
+        );
+        check_edit(
+            "Self::Record",
+            r#"
+enum E { Unit, Record { x: u32 } }
+impl E {
+    fn f(self) {
+        match self { R<|> }
+    }
+}
+"#,
+            r#"
+enum E { Unit, Record { x: u32 } }
+impl E {
+    fn f(self) {
+        match self { Self::Record { .. } }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn completes_variants_with_enum_name_if_configured() {
+        let config = CompletionConfig { qualify_variants_with_self: false, ..Default::default() };
+        let actual = completion_list_with_config(
+            config,
+            r#"
+enum E { Unit, Tuple(u32) }
+impl E {
+    fn f(self) {
+        match self { T<|> }
+    }
 }
 "#,
+            CompletionKind::Reference,
         );
+        expect![[r#"
+            en E
+            ev E::Tuple(…) (u32)
+            ev E::Unit     ()
+        "#]]
+        .assert_eq(&actual);
     }
 }
@@ -0,0 +1,201 @@
+//! Suggests aliases for the imported item in `use foo::Bar as <|>`.
+//!
+//! Besides the imported name itself, its abbreviation is offered, like `HM`
+//! for `HashMap`. When the imported name is already taken in the scope, it is
+//! disambiguated with the name of the parent module, like `IoResult` for
+//! `std::io::Result`. Traits can be imported as `_`.
+
+use hir::{ModuleDef, PathResolution};
+use rustc_hash::FxHashSet;
+use stdx::to_lower_snake_case;
+use syntax::{ast, AstNode};
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_use_alias(acc: &mut Completions, ctx: &CompletionContext) {
+    let use_tree = match &ctx.renamed_use_tree {
+        Some(it) => it,
+        None => return,
+    };
+    let segments = imported_path(use_tree);
+    let (name, parent) = match segments.as_slice() {
+        [.., parent, name] => (name.as_str(), Some(parent.as_str())),
+        [name] => (name.as_str(), None),
+        [] => return,
+    };
+
+    let mut in_scope = FxHashSet::default();
+    ctx.scope.process_all_names(&mut |name, _| {
+        in_scope.insert(name.to_string());
+    });
+
+    let mut aliases = vec![name.to_string()];
+    aliases.extend(abbreviation(name));
+    if in_scope.contains(name) {
+        let parent = parent.filter(|it| !matches!(*it, "crate" | "self" | "super"));
+        aliases.extend(parent.map(|parent| disambiguated(parent, name)));
+    }
+    let resolution = ast::Path::parse(&segments.join("::"))
+        .ok()
+        .and_then(|path| ctx.scope.speculative_resolve(&path));
+    if let Some(PathResolution::Def(ModuleDef::Trait(_))) = resolution {
+        aliases.push("_".to_string());
+    }
+
+    let mut seen = FxHashSet::default();
+    for alias in aliases {
+        if in_scope.contains(&alias) || !seen.insert(alias.clone()) {
+            continue;
+        }
+        CompletionItem::new(CompletionKind::Magic, ctx.source_range(), alias)
+            .kind(CompletionItemKind::Binding)
+            .add_to(acc);
+    }
+}
+
+/// Returns the segments of the path imported by `use_tree`, including the
+/// prefixes of the enclosing use trees.
+fn imported_path(use_tree: &ast::UseTree) -> Vec<String> {
+    let mut paths: Vec<_> = use_tree
+        .syntax()
+        .ancestors()
+        .filter_map(ast::UseTree::cast)
+        .filter_map(|it| it.path())
+        .collect();
+    paths.reverse();
+    let mut segments: Vec<String> = paths
+        .iter()
+        .flat_map(|path| {
+            path.syntax()
+                .text()
+                .to_string()
+                .split("::")
+                .map(|it| it.trim().to_string())
+                .collect::<Vec<_>>()
+        })
+        .filter(|it| !it.is_empty())
+        .collect();
+    // `use foo::{self as bar}` renames `foo`.
+    if segments.len() > 1 && segments.last().map(String::as_str) == Some("self") {
+        segments.pop();
+    }
+    segments
+}
+
+/// Returns the initials of the words of a name with several words, `HM` for
+/// `HashMap` or `sj` for `serde_json`.
+fn abbreviation(name: &str) -> Option<String> {
+    let words: Vec<_> = to_lower_snake_case(name)
+        .split('_')
+        .filter(|it| !it.is_empty())
+        .map(str::to_string)
+        .collect();
+    if words.len() < 2 {
+        return None;
+    }
+    let initials = words.iter().filter_map(|it| it.chars().next());
+    if is_type_name(name) {
+        Some(initials.map(|it| it.to_ascii_uppercase()).collect())
+    } else {
+        Some(initials.collect())
+    }
+}
+
+/// Prefixes the name with the name of its parent module, in the case of the
+/// name: `IoResult` for `io::Result`, `fmt_write` for `fmt::write`.
+fn disambiguated(parent: &str, name: &str) -> String {
+    if is_type_name(name) {
+        let parent: String = parent
+            .split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|it| it.to_ascii_uppercase())
+                    .into_iter()
+                    .chain(chars)
+                    .collect::<String>()
+            })
+            .collect();
+        format!("{}{}", parent, name)
+    } else {
+        format!("{}_{}", parent, name)
+    }
+}
+
+fn is_type_name(name: &str) -> bool {
+    name.chars().next().filter(|it| it.is_uppercase()).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{test_utils::completion_list, CompletionKind};
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Magic);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn suggests_name_and_abbreviation() {
+        check(
+            r#"
+mod collections { pub struct HashMap; }
+use collections::HashMap as <|>;
+"#,
+            expect![[r#"
+                bn HM
+                bn HashMap
+            "#]],
+        );
+        check(
+            r#"
+mod serde_json { pub fn to_string() {} }
+use serde_json::{self as <|>};
+"#,
+            expect![[r#"
+                bn sj
+            "#]],
+        );
+    }
+
+    #[test]
+    fn disambiguates_names_in_scope() {
+        check(
+            r#"
+mod io { pub struct Result; }
+struct Result;
+use io::Result as <|>;
+"#,
+            expect![[r#"
+                bn IoResult
+            "#]],
+        );
+        check(
+            r#"
+mod fmt { pub fn write() {} }
+fn write() {}
+use crate::{fmt::{write as <|>}};
+"#,
+            expect![[r#"
+                bn fmt_write
+            "#]],
+        );
+    }
+
+    #[test]
+    fn suggests_underscore_for_traits() {
+        check(
+            r#"
+mod io { pub trait Read {} }
+use io::Read as <|>;
+"#,
+            expect![[r#"
+                bn Read
+                bn _
+            "#]],
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! Suggests merging an import into an existing one with the same prefix.
+//!
+//! When typing `use std::collections::<|>` next to `use std::collections::HashMap;`,
+//! the typed path is turned into the group `use std::collections::{HashMap, <|>}`
+//! and the other import is removed.
+
+use syntax::{
+    ast::{self, VisibilityOwner},
+    AstNode, SyntaxKind, SyntaxNode,
+};
+use text_edit::TextEdit;
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_use_group(acc: &mut Completions, ctx: &CompletionContext) -> Option<()> {
+    let use_item = ctx.use_item_syntax.as_ref()?;
+    let qualifier = ctx.path_qual.as_ref()?;
+    let cap = ctx.config.snippet_cap?;
+    if ctx.use_tree_list.is_some()
+        || qualifier.syntax().ancestors().find_map(ast::UseTreeList::cast).is_some()
+    {
+        return None;
+    }
+    let prefix = normalized(qualifier.syntax());
+    let visibility = use_item.visibility().map(|it| it.syntax().text().to_string());
+
+    for other in use_item.syntax().parent()?.children().filter_map(ast::Use::cast) {
+        if &other == use_item
+            || other.visibility().map(|it| it.syntax().text().to_string()) != visibility
+        {
+            continue;
+        }
+        let trees = match other.use_tree().and_then(|it| trees_with_prefix(&it, &prefix)) {
+            Some(it) => it,
+            None => continue,
+        };
+
+        let mut delete = other.syntax().text_range();
+        if let Some(ws) = other.syntax().next_sibling_or_token().and_then(|it| it.into_token()) {
+            if ws.kind() == SyntaxKind::WHITESPACE {
+                delete = delete.cover(ws.text_range());
+            }
+        }
+        let mut edit = TextEdit::builder();
+        edit.delete(delete);
+        edit.replace(ctx.source_range(), format!("{{{}, $0}}", trees.join(", ")));
+        CompletionItem::new(
+            CompletionKind::Magic,
+            ctx.source_range(),
+            format!("{{{}, …}}", trees.join(", ")),
+        )
+        .kind(CompletionItemKind::Snippet)
+        .detail(format!("merges `{}`", other.syntax().text()))
+        .snippet_edit(cap, edit.finish())
+        .add_to(acc);
+    }
+    Some(())
+}
+
+/// Returns the trees imported by `tree` after `prefix`, like `Foo` and `Bar`
+/// for `prefix::{Foo, Bar}`.
+fn trees_with_prefix(tree: &ast::UseTree, prefix: &str) -> Option<Vec<String>> {
+    let path = tree.path()?;
+    if let Some(list) = tree.use_tree_list() {
+        if normalized(path.syntax()) != prefix {
+            return None;
+        }
+        return Some(list.use_trees().map(|it| it.syntax().text().to_string()).collect());
+    }
+    if tree.star_token().is_some() {
+        return if normalized(path.syntax()) == prefix {
+            Some(vec!["*".to_string()])
+        } else {
+            None
+        };
+    }
+    if normalized(path.qualifier()?.syntax()) != prefix {
+        return None;
+    }
+    let mut imported = path.segment()?.syntax().text().to_string();
+    if let Some(rename) = tree.rename() {
+        imported = format!("{} {}", imported, rename.syntax().text());
+    }
+    Some(vec![imported])
+}
+
+fn normalized(node: &SyntaxNode) -> String {
+    node.text().to_string().chars().filter(|it| !it.is_whitespace()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Magic);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn suggests_merging_imports_with_the_same_prefix() {
+        check(
+            r#"
+mod foo { pub struct Bar; pub struct Baz; pub struct Qux; }
+use foo::Bar;
+use foo::{Baz as B, Qux};
+use foo::<|>
+"#,
+            expect![[r#"
+                sn {Bar, …}         merges `use foo::Bar;`
+                sn {Baz as B, Qux, …} merges `use foo::{Baz as B, Qux};`
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_merge_for_other_prefixes_or_groups() {
+        check(
+            r#"
+mod foo { pub mod bar { pub struct Baz; } pub struct Qux; }
+use foo::bar::Baz;
+use foo::<|>
+"#,
+            expect![[""]],
+        );
+        check(
+            r#"
+mod foo { pub struct Bar; pub struct Baz; }
+use foo::Bar;
+use foo::{Baz, <|>};
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn merges_into_a_group() {
+        check_edit(
+            "{Bar, …}",
+            r#"
+mod foo { pub struct Bar; pub struct Baz; }
+use foo::Bar;
+use foo::B<|>;
+"#,
+            r#"
+mod foo { pub struct Bar; pub struct Baz; }
+use foo::{Bar, $0};
+"#,
+        );
+    }
+}
@@ -0,0 +1,108 @@
+//! Completes the path of `pub(in <|>)` with the ancestor modules of the
+//! current module, the only ones an item can be made visible in.
+
+use hir::{ModuleDef, PathResolution};
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_visibility_path(acc: &mut Completions, ctx: &CompletionContext) {
+    if !ctx.is_visibility_path {
+        return;
+    }
+    let module = match ctx.scope.module() {
+        Some(it) => it,
+        None => return,
+    };
+    // From the current module up to the crate root.
+    let ancestors = module.path_to_root(ctx.db);
+
+    match &ctx.path_qual {
+        None => {
+            let mut keywords = vec!["crate", "self"];
+            if module.parent(ctx.db).is_some() {
+                keywords.push("super");
+            }
+            for keyword in keywords {
+                CompletionItem::new(CompletionKind::Keyword, ctx.source_range(), keyword)
+                    .kind(CompletionItemKind::Keyword)
+                    .add_to(acc);
+            }
+            // `crate::a`, `crate::a::b`, ... up to the current module.
+            let mut path = "crate".to_string();
+            for ancestor in ancestors.iter().rev().skip(1) {
+                let name = match ancestor.name(ctx.db) {
+                    Some(it) => it,
+                    None => return,
+                };
+                path = format!("{}::{}", path, name);
+                CompletionItem::new(CompletionKind::Reference, ctx.source_range(), path.as_str())
+                    .kind(CompletionItemKind::Module)
+                    .add_to(acc);
+            }
+        }
+        Some(qual) => {
+            let qual = match ctx.sema.resolve_path(qual) {
+                Some(PathResolution::Def(ModuleDef::Module(it))) => it,
+                _ => return,
+            };
+            let idx = match ancestors.iter().position(|it| *it == qual) {
+                Some(idx) if idx > 0 => idx,
+                _ => return,
+            };
+            if let Some(name) = ancestors[idx - 1].name(ctx.db) {
+                CompletionItem::new(
+                    CompletionKind::Reference,
+                    ctx.source_range(),
+                    name.to_string(),
+                )
+                .kind(CompletionItemKind::Module)
+                .add_to(acc);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{test_utils::completion_list, CompletionKind};
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Reference);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_ancestor_modules() {
+        check(
+            r#"
+struct S;
+mod a { mod b { mod c {} pub(in <|>) fn f() {} } }
+"#,
+            expect![[r#"
+                md crate::a
+                md crate::a::b
+            "#]],
+        );
+        check(
+            r#"
+mod a { mod b { mod c {} pub(in crate::<|>) fn f() {} } }
+"#,
+            expect![[r#"
+                md a
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_modules_outside_of_ancestors() {
+        check(
+            r#"
+mod a { mod b {} }
+mod c { pub(in crate::a::<|>) fn f() {} }
+"#,
+            expect![[""]],
+        );
+    }
+}
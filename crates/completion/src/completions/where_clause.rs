@@ -0,0 +1,140 @@
+//! Completes where predicates. On the left of a predicate, the generic
+//! parameters, `Self` and the associated types of their bounds, like `T::Item`
+//! in `where T::Item: Debug`, are completed. As bounds, the lifetimes in scope
+//! are completed next to the traits.
+
+use hir::{PathResolution, ScopeDef};
+use rustc_hash::FxHashSet;
+use syntax::{ast, AstNode, SyntaxKind};
+
+use crate::{
+    completions::lifetime::{add_lifetime, lifetimes_in_scope},
+    CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions,
+};
+
+pub(crate) fn complete_where_predicate(acc: &mut Completions, ctx: &CompletionContext) {
+    if ctx.is_where_predicate_type {
+        complete_predicate_type(acc, ctx);
+        return;
+    }
+    let bound = match &ctx.type_bound {
+        Some(it) => it,
+        None => return,
+    };
+    let in_where_clause = bound
+        .syntax()
+        .parent()
+        .and_then(ast::TypeBoundList::cast)
+        .and_then(|it| it.syntax().parent())
+        .filter(|it| it.kind() == SyntaxKind::WHERE_PRED)
+        .is_some();
+    if !in_where_clause || bound.question_mark_token().is_some() {
+        return;
+    }
+    let other_bounds = ctx.other_bounds();
+    for name in lifetimes_in_scope(&ctx.token.parent(), None) {
+        if !other_bounds.contains(&name) {
+            add_lifetime(acc, ctx, name);
+        }
+    }
+}
+
+fn complete_predicate_type(acc: &mut Completions, ctx: &CompletionContext) {
+    if ctx.path_qual.is_some() {
+        return;
+    }
+    // With nothing typed yet, the caret is in the whitespace after the where
+    // clause, where the generic parameters are out of scope.
+    let where_clause_scope;
+    let scope = match ctx.token.parent().children().find_map(ast::WhereClause::cast) {
+        Some(where_clause) => {
+            where_clause_scope = ctx.sema.scope(where_clause.syntax());
+            &where_clause_scope
+        }
+        None => &ctx.scope,
+    };
+    let mut params = Vec::new();
+    scope.process_all_names(&mut |name, def| {
+        let resolution = match def {
+            ScopeDef::GenericParam(it) => Some(PathResolution::TypeParam(it)),
+            ScopeDef::ImplSelfType(it) => Some(PathResolution::SelfType(it)),
+            ScopeDef::AdtSelfType(_) => None,
+            _ => return,
+        };
+        params.push((name.to_string(), def, resolution));
+    });
+    let mut seen = FxHashSet::default();
+    for (name, def, resolution) in params {
+        acc.add_resolution(ctx, name.clone(), &def);
+        let resolution = match resolution {
+            Some(it) => it,
+            None => continue,
+        };
+        resolution.assoc_type_shorthand_candidates(ctx.db, |alias| {
+            let label = format!("{}::{}", name, alias.name(ctx.db));
+            if !seen.insert(label.clone()) {
+                return None;
+            }
+            CompletionItem::new(CompletionKind::Reference, ctx.source_range(), label)
+                .kind(CompletionItemKind::TypeAlias)
+                .add_to(acc);
+            None::<()>
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{test_utils::completion_list, CompletionKind};
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Reference);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_params_and_projections_on_the_left() {
+        check(
+            r#"
+trait Iterator { type Item; }
+struct S;
+fn foo<I: Iterator, T>() where <|> {}
+"#,
+            expect![[r#"
+                tp I
+                ta I::Item
+                tp T
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_self_on_the_left() {
+        check(
+            r#"
+trait Iterator { type Item; }
+trait Tr: Iterator { fn foo() where <|>; }
+"#,
+            expect![[r#"
+                tp Self
+                ta Self::Item
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_lifetimes_as_bounds() {
+        check(
+            r#"
+trait Clone {}
+fn foo<'a, 'b, T>() where T: 'b + <|> {}
+"#,
+            expect![[r#"
+                tp 'a
+                tt Clone
+            "#]],
+        );
+    }
+}
@@ -0,0 +1,125 @@
+//! Completes the locals wrapped in the variant the expected `Option` or
+//! `Result` holds them in, like `Ok(config)` for the `config` local in
+//! `fn load() -> Result<Config, Error> { <|> }`.
+
+use hir::{HirDisplay, Type};
+use ide_db::helpers::FamousDefs;
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_wrap_variant(acc: &mut Completions, ctx: &CompletionContext) {
+    if !ctx.is_trivial_path
+        || ctx.function_syntax.is_none()
+        || ctx.is_path_type
+        || ctx.is_pat_binding_or_const
+    {
+        return;
+    }
+    let expected = match ctx.expected_types().find(|it| !it.is_unknown()) {
+        Some(it) => it,
+        None => return,
+    };
+    let enum_ = match expected.as_adt() {
+        Some(hir::Adt::Enum(it)) => it,
+        _ => return,
+    };
+    let args: Vec<Type> = expected.type_arguments().collect();
+    let famous_defs = FamousDefs(&ctx.sema, ctx.krate);
+    let variants: &[&str] = if famous_defs.core_option_Option() == Some(enum_) {
+        &["Some"]
+    } else if famous_defs.core_result_Result() == Some(enum_) {
+        &["Ok", "Err"]
+    } else {
+        return;
+    };
+
+    for (variant, inner) in variants.iter().zip(args.iter()) {
+        if inner.is_unknown() {
+            continue;
+        }
+        for (name, local) in ctx.locals.iter() {
+            if local.ty(ctx.db) != *inner {
+                continue;
+            }
+            let expr = format!("{}({})", variant, name);
+            CompletionItem::new(CompletionKind::Magic, ctx.source_range(), expr)
+                .kind(CompletionItemKind::EnumVariant)
+                .detail(format!("{} wrapped in {}", name, expected.display(ctx.db)))
+                .add_to(acc);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list, with_core},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(&with_core(ra_fixture), CompletionKind::Magic);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn wraps_locals_of_the_inner_types() {
+        check(
+            r#"
+struct Config;
+struct Error;
+fn load(config: Config, error: Error, count: u32) -> Result<Config, Error> {
+    return <|>;
+}
+"#,
+            expect![[r#"
+                ev Err(error) error wrapped in Result<Config, Error>
+                ev Ok(config) config wrapped in Result<Config, Error>
+            "#]],
+        );
+        check(
+            r#"
+fn first(x: u32) -> Option<u32> { return <|> }
+"#,
+            expect![[r#"
+                ev Some(x) x wrapped in Option<u32>
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_wrapping_for_other_enums() {
+        check(
+            r#"
+enum Either<L, R> { Left(L), Right(R) }
+fn side(x: u32) -> Either<u32, u32> { <|> }
+"#,
+            expect![[""]],
+        );
+        check(
+            r#"
+enum Option<T> { None, Some(T) }
+use Option::*;
+fn first(x: u32) -> Option<u32> { return <|> }
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn inserts_wrapped_local() {
+        check_edit(
+            "Some(x)",
+            &with_core(
+                r#"
+fn first(x: u32) { let y: Option<u32> = <|>; }
+"#,
+            ),
+            r#"
+fn first(x: u32) { let y: Option<u32> = Some(x); }
+"#,
+        );
+    }
+}
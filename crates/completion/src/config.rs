@@ -4,16 +4,63 @@
 //! module, and we use to statically check that we only produce snippet
 //! completions if we are allowed to.
 
+use std::time::Duration;
+
+use hir::{AsName, ModPath, PathKind};
 use ide_db::helpers::insert_use::MergeBehaviour;
+use syntax::ast;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CompletionConfig {
     pub enable_postfix_completions: bool,
-    pub enable_experimental_completions: bool,
-    pub add_call_parenthesis: bool,
-    pub add_call_argument_snippets: bool,
+    /// Whether to complete items which are not in scope yet, adding a `use`
+    /// for them.
+    pub enable_autoimport_completions: bool,
+    /// How many characters of the name have to be typed before the items to
+    /// import are searched for.
+    pub autoimport_min_prefix_length: usize,
+    /// The most items to import that are offered at once.
+    pub autoimport_max_candidates: usize,
+    /// Experimental: whether to complete whole expressions of the expected
+    /// type built from what is in scope, like `Foo::new()` or `local.field`.
+    pub enable_term_search: bool,
+    /// What to insert after functions and tuple variants, as well as whether
+    /// to add `<>` after generic types.
+    pub callable_snippets: CallableSnippets,
     pub snippet_cap: Option<SnippetCap>,
     pub merge: Option<MergeBehaviour>,
+    /// Once completion takes longer than this, the expensive completions, like
+    /// the ones that search the whole workspace, are skipped.
+    pub time_budget: Option<Duration>,
+    /// Attributes the test snippets mark test functions with, like `test` or
+    /// `wasm_bindgen_test`, one snippet per attribute.
+    pub test_attributes: Vec<String>,
+    /// Whether to leave out the functions that are not `const fn`s in const
+    /// contexts, instead of only marking them.
+    pub hide_non_const_fns: bool,
+    /// Whether to leave out the traits that are not object safe after `dyn`,
+    /// instead of only marking them.
+    pub hide_non_object_safe_traits: bool,
+    /// Whether to offer the generic functions whose type parameters can't be
+    /// inferred from the arguments a second time, with a turbofish for them,
+    /// like `collect::<${1:B}>()`.
+    pub turbofish_snippets: bool,
+    /// Whether enum variants in patterns inside an `impl` of the enum are
+    /// completed as `Self::Variant` rather than with the enum name.
+    pub qualify_variants_with_self: bool,
+    /// Whether the variants of the expected enum which are not in scope are
+    /// inserted by their name together with a `use` for them, rather than with
+    /// the path of the enum.
+    pub import_enum_variants: bool,
+    /// Whether to leave out the detail, the documentation and the `use` edits
+    /// of the items, for the editor to get them with `resolve_completion` once
+    /// an item is selected.
+    pub resolve_lazily: bool,
+    /// Postfix completions declared by the user. One with the label of a
+    /// built-in one replaces it.
+    pub postfix_templates: Vec<PostfixTemplate>,
+    /// Snippets declared by the user, offered next to the built-in ones.
+    pub snippets: Vec<CustomSnippet>,
 }
 
 impl CompletionConfig {
@@ -22,6 +69,18 @@ impl CompletionConfig {
     }
 }
 
+/// What is inserted when completing something that can be called.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CallableSnippets {
+    /// Parentheses with a placeholder for each argument, like `foo(${1:x})`.
+    FillArguments,
+    /// Only the parentheses, with the cursor inside of them if there are
+    /// arguments to pass.
+    AddParentheses,
+    /// Just the name.
+    None,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct SnippetCap {
     _private: (),
@@ -31,11 +90,158 @@ impl Default for CompletionConfig {
     fn default() -> Self {
         CompletionConfig {
             enable_postfix_completions: true,
-            enable_experimental_completions: true,
-            add_call_parenthesis: true,
-            add_call_argument_snippets: true,
+            enable_autoimport_completions: true,
+            autoimport_min_prefix_length: 1,
+            autoimport_max_candidates: 20,
+            enable_term_search: false,
+            callable_snippets: CallableSnippets::FillArguments,
             snippet_cap: Some(SnippetCap { _private: () }),
             merge: Some(MergeBehaviour::Full),
+            time_budget: None,
+            test_attributes: vec!["test".to_string()],
+            hide_non_const_fns: false,
+            hide_non_object_safe_traits: false,
+            turbofish_snippets: true,
+            qualify_variants_with_self: true,
+            import_enum_variants: false,
+            resolve_lazily: false,
+            postfix_templates: Vec::new(),
+            snippets: Vec::new(),
+        }
+    }
+}
+
+/// A postfix completion declared by the user, like `wrapok` for
+/// `Ok(${receiver})`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PostfixTemplate {
+    pub(crate) label: String,
+    pub(crate) snippet: String,
+    pub(crate) imports: Vec<ModPath>,
+}
+
+impl PostfixTemplate {
+    /// The placeholder which is replaced with the receiver expression.
+    pub const RECEIVER: &'static str = "${receiver}";
+
+    /// Checks the template, so that a broken one is reported when the config
+    /// is loaded rather than silently not completed.
+    ///
+    /// Besides `${receiver}`, the snippet may contain tab stops like `$0` or
+    /// `${1:placeholder}`. The imports are added when the completion is
+    /// applied, they are paths like `std::sync::Arc`, possibly starting with
+    /// `crate`.
+    pub fn new(label: &str, snippet: &str, imports: &[String]) -> Result<PostfixTemplate, String> {
+        if label.is_empty() || !label.chars().all(|it| it.is_alphanumeric() || it == '_') {
+            return Err(format!("invalid postfix template name `{}`", label));
+        }
+        if !snippet.contains(Self::RECEIVER) {
+            return Err(format!("postfix template `{}` doesn't use {}", label, Self::RECEIVER));
+        }
+        for (offset, _) in snippet.match_indices("${") {
+            let placeholder = &snippet[offset + 2..];
+            if !placeholder.starts_with("receiver}")
+                && !placeholder.starts_with(|it: char| it.is_ascii_digit())
+            {
+                return Err(format!(
+                    "postfix template `{}` has an unknown placeholder at `{}`",
+                    label,
+                    &snippet[offset..]
+                ));
+            }
+        }
+        let imports = imports
+            .iter()
+            .map(|import| {
+                parse_import(import).ok_or_else(|| {
+                    format!("postfix template `{}` has an invalid import `{}`", label, import)
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(PostfixTemplate { label: label.to_string(), snippet: snippet.to_string(), imports })
+    }
+}
+
+/// Where a custom snippet is offered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SnippetScope {
+    /// Where an expression can be typed, inside of a function.
+    Expr,
+    /// Where an item can be declared.
+    Item,
+}
+
+/// A snippet declared by the user, like `arc` for `Arc::new($0)` with a `use`
+/// of `std::sync::Arc`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CustomSnippet {
+    pub(crate) label: String,
+    pub(crate) body: String,
+    pub(crate) description: Option<String>,
+    pub(crate) scope: SnippetScope,
+    pub(crate) imports: Vec<ModPath>,
+}
+
+impl CustomSnippet {
+    /// Checks the snippet, like `PostfixTemplate::new` does. The body may
+    /// contain tab stops like `$0` or `${1:placeholder}`, and the description
+    /// is shown next to the label.
+    pub fn new(
+        label: &str,
+        body: &str,
+        description: Option<&str>,
+        scope: SnippetScope,
+        imports: &[String],
+    ) -> Result<CustomSnippet, String> {
+        if label.trim().is_empty() {
+            return Err("snippets need a name".to_string());
+        }
+        if body.is_empty() {
+            return Err(format!("snippet `{}` has an empty body", label));
+        }
+        let imports = imports
+            .iter()
+            .map(|import| {
+                parse_import(import).ok_or_else(|| {
+                    format!("snippet `{}` has an invalid import `{}`", label, import)
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(CustomSnippet {
+            label: label.to_string(),
+            body: body.to_string(),
+            description: description.map(ToString::to_string),
+            scope,
+            imports,
+        })
+    }
+}
+
+fn parse_import(import: &str) -> Option<ModPath> {
+    let mut path = ast::Path::parse(import).ok()?;
+    let mut segments = Vec::new();
+    let kind = loop {
+        let segment = path.segment()?;
+        if segment.generic_arg_list().is_some() || segment.param_list().is_some() {
+            return None;
+        }
+        match (segment.kind()?, path.qualifier()) {
+            (ast::PathSegmentKind::Name(name_ref), qualifier) => {
+                segments.push(name_ref.as_name());
+                match qualifier {
+                    Some(it) => path = it,
+                    None if segment.coloncolon_token().is_some() => break PathKind::Abs,
+                    None => break PathKind::Plain,
+                }
+            }
+            (ast::PathSegmentKind::CrateKw, None) => break PathKind::Crate,
+            _ => return None,
         }
+    };
+    // Only items can be imported, not the crate itself.
+    if segments.is_empty() {
+        return None;
     }
+    segments.reverse();
+    Some(ModPath::from_segments(kind, segments))
 }
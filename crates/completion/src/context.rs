@@ -1,24 +1,34 @@
 //! See `CompletionContext` structure.
 
-use hir::{Local, ScopeDef, Semantics, SemanticsScope, Type};
-use ide_db::base_db::{FilePosition, SourceDatabase};
+use std::{iter, sync::Arc};
+
+use hir::{
+    HasAttrs, HasSource, InFile, Local, ModuleDef, PathResolution, ScopeDef, Semantics,
+    SemanticsScope, Type,
+};
+use ide_db::base_db::{FilePosition, SourceDatabase, SourceDatabaseExt, SourceRoot};
 use ide_db::{call_info::ActiveParameter, RootDatabase};
+use rustc_hash::FxHashSet;
 use syntax::{
     algo::{find_covering_element, find_node_at_offset},
-    ast, match_ast, AstNode, NodeOrToken,
+    ast::{self, GenericParamsOwner, LoopBodyOwner, NameOwner},
+    match_ast, AstNode, NodeOrToken,
     SyntaxKind::*,
-    SyntaxNode, SyntaxToken, TextRange, TextSize,
+    SyntaxNode, SyntaxToken, TextRange, TextSize, T,
 };
 use test_utils::mark;
 use text_edit::Indel;
 
 use crate::{
+    doc_comment::{doc_comment_at, CodeBlockAttrs, DocTest, IntraDocLink},
     patterns::{
-        fn_is_prev, for_is_prev2, has_bind_pat_parent, has_block_expr_parent,
-        has_field_list_parent, has_impl_as_prev_sibling, has_impl_parent,
-        has_item_list_or_source_file_parent, has_ref_parent, has_trait_as_prev_sibling,
-        has_trait_parent, if_is_prev, inside_impl_trait_block, is_in_loop_body, is_match_arm,
-        unsafe_is_prev,
+        amp_is_prev, default_is_prev_in_impl, fn_is_prev, for_is_prev2, has_bind_pat_parent,
+        has_block_expr_parent, has_extern_block_parent, has_field_list_parent,
+        has_impl_as_prev_sibling, has_impl_parent, has_item_list_or_source_file_parent,
+        has_ref_parent, has_trait_as_prev_sibling, has_trait_parent, if_is_prev,
+        inside_impl_trait_block, is_extern_crate_name, is_in_const_context, is_in_doc_comment,
+        is_in_loop_body, is_in_visibility_path, is_in_where_predicate_type, is_label_def,
+        is_label_ref, is_match_arm, unsafe_is_prev,
     },
     CompletionConfig,
 };
@@ -38,9 +48,19 @@ pub(crate) struct CompletionContext<'a> {
     pub(super) token: SyntaxToken,
     pub(super) krate: Option<hir::Crate>,
     pub(super) expected_type: Option<Type>,
+    /// The type of the value of the `return` or `break` whose operand is being
+    /// completed, like the return type of the function.
+    pub(super) return_or_break_ty: Option<Type>,
+    /// The name and declared type of the `let` whose initializer is being
+    /// completed, like `x` and `u32` in `let x: u32 = <|>`.
+    pub(super) let_name_and_type: Option<(String, Type)>,
     pub(super) name_ref_syntax: Option<ast::NameRef>,
     pub(super) function_syntax: Option<ast::Fn>,
     pub(super) use_item_syntax: Option<ast::Use>,
+    /// The brace group of the use tree the path under caret is the whole path
+    /// of, like in `use foo::{Bar, <|>}`. Taken from the file with the fake
+    /// identifier.
+    pub(super) use_tree_list: Option<ast::UseTreeList>,
     pub(super) record_lit_syntax: Option<ast::RecordExpr>,
     pub(super) record_pat_syntax: Option<ast::RecordPat>,
     pub(super) record_field_syntax: Option<ast::RecordExprField>,
@@ -48,12 +68,20 @@ pub(crate) struct CompletionContext<'a> {
     /// FIXME: `ActiveParameter` is string-based, which is very very wrong
     pub(super) active_parameter: Option<ActiveParameter>,
     pub(super) is_param: bool,
+    /// The type of the parameter whose name is being completed, like `Config`
+    /// in `fn foo(<|>: Config)`.
+    pub(super) param_type: Option<ast::Type>,
+    /// The use tree renamed by the name under caret, like `foo::Bar` in
+    /// `use foo::Bar as <|>`.
+    pub(super) renamed_use_tree: Option<ast::UseTree>,
     /// If a name-binding or reference to a const in a pattern.
     /// Irrefutable patterns (like let) are excluded.
     pub(super) is_pat_binding_or_const: bool,
     pub(super) is_irrefutable_let_pat_binding: bool,
     /// A single-indent path, like `foo`. `::foo` should not be considered a trivial path.
     pub(super) is_trivial_path: bool,
+    /// A path starting with `::`, like `::foo`.
+    pub(super) is_global_path: bool,
     /// If not a trivial path, the prefix (qualifier).
     pub(super) path_qual: Option<ast::Path>,
     pub(super) after_if: bool,
@@ -70,20 +98,94 @@ pub(crate) struct CompletionContext<'a> {
     pub(super) is_call: bool,
     /// Like `is_call`, but for tuple patterns.
     pub(super) is_pattern_call: bool,
+    /// `true` if the path is a pattern or the path of one, like `E::<|>` in
+    /// `match e { E::<|> => () }`.
+    pub(super) is_pat_path: bool,
     /// If this is a macro call, i.e. the () are already there.
     pub(super) is_macro_call: bool,
     pub(super) is_path_type: bool,
     pub(super) has_type_args: bool,
+    /// `true` if a trait object is a likely candidate at the cursor, like `&<|>`
+    /// or `Box<<|>>`.
+    pub(super) can_be_trait_object: bool,
+    /// The bound the cursor is at, like `T: Clone + <|>`.
+    pub(super) type_bound: Option<ast::TypeBound>,
+    /// `true` if the path is the trait of an impl, like `impl <|> for Foo`.
+    pub(super) is_impl_trait_path: bool,
+    /// `true` if the path is the self type of a trait impl, like
+    /// `impl Foo for <|>`.
+    pub(super) is_impl_self_ty_path: bool,
+    /// The trait of that impl.
+    pub(super) implemented_trait: Option<hir::Trait>,
+    /// The trait whose supertraits the bound at the cursor is in, like `Foo` in
+    /// `trait Foo: <|>`.
+    pub(super) supertrait_of: Option<hir::Trait>,
+    /// The trait whose generic arguments the path is in, like `Iterator` in
+    /// `impl Iterator<<|>>`.
+    pub(super) generic_arg_trait: Option<hir::Trait>,
+    /// The const parameter the generic argument at the cursor is for, like
+    /// `const CAP: usize` of `struct ArrayVec<T, const CAP: usize>` in
+    /// `ArrayVec<u8, <|>>`.
+    pub(super) const_arg_param: Option<ast::ConstParam>,
+    /// The type of `const_arg_param`.
+    pub(super) const_arg_ty: Option<Type>,
+    /// The lifetime the cursor is at, like `&'<|> str` or `for<'<|>>`, taken
+    /// from the file with the fake ident.
+    pub(super) lifetime_token: Option<SyntaxToken>,
     pub(super) attribute_under_caret: Option<ast::Attr>,
+    /// The string literal the cursor is inside of, in the original file.
+    /// Raw (`r#"…"#`), byte (`b"…"`) and raw byte strings are included.
+    pub(super) string_literal_under_caret: Option<SyntaxToken>,
+    /// `true` if that string literal is the format string of a macro like
+    /// `format!` or `println!`, which is found out by looking at the expansion.
+    pub(super) is_format_string: bool,
+    /// The key of the predicate if that string literal is the value of a cfg
+    /// predicate, like `feature` in `#[cfg(feature = "<|>")]` or `target_os`
+    /// in `cfg!(target_os = "<|>")`.
+    pub(super) cfg_value_key: Option<String>,
+    /// `true` if that string literal is the key of `env!("<|>")` or `option_env!("<|>")`.
+    pub(super) is_env_var_key: bool,
+    /// `true` if that string literal is the path of `include!`, `include_str!`
+    /// or `include_bytes!`.
+    pub(super) is_include_path: bool,
+    /// `true` if that string literal is the value of a `#[path = "<|>"]`
+    /// attribute of a module.
+    pub(super) is_mod_path_attr: bool,
+    /// `true` if that string literal is the ABI of `extern "<|>"`.
+    pub(super) is_abi_string: bool,
+    /// `true` if the cursor is inside a char or byte literal, like `'a<|>'`.
+    pub(super) is_in_char_literal: bool,
+    /// The doc test code block the cursor is in.
+    pub(super) doc_test: Option<DocTest>,
+    /// `true` if the cursor is in a doc comment.
+    pub(super) in_doc_comment: bool,
+    /// The intra-doc link in a doc comment the cursor is in.
+    pub(super) intra_doc_link: Option<IntraDocLink>,
+    /// The info string of a doc comment code fence the cursor is in.
+    pub(super) code_block_attrs: Option<CodeBlockAttrs>,
     pub(super) mod_declaration_under_caret: Option<ast::Module>,
+    /// The item of an `extern` block whose name is being completed, like `fn <|>`.
+    pub(super) extern_item_under_caret: Option<ast::ExternItem>,
     pub(super) unsafe_is_prev: bool,
+    pub(super) default_is_prev_in_impl: bool,
+    /// The lifetime being completed is the label of a `break` or a `continue`.
+    pub(super) is_label_ref: bool,
+    /// The lifetime being completed is the label of a loop.
+    pub(super) is_label_def: bool,
+    pub(super) amp_is_prev: bool,
     pub(super) if_is_prev: bool,
     pub(super) block_expr_parent: bool,
     pub(super) bind_pat_parent: bool,
     pub(super) ref_pat_parent: bool,
     pub(super) in_loop_body: bool,
+    /// `true` if the cursor is in code evaluated at compile time, like the body of a `const fn`.
+    pub(super) in_const_context: bool,
+    /// `true` if the cursor is in code which is built with the dev-dependencies:
+    /// a test, bench or example target, or code behind `#[cfg(test)]`.
+    pub(super) in_test_code: bool,
     pub(super) has_trait_parent: bool,
     pub(super) has_impl_parent: bool,
+    pub(super) has_extern_block_parent: bool,
     pub(super) inside_impl_trait_block: bool,
     pub(super) has_field_list_parent: bool,
     pub(super) trait_as_prev_sibling: bool,
@@ -92,6 +194,13 @@ pub(crate) struct CompletionContext<'a> {
     pub(super) has_item_list_or_source_file_parent: bool,
     pub(super) for_is_prev2: bool,
     pub(super) fn_is_prev: bool,
+    /// `true` for the crate name of `extern crate <|>`.
+    pub(super) is_extern_crate_name: bool,
+    /// `true` for the path of `pub(in <|>)`, which can only be an ancestor module.
+    pub(super) is_visibility_path: bool,
+    /// `true` for the type on the left of a where predicate, like
+    /// `where <|>: Clone`.
+    pub(super) is_where_predicate_type: bool,
     pub(super) locals: Vec<(String, Local)>,
 }
 
@@ -101,6 +210,7 @@ impl<'a> CompletionContext<'a> {
         position: FilePosition,
         config: &'a CompletionConfig,
     ) -> Option<CompletionContext<'a>> {
+        let _p = profile::span("CompletionContext::new");
         let sema = Semantics::new(db);
 
         let original_file = sema.parse(position.file_id);
@@ -119,8 +229,33 @@ impl<'a> CompletionContext<'a> {
         let krate = sema.to_module_def(position.file_id).map(|m| m.krate());
         let original_token =
             original_file.syntax().token_at_offset(position.offset).left_biased()?;
+        let string_literal_under_caret =
+            Some(original_token.clone()).filter(|it| is_inside_string(it, position.offset));
+        // A lone `'` starts an unterminated char literal, unless the fake ident
+        // turns it into a lifetime.
+        let is_in_char_literal =
+            is_inside_char(&original_token, position.offset) && fake_ident_token.kind() != LIFETIME;
+        let doc_comment = doc_comment_at(&original_token, position.offset);
+        let doc_test = doc_comment.as_ref().and_then(|it| it.doc_test());
+        let intra_doc_link = doc_comment.as_ref().and_then(|it| it.intra_doc_link());
+        let code_block_attrs = doc_comment.as_ref().and_then(|it| it.code_block_attrs());
         let token = sema.descend_into_macros(original_token.clone());
+        let is_format_string = string_literal_under_caret.is_some() && is_format_string(&token);
+        let cfg_value_key = string_literal_under_caret.as_ref().and_then(cfg_value_key);
+        let is_env_var_key = string_literal_under_caret
+            .as_ref()
+            .filter(|it| is_first_macro_arg(it, &["env", "option_env"]))
+            .is_some();
+        let is_include_path = string_literal_under_caret
+            .as_ref()
+            .filter(|it| is_first_macro_arg(it, &["include", "include_str", "include_bytes"]))
+            .is_some();
+        let is_mod_path_attr =
+            string_literal_under_caret.as_ref().filter(|it| is_mod_path_attr(it)).is_some();
+        let is_abi_string =
+            string_literal_under_caret.as_ref().filter(|it| it.parent().kind() == ABI).is_some();
         let scope = sema.scope_at_offset(&token.parent(), position.offset);
+        let in_test_code = is_in_test_code(&sema, &scope, &original_token);
         let mut locals = vec![];
         scope.process_all_names(&mut |name, scope| {
             if let ScopeDef::Local(local) = scope {
@@ -137,18 +272,24 @@ impl<'a> CompletionContext<'a> {
             position,
             krate,
             expected_type: None,
+            return_or_break_ty: None,
+            let_name_and_type: None,
             name_ref_syntax: None,
             function_syntax: None,
             use_item_syntax: None,
+            use_tree_list: None,
             record_lit_syntax: None,
             record_pat_syntax: None,
             record_field_syntax: None,
             impl_def: None,
             active_parameter: ActiveParameter::at(db, position),
             is_param: false,
+            param_type: None,
+            renamed_use_tree: None,
             is_pat_binding_or_const: false,
             is_irrefutable_let_pat_binding: false,
             is_trivial_path: false,
+            is_global_path: false,
             path_qual: None,
             after_if: false,
             can_be_stmt: false,
@@ -157,19 +298,50 @@ impl<'a> CompletionContext<'a> {
             dot_receiver: None,
             is_call: false,
             is_pattern_call: false,
+            is_pat_path: false,
             is_macro_call: false,
             is_path_type: false,
             has_type_args: false,
+            can_be_trait_object: false,
+            type_bound: None,
+            is_impl_trait_path: false,
+            is_impl_self_ty_path: false,
+            implemented_trait: None,
+            supertrait_of: None,
+            generic_arg_trait: None,
+            const_arg_param: None,
+            const_arg_ty: None,
+            lifetime_token: None,
             dot_receiver_is_ambiguous_float_literal: false,
             attribute_under_caret: None,
+            string_literal_under_caret,
+            is_format_string,
+            cfg_value_key,
+            is_env_var_key,
+            is_include_path,
+            is_mod_path_attr,
+            is_abi_string,
+            is_in_char_literal,
+            doc_test,
+            in_doc_comment: false,
+            intra_doc_link,
+            code_block_attrs,
             mod_declaration_under_caret: None,
+            extern_item_under_caret: None,
             unsafe_is_prev: false,
+            default_is_prev_in_impl: false,
+            is_label_ref: false,
+            is_label_def: false,
+            amp_is_prev: false,
             in_loop_body: false,
+            in_const_context: false,
+            in_test_code,
             ref_pat_parent: false,
             bind_pat_parent: false,
             block_expr_parent: false,
             has_trait_parent: false,
             has_impl_parent: false,
+            has_extern_block_parent: false,
             inside_impl_trait_block: false,
             has_field_list_parent: false,
             trait_as_prev_sibling: false,
@@ -179,6 +351,9 @@ impl<'a> CompletionContext<'a> {
             has_item_list_or_source_file_parent: false,
             for_is_prev2: false,
             fn_is_prev: false,
+            is_extern_crate_name: false,
+            is_visibility_path: false,
+            is_where_predicate_type: false,
             locals,
         };
 
@@ -229,14 +404,174 @@ impl<'a> CompletionContext<'a> {
     /// Checks whether completions in that particular case don't make much sense.
     /// Examples:
     /// - `fn <|>` -- we expect function name, it's unlikely that "hint" will be helpful.
-    ///   Exception for this case is `impl Trait for Foo`, where we would like to hint trait method names.
+    ///   Exception for this case is `impl Trait for Foo`, where we would like to hint trait method names,
+    ///   and `extern` blocks, where we hint functions declared in other `extern` blocks.
     /// - `for _ i<|>` -- obviously, it'll be "in" keyword.
+    /// - `b'a<|>'` -- there's nothing to complete inside of char and byte literals.
     pub(crate) fn no_completion_required(&self) -> bool {
-        (self.fn_is_prev && !self.inside_impl_trait_block) || self.for_is_prev2
+        (self.fn_is_prev && !self.inside_impl_trait_block && self.extern_item_under_caret.is_none())
+            || (self.for_is_prev2 && self.lifetime_token.is_none())
+            || self.is_in_char_literal
+    }
+
+    /// Whether the item only exists in test builds: it is gated behind
+    /// `#[cfg(test)]`, is defined in a test module or comes from a
+    /// dev-dependency. Such items are only offered in test code.
+    pub(crate) fn is_test_only(&self, def: &ScopeDef) -> bool {
+        let db = self.db;
+        let (module, is_test_only) = match *def {
+            ScopeDef::ModuleDef(ModuleDef::Module(it)) => (it, false),
+            ScopeDef::ModuleDef(def) => {
+                let attrs = match def {
+                    ModuleDef::Function(it) => it.attrs(db),
+                    ModuleDef::Adt(it) => it.attrs(db),
+                    ModuleDef::EnumVariant(it) => it.attrs(db),
+                    ModuleDef::Const(it) => it.attrs(db),
+                    ModuleDef::Static(it) => it.attrs(db),
+                    ModuleDef::Trait(it) => it.attrs(db),
+                    ModuleDef::TypeAlias(it) => it.attrs(db),
+                    ModuleDef::Module(_) | ModuleDef::BuiltinType(_) => Default::default(),
+                };
+                match def.module(db) {
+                    Some(module) => (module, attrs.is_test_only()),
+                    None => return false,
+                }
+            }
+            ScopeDef::MacroDef(it) => match it.module(db) {
+                Some(module) => (module, it.attrs(db).is_test_only()),
+                None => return false,
+            },
+            _ => return false,
+        };
+        if is_test_only || module.is_test_only(db) {
+            return true;
+        }
+        let krate = module.krate();
+        match self.krate {
+            Some(current) if current != krate => {
+                current.dependencies(db).into_iter().any(|dep| dep.is_dev && dep.krate == krate)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether only traits, and the modules they can be reached through, are
+    /// expected at the caret, like in bounds or in `impl <|> for Foo`.
+    pub(crate) fn expects_trait(&self) -> bool {
+        self.type_bound.is_some() || self.is_impl_trait_path
+    }
+
+    /// Whether the bound at the caret is one of a trait object, like
+    /// `Box<dyn <|>>` or `&dyn Debug + <|>`.
+    pub(crate) fn is_trait_object_bound(&self) -> bool {
+        self.type_bound
+            .as_ref()
+            .and_then(|it| it.syntax().parent())
+            .and_then(|it| it.parent())
+            .and_then(ast::DynTraitType::cast)
+            .is_some()
+    }
+
+    /// The bounds next to the one at the caret in its bound list, like
+    /// `Debug` and `'static` in `T: Debug + <|> + 'static`.
+    pub(crate) fn other_bounds(&self) -> FxHashSet<String> {
+        let current = match &self.type_bound {
+            Some(it) => it,
+            None => return FxHashSet::default(),
+        };
+        match current.syntax().parent().and_then(ast::TypeBoundList::cast) {
+            Some(list) => list
+                .bounds()
+                .filter(|it| it != current)
+                .map(|it| it.syntax().text().to_string())
+                .collect(),
+            None => FxHashSet::default(),
+        }
+    }
+
+    /// Whether the bound at the caret follows a `+`, like `Debug + <|>`.
+    pub(crate) fn is_additional_bound(&self) -> bool {
+        let current = match &self.type_bound {
+            Some(it) => it,
+            None => return false,
+        };
+        current
+            .syntax()
+            .parent()
+            .and_then(ast::TypeBoundList::cast)
+            .and_then(|list| list.bounds().next())
+            .filter(|first| first != current)
+            .is_some()
+    }
+
+    /// The names imported by the use trees next to the one under caret in its
+    /// brace group, and `*` for a glob, like `Bar` and `self` in
+    /// `use foo::{Bar, self, baz::Qux, <|>}`.
+    pub(crate) fn listed_use_trees(&self) -> FxHashSet<String> {
+        let list = match &self.use_tree_list {
+            Some(it) => it,
+            None => return FxHashSet::default(),
+        };
+        list.use_trees()
+            .filter(|it| !it.syntax().text_range().contains_inclusive(self.position.offset))
+            .filter_map(|it| {
+                if it.star_token().is_some() && it.path().is_none() {
+                    return Some("*".to_string());
+                }
+                let path = it.path().filter(|it| it.qualifier().is_none())?;
+                if it.use_tree_list().is_some() || it.star_token().is_some() {
+                    return None;
+                }
+                Some(path.segment()?.syntax().text().to_string())
+            })
+            .collect()
+    }
+
+    /// The source root of the file being completed.
+    pub(crate) fn source_root(&self) -> Arc<SourceRoot> {
+        self.db.source_root(self.db.file_source_root(self.position.file_id))
+    }
+
+    /// The types expected at the caret. The argument or the type annotated on
+    /// the `let` statement under the caret are more reliable than the type of
+    /// the surrounding expression, so they come first.
+    pub(crate) fn expected_types(&self) -> impl Iterator<Item = Type> + '_ {
+        let param_ty = self.active_parameter.as_ref().map(|it| it.ty.clone());
+        let let_ty = self
+            .token
+            .ancestors()
+            .find_map(ast::LetStmt::cast)
+            .and_then(|it| it.pat())
+            .and_then(|pat| self.sema.type_of_pat(&pat));
+        param_ty.into_iter().chain(let_ty).chain(self.expected_type.clone())
+    }
+
+    /// Whether a local can initialize the record field `name` of type `ty` with
+    /// the field init shorthand.
+    pub(crate) fn has_local_for_field(&self, name: &str, ty: &Type) -> bool {
+        self.locals.iter().any(|(local_name, local)| local_name == name && local.ty(self.db) == *ty)
+    }
+
+    /// Whether the record field under caret is already followed by a `:`, like
+    /// in `S { fo<|>: 1 }`, so that completing it must not insert another one.
+    pub(crate) fn record_field_has_value(&self) -> bool {
+        self.record_field_syntax.as_ref().and_then(|it| it.colon_token()).is_some()
     }
 
     /// The range of the identifier that is being completed.
     pub(crate) fn source_range(&self) -> TextRange {
+        if let Some(doc_test) = &self.doc_test {
+            return doc_test.source_range;
+        }
+        if let Some(link) = &self.intra_doc_link {
+            return link.source_range;
+        }
+        if let Some(attrs) = &self.code_block_attrs {
+            return attrs.source_range;
+        }
+        if let Some(lifetime) = &self.lifetime_token {
+            return TextRange::new(lifetime.text_range().start(), self.position.offset);
+        }
         // check kind of macro-expanded token, but use range of original token
         let kind = self.token.kind();
         if kind == IDENT || kind == UNDERSCORE || kind.is_keyword() {
@@ -252,12 +587,19 @@ impl<'a> CompletionContext<'a> {
         let syntax_element = NodeOrToken::Token(fake_ident_token);
         self.block_expr_parent = has_block_expr_parent(syntax_element.clone());
         self.unsafe_is_prev = unsafe_is_prev(syntax_element.clone());
+        self.default_is_prev_in_impl = default_is_prev_in_impl(syntax_element.clone());
+        self.is_label_ref = is_label_ref(syntax_element.clone());
+        self.is_label_def = is_label_def(syntax_element.clone());
+        self.amp_is_prev = amp_is_prev(syntax_element.clone());
         self.if_is_prev = if_is_prev(syntax_element.clone());
         self.bind_pat_parent = has_bind_pat_parent(syntax_element.clone());
         self.ref_pat_parent = has_ref_parent(syntax_element.clone());
         self.in_loop_body = is_in_loop_body(syntax_element.clone());
+        self.in_const_context = is_in_const_context(syntax_element.clone());
+        self.in_doc_comment = is_in_doc_comment(syntax_element.clone());
         self.has_trait_parent = has_trait_parent(syntax_element.clone());
         self.has_impl_parent = has_impl_parent(syntax_element.clone());
+        self.has_extern_block_parent = has_extern_block_parent(syntax_element.clone());
         self.inside_impl_trait_block = inside_impl_trait_block(syntax_element.clone());
         self.has_field_list_parent = has_field_list_parent(syntax_element.clone());
         self.impl_as_prev_sibling = has_impl_as_prev_sibling(syntax_element.clone());
@@ -270,6 +612,97 @@ impl<'a> CompletionContext<'a> {
                 .filter(|module| module.item_list().is_none());
         self.for_is_prev2 = for_is_prev2(syntax_element.clone());
         self.fn_is_prev = fn_is_prev(syntax_element.clone());
+        self.is_extern_crate_name = is_extern_crate_name(syntax_element.clone());
+        self.is_visibility_path = is_in_visibility_path(syntax_element.clone());
+        self.is_where_predicate_type = is_in_where_predicate_type(syntax_element.clone());
+    }
+
+    /// The type of the initializer, when completing the whole pattern of an
+    /// `if let` or a `while let`.
+    fn let_condition_ty(&self) -> Option<Type> {
+        let node = self
+            .token
+            .ancestors()
+            .find(|it| ast::Pat::can_cast(it.kind()) || ast::Condition::can_cast(it.kind()))?;
+        let condition = match ast::Pat::cast(node.clone()) {
+            Some(pat) => ast::Condition::cast(pat.syntax().parent()?)?,
+            None => ast::Condition::cast(node)?,
+        };
+        // The `=` ends up in an error node while the pattern is still missing,
+        // so compare against the initializer instead.
+        let expr = condition.expr()?;
+        if self.position.offset >= expr.syntax().text_range().start() {
+            return None;
+        }
+        self.sema.type_of_expr(&expr)
+    }
+
+    fn compute_let_name_and_type(&self) -> Option<(String, Type)> {
+        let let_stmt = self.token.ancestors().find_map(ast::LetStmt::cast)?;
+        if self.position.offset < let_stmt.eq_token()?.text_range().end() {
+            return None;
+        }
+        // Without an annotation, the type would be inferred from the
+        // initializer that is being completed.
+        let_stmt.ty()?;
+        let pat = let_stmt.pat()?;
+        let ty = self.sema.type_of_pat(&pat).filter(|it| !it.is_unknown())?;
+        let name = match &pat {
+            ast::Pat::IdentPat(it) => it.name().map(|it| it.text().to_string()),
+            _ => None,
+        };
+        Some((name.unwrap_or_default(), ty))
+    }
+
+    fn compute_return_or_break_ty(&self, name_ref: &ast::NameRef) -> Option<Type> {
+        let path_expr = name_ref.syntax().ancestors().find_map(ast::PathExpr::cast)?;
+        let jump = path_expr.syntax().parent()?;
+        // `name_ref` is in the file with the fake ident, which `sema` doesn't
+        // know, so the targets are looked up from the original token instead.
+        let is_body_owner =
+            |it: &SyntaxNode| ast::Fn::can_cast(it.kind()) || ast::ClosureExpr::can_cast(it.kind());
+        let ancestors = self.token.ancestors().take_while(|it| !is_body_owner(it));
+        let ty = if ast::ReturnExpr::can_cast(jump.kind()) {
+            // The `return` of a closure returns from the closure, whose return
+            // type is not known here.
+            let body_owner = self.token.ancestors().find(is_body_owner)?;
+            if ast::ClosureExpr::can_cast(body_owner.kind()) {
+                return None;
+            }
+            self.sema.to_def(self.function_syntax.as_ref()?)?.ret_type(self.db)
+        } else {
+            let label = ast::BreakExpr::cast(jump)?.lifetime_token();
+            let label = label.as_ref().map(|it| it.text());
+            let (loop_expr, _) = ancestors
+                .filter_map(|node| {
+                    let (loop_expr, loop_label) = match_ast! {
+                        match node {
+                            ast::LoopExpr(it) => (Some(it.clone()), it.label()),
+                            ast::WhileExpr(it) => (None, it.label()),
+                            ast::ForExpr(it) => (None, it.label()),
+                            _ => return None,
+                        }
+                    };
+                    Some((loop_expr, loop_label.and_then(|it| it.lifetime_token())))
+                })
+                .find(|(_, loop_label)| {
+                    label.is_none() || loop_label.as_ref().map(|it| it.text()) == label
+                })?;
+            // Only `loop`s can be broken out of with a value. The `break` without
+            // a value makes the loop `()`, so prefer the type it is assigned to.
+            let loop_expr = loop_expr?;
+            let annotated = loop_expr
+                .syntax()
+                .parent()
+                .and_then(ast::LetStmt::cast)
+                .filter(|it| it.ty().is_some())
+                .and_then(|it| self.sema.type_of_pat(&it.pat()?));
+            match annotated {
+                Some(it) => it,
+                None => self.sema.type_of_expr(&loop_expr.into())?,
+            }
+        };
+        Some(ty).filter(|it| !it.is_unit() && !it.is_unknown())
     }
 
     fn fill(
@@ -281,21 +714,24 @@ impl<'a> CompletionContext<'a> {
         // FIXME: this is wrong in at least two cases:
         //  * when there's no token `foo(<|>)`
         //  * when there is a token, but it happens to have type of it's own
-        self.expected_type = self
-            .token
-            .ancestors()
-            .find_map(|node| {
-                let ty = match_ast! {
-                    match node {
-                        ast::Pat(it) => self.sema.type_of_pat(&it),
-                        ast::Expr(it) => self.sema.type_of_expr(&it),
-                        _ => return None,
-                    }
-                };
-                Some(ty)
-            })
-            .flatten();
+        self.expected_type = self.let_condition_ty().or_else(|| {
+            self.token
+                .ancestors()
+                .find_map(|node| {
+                    let ty = match_ast! {
+                        match node {
+                            ast::Pat(it) => self.sema.type_of_pat(&it),
+                            ast::Expr(it) => self.sema.type_of_expr(&it),
+                            _ => return None,
+                        }
+                    };
+                    Some(ty)
+                })
+                .flatten()
+        });
         self.attribute_under_caret = find_node_at_offset(&file_with_fake_ident, offset);
+        self.lifetime_token =
+            file_with_fake_ident.token_at_offset(offset).find(|it| it.kind() == LIFETIME);
 
         // First, let's try to complete a reference to some declaration.
         if let Some(name_ref) = find_node_at_offset::<ast::NameRef>(&file_with_fake_ident, offset) {
@@ -310,12 +746,30 @@ impl<'a> CompletionContext<'a> {
                 self.record_pat_syntax =
                     self.sema.find_node_at_offset_with_macros(&original_file, offset);
             }
-            self.classify_name_ref(original_file, name_ref, offset);
+            self.classify_name_ref(original_file, name_ref.clone(), offset);
+            self.return_or_break_ty = self.compute_return_or_break_ty(&name_ref);
+            self.let_name_and_type = self.compute_let_name_and_type();
         }
 
         // Otherwise, see if this is a declaration. We can use heuristics to
         // suggest declaration names, see `CompletionKind::Magic`.
         if let Some(name) = find_node_at_offset::<ast::Name>(&file_with_fake_ident, offset) {
+            let typed_param = name
+                .syntax()
+                .parent()
+                .and_then(ast::IdentPat::cast)
+                .and_then(|it| it.syntax().parent())
+                .and_then(ast::Param::cast)
+                .filter(|it| {
+                    let param_list_owner = it.syntax().parent().and_then(|it| it.parent());
+                    param_list_owner.map_or(false, |it| ast::Fn::can_cast(it.kind()))
+                })
+                .and_then(|it| it.ty());
+            if let Some(ty) = typed_param {
+                self.is_param = true;
+                self.param_type = Some(ty);
+                return;
+            }
             if let Some(bind_pat) = name.syntax().ancestors().find_map(ast::IdentPat::cast) {
                 self.is_pat_binding_or_const = true;
                 if bind_pat.at_token().is_some()
@@ -337,10 +791,25 @@ impl<'a> CompletionContext<'a> {
                     }
                 }
             }
+            if let Some(item) = name.syntax().parent().and_then(ast::ExternItem::cast) {
+                if item.syntax().parent().map_or(false, |it| it.kind() == EXTERN_ITEM_LIST) {
+                    self.extern_item_under_caret = Some(item);
+                }
+            }
             if is_node::<ast::Param>(name.syntax()) {
                 self.is_param = true;
                 return;
             }
+            let renamed_use_tree = name
+                .syntax()
+                .parent()
+                .and_then(ast::Rename::cast)
+                .and_then(|it| it.syntax().parent())
+                .and_then(ast::UseTree::cast);
+            if let Some(use_tree) = renamed_use_tree {
+                self.renamed_use_tree = Some(use_tree);
+                return;
+            }
             // FIXME: remove this (^) duplication and make the check more precise
             if name.syntax().ancestors().find_map(ast::RecordPatFieldList::cast).is_some() {
                 self.record_pat_syntax =
@@ -386,6 +855,16 @@ impl<'a> CompletionContext<'a> {
 
         self.use_item_syntax =
             self.sema.ancestors_with_macros(self.token.parent()).find_map(ast::Use::cast);
+        self.use_tree_list = name_ref
+            .syntax()
+            .parent()
+            .and_then(ast::PathSegment::cast)
+            .map(|it| it.parent_path())
+            .filter(|it| it.qualifier().is_none())
+            .and_then(|it| it.syntax().parent())
+            .filter(|it| it.kind() == USE_TREE)
+            .and_then(|it| it.parent())
+            .and_then(ast::UseTreeList::cast);
 
         self.function_syntax = self
             .sema
@@ -417,9 +896,50 @@ impl<'a> CompletionContext<'a> {
             self.is_macro_call = path.syntax().parent().and_then(ast::MacroCall::cast).is_some();
             self.is_pattern_call =
                 path.syntax().parent().and_then(ast::TupleStructPat::cast).is_some();
+            self.is_pat_path = path
+                .syntax()
+                .parent()
+                .filter(|it| {
+                    ast::PathPat::can_cast(it.kind())
+                        || ast::TupleStructPat::can_cast(it.kind())
+                        || ast::RecordPat::can_cast(it.kind())
+                })
+                .is_some();
 
             self.is_path_type = path.syntax().parent().and_then(ast::PathType::cast).is_some();
             self.has_type_args = segment.generic_arg_list().is_some();
+            let impl_and_ty = path.syntax().parent().and_then(ast::PathType::cast).and_then(|ty| {
+                let impl_ = ty.syntax().parent().and_then(ast::Impl::cast)?;
+                Some((impl_, ty))
+            });
+            if let Some((impl_, ty)) = impl_and_ty {
+                match impl_.trait_() {
+                    Some(trait_) if trait_.syntax() == ty.syntax() => {
+                        self.is_impl_trait_path = true
+                    }
+                    Some(trait_) => {
+                        self.is_impl_self_ty_path = true;
+                        // The trait comes before the caret, so it is the same in
+                        // the original file.
+                        self.implemented_trait = match trait_ {
+                            ast::Type::PathType(it) => it
+                                .path()
+                                .and_then(|it| {
+                                    find_node_with_range::<ast::Path>(
+                                        original_file,
+                                        it.syntax().text_range(),
+                                    )
+                                })
+                                .and_then(|it| match self.sema.resolve_path(&it)? {
+                                    PathResolution::Def(ModuleDef::Trait(it)) => Some(it),
+                                    _ => None,
+                                }),
+                            _ => None,
+                        };
+                    }
+                    None => {}
+                }
+            }
 
             if let Some(path) = path_or_use_tree_qualifier(&path) {
                 self.path_qual = path
@@ -436,12 +956,72 @@ impl<'a> CompletionContext<'a> {
 
             if let Some(segment) = path.segment() {
                 if segment.coloncolon_token().is_some() {
+                    self.is_global_path = true;
                     return;
                 }
             }
 
             self.is_trivial_path = true;
 
+            let type_arg = path
+                .syntax()
+                .parent()
+                .and_then(ast::PathType::cast)
+                .and_then(|it| it.syntax().parent())
+                .and_then(ast::TypeArg::cast);
+            // The path with the generic arguments comes before the caret, so it
+            // is the same in the original file.
+            let generic_args_owner = type_arg
+                .as_ref()
+                .and_then(|it| it.syntax().parent())
+                .and_then(ast::GenericArgList::cast)
+                .and_then(|it| it.syntax().parent())
+                .and_then(ast::PathSegment::cast)
+                .and_then(|it| it.name_ref())
+                .and_then(|it| {
+                    find_node_with_range::<ast::NameRef>(original_file, it.syntax().text_range())
+                })
+                .and_then(|it| ast::PathSegment::cast(it.syntax().parent()?))
+                .and_then(|it| self.sema.resolve_path(&it.parent_path()));
+            self.generic_arg_trait = match generic_args_owner {
+                Some(PathResolution::Def(ModuleDef::Trait(it))) => Some(it),
+                _ => None,
+            };
+            if let (Some(type_arg), Some(PathResolution::Def(def))) =
+                (&type_arg, generic_args_owner)
+            {
+                let param = const_param_of_arg(self.db, def, type_arg);
+                self.const_arg_ty = param.clone().and_then(|it| const_param_ty(&self.sema, it));
+                self.const_arg_param = param.map(|it| it.value);
+            }
+
+            self.can_be_trait_object = path
+                .syntax()
+                .parent()
+                .and_then(ast::PathType::cast)
+                .map_or(false, |it| is_trait_object_position(&it));
+            self.type_bound = path
+                .syntax()
+                .parent()
+                .and_then(ast::PathType::cast)
+                .and_then(|it| it.syntax().parent())
+                .and_then(ast::TypeBound::cast);
+            let is_supertrait_bound = self
+                .type_bound
+                .as_ref()
+                .and_then(|it| it.syntax().parent())
+                .and_then(|it| it.parent())
+                .filter(|it| it.kind() == TRAIT)
+                .is_some();
+            if is_supertrait_bound {
+                self.supertrait_of = self
+                    .token
+                    .parent()
+                    .ancestors()
+                    .find_map(ast::Trait::cast)
+                    .and_then(|it| self.sema.to_def(&it));
+            }
+
             // Find either enclosing expr statement (thing with `;`) or a
             // block. If block, check that we are the last expr.
             self.can_be_stmt = name_ref
@@ -512,6 +1092,177 @@ fn is_node<N: AstNode>(node: &SyntaxNode) -> bool {
     }
 }
 
+fn is_trait_object_position(path_type: &ast::PathType) -> bool {
+    let parent = match path_type.syntax().parent() {
+        Some(it) => it,
+        None => return false,
+    };
+    match parent.kind() {
+        REF_TYPE => true,
+        TYPE_ARG => parent
+            .parent()
+            .and_then(ast::GenericArgList::cast)
+            .and_then(|it| it.syntax().parent())
+            .and_then(ast::PathSegment::cast)
+            .and_then(|it| it.name_ref())
+            .map_or(false, |it| it.text() == "Box"),
+        _ => false,
+    }
+}
+
+/// Const generic parameters are not lowered yet, so the parameter an argument
+/// is for is looked up in the source of the generic item.
+fn const_param_of_arg(
+    db: &RootDatabase,
+    def: ModuleDef,
+    arg: &ast::TypeArg,
+) -> Option<InFile<ast::ConstParam>> {
+    let arg_list = arg.syntax().parent().and_then(ast::GenericArgList::cast)?;
+    // Lifetimes come first and are matched separately.
+    let idx = arg_list
+        .generic_args()
+        .filter(|it| matches!(it, ast::GenericArg::TypeArg(_) | ast::GenericArg::ConstArg(_)))
+        .position(|it| it.syntax() == arg.syntax())?;
+    let param_list = match def {
+        ModuleDef::Adt(hir::Adt::Struct(it)) => it.source(db).map(|it| it.generic_param_list()),
+        ModuleDef::Adt(hir::Adt::Enum(it)) => it.source(db).map(|it| it.generic_param_list()),
+        ModuleDef::Adt(hir::Adt::Union(it)) => it.source(db).map(|it| it.generic_param_list()),
+        ModuleDef::Function(it) => it.source(db).map(|it| it.generic_param_list()),
+        ModuleDef::Trait(it) => it.source(db).map(|it| it.generic_param_list()),
+        ModuleDef::TypeAlias(it) => it.source(db).map(|it| it.generic_param_list()),
+        _ => return None,
+    }
+    .transpose()?;
+    let param = param_list
+        .value
+        .generic_params()
+        .filter(|it| !matches!(it, ast::GenericParam::LifetimeParam(_)))
+        .nth(idx)?;
+    match param {
+        ast::GenericParam::ConstParam(it) => Some(param_list.with_value(it)),
+        _ => None,
+    }
+}
+
+/// The type of a const parameter, resolved in the scope of its generic item.
+/// `sema` only resolves the nodes of the trees it parsed, so the parameter is
+/// looked up again in the parse of its file.
+fn const_param_ty(sema: &Semantics<RootDatabase>, param: InFile<ast::ConstParam>) -> Option<Type> {
+    let file_id = param.file_id.original_file(sema.db);
+    if param.file_id != file_id.into() {
+        return None;
+    }
+    let file = sema.parse(file_id);
+    let param =
+        find_node_with_range::<ast::ConstParam>(file.syntax(), param.value.syntax().text_range())?;
+    sema.resolve_type(&param.ty()?)
+}
+
+fn is_in_test_code(
+    sema: &Semantics<RootDatabase>,
+    scope: &SemanticsScope,
+    token: &SyntaxToken,
+) -> bool {
+    let module = match scope.module() {
+        Some(it) => it,
+        None => return false,
+    };
+    if module.krate().is_dev_target(sema.db) || module.is_test_only(sema.db) {
+        return true;
+    }
+    token
+        .parent()
+        .ancestors()
+        .filter_map(ast::Fn::cast)
+        .filter_map(|it| sema.to_def(&it))
+        .any(|it| it.attrs(sema.db).is_test_only())
+}
+
+/// Checks whether `offset` lies between the quotes of a (possibly raw and/or
+/// byte) string literal. Unterminated literals extend up to the end of the token.
+fn is_inside_string(token: &SyntaxToken, offset: TextSize) -> bool {
+    if !matches!(token.kind(), STRING | BYTE_STRING) {
+        return false;
+    }
+    inside_quotes(token, '"', offset)
+}
+
+/// The macros taking a format string expand to the builtin `format_args!` or
+/// `format_args_nl!`, with the format string as the first argument.
+fn is_format_string(token: &SyntaxToken) -> bool {
+    is_first_macro_arg(token, &["format_args", "format_args_nl"])
+}
+
+/// Whether the token is the first argument of a call of one of the given macros.
+fn is_first_macro_arg(token: &SyntaxToken, macros: &[&str]) -> bool {
+    let token_tree = token.parent();
+    let macro_name = token_tree
+        .parent()
+        .and_then(ast::MacroCall::cast)
+        .and_then(|it| it.path())
+        .and_then(|it| it.segment())
+        .and_then(|it| it.name_ref());
+    if !matches!(&macro_name, Some(name) if macros.contains(&name.text().as_str())) {
+        return false;
+    }
+    token_tree.children_with_tokens().filter(|it| it.kind() != WHITESPACE).nth(1)
+        == Some(token.clone().into())
+}
+
+/// Returns the key if the string literal follows `key =` in the predicate of
+/// `#[cfg]`, `#[cfg_attr]` or `cfg!`, also when nested in `all`, `any` or `not`.
+fn cfg_value_key(string: &SyntaxToken) -> Option<String> {
+    let mut prev_tokens = iter::successors(string.prev_token(), |it| it.prev_token())
+        .filter(|it| it.kind() != WHITESPACE);
+    prev_tokens.next().filter(|it| it.kind() == T![=])?;
+    let key = prev_tokens.next().filter(|it| it.kind() == IDENT)?;
+    let is_cfg = string.parent().ancestors().find_map(|node| {
+        if let Some(attr) = ast::Attr::cast(node.clone()) {
+            let path = attr.path()?.to_string();
+            return Some(path == "cfg" || path == "cfg_attr");
+        }
+        let macro_call = ast::MacroCall::cast(node)?;
+        Some(macro_call.path()?.to_string() == "cfg")
+    })?;
+    if is_cfg {
+        Some(key.text().to_string())
+    } else {
+        None
+    }
+}
+
+fn is_mod_path_attr(string: &SyntaxToken) -> bool {
+    let attr = match string.parent().parent().and_then(ast::Attr::cast) {
+        Some(it) => it,
+        None => return false,
+    };
+    attr.simple_name().as_deref() == Some("path")
+        && attr.syntax().parent().filter(|it| it.kind() == MODULE).is_some()
+}
+
+fn is_inside_char(token: &SyntaxToken, offset: TextSize) -> bool {
+    if !matches!(token.kind(), CHAR | BYTE) {
+        return false;
+    }
+    inside_quotes(token, '\'', offset)
+}
+
+fn inside_quotes(token: &SyntaxToken, quote: char, offset: TextSize) -> bool {
+    let text = token.text().as_str();
+    let range = token.text_range();
+    let left_quote = match text.find(quote) {
+        Some(it) => range.start() + TextSize::of(&text[..it]) + TextSize::of(quote),
+        None => return false,
+    };
+    let right_quote = match text.rfind(quote) {
+        Some(it) if range.start() + TextSize::of(&text[..it]) >= left_quote => {
+            range.start() + TextSize::of(&text[..it])
+        }
+        _ => range.end(),
+    };
+    left_quote <= offset && offset <= right_quote
+}
+
 fn path_or_use_tree_qualifier(path: &ast::Path) -> Option<ast::Path> {
     if let Some(qual) = path.qualifier() {
         return Some(qual);
@@ -0,0 +1,121 @@
+//! Aggregates per-provider statistics over many completion requests, for
+//! benchmarking completion on real-world code offline.
+
+use std::{collections::BTreeMap, time::Duration};
+
+use ide_db::{base_db::FilePosition, RootDatabase};
+
+use crate::{completions_impl, CompletionConfig, CompletionScore};
+
+/// Statistics of the completion requests run by [`completions_with_profile`].
+#[derive(Debug, Default)]
+pub struct CompletionProfile {
+    /// The number of positions completion was requested at.
+    pub positions: usize,
+    /// The number of positions where no completion was computed, like after
+    /// `fn` or inside a char literal.
+    pub no_completion: usize,
+    /// One entry per provider, in the order the providers are run.
+    pub providers: Vec<ProviderProfile>,
+}
+
+#[derive(Debug)]
+pub struct ProviderProfile {
+    /// The profiling span label of the provider, like `completion::dot`.
+    pub name: &'static str,
+    /// The total time spent in the provider.
+    pub time: Duration,
+    /// The number of positions where the provider was skipped because the
+    /// time budget was exceeded.
+    pub skipped: usize,
+    /// The total number of items the provider added.
+    pub items: usize,
+    /// The number of items per score, with `None` counting the unscored ones.
+    pub scores: BTreeMap<Option<CompletionScore>, usize>,
+}
+
+impl ProviderProfile {
+    fn new(name: &'static str) -> ProviderProfile {
+        ProviderProfile {
+            name,
+            time: Duration::default(),
+            skipped: 0,
+            items: 0,
+            scores: BTreeMap::default(),
+        }
+    }
+}
+
+/// Runs completion at each of the `positions` and reports how long every
+/// provider took, how many items it produced and how these were scored.
+pub fn completions_with_profile(
+    db: &RootDatabase,
+    config: &CompletionConfig,
+    positions: &[FilePosition],
+) -> CompletionProfile {
+    let mut res = CompletionProfile::default();
+    for &position in positions {
+        res.positions += 1;
+        let providers = &mut res.providers;
+        let completions = completions_impl(db, config, position, |name, run| {
+            let provider = match providers.iter_mut().find(|it| it.name == name) {
+                Some(it) => it,
+                None => {
+                    providers.push(ProviderProfile::new(name));
+                    providers.last_mut().unwrap()
+                }
+            };
+            match run {
+                Some((time, items)) => {
+                    provider.time += time;
+                    provider.items += items.len();
+                    for item in items {
+                        *provider.scores.entry(item.score()).or_default() += 1;
+                    }
+                }
+                None => provider.skipped += 1,
+            }
+        });
+        if completions.is_none() {
+            res.no_completion += 1;
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use ide_db::base_db::{FilePosition, SourceDatabaseExt};
+    use syntax::TextSize;
+
+    use crate::{test_utils, CompletionConfig, CompletionScore};
+
+    use super::completions_with_profile;
+
+    #[test]
+    fn aggregates_provider_statistics() {
+        let (db, position) = test_utils::position(
+            r#"
+struct S { foo: u32, bar: i32 }
+fn take(bar: u32) {}
+fn main(s: S) { take(s.<|>) }
+"#,
+        );
+        let after_fn = db.file_text(position.file_id).find("take").unwrap();
+        let no_completion =
+            FilePosition { file_id: position.file_id, offset: TextSize::from(after_fn as u32) };
+        let profile = completions_with_profile(
+            &db,
+            &CompletionConfig::default(),
+            &[position, position, no_completion],
+        );
+        assert_eq!(profile.positions, 3);
+        assert_eq!(profile.no_completion, 1);
+
+        let dot = profile.providers.iter().find(|it| it.name == "completion::dot").unwrap();
+        assert_eq!(dot.items, 4);
+        assert_eq!(dot.skipped, 0);
+        assert_eq!(dot.scores.get(&Some(CompletionScore::TypeMatch)), Some(&2));
+        assert_eq!(dot.scores.get(&None), Some(&2));
+    }
+}
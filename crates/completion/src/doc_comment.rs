@@ -0,0 +1,301 @@
+//! Figures out where exactly the cursor is inside of a line doc comment.
+//!
+//! Doc comments are single tokens for the parser, so we reconstruct their
+//! markdown structure here, which, most importantly, allows treating doc test
+//! code blocks as (virtual) Rust files.
+
+use syntax::{
+    ast::{self, AstToken},
+    Direction, NodeOrToken, SourceFile, SyntaxElement, SyntaxKind, SyntaxToken, TextRange,
+    TextSize,
+};
+
+/// A line of a doc comment, without the `///` or `//!` prefix.
+#[derive(Debug)]
+struct DocLine {
+    text: String,
+    /// The range of `text` in the original file.
+    range: TextRange,
+}
+
+/// A block of consecutive line doc comments the cursor is in.
+#[derive(Debug)]
+pub(crate) struct DocComment {
+    lines: Vec<DocLine>,
+    cursor_line: usize,
+    offset: TextSize,
+}
+
+/// A doc test code block the cursor is in, parsed the way rustdoc would compile it.
+#[derive(Debug)]
+pub(crate) struct DocTest {
+    /// The doc test source wrapped into `fn main`, with a fake identifier
+    /// inserted at the cursor.
+    pub(crate) file: SourceFile,
+    /// The cursor position in `file`.
+    pub(crate) offset: TextSize,
+    /// The range of the identifier under the cursor, in the original file.
+    pub(crate) source_range: TextRange,
+}
+
+/// An intra-doc link the cursor is in, like `[Foo::ba<|>]` or `[foo](crate::ba<|>)`.
+#[derive(Debug)]
+pub(crate) struct IntraDocLink {
+    /// The path before the segment under the cursor, like `Foo` in `[Foo::ba<|>]`.
+    pub(crate) qualifier: Option<String>,
+    /// The range of the segment under the cursor, in the original file.
+    pub(crate) source_range: TextRange,
+}
+
+/// The info string of a code fence the cursor is in, like ```` ```rust,no_<|> ````.
+#[derive(Debug)]
+pub(crate) struct CodeBlockAttrs {
+    /// The attributes before the one under the cursor.
+    pub(crate) existing: Vec<String>,
+    /// The range of the attribute under the cursor, in the original file.
+    pub(crate) source_range: TextRange,
+}
+
+pub(crate) fn doc_comment_at(token: &SyntaxToken, offset: TextSize) -> Option<DocComment> {
+    let comment = ast::Comment::cast(token.clone())?;
+    let kind = comment.kind();
+    if !kind.shape.is_line() || kind.doc.is_none() {
+        return None;
+    }
+    let prefix = comment.prefix();
+
+    let same_block = |element: &SyntaxElement| match element {
+        NodeOrToken::Token(token) if token.kind() == SyntaxKind::WHITESPACE => {
+            token.text().matches('\n').count() <= 1
+        }
+        NodeOrToken::Token(token) => ast::Comment::cast(token.clone())
+            .map_or(false, |it| it.kind() == kind && it.prefix() == prefix),
+        NodeOrToken::Node(_) => false,
+    };
+    let mut comments: Vec<SyntaxToken> = token
+        .siblings_with_tokens(Direction::Prev)
+        .skip(1)
+        .take_while(same_block)
+        .filter_map(|it| it.into_token())
+        .filter(|it| it.kind() == SyntaxKind::COMMENT)
+        .collect();
+    comments.reverse();
+    let cursor_line = comments.len();
+    comments.push(token.clone());
+    comments.extend(
+        token
+            .siblings_with_tokens(Direction::Next)
+            .skip(1)
+            .take_while(same_block)
+            .filter_map(|it| it.into_token())
+            .filter(|it| it.kind() == SyntaxKind::COMMENT),
+    );
+
+    let lines = comments
+        .into_iter()
+        .map(|comment| {
+            let range = comment.text_range();
+            let start = range.start() + TextSize::of(prefix);
+            DocLine {
+                text: comment.text()[prefix.len()..].to_string(),
+                range: TextRange::new(start.min(range.end()), range.end()),
+            }
+        })
+        .collect();
+    Some(DocComment { lines, cursor_line, offset })
+}
+
+impl DocComment {
+    /// Whether the cursor line is inside of a code block or opens one.
+    pub(crate) fn in_code_block(&self) -> bool {
+        let fences =
+            self.lines[..=self.cursor_line].iter().filter(|it| code_fence_info(&it.text).is_some());
+        fences.count() % 2 == 1
+    }
+
+    /// Returns the text of the cursor line before the cursor.
+    pub(crate) fn text_before_cursor(&self) -> Option<&str> {
+        let cursor_line = &self.lines[self.cursor_line];
+        if self.offset < cursor_line.range.start() {
+            return None;
+        }
+        let cursor_in_line: usize = (self.offset - cursor_line.range.start()).into();
+        Some(&cursor_line.text[..cursor_in_line])
+    }
+
+    /// Returns the attributes of the code block opened on the cursor line, if
+    /// the cursor is in its info string.
+    pub(crate) fn code_block_attrs(&self) -> Option<CodeBlockAttrs> {
+        let opening_fence = self.lines[..self.cursor_line]
+            .iter()
+            .filter(|it| code_fence_info(&it.text).is_some())
+            .count()
+            % 2
+            == 0;
+        if !opening_fence {
+            return None;
+        }
+        let before = self.text_before_cursor()?.trim_start();
+        if !before.starts_with("```") {
+            return None;
+        }
+        let info = before.trim_start_matches('`');
+        let attr_start = info.rfind(is_attr_separator).map_or(0, |it| it + 1);
+        let existing = info[..attr_start]
+            .split(is_attr_separator)
+            .filter(|it| !it.is_empty())
+            .map(|it| it.to_string())
+            .collect();
+        let cursor_line = &self.lines[self.cursor_line];
+        let cursor_in_line: usize = (self.offset - cursor_line.range.start()).into();
+        let after = &cursor_line.text[cursor_in_line..];
+        let end = after.len() - after.trim_start_matches(is_ident_char).len();
+        let source_range = TextRange::new(
+            self.offset - TextSize::of(&info[attr_start..]),
+            self.offset + TextSize::of(&after[..end]),
+        );
+        Some(CodeBlockAttrs { existing, source_range })
+    }
+
+    /// Returns the intra-doc link the cursor is in, outside of code blocks.
+    pub(crate) fn intra_doc_link(&self) -> Option<IntraDocLink> {
+        let cursor_line = &self.lines[self.cursor_line];
+        if self.offset < cursor_line.range.start() {
+            return None;
+        }
+        if self.in_code_block() {
+            return None;
+        }
+
+        let cursor_in_line: usize = (self.offset - cursor_line.range.start()).into();
+        let (before, after) = cursor_line.text.split_at(cursor_in_line);
+        let path_start = before.trim_end_matches(|c| is_ident_char(c) || c == ':').len();
+        let opener = before[..path_start].trim_end_matches('`');
+        if !opener.ends_with('[') && !opener.ends_with("](") {
+            return None;
+        }
+        let path = &before[path_start..];
+        let (qualifier, segment) = match path.rfind("::") {
+            Some(idx) => (Some(path[..idx].to_string()), &path[idx + 2..]),
+            None => (None, path),
+        };
+        if segment.contains(':') {
+            return None;
+        }
+        let end = after.len() - after.trim_start_matches(is_ident_char).len();
+        let source_range = TextRange::new(
+            self.offset - TextSize::of(segment),
+            self.offset + TextSize::of(&after[..end]),
+        );
+        Some(IntraDocLink { qualifier, source_range })
+    }
+
+    /// Returns the doc test the cursor is in, if the cursor is inside of a
+    /// code block with Rust code.
+    pub(crate) fn doc_test(&self) -> Option<DocTest> {
+        let cursor_line = &self.lines[self.cursor_line];
+        if self.offset < cursor_line.range.start() {
+            return None;
+        }
+
+        // Find the code block around the cursor line.
+        let mut block_start = None;
+        for (idx, line) in self.lines[..self.cursor_line].iter().enumerate() {
+            if let Some(info) = code_fence_info(&line.text) {
+                block_start = match block_start {
+                    Some(_) => None,
+                    None => Some((idx + 1, is_rust_code_block(info))),
+                };
+            }
+        }
+        let first_line = match block_start {
+            Some((first_line, true)) => first_line,
+            _ => return None,
+        };
+        if code_fence_info(&cursor_line.text).is_some() {
+            return None;
+        }
+        let last_line = self.lines[self.cursor_line..]
+            .iter()
+            .position(|line| code_fence_info(&line.text).is_some())
+            .map_or(self.lines.len(), |it| self.cursor_line + it);
+
+        // rustdoc wraps doc tests into `fn main`, we do the same so that the
+        // code is parsed in a proper (statement) context.
+        let mut text = String::from("fn main() {\n");
+        let mut offset = None;
+        for line in &self.lines[first_line..last_line] {
+            let line_text = hide_doc_test_marker(&line.text);
+            if std::ptr::eq(line, cursor_line) {
+                let cursor_in_line: usize = (self.offset - line.range.start()).into();
+                offset = Some(TextSize::of(&text) + TextSize::of(&line_text[..cursor_in_line]));
+                text.push_str(&line_text[..cursor_in_line]);
+                text.push_str("intellijRulezz");
+                text.push_str(&line_text[cursor_in_line..]);
+            } else {
+                text.push_str(&line_text);
+            }
+            text.push('\n');
+        }
+        text.push('}');
+
+        let source_range = {
+            let cursor_in_line: usize = (self.offset - cursor_line.range.start()).into();
+            let (before, after) = cursor_line.text.split_at(cursor_in_line);
+            let start = before.trim_end_matches(is_ident_char).len();
+            let end = after.len() - after.trim_start_matches(is_ident_char).len();
+            TextRange::new(
+                self.offset - TextSize::of(&before[start..]),
+                self.offset + TextSize::of(&after[..end]),
+            )
+        };
+
+        Some(DocTest { file: SourceFile::parse(&text).tree(), offset: offset?, source_range })
+    }
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn is_attr_separator(c: char) -> bool {
+    c == ',' || c == ' ' || c == '\t'
+}
+
+/// If the line opens or closes a code block, returns the code block info string.
+fn code_fence_info(line: &str) -> Option<&str> {
+    let line = line.trim_start();
+    if line.starts_with("```") {
+        Some(line.trim_start_matches('`').trim())
+    } else {
+        None
+    }
+}
+
+/// Whether the code block with the given info string is compiled as a doc test.
+fn is_rust_code_block(info: &str) -> bool {
+    info.split(is_attr_separator).filter(|it| !it.is_empty()).all(|attr| {
+        matches!(
+            attr,
+            "rust"
+                | "ignore"
+                | "should_panic"
+                | "no_run"
+                | "compile_fail"
+                | "test_harness"
+                | "allow_fail"
+        ) || attr.starts_with("edition")
+    })
+}
+
+/// Lines starting with `# ` are hidden in the rendered docs, but still belong
+/// to the doc test. The marker is replaced with a space, to keep offsets intact.
+fn hide_doc_test_marker(line: &str) -> String {
+    let trimmed = line.trim_start();
+    if trimmed == "#" || trimmed.starts_with("# ") {
+        let marker = line.len() - trimmed.len();
+        format!("{} {}", &line[..marker], &line[marker + 1..])
+    } else {
+        line.to_string()
+    }
+}
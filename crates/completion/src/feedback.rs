@@ -0,0 +1,168 @@
+//! Reporting which completion the user picked, so that rankers can learn from
+//! it.
+//!
+//! The editor integration hands the accepted item over as an
+//! [`AcceptedCompletion`] to a [`CompletionRanker`], which then reorders the
+//! lists returned by `completions_ranked`. Rankers are experimental, plain
+//! `completions` doesn't use them.
+
+use std::{cmp::Reverse, panic::RefUnwindSafe, sync::Mutex};
+
+use rustc_hash::FxHashMap;
+
+use crate::{CompletionItem, CompletionItemKind, CompletionScore};
+
+/// An item which was accepted from a completion list, with what was known
+/// about it when it was offered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AcceptedCompletion {
+    pub label: String,
+    pub kind: Option<CompletionItemKind>,
+    /// The provider which produced the item, like `completion::dot`.
+    pub provider: Option<&'static str>,
+    pub score: Option<CompletionScore>,
+    /// Whether the item was a match only after taking a reference to it.
+    pub ref_match: bool,
+    pub deprecated: bool,
+    /// The position of the item in the list, as it was shown.
+    pub rank: usize,
+    /// The number of items in the list.
+    pub offered: usize,
+}
+
+impl AcceptedCompletion {
+    /// Describes the item at `rank` in `items`, the list as it was shown.
+    pub fn new(items: &[CompletionItem], rank: usize) -> Option<AcceptedCompletion> {
+        let item = items.get(rank)?;
+        Some(AcceptedCompletion {
+            label: item.label().to_string(),
+            kind: item.kind(),
+            provider: item.provider(),
+            score: item.score(),
+            ref_match: item.ref_match().is_some(),
+            deprecated: item.deprecated(),
+            rank,
+            offered: items.len(),
+        })
+    }
+}
+
+/// Reorders completion lists based on the completions accepted so far.
+///
+/// Rankers are used from within the analysis, which unwinds when it is
+/// cancelled, hence the `RefUnwindSafe` bound.
+pub trait CompletionRanker: Send + Sync + RefUnwindSafe {
+    /// Called with every accepted completion.
+    fn accepted(&self, accepted: &AcceptedCompletion);
+
+    /// Reorders `items`, most relevant first. Editors which sort by score
+    /// still do so, this is only a tie breaker for them.
+    fn rerank(&self, items: &mut [CompletionItem]);
+}
+
+/// The number of different items `FrequencyRanker` keeps the counts of.
+const MAX_COUNTED_ITEMS: usize = 1000;
+
+/// Moves the items which were accepted most often to the front, keeping the
+/// order of the others.
+///
+/// Once `MAX_COUNTED_ITEMS` different items were accepted, all the counts are
+/// halved and the items left at zero are forgotten, so that the memory stays
+/// bounded and recent choices weigh more than old ones.
+#[derive(Debug, Default)]
+pub struct FrequencyRanker {
+    counts: Mutex<FxHashMap<(String, Option<CompletionItemKind>), usize>>,
+}
+
+impl CompletionRanker for FrequencyRanker {
+    fn accepted(&self, accepted: &AcceptedCompletion) {
+        let mut counts = self.counts.lock().unwrap();
+        let key = (accepted.label.clone(), accepted.kind);
+        if counts.len() >= MAX_COUNTED_ITEMS && !counts.contains_key(&key) {
+            counts.retain(|_, count| {
+                *count /= 2;
+                *count > 0
+            });
+        }
+        *counts.entry(key).or_default() += 1;
+    }
+
+    fn rerank(&self, items: &mut [CompletionItem]) {
+        let counts = self.counts.lock().unwrap();
+        items.sort_by_cached_key(|it| {
+            let count = counts.get(&(it.label().to_string(), it.kind())).copied();
+            Reverse(count.unwrap_or(0))
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_utils::get_all_items, CompletionConfig};
+
+    use super::*;
+
+    #[test]
+    fn frequency_ranker_moves_accepted_items_first() {
+        let mut items = get_all_items(
+            CompletionConfig::default(),
+            r#"
+struct S;
+impl S {
+    fn alpha(&self) {}
+    fn beta(&self) {}
+    fn gamma(&self) {}
+}
+fn foo(s: S) { s.<|> }
+"#,
+        );
+        items.retain(|it| it.provider() == Some("completion::dot"));
+        items.sort_by(|a, b| a.label().cmp(b.label()));
+        let labels = |items: &[CompletionItem]| {
+            items.iter().map(|it| it.label().to_string()).collect::<Vec<_>>()
+        };
+        assert_eq!(labels(&items), ["alpha()", "beta()", "gamma()"]);
+
+        let ranker = FrequencyRanker::default();
+        let gamma = AcceptedCompletion::new(&items, 2).unwrap();
+        assert_eq!(gamma.label, "gamma()");
+        assert_eq!(gamma.kind, Some(CompletionItemKind::Method));
+        assert_eq!(gamma.offered, 3);
+        ranker.accepted(&gamma);
+        ranker.accepted(&gamma);
+        ranker.accepted(&AcceptedCompletion::new(&items, 1).unwrap());
+
+        ranker.rerank(&mut items);
+        assert_eq!(labels(&items), ["gamma()", "beta()", "alpha()"]);
+        assert!(AcceptedCompletion::new(&items, 3).is_none());
+    }
+
+    #[test]
+    fn frequency_ranker_forgets_rare_items() {
+        let ranker = FrequencyRanker::default();
+        let accept = |label: String| {
+            ranker.accepted(&AcceptedCompletion {
+                label,
+                kind: None,
+                provider: None,
+                score: None,
+                ref_match: false,
+                deprecated: false,
+                rank: 0,
+                offered: 1,
+            })
+        };
+        accept("often".to_string());
+        accept("often".to_string());
+        for i in 1..MAX_COUNTED_ITEMS {
+            accept(i.to_string());
+        }
+        assert_eq!(ranker.counts.lock().unwrap().len(), MAX_COUNTED_ITEMS);
+
+        accept("new".to_string());
+        let counts = ranker.counts.lock().unwrap();
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[&("often".to_string(), None)], 1);
+        assert_eq!(counts[&("new".to_string(), None)], 1);
+    }
+}
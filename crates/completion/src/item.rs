@@ -1,13 +1,16 @@
 //! See `CompletionItem` structure.
 
-use std::fmt;
+use std::{fmt, mem};
 
 use hir::{Documentation, ModPath, Mutability};
-use ide_db::helpers::{
-    insert_use::{self, ImportScope, MergeBehaviour},
-    mod_path_to_ast,
+use ide_db::{
+    base_db::FilePosition,
+    helpers::{
+        insert_use::{self, ImportScope, MergeBehaviour},
+        mod_path_to_ast,
+    },
 };
-use syntax::{algo, TextRange};
+use syntax::{algo, SmolStr, TextRange};
 use text_edit::TextEdit;
 
 use crate::config::SnippetCap;
@@ -21,7 +24,7 @@ pub struct CompletionItem {
     #[allow(unused)]
     pub(crate) completion_kind: CompletionKind,
     /// Label in the completion pop up which identifies completion.
-    label: String,
+    label: SmolStr,
     /// Range of identifier that is being completed.
     ///
     /// It should be used primarily for UI, but we also use this to convert
@@ -46,10 +49,10 @@ pub struct CompletionItem {
     ///
     /// That is, in `foo.bar<|>` lookup of `abracadabra` will be accepted (it
     /// contains `bar` sub sequence), and `quux` will rejected.
-    lookup: Option<String>,
+    lookup: Option<SmolStr>,
 
     /// Additional info to show in the UI pop up.
-    detail: Option<String>,
+    detail: Option<SmolStr>,
     documentation: Option<Documentation>,
 
     /// Whether this item is marked as deprecated
@@ -65,6 +68,17 @@ pub struct CompletionItem {
     /// Indicates that a reference or mutable reference to this variable is a
     /// possible match.
     ref_match: Option<(Mutability, CompletionScore)>,
+
+    /// The provider which produced this item, like `completion::dot`.
+    provider: Option<&'static str>,
+
+    /// The imports whose edits are not part of `text_edit` yet, see
+    /// `add_import_edit`.
+    imports_to_add: Vec<ImportToAdd>,
+
+    /// Set when the item was left unresolved, see
+    /// `CompletionConfig::resolve_lazily`.
+    resolve_token: Option<CompletionResolveToken>,
 }
 
 // We use custom debug for CompletionItem to make snapshot tests more readable.
@@ -106,13 +120,24 @@ impl fmt::Debug for CompletionItem {
 
 #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
 pub enum CompletionScore {
+    /// If the item likely doesn't compile, like a derive of `Eq` for a type
+    /// with a float field. Such items are sorted last
+    Unlikely,
+    /// If a type of the current crate is completed as the self type of a trait
+    /// impl, where foreign types usually break the orphan rules
+    LocalType,
+    /// If a generic parameter of the enclosing items is completed in type position
+    GenericParam,
+    /// If an auto trait, like `Send`, or `'static` is completed after a `+` in
+    /// a bound list
+    AutoTrait,
     /// If only type match
     TypeMatch,
     /// If type and name match
     TypeAndNameMatch,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CompletionItemKind {
     Snippet,
     Keyword,
@@ -132,11 +157,12 @@ pub enum CompletionItemKind {
     TypeParam,
     Macro,
     Attribute,
+    Label,
     UnresolvedReference,
 }
 
 impl CompletionItemKind {
-    #[cfg(test)]
+    #[cfg(any(test, feature = "test_harness"))]
     pub(crate) fn tag(&self) -> &'static str {
         match self {
             CompletionItemKind::Attribute => "at",
@@ -148,6 +174,7 @@ impl CompletionItemKind {
             CompletionItemKind::Field => "fd",
             CompletionItemKind::Function => "fn",
             CompletionItemKind::Keyword => "kw",
+            CompletionItemKind::Label => "lb",
             CompletionItemKind::Macro => "ma",
             CompletionItemKind::Method => "me",
             CompletionItemKind::Module => "md",
@@ -163,7 +190,7 @@ impl CompletionItemKind {
 }
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
-pub(crate) enum CompletionKind {
+pub enum CompletionKind {
     /// Parser-based keyword completion.
     Keyword,
     /// Your usual "complete all valid identifiers".
@@ -186,7 +213,7 @@ impl CompletionItem {
     pub(crate) fn new(
         completion_kind: CompletionKind,
         source_range: TextRange,
-        label: impl Into<String>,
+        label: impl Into<SmolStr>,
     ) -> Builder {
         let label = label.into();
         Builder {
@@ -205,6 +232,7 @@ impl CompletionItem {
             score: None,
             ref_match: None,
             import_to_add: None,
+            required_imports: Vec::new(),
         }
     }
 
@@ -228,6 +256,15 @@ impl CompletionItem {
     pub fn detail(&self) -> Option<&str> {
         self.detail.as_deref()
     }
+    /// Appends where the item comes from (like the trait of an associated
+    /// item) to the detail.
+    pub(crate) fn add_origin_to_detail(&mut self, origin: &str) {
+        let detail = match &self.detail {
+            Some(detail) => format!("{} (from {})", detail, origin),
+            None => format!("from {}", origin),
+        };
+        self.detail = Some(detail.into());
+    }
     /// A doc-comment
     pub fn documentation(&self) -> Option<Documentation> {
         self.documentation.clone()
@@ -256,6 +293,100 @@ impl CompletionItem {
     pub fn ref_match(&self) -> Option<(Mutability, CompletionScore)> {
         self.ref_match
     }
+
+    /// The provider which produced this item, like `completion::dot`. This is
+    /// `None` for the entry about skipped items.
+    pub fn provider(&self) -> Option<&'static str> {
+        self.provider
+    }
+
+    pub(crate) fn set_provider(&mut self, provider: &'static str) {
+        self.provider = Some(provider);
+    }
+
+    /// The token to pass to `resolve_completion` to get the detail, the
+    /// documentation and the imports of this item. `None` if the item is
+    /// resolved already.
+    pub fn resolve_token(&self) -> Option<&CompletionResolveToken> {
+        self.resolve_token.as_ref()
+    }
+
+    /// Adds the edit inserting the `use`s the item needs, if any. Computing it
+    /// rewrites the import section, so this is only done once the item is
+    /// known to be shown.
+    pub(crate) fn add_import_edit(&mut self) {
+        let mut imports = mem::take(&mut self.imports_to_add);
+        if imports.is_empty() {
+            return;
+        }
+        let mut import_edit = TextEdit::builder();
+        if imports.len() == 1 {
+            // Only the part of the scope the `use` goes into has to be diffed.
+            let import_data = imports.pop().unwrap();
+            let scope = import_data.import_scope.as_syntax_node();
+            let import = mod_path_to_ast(&import_data.import_path);
+            let rewriter = insert_use::insert_use(
+                &import_data.import_scope,
+                import,
+                import_data.merge_behaviour,
+            );
+            let root = rewriter.rewrite_root().unwrap_or_else(|| scope.clone());
+            algo::diff(&root, &rewriter.rewrite(&root)).into_text_edit(&mut import_edit);
+        } else {
+            // Every import goes into the scope with the previous ones inserted,
+            // so that they can be merged with each other.
+            let mut scope = imports[0].import_scope.clone();
+            let original = scope.as_syntax_node().clone();
+            for import_data in imports {
+                let import = mod_path_to_ast(&import_data.import_path);
+                let rewriter = insert_use::insert_use(&scope, import, import_data.merge_behaviour);
+                match ImportScope::from(rewriter.rewrite(scope.as_syntax_node())) {
+                    Some(it) => scope = it,
+                    None => {
+                        log::error!("failed to insert `use {};`", import_data.import_path);
+                        break;
+                    }
+                }
+            }
+            algo::diff(&original, scope.as_syntax_node()).into_text_edit(&mut import_edit);
+        }
+        self.text_edit.union(import_edit.finish()).expect("Failed to unite text edits");
+    }
+
+    /// Drops everything that `resolve_completion` computes again, leaving the
+    /// item with what is needed to show and filter it.
+    pub(crate) fn defer_resolution(&mut self, position: FilePosition) {
+        let provider = match self.provider {
+            Some(it) => it,
+            None => return,
+        };
+        self.resolve_token = Some(CompletionResolveToken {
+            position,
+            provider: provider.to_string(),
+            label: self.label.to_string(),
+            lookup: self.lookup().to_string(),
+        });
+        self.detail = None;
+        self.documentation = None;
+        self.imports_to_add.clear();
+    }
+
+    /// Whether this is the item `token` was made for.
+    pub(crate) fn matches_token(&self, token: &CompletionResolveToken) -> bool {
+        self.label == token.label && self.lookup() == token.lookup
+    }
+}
+
+/// Identifies an item which was left unresolved. It is plain data, so that the
+/// editor can send it back when the item is selected, and is only meaningful
+/// for the file contents it was made for: resolving runs the provider of the
+/// item at its position again.
+#[derive(Debug, Clone)]
+pub struct CompletionResolveToken {
+    pub position: FilePosition,
+    pub provider: String,
+    pub label: String,
+    pub lookup: String,
 }
 
 /// An extra import to add after the completion is applied.
@@ -273,12 +404,13 @@ pub(crate) struct Builder {
     source_range: TextRange,
     completion_kind: CompletionKind,
     import_to_add: Option<ImportToAdd>,
-    label: String,
+    required_imports: Vec<ImportToAdd>,
+    label: SmolStr,
     insert_text: Option<String>,
     insert_text_format: InsertTextFormat,
-    detail: Option<String>,
+    detail: Option<SmolStr>,
     documentation: Option<Documentation>,
-    lookup: Option<String>,
+    lookup: Option<SmolStr>,
     kind: Option<CompletionItemKind>,
     text_edit: Option<TextEdit>,
     deprecated: Option<bool>,
@@ -294,11 +426,9 @@ impl Builder {
         let mut label = self.label;
         let mut lookup = self.lookup;
         let mut insert_text = self.insert_text;
-        let mut text_edits = TextEdit::builder();
 
-        if let Some(import_data) = self.import_to_add {
-            let import = mod_path_to_ast(&import_data.import_path);
-            let mut import_path_without_last_segment = import_data.import_path;
+        if let Some(import_data) = &self.import_to_add {
+            let mut import_path_without_last_segment = import_data.import_path.clone();
             let _ = import_path_without_last_segment.segments.pop();
 
             if !import_path_without_last_segment.segments.is_empty() {
@@ -306,36 +436,25 @@ impl Builder {
                     lookup = Some(label.clone());
                 }
                 if insert_text.is_none() {
-                    insert_text = Some(label.clone());
+                    insert_text = Some(label.to_string());
                 }
-                label = format!("{}::{}", import_path_without_last_segment, label);
-            }
-
-            let rewriter = insert_use::insert_use(
-                &import_data.import_scope,
-                import,
-                import_data.merge_behaviour,
-            );
-            if let Some(old_ast) = rewriter.rewrite_root() {
-                algo::diff(&old_ast, &rewriter.rewrite(&old_ast)).into_text_edit(&mut text_edits);
+                label = format!("{}::{}", import_path_without_last_segment, label).into();
             }
         }
 
-        let original_edit = match self.text_edit {
+        let text_edit = match self.text_edit {
             Some(it) => it,
-            None => {
-                TextEdit::replace(self.source_range, insert_text.unwrap_or_else(|| label.clone()))
-            }
+            None => TextEdit::replace(
+                self.source_range,
+                insert_text.unwrap_or_else(|| label.to_string()),
+            ),
         };
 
-        let mut resulting_edit = text_edits.finish();
-        resulting_edit.union(original_edit).expect("Failed to unite text edits");
-
         CompletionItem {
             source_range: self.source_range,
             label,
             insert_text_format: self.insert_text_format,
-            text_edit: resulting_edit,
+            text_edit,
             detail: self.detail,
             documentation: self.documentation,
             lookup,
@@ -345,13 +464,16 @@ impl Builder {
             trigger_call_info: self.trigger_call_info.unwrap_or(false),
             score: self.score,
             ref_match: self.ref_match,
+            provider: None,
+            imports_to_add: self.import_to_add.into_iter().chain(self.required_imports).collect(),
+            resolve_token: None,
         }
     }
-    pub(crate) fn lookup_by(mut self, lookup: impl Into<String>) -> Builder {
+    pub(crate) fn lookup_by(mut self, lookup: impl Into<SmolStr>) -> Builder {
         self.lookup = Some(lookup.into());
         self
     }
-    pub(crate) fn label(mut self, label: impl Into<String>) -> Builder {
+    pub(crate) fn label(mut self, label: impl Into<SmolStr>) -> Builder {
         self.label = label.into();
         self
     }
@@ -380,10 +502,10 @@ impl Builder {
         self.text_edit(edit)
     }
     #[allow(unused)]
-    pub(crate) fn detail(self, detail: impl Into<String>) -> Builder {
+    pub(crate) fn detail(self, detail: impl Into<SmolStr>) -> Builder {
         self.set_detail(Some(detail))
     }
-    pub(crate) fn set_detail(mut self, detail: Option<impl Into<String>>) -> Builder {
+    pub(crate) fn set_detail(mut self, detail: Option<impl Into<SmolStr>>) -> Builder {
         self.detail = detail.map(Into::into);
         self
     }
@@ -411,6 +533,12 @@ impl Builder {
         self.import_to_add = import_to_add;
         self
     }
+    /// Imports to add besides the one of the item itself. Unlike that one,
+    /// they don't show up in the label.
+    pub(crate) fn require_imports(mut self, imports: Vec<ImportToAdd>) -> Builder {
+        self.required_imports = imports;
+        self
+    }
     pub(crate) fn set_ref_match(
         mut self,
         ref_match: Option<(Mutability, CompletionScore)>,
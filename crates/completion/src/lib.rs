@@ -3,22 +3,34 @@
 mod config;
 mod item;
 mod context;
+mod doc_comment;
 mod patterns;
+mod name_suggestion;
 mod generated_lint_completions;
-#[cfg(test)]
-mod test_utils;
+#[cfg(any(test, feature = "test_harness"))]
+pub mod test_utils;
 mod render;
 
 mod completions;
+mod corpus;
+mod feedback;
+
+use std::time::{Duration, Instant};
 
+use ::test_utils::mark;
 use ide_db::base_db::FilePosition;
 use ide_db::RootDatabase;
 
 use crate::{completions::Completions, context::CompletionContext, item::CompletionKind};
 
 pub use crate::{
-    config::CompletionConfig,
-    item::{CompletionItem, CompletionItemKind, CompletionScore, InsertTextFormat},
+    config::{CallableSnippets, CompletionConfig, CustomSnippet, PostfixTemplate, SnippetScope},
+    corpus::{completions_with_profile, CompletionProfile, ProviderProfile},
+    feedback::{AcceptedCompletion, CompletionRanker, FrequencyRanker},
+    item::{
+        CompletionItem, CompletionItemKind, CompletionResolveToken, CompletionScore,
+        InsertTextFormat,
+    },
 };
 
 //FIXME: split the following feature into fine-grained features.
@@ -51,6 +63,9 @@ pub use crate::{
 // - `expr.dbgr` -> `dbg!(&expr)`
 // - `expr.call` -> `(expr)`
 //
+// More of them can be declared with the `rust-analyzer.completion.postfix.templates` setting,
+// like `"wrapok": "Ok(${receiver})"`.
+//
 // There also snippet completions:
 //
 // .Expressions
@@ -70,12 +85,14 @@ pub use crate::{
 // }
 // ```
 //
-// And experimental completions, enabled with the `rust-analyzer.completion.enableExperimental` setting.
-// This flag enables or disables:
-//
-// - Auto import: additional completion options with automatic `use` import and options from all project importable items, matched for the input
+// Projects can add their own with the `rust-analyzer.completion.snippets` setting, together
+// with the paths they need imported.
 //
-// Experimental completions might cause issues with performance and completion list look.
+// And auto import completions, enabled with the `rust-analyzer.completion.autoimport.enable` setting:
+// as a name is typed, items from the whole project and its dependencies which aren't in scope yet
+// are offered together with a `use` for them. Use `rust-analyzer.completion.autoimport.minPrefixLength`
+// to only search once enough of the name is typed, and `rust-analyzer.completion.autoimport.maxCandidates`
+// to limit how many of these items are offered.
 
 /// Main entry point for completion. We run completion as a two-phase process.
 ///
@@ -104,6 +121,54 @@ pub fn completions(
     config: &CompletionConfig,
     position: FilePosition,
 ) -> Option<Completions> {
+    completions_impl(db, config, position, |_, _| ())
+}
+
+/// Like `completions`, but with the items reordered by `ranker`, which the
+/// editor integration keeps informed with `CompletionRanker::accepted`.
+pub fn completions_ranked(
+    db: &RootDatabase,
+    config: &CompletionConfig,
+    position: FilePosition,
+    ranker: &dyn CompletionRanker,
+) -> Option<Completions> {
+    let mut acc = completions(db, config, position)?;
+    acc.rerank(ranker);
+    Some(acc)
+}
+
+/// Fills in the item that `token` was made for, if it is still offered at its
+/// position. The editor calls this for the items it got from `completions`
+/// with `CompletionConfig::resolve_lazily` set, once one is selected.
+pub fn resolve_completion(
+    db: &RootDatabase,
+    config: &CompletionConfig,
+    token: &CompletionResolveToken,
+) -> Option<CompletionItem> {
+    let _p = profile::span("completion::resolve_completion");
+    let config = CompletionConfig { resolve_lazily: false, ..config.clone() };
+    let ctx = CompletionContext::new(db, token.position, &config)?;
+    // Only the provider of the item has to run again.
+    let provider = PROVIDERS.iter().find(|it| it.name == token.provider)?;
+    let mut acc = Completions::default();
+    (provider.complete)(&mut acc, &ctx);
+    let items: Vec<CompletionItem> = acc.into();
+    let mut item = items.into_iter().find(|it| it.matches_token(token))?;
+    item.set_provider(provider.name);
+    item.add_import_edit();
+    Some(item)
+}
+
+/// Runs the providers at `position`, reporting each of them to `on_provider`
+/// with the time it took and the items it added, or with `None` if it was
+/// skipped because the time budget was exceeded.
+pub(crate) fn completions_impl(
+    db: &RootDatabase,
+    config: &CompletionConfig,
+    position: FilePosition,
+    mut on_provider: impl FnMut(&'static str, Option<(Duration, &[CompletionItem])>),
+) -> Option<Completions> {
+    let _p = profile::span("completion::completions");
     let ctx = CompletionContext::new(db, position, config)?;
 
     if ctx.no_completion_required() {
@@ -112,27 +177,285 @@ pub fn completions(
     }
 
     let mut acc = Completions::default();
-    completions::attribute::complete_attribute(&mut acc, &ctx);
-    completions::fn_param::complete_fn_param(&mut acc, &ctx);
-    completions::keyword::complete_expr_keyword(&mut acc, &ctx);
-    completions::keyword::complete_use_tree_keyword(&mut acc, &ctx);
-    completions::snippet::complete_expr_snippet(&mut acc, &ctx);
-    completions::snippet::complete_item_snippet(&mut acc, &ctx);
-    completions::qualified_path::complete_qualified_path(&mut acc, &ctx);
-    completions::unqualified_path::complete_unqualified_path(&mut acc, &ctx);
-    completions::dot::complete_dot(&mut acc, &ctx);
-    completions::record::complete_record(&mut acc, &ctx);
-    completions::pattern::complete_pattern(&mut acc, &ctx);
-    completions::postfix::complete_postfix(&mut acc, &ctx);
-    completions::macro_in_item_position::complete_macro_in_item_position(&mut acc, &ctx);
-    completions::trait_impl::complete_trait_impl(&mut acc, &ctx);
-    completions::mod_::complete_mod(&mut acc, &ctx);
+    let deadline = config.time_budget.map(|budget| Instant::now() + budget);
+    for provider in PROVIDERS {
+        if provider.cost == Cost::Expensive && deadline.map_or(false, |it| Instant::now() >= it) {
+            mark::hit!(skips_expensive_providers_over_time_budget);
+            log::info!("completion time budget exceeded, skipping {}", provider.name);
+            on_provider(provider.name, None);
+            continue;
+        }
+        let _p = profile::span(provider.name);
+        let (start, items_before) = (Instant::now(), acc.items().len());
+        (provider.complete)(&mut acc, &ctx);
+        acc.set_provider(items_before, provider.name);
+        on_provider(provider.name, Some((start.elapsed(), &acc.items()[items_before..])));
+    }
+    acc.add_skipped_items_placeholder(&ctx);
+    acc.finish_items(&ctx);
 
     Some(acc)
 }
 
+#[derive(PartialEq, Eq)]
+enum Cost {
+    Cheap,
+    /// Searches beyond the current scope, like the whole workspace or all of
+    /// the dependencies. Skipped once the time budget is exceeded.
+    Expensive,
+}
+
+struct Provider {
+    /// Used as the profiling span label, named after the provider module.
+    name: &'static str,
+    cost: Cost,
+    complete: fn(&mut Completions, &CompletionContext),
+}
+
+/// All the completion providers, in the order they are run. The cheap and most
+/// relevant ones go first, so that they always have a chance to run.
+const PROVIDERS: &[Provider] = &[
+    Provider {
+        name: "completion::dot",
+        cost: Cost::Cheap,
+        complete: completions::dot::complete_dot,
+    },
+    Provider {
+        name: "completion::record",
+        cost: Cost::Cheap,
+        complete: |acc, ctx| {
+            completions::record::complete_record(acc, ctx);
+        },
+    },
+    Provider {
+        name: "completion::unqualified_path",
+        cost: Cost::Cheap,
+        complete: completions::unqualified_path::complete_unqualified_path,
+    },
+    Provider {
+        name: "completion::dependency",
+        cost: Cost::Cheap,
+        complete: completions::dependency::complete_dependency,
+    },
+    Provider {
+        name: "completion::visibility",
+        cost: Cost::Cheap,
+        complete: completions::visibility::complete_visibility_path,
+    },
+    Provider {
+        name: "completion::use_group",
+        cost: Cost::Cheap,
+        complete: |acc, ctx| {
+            completions::use_group::complete_use_group(acc, ctx);
+        },
+    },
+    Provider {
+        name: "completion::use_alias",
+        cost: Cost::Cheap,
+        complete: completions::use_alias::complete_use_alias,
+    },
+    Provider {
+        name: "completion::dependency::extern_crate",
+        cost: Cost::Cheap,
+        complete: completions::dependency::complete_extern_crate,
+    },
+    Provider {
+        name: "completion::qualified_path",
+        cost: Cost::Cheap,
+        complete: completions::qualified_path::complete_qualified_path,
+    },
+    Provider {
+        name: "completion::fn_param",
+        cost: Cost::Expensive,
+        complete: completions::fn_param::complete_fn_param,
+    },
+    Provider {
+        name: "completion::keyword::expr",
+        cost: Cost::Cheap,
+        complete: completions::keyword::complete_expr_keyword,
+    },
+    Provider {
+        name: "completion::keyword::use_tree",
+        cost: Cost::Cheap,
+        complete: completions::keyword::complete_use_tree_keyword,
+    },
+    Provider {
+        name: "completion::attribute",
+        cost: Cost::Cheap,
+        complete: |acc, ctx| {
+            completions::attribute::complete_attribute(acc, ctx);
+        },
+    },
+    Provider {
+        name: "completion::pattern",
+        cost: Cost::Cheap,
+        complete: completions::pattern::complete_pattern,
+    },
+    Provider {
+        name: "completion::where_clause",
+        cost: Cost::Cheap,
+        complete: completions::where_clause::complete_where_predicate,
+    },
+    Provider {
+        name: "completion::assoc_type_arg",
+        cost: Cost::Cheap,
+        complete: completions::assoc_type_arg::complete_assoc_type_arg,
+    },
+    Provider {
+        name: "completion::const_arg",
+        cost: Cost::Cheap,
+        complete: completions::const_arg::complete_const_arg,
+    },
+    Provider {
+        name: "completion::type_bound",
+        cost: Cost::Cheap,
+        complete: completions::type_bound::complete_type_bound,
+    },
+    Provider {
+        name: "completion::lifetime",
+        cost: Cost::Cheap,
+        complete: completions::lifetime::complete_lifetime,
+    },
+    Provider {
+        name: "completion::label",
+        cost: Cost::Cheap,
+        complete: completions::label::complete_label,
+    },
+    Provider {
+        name: "completion::format_string",
+        cost: Cost::Cheap,
+        complete: completions::format_string::complete_format_string,
+    },
+    Provider {
+        name: "completion::env_var",
+        cost: Cost::Cheap,
+        complete: completions::env_var::complete_env_var,
+    },
+    Provider {
+        name: "completion::include_path",
+        cost: Cost::Cheap,
+        complete: completions::include_path::complete_include_path,
+    },
+    Provider {
+        name: "completion::trait_impl",
+        cost: Cost::Cheap,
+        complete: completions::trait_impl::complete_trait_impl,
+    },
+    Provider {
+        name: "completion::inherent_impl",
+        cost: Cost::Cheap,
+        complete: completions::inherent_impl::complete_inherent_impl,
+    },
+    Provider {
+        name: "completion::postfix",
+        cost: Cost::Cheap,
+        complete: completions::postfix::complete_postfix,
+    },
+    Provider {
+        name: "completion::closure",
+        cost: Cost::Cheap,
+        complete: completions::closure::complete_closure,
+    },
+    Provider {
+        name: "completion::wrap_variant",
+        cost: Cost::Cheap,
+        complete: completions::wrap_variant::complete_wrap_variant,
+    },
+    Provider {
+        name: "completion::into",
+        cost: Cost::Cheap,
+        complete: completions::into::complete_into,
+    },
+    Provider {
+        name: "completion::struct_literal",
+        cost: Cost::Cheap,
+        complete: completions::struct_literal::complete_struct_literal,
+    },
+    Provider {
+        name: "completion::snippet::expr",
+        cost: Cost::Cheap,
+        complete: completions::snippet::complete_expr_snippet,
+    },
+    Provider {
+        name: "completion::snippet::item",
+        cost: Cost::Cheap,
+        complete: completions::snippet::complete_item_snippet,
+    },
+    Provider {
+        name: "completion::snippet::doc",
+        cost: Cost::Cheap,
+        complete: completions::snippet::complete_doc_snippet,
+    },
+    Provider {
+        name: "completion::macro_in_item_position",
+        cost: Cost::Cheap,
+        complete: completions::macro_in_item_position::complete_macro_in_item_position,
+    },
+    Provider {
+        name: "completion::mod_",
+        cost: Cost::Cheap,
+        complete: |acc, ctx| {
+            completions::mod_::complete_mod(acc, ctx);
+        },
+    },
+    Provider {
+        name: "completion::mod_::path_attr",
+        cost: Cost::Cheap,
+        complete: |acc, ctx| {
+            completions::mod_::complete_mod_path_attr(acc, ctx);
+        },
+    },
+    Provider {
+        name: "completion::doc_test",
+        cost: Cost::Cheap,
+        complete: |acc, ctx| {
+            completions::doc_test::complete_doc_test(acc, ctx);
+        },
+    },
+    Provider {
+        name: "completion::intra_doc_link",
+        cost: Cost::Cheap,
+        complete: completions::intra_doc_link::complete_intra_doc_link,
+    },
+    Provider {
+        name: "completion::code_block_attr",
+        cost: Cost::Cheap,
+        complete: completions::code_block_attr::complete_code_block_attr,
+    },
+    Provider {
+        name: "completion::trait_object",
+        cost: Cost::Expensive,
+        complete: completions::trait_object::complete_trait_object_implementors,
+    },
+    Provider {
+        name: "completion::term_search",
+        cost: Cost::Expensive,
+        complete: completions::term_search::complete_term_search,
+    },
+    Provider {
+        name: "completion::flyimport",
+        cost: Cost::Expensive,
+        complete: completions::flyimport::complete_flyimport,
+    },
+    Provider {
+        name: "completion::extern_block",
+        cost: Cost::Expensive,
+        complete: completions::extern_block::complete_extern_item,
+    },
+    Provider {
+        name: "completion::abi",
+        cost: Cost::Cheap,
+        complete: completions::abi::complete_abi,
+    },
+];
+
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
+    use ::test_utils::mark;
+    use ide_db::base_db::SourceDatabaseExt;
+
     use crate::config::CompletionConfig;
     use crate::test_utils;
 
@@ -239,6 +562,13 @@ mod tests {
             }
             "#,
         );
+        // Nothing to complete inside of char and byte literals.
+        check_no_completion(
+            r#"
+            macro_rules! m { ($($tt:tt)*) => { $($tt)* } }
+            fn foo() { m!(b'<|>'); }
+            "#,
+        );
         // After 'in' keyword hints may be spawned.
         check_detail_and_documentation(
             r#"
@@ -255,4 +585,99 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn skips_expensive_providers_over_time_budget() {
+        let (db, position) = test_utils::position(
+            r#"
+//- /lib.rs crate:dep
+pub mod some_module {
+    pub struct FarAwayStruct;
+}
+//- /main.rs crate:main deps:dep
+fn main() {
+    let far_local = 92;
+    far<|>
+}
+"#,
+        );
+        let labels = |config: &CompletionConfig| -> Vec<String> {
+            let completions: Vec<_> = crate::completions(&db, config, position).unwrap().into();
+            completions.into_iter().map(|it| it.label().to_string()).collect()
+        };
+        let config = CompletionConfig::default();
+        assert!(labels(&config).iter().any(|it| it.ends_with("FarAwayStruct")));
+
+        let config = CompletionConfig { time_budget: Some(Duration::from_secs(0)), ..config };
+        mark::check!(skips_expensive_providers_over_time_budget);
+        let labels = labels(&config);
+        assert!(labels.iter().any(|it| it == "far_local"));
+        assert!(!labels.iter().any(|it| it.ends_with("FarAwayStruct")));
+    }
+
+    #[test]
+    fn resolves_lazily_completed_items() {
+        let (db, position) = test_utils::position(
+            r#"
+//- /lib.rs crate:dep
+pub mod some_module {
+    /// A struct from far away.
+    pub struct FarAwayStruct;
+}
+//- /main.rs crate:main deps:dep
+/// Returns nothing.
+fn far_fn() {}
+fn main() {
+    far<|>
+}
+"#,
+        );
+        let config = CompletionConfig { resolve_lazily: true, ..CompletionConfig::default() };
+        let completions: Vec<_> = crate::completions(&db, &config, position).unwrap().into();
+        let find = |label: &str| completions.iter().find(|it| it.label() == label).unwrap();
+
+        let far_fn = find("far_fn()");
+        assert_eq!(far_fn.detail(), None);
+        assert!(far_fn.documentation().is_none());
+        let resolved =
+            crate::resolve_completion(&db, &config, far_fn.resolve_token().unwrap()).unwrap();
+        assert_eq!(resolved.label(), "far_fn()");
+        assert_eq!(resolved.detail(), Some("fn far_fn()"));
+        assert_eq!(resolved.documentation().unwrap().as_str(), "Returns nothing.");
+        assert!(resolved.resolve_token().is_none());
+
+        let far_away = find("dep::some_module::FarAwayStruct");
+        assert_eq!(far_away.text_edit().len(), 1);
+        let resolved =
+            crate::resolve_completion(&db, &config, far_away.resolve_token().unwrap()).unwrap();
+        assert_eq!(resolved.documentation().unwrap().as_str(), "A struct from far away.");
+        let mut text = db.file_text(position.file_id).to_string();
+        resolved.text_edit().apply(&mut text);
+        assert!(text.starts_with("use dep::some_module::FarAwayStruct;"));
+    }
+
+    #[test]
+    fn renders_a_limited_number_of_items_in_gigantic_scopes() {
+        let variants: Vec<_> = (0..1100).map(|it| format!("V{}", it)).collect();
+        let fixture =
+            format!("enum Gigantic {{ {} }}\nfn main() {{ Gigantic::V<|> }}", variants.join(", "));
+        let completions = test_utils::get_all_items(CompletionConfig::default(), &fixture);
+        assert_eq!(completions.len(), 1001);
+        let placeholder = completions.last().unwrap();
+        assert_eq!(placeholder.label(), "100 more items, keep typing…");
+        assert_eq!(placeholder.lookup(), "V");
+    }
+
+    #[test]
+    fn renders_the_items_past_the_limit_which_start_with_what_is_typed() {
+        let variants: Vec<_> = (0..1100).map(|it| format!("V{}", it)).collect();
+        let fixture = format!(
+            "enum Gigantic {{ {} }}\nfn main() {{ Gigantic::V105<|> }}",
+            variants.join(", ")
+        );
+        let completions = test_utils::get_all_items(CompletionConfig::default(), &fixture);
+        assert!(completions.iter().any(|it| it.label() == "V1050"));
+        let placeholder = completions.last().unwrap();
+        assert_eq!(placeholder.label(), "90 more items, keep typing…");
+    }
 }
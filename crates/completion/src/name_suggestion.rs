@@ -0,0 +1,158 @@
+//! Names for bindings, derived from their type or from the expression they are
+//! bound to, like `config` for `let config = get_config();`.
+
+use ide_db::RootDatabase;
+use syntax::{ast, AstNode};
+
+/// The types whose names say nothing about the value, which is named after the
+/// wrapped type instead.
+const WRAPPERS: &[&str] = &["Box", "Rc", "Arc", "Option", "RefCell", "Cell", "Mutex", "RwLock"];
+
+/// Methods and functions whose names say nothing about the value they return,
+/// like constructors and conversions.
+const USELESS_FN_NAMES: &[&str] =
+    &["new", "default", "unwrap", "expect", "clone", "from", "into", "build", "iter"];
+
+/// Idiomatic names for a parameter of the given type, like `reader` and
+/// `buf_reader` for `BufReader<File>` or `buf` for `&mut [u8]`.
+pub(crate) fn for_type(ty: &ast::Type) -> Vec<String> {
+    match ty {
+        ast::Type::RefType(it) => it.ty().map_or_else(Vec::new, |it| for_type(&it)),
+        ast::Type::PtrType(it) => it.ty().map_or_else(Vec::new, |it| for_type(&it)),
+        ast::Type::ParenType(it) => it.ty().map_or_else(Vec::new, |it| for_type(&it)),
+        ast::Type::SliceType(it) if is_byte_type(it.ty()) => vec!["buf".to_string()],
+        ast::Type::ArrayType(it) if is_byte_type(it.ty()) => vec!["buf".to_string()],
+        ast::Type::PathType(it) => {
+            let segment = match it.path().and_then(|it| it.segment()) {
+                Some(it) => it,
+                None => return Vec::new(),
+            };
+            let name = match segment.name_ref() {
+                Some(it) => it.text().to_string(),
+                None => return Vec::new(),
+            };
+            let first_generic_arg = || {
+                segment.generic_arg_list()?.generic_args().find_map(|arg| match arg {
+                    ast::GenericArg::TypeArg(it) => it.ty(),
+                    _ => None,
+                })
+            };
+            match name.as_str() {
+                "Vec" if is_byte_type(first_generic_arg()) => vec!["buf".to_string()],
+                name if WRAPPERS.contains(&name) => {
+                    first_generic_arg().map_or_else(Vec::new, |it| for_type(&it))
+                }
+                _ => names_from_type_name(&name),
+            }
+        }
+        ast::Type::ImplTraitType(it) => trait_bound_names(it.type_bound_list()),
+        ast::Type::DynTraitType(it) => trait_bound_names(it.type_bound_list()),
+        _ => Vec::new(),
+    }
+}
+
+/// The usual short names of the values of some types, like `req` for a
+/// `HttpRequest`, by the last word of the type name.
+const ABBREVIATIONS: &[(&str, &str)] = &[
+    ("request", "req"),
+    ("response", "resp"),
+    ("context", "ctx"),
+    ("buffer", "buf"),
+    ("message", "msg"),
+    ("connection", "conn"),
+    ("database", "db"),
+    ("parameter", "param"),
+    ("argument", "arg"),
+    ("iterator", "iter"),
+];
+
+/// Names for a value of the type named `name`, like `request`, `http_request`
+/// and `req` for `HttpRequest`. The first one is the preferred one.
+pub(crate) fn names_from_type_name(name: &str) -> Vec<String> {
+    if name.chars().next().map_or(true, |it| !it.is_uppercase()) {
+        // Primitive types and type parameters don't make good names.
+        return Vec::new();
+    }
+    let name = stdx::to_lower_snake_case(name);
+    let last_word = stdx::rsplit_once(&name, '_').map(|(_, it)| it.to_string());
+    let abbreviation = ABBREVIATIONS
+        .iter()
+        .find(|(word, _)| *word == last_word.as_deref().unwrap_or(&name))
+        .map(|(_, abbreviation)| abbreviation.to_string());
+    let mut res: Vec<String> = last_word.into_iter().collect();
+    res.push(name);
+    res.extend(abbreviation);
+    res
+}
+
+fn trait_bound_names(bounds: Option<ast::TypeBoundList>) -> Vec<String> {
+    let trait_name =
+        bounds.and_then(|it| it.bounds().next()).and_then(|it| it.ty()).and_then(|it| match it {
+            ast::Type::PathType(it) => it.path()?.segment()?.name_ref(),
+            _ => None,
+        });
+    let trait_name = match trait_name {
+        Some(it) => it.text().to_string(),
+        None => return Vec::new(),
+    };
+    match trait_name.as_str() {
+        "Read" | "BufRead" => vec!["reader".to_string()],
+        "Write" => vec!["writer".to_string()],
+        "Iterator" | "IntoIterator" => vec!["iter".to_string()],
+        "Fn" | "FnMut" | "FnOnce" => vec!["f".to_string()],
+        _ => names_from_type_name(&trait_name),
+    }
+}
+
+fn is_byte_type(ty: Option<ast::Type>) -> bool {
+    match ty {
+        Some(ast::Type::PathType(it)) => it.syntax().text() == "u8",
+        _ => false,
+    }
+}
+
+/// Like `for_type`, but for an inferred type, like the one of an initializer.
+pub(crate) fn for_hir_type(db: &RootDatabase, ty: &hir::Type) -> Vec<String> {
+    let ty = ty.remove_ref().unwrap_or_else(|| ty.clone());
+    let adt = match ty.as_adt() {
+        Some(it) => it,
+        None => return Vec::new(),
+    };
+    let name = adt.name(db).to_string();
+    if WRAPPERS.contains(&name.as_str()) || name == "Result" {
+        return ty.type_arguments().next().map_or_else(Vec::new, |it| for_hir_type(db, &it));
+    }
+    names_from_type_name(&name)
+}
+
+/// A name for the value of `expr`, after the function or the field it comes
+/// from, like `config` for `get_config()` or `self.config.clone()`.
+pub(crate) fn for_expr(expr: &ast::Expr) -> Option<String> {
+    let fn_name = match expr {
+        ast::Expr::CallExpr(it) => match it.expr()? {
+            ast::Expr::PathExpr(it) => it.path()?.segment()?.name_ref()?,
+            _ => return None,
+        },
+        ast::Expr::MethodCallExpr(it) => {
+            let name = it.name_ref()?;
+            if USELESS_FN_NAMES.contains(&name.text().as_str()) {
+                return for_expr(&it.receiver()?);
+            }
+            name
+        }
+        ast::Expr::FieldExpr(it) => return Some(it.name_ref()?.text().to_string()),
+        ast::Expr::TryExpr(it) => return for_expr(&it.expr()?),
+        ast::Expr::AwaitExpr(it) => return for_expr(&it.expr()?),
+        ast::Expr::RefExpr(it) => return for_expr(&it.expr()?),
+        _ => return None,
+    };
+    let fn_name = fn_name.text().to_string();
+    let name = ["get_", "into_", "to_", "as_"]
+        .iter()
+        .find_map(|prefix| fn_name.strip_prefix(prefix))
+        .unwrap_or(&fn_name);
+    if name.is_empty() || USELESS_FN_NAMES.contains(&name) {
+        return None;
+    }
+    Some(name.to_string())
+}
@@ -2,10 +2,10 @@
 
 use syntax::{
     algo::non_trivia_sibling,
-    ast::{self, LoopBodyOwner},
+    ast::{self, AstToken, LoopBodyOwner},
     match_ast, AstNode, Direction, NodeOrToken, SyntaxElement,
     SyntaxKind::*,
-    SyntaxNode, SyntaxToken,
+    SyntaxNode, SyntaxToken, T,
 };
 
 #[cfg(test)]
@@ -35,6 +35,16 @@ fn test_has_impl_parent() {
     check_pattern_is_applicable(r"impl A { f<|> }", has_impl_parent);
 }
 
+pub(crate) fn has_extern_block_parent(element: SyntaxElement) -> bool {
+    not_same_range_ancestor(element).filter(|it| it.kind() == EXTERN_ITEM_LIST).is_some()
+}
+#[test]
+fn test_has_extern_block_parent() {
+    check_pattern_is_applicable(r#"extern "C" { f<|> }"#, has_extern_block_parent);
+    check_pattern_is_applicable(r"extern { f<|> }", has_extern_block_parent);
+    check_pattern_is_not_applicable(r"mod foo { f<|> }", has_extern_block_parent);
+}
+
 pub(crate) fn inside_impl_trait_block(element: SyntaxElement) -> bool {
     // Here we search `impl` keyword up through the all ancestors, unlike in `has_impl_parent`,
     // where we only check the first parent with different text range.
@@ -103,6 +113,55 @@ fn test_has_item_list_or_source_file_parent() {
     check_pattern_is_applicable(r"mod foo { f<|> }", has_item_list_or_source_file_parent);
 }
 
+/// Whether the element is the crate name of an `extern crate`.
+pub(crate) fn is_extern_crate_name(element: SyntaxElement) -> bool {
+    not_same_range_ancestor(element).filter(|it| it.kind() == EXTERN_CRATE).is_some()
+}
+#[test]
+fn test_is_extern_crate_name() {
+    check_pattern_is_applicable(r"extern crate s<|>", is_extern_crate_name);
+    check_pattern_is_applicable(r"mod m { extern crate s<|>; }", is_extern_crate_name);
+    check_pattern_is_not_applicable(r"extern crate serde as s<|>;", is_extern_crate_name);
+}
+
+/// Whether the element is in the path of a `pub(in path)` visibility.
+pub(crate) fn is_in_visibility_path(element: SyntaxElement) -> bool {
+    element
+        .ancestors()
+        .take_while(|it| it.kind() == PATH_SEGMENT || it.kind() == PATH || it.kind() == NAME_REF)
+        .last()
+        .and_then(|it| it.parent())
+        .filter(|it| it.kind() == VISIBILITY)
+        .is_some()
+}
+#[test]
+fn test_is_in_visibility_path() {
+    check_pattern_is_applicable(r"pub(in c<|>) fn f() {}", is_in_visibility_path);
+    check_pattern_is_applicable(r"pub(in crate::a<|>) fn f() {}", is_in_visibility_path);
+    check_pattern_is_not_applicable(r"pub(crate) fn f(a<|>) {}", is_in_visibility_path);
+}
+
+/// Whether the element is in the type on the left of a where predicate, like
+/// `T` in `where T: Clone`.
+pub(crate) fn is_in_where_predicate_type(element: SyntaxElement) -> bool {
+    element
+        .ancestors()
+        .take_while(|it| matches!(it.kind(), PATH_SEGMENT | PATH | NAME_REF | PATH_TYPE))
+        .last()
+        .and_then(|it| it.parent())
+        .filter(|it| it.kind() == WHERE_PRED)
+        .is_some()
+}
+#[test]
+fn test_is_in_where_predicate_type() {
+    check_pattern_is_applicable(r"fn f<T>() where T<|> {}", is_in_where_predicate_type);
+    check_pattern_is_applicable(
+        r"fn f<T>() where U: Clone, T<|>: Copy {}",
+        is_in_where_predicate_type,
+    );
+    check_pattern_is_not_applicable(r"fn f<T>() where T: Cl<|> {}", is_in_where_predicate_type);
+}
+
 pub(crate) fn is_match_arm(element: SyntaxElement) -> bool {
     not_same_range_ancestor(element.clone()).filter(|it| it.kind() == MATCH_ARM).is_some()
         && previous_sibling_or_ancestor_sibling(element)
@@ -127,6 +186,25 @@ fn test_unsafe_is_prev() {
     check_pattern_is_applicable(r"unsafe i<|>", unsafe_is_prev);
 }
 
+pub(crate) fn default_is_prev_in_impl(element: SyntaxElement) -> bool {
+    // `default` is contextual, so `default f` parses as a macro call rather
+    // than an item of the impl.
+    element
+        .into_token()
+        .and_then(previous_non_trivia_token)
+        .filter(|it| it.kind() == DEFAULT_KW || (it.kind() == IDENT && it.text() == "default"))
+        .and_then(|it| it.ancestors().find(|it| it.kind() == ASSOC_ITEM_LIST))
+        .and_then(|it| it.parent())
+        .filter(|it| it.kind() == IMPL)
+        .is_some()
+}
+#[test]
+fn test_default_is_prev_in_impl() {
+    check_pattern_is_applicable(r"impl S { default f<|> }", default_is_prev_in_impl);
+    check_pattern_is_not_applicable(r"impl S { f<|> }", default_is_prev_in_impl);
+    check_pattern_is_not_applicable(r"trait T { default f<|> }", default_is_prev_in_impl);
+}
+
 pub(crate) fn if_is_prev(element: SyntaxElement) -> bool {
     element
         .into_token()
@@ -135,6 +213,20 @@ pub(crate) fn if_is_prev(element: SyntaxElement) -> bool {
         .is_some()
 }
 
+pub(crate) fn amp_is_prev(element: SyntaxElement) -> bool {
+    element
+        .into_token()
+        .and_then(|it| previous_non_trivia_token(it))
+        .filter(|it| it.kind() == T![&] || it.kind() == T![&&])
+        .is_some()
+}
+#[test]
+fn test_amp_is_prev() {
+    check_pattern_is_applicable(r"fn f() { &r<|> }", amp_is_prev);
+    check_pattern_is_applicable(r"fn f() { &&r<|> }", amp_is_prev);
+    check_pattern_is_not_applicable(r"fn f() { r<|> }", amp_is_prev);
+}
+
 pub(crate) fn fn_is_prev(element: SyntaxElement) -> bool {
     element
         .into_token()
@@ -209,6 +301,81 @@ pub(crate) fn is_in_loop_body(element: SyntaxElement) -> bool {
     false
 }
 
+/// Whether the element is the label of a `break` or a `continue`.
+pub(crate) fn is_label_ref(element: SyntaxElement) -> bool {
+    element.kind() == LIFETIME
+        && element.parent().filter(|it| matches!(it.kind(), BREAK_EXPR | CONTINUE_EXPR)).is_some()
+}
+#[test]
+fn test_is_label_ref() {
+    check_pattern_is_applicable(r"fn f() { 'a: loop { break 'a<|> } }", is_label_ref);
+    check_pattern_is_applicable(r"fn f() { 'a: loop { continue 'a<|> } }", is_label_ref);
+    check_pattern_is_not_applicable(r"fn f<'a>(x: &'a<|> str) {}", is_label_ref);
+}
+
+/// Whether the element is the label a loop is declared with.
+pub(crate) fn is_label_def(element: SyntaxElement) -> bool {
+    element.kind() == LIFETIME && element.parent().filter(|it| it.kind() == LABEL).is_some()
+}
+#[test]
+fn test_is_label_def() {
+    check_pattern_is_applicable(r"fn f() { 'a<|>: loop {} }", is_label_def);
+    check_pattern_is_not_applicable(r"fn f() { 'a: loop { break 'a<|> } }", is_label_def);
+}
+
+/// Whether the element is a line or block doc comment, outer or inner.
+pub(crate) fn is_in_doc_comment(element: SyntaxElement) -> bool {
+    element.into_token().and_then(ast::Comment::cast).filter(|it| it.kind().doc.is_some()).is_some()
+}
+#[test]
+fn test_is_in_doc_comment() {
+    check_pattern_is_applicable(r"/// # Ex<|>", is_in_doc_comment);
+    check_pattern_is_applicable(r"//! Fo<|>", is_in_doc_comment);
+    check_pattern_is_not_applicable(r"// Fo<|>", is_in_doc_comment);
+}
+
+/// Whether the element is evaluated at compile time, where only `const fn`s
+/// can be called: the body of a `const fn`, the initializer of a `const` or a
+/// `static`, an array length or an enum discriminant.
+pub(crate) fn is_in_const_context(element: SyntaxElement) -> bool {
+    let leaf = match element {
+        NodeOrToken::Node(node) => node,
+        NodeOrToken::Token(token) => token.parent(),
+    };
+    let contains_leaf = |expr: Option<ast::Expr>| matches!(expr, Some(it) if it.syntax().text_range().contains_range(leaf.text_range()));
+    for node in leaf.ancestors() {
+        match_ast! {
+            match node {
+                ast::Fn(it) => return it.const_token().is_some(),
+                ast::ClosureExpr(_it) => return false,
+                ast::Const(_it) => return true,
+                ast::Static(_it) => return true,
+                ast::ArrayType(it) => if contains_leaf(it.expr()) { return true },
+                ast::ArrayExpr(it) => {
+                    if let ast::ArrayExprKind::Repeat { repeat, .. } = it.kind() {
+                        if contains_leaf(repeat) {
+                            return true;
+                        }
+                    }
+                },
+                ast::Variant(it) => return contains_leaf(it.expr()),
+                _ => (),
+            }
+        }
+    }
+    false
+}
+#[test]
+fn test_is_in_const_context() {
+    check_pattern_is_applicable(r"const fn f() { g<|> }", is_in_const_context);
+    check_pattern_is_applicable(r"const C: u32 = g<|>;", is_in_const_context);
+    check_pattern_is_applicable(r"fn f() { let a = [0; g<|>]; }", is_in_const_context);
+    check_pattern_is_applicable(r"enum E { A = g<|> }", is_in_const_context);
+    check_pattern_is_not_applicable(r"fn f() { g<|> }", is_in_const_context);
+    check_pattern_is_not_applicable(r"fn f() { let a = [g<|>; 2]; }", is_in_const_context);
+    check_pattern_is_not_applicable(r"const fn f() { let c = || g<|>; }", is_in_const_context);
+}
+
 fn not_same_range_ancestor(element: SyntaxElement) -> Option<SyntaxNode> {
     element
         .ancestors()
@@ -9,15 +9,19 @@ pub(crate) mod type_alias;
 
 mod builder_ext;
 
-use hir::{Documentation, HasAttrs, HirDisplay, ModPath, Mutability, ScopeDef, Type};
+use hir::{Documentation, HasAttrs, HasSource, HirDisplay, ModPath, Mutability, ScopeDef, Type};
 use ide_db::helpers::insert_use::{ImportScope, MergeBehaviour};
 use ide_db::RootDatabase;
-use syntax::TextRange;
+use syntax::{
+    ast::{self, GenericParamsOwner, TypeBoundsOwner},
+    AstNode, SyntaxNode, TextRange,
+};
 use test_utils::mark;
 
 use crate::{
-    config::SnippetCap, item::ImportToAdd, CompletionContext, CompletionItem, CompletionItemKind,
-    CompletionKind, CompletionScore,
+    config::{CallableSnippets, SnippetCap},
+    item::ImportToAdd,
+    CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, CompletionScore,
 };
 
 use crate::render::{enum_variant::render_enum_variant, function::render_fn, macro_::render_macro};
@@ -88,10 +92,22 @@ impl<'a> RenderContext<'a> {
         node.attrs(self.db()).by_key("deprecated").exists()
     }
 
+    /// The documentation of `node`, unless it is left for `resolve_completion`.
     fn docs(&self, node: impl HasAttrs) -> Option<Documentation> {
+        if self.completion.config.resolve_lazily {
+            return None;
+        }
         node.docs(self.db())
     }
 
+    /// The detail computed by `f`, unless it is left for `resolve_completion`.
+    fn detail(&self, f: impl FnOnce() -> String) -> Option<String> {
+        if self.completion.config.resolve_lazily {
+            return None;
+        }
+        Some(f())
+    }
+
     fn active_name_and_type(&self) -> Option<(String, Type)> {
         if let Some(record_field) = &self.completion.record_field_syntax {
             mark::hit!(record_field_type_match);
@@ -100,6 +116,12 @@ impl<'a> RenderContext<'a> {
         } else if let Some(active_parameter) = &self.completion.active_parameter {
             mark::hit!(active_param_type_match);
             Some((active_parameter.name.clone(), active_parameter.ty.clone()))
+        } else if let Some(ty) = &self.completion.return_or_break_ty {
+            mark::hit!(return_or_break_type_match);
+            Some((String::new(), ty.clone()))
+        } else if let Some((name, ty)) = &self.completion.let_name_and_type {
+            mark::hit!(let_initializer_type_match);
+            Some((name.clone(), ty.clone()))
         } else {
             None
         }
@@ -126,21 +148,33 @@ impl<'a> Render<'a> {
             name.to_string(),
         )
         .kind(CompletionItemKind::Field)
-        .detail(ty.display(self.ctx.db()).to_string())
-        .set_documentation(field.docs(self.ctx.db()))
+        .set_detail(self.ctx.detail(|| ty.display(self.ctx.db()).to_string()))
+        .set_documentation(self.ctx.docs(field))
         .set_deprecated(is_deprecated);
 
         if let Some(score) = compute_score(&self.ctx, &ty, &name.to_string()) {
             item = item.set_score(score);
         }
 
+        // In record literals, the field is initialized by a local of the same
+        // name with the shorthand, and gets a value to fill in otherwise,
+        // unless the field already has one after the `:`.
+        if self.ctx.completion.record_lit_syntax.is_some() {
+            let has_value = self.ctx.completion.record_field_has_value();
+            if self.ctx.completion.has_local_for_field(&name.to_string(), ty) {
+                item = item.set_score(CompletionScore::TypeAndNameMatch);
+            } else if let Some(cap) = self.ctx.snippet_cap().filter(|_| !has_value) {
+                item = item.insert_snippet(cap, format!("{}: $0", name));
+            }
+        }
+
         item.build()
     }
 
     fn add_tuple_field(&mut self, field: usize, ty: &Type) -> CompletionItem {
         CompletionItem::new(CompletionKind::Reference, self.ctx.source_range(), field.to_string())
             .kind(CompletionItemKind::Field)
-            .detail(ty.display(self.ctx.db()).to_string())
+            .set_detail(self.ctx.detail(|| ty.display(self.ctx.db()).to_string()))
             .build()
     }
 
@@ -160,8 +194,7 @@ impl<'a> Render<'a> {
 
         let kind = match resolution {
             ScopeDef::ModuleDef(Function(func)) => {
-                let item = render_fn(self.ctx, import_to_add, Some(local_name), *func);
-                return Some(item);
+                return render_fn(self.ctx, import_to_add, Some(local_name), *func);
             }
             ScopeDef::ModuleDef(EnumVariant(var)) => {
                 let item =
@@ -200,21 +233,35 @@ impl<'a> Render<'a> {
             }
         };
 
-        let docs = self.docs(resolution);
-
         let mut item =
             CompletionItem::new(completion_kind, self.ctx.source_range(), local_name.clone());
+        if let ScopeDef::ModuleDef(Trait(trait_)) = resolution {
+            if self.ctx.completion.is_trait_object_bound()
+                && !is_object_safe(self.ctx.db(), *trait_)
+            {
+                if self.ctx.completion.config.hide_non_object_safe_traits {
+                    mark::hit!(hides_non_object_safe_traits);
+                    return None;
+                }
+                item = item.detail("not object safe");
+            }
+        }
         if let ScopeDef::Local(local) = resolution {
             let ty = local.ty(self.ctx.db());
             if !ty.is_unknown() {
-                item = item.detail(ty.display(self.ctx.db()).to_string());
+                item = item.set_detail(self.ctx.detail(|| ty.display(self.ctx.db()).to_string()));
             }
         };
 
         let mut ref_match = None;
-        if let ScopeDef::Local(local) = resolution {
-            if let Some((active_name, active_type)) = self.ctx.active_name_and_type() {
-                let ty = local.ty(self.ctx.db());
+        if let Some((active_name, active_type)) = self.ctx.active_name_and_type() {
+            let ty = match resolution {
+                ScopeDef::Local(local) => Some(local.ty(self.ctx.db())),
+                ScopeDef::ModuleDef(Const(it)) => Some(it.ty(self.ctx.db())),
+                ScopeDef::ModuleDef(Static(it)) => Some(it.ty(self.ctx.db())),
+                _ => None,
+            };
+            if let Some(ty) = ty {
                 if let Some(score) =
                     compute_score_from_active(&active_type, &active_name, &ty, &local_name)
                 {
@@ -224,10 +271,41 @@ impl<'a> Render<'a> {
             }
         }
 
+        if let ScopeDef::GenericParam(_) = resolution {
+            if self.ctx.completion.is_path_type {
+                item = item.set_score(CompletionScore::GenericParam);
+            }
+        }
+
+        if let ScopeDef::ModuleDef(Trait(trait_)) = resolution {
+            if self.ctx.completion.is_additional_bound() && trait_.is_auto(self.ctx.db()) {
+                item = item.set_score(CompletionScore::AutoTrait);
+            }
+        }
+
+        if self.ctx.completion.is_impl_self_ty_path {
+            let db = self.ctx.db();
+            let module_and_ty = match resolution {
+                ScopeDef::ModuleDef(Adt(it)) => Some((it.module(db), it.ty(db))),
+                ScopeDef::ModuleDef(TypeAlias(it)) => Some((it.module(db), it.ty(db))),
+                _ => None,
+            };
+            if let Some((module, ty)) = module_and_ty {
+                if Some(module.krate()) == self.ctx.completion.krate {
+                    item = item.set_score(CompletionScore::LocalType);
+                }
+                if let Some(trait_) = self.ctx.completion.implemented_trait {
+                    if ty.impls_trait(db, trait_, &[]) {
+                        item = item.detail(format!("implements {}", trait_.name(db)));
+                    }
+                }
+            }
+        }
+
         // Add `<>` for generic types
         if self.ctx.completion.is_path_type
             && !self.ctx.completion.has_type_args
-            && self.ctx.completion.config.add_call_parenthesis
+            && self.ctx.completion.config.callable_snippets != CallableSnippets::None
         {
             if let Some(cap) = self.ctx.snippet_cap() {
                 let has_non_default_type_params = match resolution {
@@ -250,7 +328,7 @@ impl<'a> Render<'a> {
         let item = item
             .kind(kind)
             .add_import(import_to_add)
-            .set_documentation(docs)
+            .set_documentation(self.docs(resolution))
             .set_ref_match(ref_match)
             .build();
         Some(item)
@@ -259,18 +337,76 @@ impl<'a> Render<'a> {
     fn docs(&self, resolution: &ScopeDef) -> Option<Documentation> {
         use hir::ModuleDef::*;
         match resolution {
-            ScopeDef::ModuleDef(Module(it)) => it.docs(self.ctx.db()),
-            ScopeDef::ModuleDef(Adt(it)) => it.docs(self.ctx.db()),
-            ScopeDef::ModuleDef(EnumVariant(it)) => it.docs(self.ctx.db()),
-            ScopeDef::ModuleDef(Const(it)) => it.docs(self.ctx.db()),
-            ScopeDef::ModuleDef(Static(it)) => it.docs(self.ctx.db()),
-            ScopeDef::ModuleDef(Trait(it)) => it.docs(self.ctx.db()),
-            ScopeDef::ModuleDef(TypeAlias(it)) => it.docs(self.ctx.db()),
+            ScopeDef::ModuleDef(Module(it)) => self.ctx.docs(*it),
+            ScopeDef::ModuleDef(Adt(it)) => self.ctx.docs(*it),
+            ScopeDef::ModuleDef(EnumVariant(it)) => self.ctx.docs(*it),
+            ScopeDef::ModuleDef(Const(it)) => self.ctx.docs(*it),
+            ScopeDef::ModuleDef(Static(it)) => self.ctx.docs(*it),
+            ScopeDef::ModuleDef(Trait(it)) => self.ctx.docs(*it),
+            ScopeDef::ModuleDef(TypeAlias(it)) => self.ctx.docs(*it),
             _ => None,
         }
     }
 }
 
+/// Whether `dyn Trait` can be written, approximated from the syntax of the
+/// trait. The methods that require `Self: Sized` don't count, and supertraits
+/// other than `Sized` are not checked.
+fn is_object_safe(db: &RootDatabase, trait_: hir::Trait) -> bool {
+    let source = trait_.source(db).value;
+    if requires_sized(source.type_bound_list(), source.where_clause()) {
+        return false;
+    }
+    trait_.items(db).into_iter().all(|item| match item {
+        hir::AssocItem::Const(_) => false,
+        hir::AssocItem::TypeAlias(_) => true,
+        hir::AssocItem::Function(func) => {
+            let source = func.source(db).value;
+            if requires_sized(None, source.where_clause()) {
+                return true;
+            }
+            let has_type_params =
+                source.generic_param_list().and_then(|it| it.type_params().next()).is_some();
+            let params = source.param_list();
+            let mentions_self = params
+                .iter()
+                .flat_map(|it| it.params())
+                .filter_map(|it| it.ty())
+                .chain(source.ret_type().and_then(|it| it.ty()))
+                .any(|ty| mentions_self_type(ty.syntax()));
+            params.and_then(|it| it.self_param()).is_some() && !has_type_params && !mentions_self
+        }
+    })
+}
+
+/// Whether the bounds or the where clause contain `Self: Sized`.
+fn requires_sized(
+    bounds: Option<ast::TypeBoundList>,
+    where_clause: Option<ast::WhereClause>,
+) -> bool {
+    let is_sized = |bounds: Option<ast::TypeBoundList>| {
+        bounds.into_iter().flat_map(|it| it.bounds()).any(|bound| {
+            bound.question_mark_token().is_none()
+                && bound.ty().filter(|ty| ty.syntax().text() == "Sized").is_some()
+        })
+    };
+    is_sized(bounds)
+        || where_clause.into_iter().flat_map(|it| it.predicates()).any(|pred| {
+            pred.ty().filter(|ty| ty.syntax().text() == "Self").is_some()
+                && is_sized(pred.type_bound_list())
+        })
+}
+
+/// Whether `Self` is used as a type, not only as the qualifier of an
+/// associated type like `Self::Item`.
+fn mentions_self_type(node: &SyntaxNode) -> bool {
+    node.descendants().filter_map(ast::Path::cast).any(|path| {
+        path.qualifier().is_none()
+            && path.syntax().text() == "Self"
+            && path.syntax().parent().and_then(ast::Path::cast).is_none()
+    })
+}
+
 fn compute_score_from_active(
     active_type: &Type,
     active_name: &str,
@@ -306,7 +442,7 @@ fn refed_type_matches(
     ))
 }
 
-fn compute_score(ctx: &RenderContext, ty: &Type, name: &str) -> Option<CompletionScore> {
+pub(crate) fn compute_score(ctx: &RenderContext, ty: &Type, name: &str) -> Option<CompletionScore> {
     let (active_name, active_type) = ctx.active_name_and_type()?;
     compute_score_from_active(&active_type, &active_name, ty, name)
 }
@@ -319,7 +455,9 @@ mod tests {
     use test_utils::mark;
 
     use crate::{
-        test_utils::{check_edit, do_completion, get_all_items},
+        test_utils::{
+            check_edit, completion_list_with_config, do_completion, get_all_items, with_core,
+        },
         CompletionConfig, CompletionKind, CompletionScore,
     };
 
@@ -331,6 +469,10 @@ mod tests {
     fn check_scores(ra_fixture: &str, expect: Expect) {
         fn display_score(score: Option<CompletionScore>) -> &'static str {
             match score {
+                Some(CompletionScore::Unlikely) => "[unlikely]",
+                Some(CompletionScore::LocalType) => "[local]",
+                Some(CompletionScore::GenericParam) => "[generic]",
+                Some(CompletionScore::AutoTrait) => "[auto]",
                 Some(CompletionScore::TypeMatch) => "[type]",
                 Some(CompletionScore::TypeAndNameMatch) => "[type+name]",
                 None => "[]".into(),
@@ -441,6 +583,7 @@ fn main() { let _: m::Spam = S<|> }
                         kind: EnumVariant,
                         lookup: "Spam::Bar",
                         detail: "(i32)",
+                        score: TypeMatch,
                         trigger_call_info: true,
                     },
                     CompletionItem {
@@ -480,6 +623,7 @@ fn main() { let _: m::Spam = S<|> }
                         kind: EnumVariant,
                         lookup: "Spam::Foo",
                         detail: "()",
+                        score: TypeMatch,
                     },
                     CompletionItem {
                         label: "main()",
@@ -552,7 +696,7 @@ fn foo() { A { the<|> } }
                         label: "the_field",
                         source_range: 57..60,
                         delete: 57..60,
-                        insert: "the_field",
+                        insert: "the_field: $0",
                         kind: Field,
                         detail: "u32",
                         deprecated: true,
@@ -816,6 +960,228 @@ fn foo(s: S) { test(s.<|>) }
         );
     }
 
+    #[test]
+    fn local_type_scores_in_impl_self_ty() {
+        check_scores(
+            r#"
+//- /lib.rs crate:dep
+pub struct Foreign;
+
+//- /main.rs crate:main deps:dep
+use dep::Foreign;
+trait Shape {}
+struct Circle;
+impl Shape for <|> {}
+"#,
+            expect![[r#"
+                st Circle [local]
+                st Foreign []
+                md dep []
+            "#]],
+        );
+    }
+
+    #[test]
+    fn marks_traits_that_are_not_object_safe() {
+        let fixture = r#"
+trait Shape { fn area(&self) -> f64; fn scaled(&self, by: f64) -> Self where Self: Sized; }
+trait Iter { type Item; fn next(&mut self) -> Option<Self::Item>; }
+trait Cloned: Sized {}
+trait Eq { fn eq(&self, other: &Self) -> bool; }
+trait New { fn new() -> u32; }
+trait Generic { fn map<T>(&self, t: T); }
+trait Konst { const K: u32; }
+
+fn f(x: &dyn <|>) {}
+"#;
+        expect![[r#"
+            tt Cloned  not object safe
+            tt Eq      not object safe
+            tt Generic not object safe
+            tt Iter
+            tt Konst   not object safe
+            tt New     not object safe
+            tt Shape
+        "#]]
+        .assert_eq(&completion_list_with_config(
+            CompletionConfig::default(),
+            fixture,
+            CompletionKind::Reference,
+        ));
+        mark::check!(hides_non_object_safe_traits);
+        expect![[r#"
+            tt Iter
+            tt Shape
+        "#]]
+        .assert_eq(&completion_list_with_config(
+            CompletionConfig { hide_non_object_safe_traits: true, ..CompletionConfig::default() },
+            fixture,
+            CompletionKind::Reference,
+        ));
+    }
+
+    #[test]
+    fn auto_trait_scores_after_plus() {
+        check_scores(
+            r#"
+auto trait Send {}
+trait Debug {}
+trait Display {}
+fn foo(x: Box<dyn Debug + <|>>) {}
+"#,
+            expect![[r#"
+                tt Send [auto]
+                tt Display []
+            "#]],
+        );
+        check_scores(
+            r#"
+auto trait Send {}
+trait Debug {}
+fn foo<T: <|>>() {}
+"#,
+            expect![[r#"
+                tt Debug []
+                tt Send []
+            "#]],
+        );
+    }
+
+    #[test]
+    fn generic_param_scores() {
+        check_scores(
+            r#"
+struct Item;
+struct Wrapper<T, const N: usize>([T; N]);
+impl<T, const N: usize> Wrapper<T, N> {
+    fn map<E>(self) -> Result<<|>, E> {}
+}
+"#,
+            expect![[r#"
+                tp E [generic]
+                ct N [generic]
+                tp T [generic]
+                st Item []
+                tp Self []
+                st Wrapper<…> []
+                bn self []
+            "#]],
+        );
+    }
+
+    #[test]
+    fn let_initializer_scores() {
+        mark::check!(let_initializer_type_match);
+        check_scores(
+            r#"
+const LIMIT: u32 = 10;
+static NAME: &str = "";
+fn len() -> u32 { 0 }
+fn title() -> String { String::new() }
+struct String;
+impl String { fn new() -> String { String } }
+fn foo(n: u32, s: String) {
+    let len: u32 = <|>;
+}
+"#,
+            expect![[r#"
+                fn len() [type+name]
+                ct LIMIT [type]
+                bn n [type]
+                sc NAME []
+                st String []
+                fn foo(…) []
+                bn s []
+                fn title() []
+            "#]],
+        );
+    }
+
+    #[test]
+    fn if_let_pattern_scores() {
+        check_scores(
+            &with_core(
+                r#"
+fn foo(x: Option<u32>) {
+    if let <|> = x {}
+}
+"#,
+            ),
+            expect![[r#"
+                ev Some(…) [type+name]
+                ev None [type]
+                ev Err(…) []
+                ev Ok(…) []
+                en Option []
+                en Result []
+                md alloc []
+                md core []
+            "#]],
+        );
+    }
+
+    #[test]
+    fn return_and_break_value_scores() {
+        mark::check!(return_or_break_type_match);
+        check_scores(
+            r#"
+enum Result<T, E> { Ok(T), Err(E) }
+use Result::*;
+fn foo(a: u32, b: i64) -> Result<u32, ()> {
+    let res: Result<u32, ()> = Ok(a);
+    if b > 0 { return <|> }
+}
+"#,
+            expect![[r#"
+                ev Err(…) [type]
+                ev Ok(…) [type]
+                fn foo(…) [type]
+                bn res [type]
+                en Result []
+                bn a []
+                bn b []
+            "#]],
+        );
+        check_scores(
+            r#"
+fn foo(a: u32, b: i64) {
+    let x: i64 = 'outer: loop {
+        loop { break 'outer <|> }
+    };
+}
+"#,
+            expect![[r#"
+                bn b [type]
+                bn a []
+                fn foo(…) []
+            "#]],
+        );
+        check_scores(
+            r#"
+fn foo(a: u32, b: i64) {
+    let x = loop { while true { break <|> } };
+}
+"#,
+            expect![[r#"
+                bn a []
+                bn b []
+                fn foo(…) []
+            "#]],
+        );
+        check_scores(
+            r#"
+fn foo(a: u32, b: i64) -> u32 {
+    let c = || { return <|> };
+}
+"#,
+            expect![[r#"
+                bn a []
+                bn b []
+                fn foo(…) []
+            "#]],
+        );
+    }
+
     #[test]
     fn record_field_scores() {
         mark::check!(record_field_type_match);
@@ -3,7 +3,7 @@
 use itertools::Itertools;
 use test_utils::mark;
 
-use crate::{item::Builder, CompletionContext};
+use crate::{config::CallableSnippets, item::Builder, CompletionContext};
 
 #[derive(Debug)]
 pub(super) enum Params {
@@ -26,13 +26,16 @@ impl Params {
 
 impl Builder {
     fn should_add_parens(&self, ctx: &CompletionContext) -> bool {
-        if !ctx.config.add_call_parenthesis {
+        if ctx.config.callable_snippets == CallableSnippets::None {
             return false;
         }
         if ctx.use_item_syntax.is_some() {
             mark::hit!(no_parens_in_use_item);
             return false;
         }
+        if ctx.intra_doc_link.is_some() {
+            return false;
+        }
         if ctx.is_pattern_call {
             mark::hit!(dont_duplicate_pattern_parens);
             return false;
@@ -74,8 +77,8 @@ impl Builder {
             (format!("{}()$0", name), format!("{}()", name))
         } else {
             self = self.trigger_call_info();
-            let snippet = match (ctx.config.add_call_argument_snippets, params) {
-                (true, Params::Named(params)) => {
+            let snippet = match (ctx.config.callable_snippets, params) {
+                (CallableSnippets::FillArguments, Params::Named(params)) => {
                     let function_params_snippet =
                         params.iter().enumerate().format_with(", ", |(index, param_name), f| {
                             f(&format_args!("${{{}:{}}}", index + 1, param_name))
@@ -92,4 +95,45 @@ impl Builder {
         };
         self.lookup_by(name).label(label).insert_snippet(cap, snippet)
     }
+
+    /// Like `add_call_parens`, with a turbofish before the parentheses that has
+    /// a placeholder for each of `type_args`, like `collect::<${1:B}>()$0`.
+    pub(super) fn add_turbofish_call(
+        mut self,
+        ctx: &CompletionContext,
+        name: String,
+        type_args: Vec<String>,
+        params: Params,
+    ) -> Builder {
+        let cap = match ctx.config.snippet_cap {
+            Some(it) => it,
+            None => return self,
+        };
+        let type_args_snippet = type_args
+            .iter()
+            .enumerate()
+            .format_with(", ", |(index, arg), f| f(&format_args!("${{{}:{}}}", index + 1, arg)));
+        let turbofish = format!("{}::<{}>", name, type_args_snippet);
+
+        let (snippet, label) = if !self.should_add_parens(ctx) {
+            (format!("{}$0", turbofish), format!("{}::<…>", name))
+        } else if params.is_empty() {
+            (format!("{}()$0", turbofish), format!("{}::<…>()", name))
+        } else {
+            self = self.trigger_call_info();
+            let snippet = match (ctx.config.callable_snippets, params) {
+                (CallableSnippets::FillArguments, Params::Named(params)) => {
+                    let function_params_snippet =
+                        params.iter().enumerate().format_with(", ", |(index, param_name), f| {
+                            f(&format_args!("${{{}:{}}}", type_args.len() + index + 1, param_name))
+                        });
+                    format!("{}({})$0", turbofish, function_params_snippet)
+                }
+                _ => format!("{}($0)", turbofish),
+            };
+            (snippet, format!("{}::<…>(…)", name))
+        };
+        // Distinct from the lookup of the plain call, which is just the name.
+        self.lookup_by(format!("{}::<>", name)).label(label).insert_snippet(cap, snippet)
+    }
 }
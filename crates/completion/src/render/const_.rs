@@ -7,15 +7,16 @@ use syntax::{
 };
 
 use crate::{
-    item::{CompletionItem, CompletionItemKind, CompletionKind},
+    item::{CompletionItem, CompletionItemKind, CompletionKind, ImportToAdd},
     render::RenderContext,
 };
 
 pub(crate) fn render_const<'a>(
     ctx: RenderContext<'a>,
+    import_to_add: Option<ImportToAdd>,
     const_: hir::Const,
 ) -> Option<CompletionItem> {
-    ConstRender::new(ctx, const_).render()
+    ConstRender::new(ctx, const_).render(import_to_add)
 }
 
 #[derive(Debug)]
@@ -31,15 +32,25 @@ impl<'a> ConstRender<'a> {
         ConstRender { ctx, const_, ast_node }
     }
 
-    fn render(self) -> Option<CompletionItem> {
+    fn render(self, import_to_add: Option<ImportToAdd>) -> Option<CompletionItem> {
         let name = self.name()?;
-        let detail = self.detail();
+        let detail = self.ctx.detail(|| self.detail());
 
-        let item = CompletionItem::new(CompletionKind::Reference, self.ctx.source_range(), name)
+        // The import brings the trait of an associated const into scope, so
+        // show the const under the trait's path but insert just its name.
+        let label = match import_to_add.as_ref().and_then(|it| it.import_path.segments.last()) {
+            Some(trait_name) => format!("{}::{}", trait_name, name),
+            None => name.clone(),
+        };
+
+        let item = CompletionItem::new(CompletionKind::Reference, self.ctx.source_range(), label)
             .kind(CompletionItemKind::Const)
             .set_documentation(self.ctx.docs(self.const_))
             .set_deprecated(self.ctx.is_deprecated(self.const_))
-            .detail(detail)
+            .set_detail(detail)
+            .lookup_by(name.clone())
+            .insert_text(name)
+            .add_import(import_to_add)
             .build();
 
         Some(item)
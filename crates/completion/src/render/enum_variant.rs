@@ -1,11 +1,12 @@
 //! Renderer for `enum` variants.
 
-use hir::{HasAttrs, HirDisplay, ModPath, StructKind};
+use hir::{HirDisplay, ModPath, StructKind};
+use ide_db::helpers::FamousDefs;
 use itertools::Itertools;
 use test_utils::mark;
 
 use crate::{
-    item::{CompletionItem, CompletionItemKind, CompletionKind, ImportToAdd},
+    item::{CompletionItem, CompletionItemKind, CompletionKind, CompletionScore, ImportToAdd},
     render::{builder_ext::Params, RenderContext},
 };
 
@@ -69,23 +70,67 @@ impl<'a> EnumVariantRender<'a> {
             self.qualified_name.clone(),
         )
         .kind(CompletionItemKind::EnumVariant)
-        .set_documentation(self.variant.docs(self.ctx.db()))
+        .set_documentation(self.ctx.docs(self.variant))
         .set_deprecated(self.ctx.is_deprecated(self.variant))
         .add_import(import_to_add)
-        .detail(self.detail());
+        .set_detail(self.ctx.detail(|| self.detail()));
+
+        if let Some(score) = self.score() {
+            builder = builder.set_score(score);
+        }
 
         if self.variant_kind == StructKind::Tuple {
             mark::hit!(inserts_parens_for_tuple_enums);
-            let params = Params::Anonymous(self.variant.fields(self.ctx.db()).len());
+            let len = self.variant.fields(self.ctx.db()).len();
+            let completion = self.ctx.completion;
+            let params = if completion.is_pat_binding_or_const || completion.is_pat_path {
+                Params::Named(vec!["_".to_string(); len])
+            } else {
+                Params::Anonymous(len)
+            };
             builder =
                 builder.add_call_parens(self.ctx.completion, self.short_qualified_name, params);
-        } else if self.path.is_some() {
-            builder = builder.lookup_by(self.short_qualified_name);
+        } else {
+            if self.variant_kind == StructKind::Record
+                && self.ctx.completion.is_pat_binding_or_const
+            {
+                builder = builder.insert_text(format!("{} {{ .. }}", self.qualified_name));
+            }
+            if self.path.is_some() {
+                builder = builder.lookup_by(self.short_qualified_name);
+            }
         }
 
         builder.build()
     }
 
+    /// Variants of the expected enum go first. When matching, the variants
+    /// holding a value, `Some` and `Ok` of `core`, are the usual ones to start
+    /// with.
+    fn score(&self) -> Option<CompletionScore> {
+        let completion = self.ctx.completion;
+        let ty = if completion.is_pat_binding_or_const {
+            completion.expected_type.clone()?
+        } else {
+            self.ctx.active_name_and_type()?.1
+        };
+        let enum_ = self.variant.parent_enum(self.ctx.db());
+        if ty.as_adt() != Some(hir::Adt::Enum(enum_)) {
+            return None;
+        }
+        let famous_defs = FamousDefs(&completion.sema, completion.krate);
+        let holds_value = match self.variant.name(self.ctx.db()).to_string().as_str() {
+            "Some" => famous_defs.core_option_Option() == Some(enum_),
+            "Ok" => famous_defs.core_result_Result() == Some(enum_),
+            _ => false,
+        };
+        if holds_value && completion.is_pat_binding_or_const {
+            Some(CompletionScore::TypeAndNameMatch)
+        } else {
+            Some(CompletionScore::TypeMatch)
+        }
+    }
+
     fn detail(&self) -> String {
         let detail_types = self
             .variant
@@ -150,7 +195,7 @@ enum Option<T> { Some(T), None }
 use Option::*;
 fn main(value: Option<i32>) {
     match value {
-        Some($0)
+        Some(${1:_})$0
     }
 }
 "#,
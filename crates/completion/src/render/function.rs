@@ -1,12 +1,18 @@
 //! Renderer for function calls.
 
-use hir::{HasSource, Type};
-use syntax::{ast::Fn, display::function_declaration};
+use hir::{HasSource, HirDisplay, Type};
+use rustc_hash::FxHashSet;
+use stdx::to_lower_snake_case;
+use syntax::{
+    ast::{self, Fn, GenericParamsOwner, NameOwner, TypeBoundsOwner},
+    display::function_declaration,
+    AstNode, SyntaxKind, SyntaxNode,
+};
 use test_utils::mark;
 
 use crate::{
     item::{CompletionItem, CompletionItemKind, CompletionKind, ImportToAdd},
-    render::{builder_ext::Params, RenderContext},
+    render::{builder_ext::Params, compute_score, RenderContext},
 };
 
 pub(crate) fn render_fn<'a>(
@@ -14,17 +20,43 @@ pub(crate) fn render_fn<'a>(
     import_to_add: Option<ImportToAdd>,
     local_name: Option<String>,
     fn_: hir::Function,
-) -> CompletionItem {
+) -> Option<CompletionItem> {
     let _p = profile::span("render_fn");
     FunctionRender::new(ctx, local_name, fn_).render(import_to_add)
 }
 
+/// Renders a function from a generic impl reached through a more specific
+/// self type, showing the types its type parameters take in the signature.
+pub(crate) fn render_fn_with_substs<'a>(
+    ctx: RenderContext<'a>,
+    fn_: hir::Function,
+    substs: Vec<(String, String)>,
+) -> Option<CompletionItem> {
+    let _p = profile::span("render_fn");
+    let mut render = FunctionRender::new(ctx, None, fn_);
+    render.substs = substs;
+    render.render(None)
+}
+
+/// Renders a generic function whose type parameters can't be inferred from the
+/// arguments with a turbofish for them, like `collect::<B>()`.
+pub(crate) fn render_fn_with_turbofish<'a>(
+    ctx: RenderContext<'a>,
+    local_name: Option<String>,
+    fn_: hir::Function,
+) -> Option<CompletionItem> {
+    let _p = profile::span("render_fn_with_turbofish");
+    FunctionRender::new(ctx, local_name, fn_).render_with_turbofish()
+}
+
 #[derive(Debug)]
 struct FunctionRender<'a> {
     ctx: RenderContext<'a>,
     name: String,
     func: hir::Function,
     ast_node: Fn,
+    /// Type parameter names to replace in the signature, with their types.
+    substs: Vec<(String, String)>,
 }
 
 impl<'a> FunctionRender<'a> {
@@ -36,23 +68,136 @@ impl<'a> FunctionRender<'a> {
         let name = local_name.unwrap_or_else(|| fn_.name(ctx.db()).to_string());
         let ast_node = fn_.source(ctx.db()).value;
 
-        FunctionRender { ctx, name, func: fn_, ast_node }
+        FunctionRender { ctx, name, func: fn_, ast_node, substs: Vec::new() }
     }
 
-    fn render(self, import_to_add: Option<ImportToAdd>) -> CompletionItem {
+    fn render(self, import_to_add: Option<ImportToAdd>) -> Option<CompletionItem> {
+        if self.is_not_const_in_const_context() && self.ctx.completion.config.hide_non_const_fns {
+            mark::hit!(hides_non_const_fns_in_const_contexts);
+            return None;
+        }
         let params = self.params();
-        CompletionItem::new(CompletionKind::Reference, self.ctx.source_range(), self.name.clone())
-            .kind(self.kind())
-            .set_documentation(self.ctx.docs(self.func))
-            .set_deprecated(self.ctx.is_deprecated(self.func))
-            .detail(self.detail())
+        let mut item = CompletionItem::new(
+            CompletionKind::Reference,
+            self.ctx.source_range(),
+            self.name.clone(),
+        )
+        .kind(self.kind())
+        .set_documentation(self.ctx.docs(self.func))
+        .set_deprecated(self.ctx.is_deprecated(self.func))
+        .set_detail(self.ctx.detail(|| self.detail()));
+        // The function is most likely called, so its value is what it returns.
+        let ret_type = self.func.ret_type(self.ctx.db());
+        if let Some(score) = compute_score(&self.ctx, &ret_type, &self.name) {
+            item = item.set_score(score);
+        }
+        let item = item
             .add_call_parens(self.ctx.completion, self.name, params)
             .add_import(import_to_add)
-            .build()
+            .build();
+        Some(item)
+    }
+
+    fn render_with_turbofish(self) -> Option<CompletionItem> {
+        let completion = self.ctx.completion;
+        if completion.use_item_syntax.is_some()
+            || completion.intra_doc_link.is_some()
+            || (self.is_not_const_in_const_context() && completion.config.hide_non_const_fns)
+        {
+            return None;
+        }
+        let type_params = self.uninferable_type_params();
+        if type_params.is_empty() {
+            return None;
+        }
+        // A type parameter that is returned is best filled with the expected type.
+        let ret_type = self.ast_node.ret_type().and_then(|it| it.ty()).map(|it| it.to_string());
+        let expected_type = completion
+            .expected_type
+            .as_ref()
+            .filter(|it| !it.is_unknown())
+            .map(|it| it.display(self.ctx.db()).to_string());
+        let type_args = type_params
+            .into_iter()
+            .map(|name| match &expected_type {
+                Some(ty) if ret_type.as_ref() == Some(&name) => ty.clone(),
+                _ => name,
+            })
+            .collect();
+
+        let params = self.params();
+        let item = CompletionItem::new(
+            CompletionKind::Reference,
+            self.ctx.source_range(),
+            self.name.clone(),
+        )
+        .kind(self.kind())
+        .set_documentation(self.ctx.docs(self.func))
+        .set_deprecated(self.ctx.is_deprecated(self.func))
+        .set_detail(self.ctx.detail(|| self.detail()))
+        .add_turbofish_call(completion, self.name, type_args, params)
+        .build();
+        Some(item)
+    }
+
+    /// The type parameters which can't be inferred from the arguments, as they
+    /// appear neither in the types of the parameters nor in the bounds of the
+    /// type parameters which do, like `B` of
+    /// `fn collect<B: FromIterator<Self::Item>>(self) -> B`.
+    fn uninferable_type_params(&self) -> Vec<String> {
+        let generic_params = match self.ast_node.generic_param_list() {
+            Some(it) => it,
+            None => return Vec::new(),
+        };
+        let mut bounds: Vec<(String, Vec<SyntaxNode>)> = generic_params
+            .type_params()
+            .filter_map(|it| {
+                let name = it.name()?.text().to_string();
+                Some((
+                    name,
+                    it.type_bound_list().map(|it| it.syntax().clone()).into_iter().collect(),
+                ))
+            })
+            .collect();
+        for pred in self.ast_node.where_clause().into_iter().flat_map(|it| it.predicates()) {
+            let ty = pred.ty().map(|it| it.to_string());
+            let param = bounds.iter_mut().find(|(name, _)| Some(name) == ty.as_ref());
+            if let (Some((_, bounds)), Some(bound_list)) = (param, pred.type_bound_list()) {
+                bounds.push(bound_list.syntax().clone());
+            }
+        }
+
+        let mut inferable: FxHashSet<String> =
+            self.ast_node.param_list().into_iter().flat_map(|it| idents(it.syntax())).collect();
+        loop {
+            let newly_inferable: Vec<String> = bounds
+                .iter()
+                .filter(|(name, _)| inferable.contains(name))
+                .flat_map(|(_, bounds)| bounds.iter().flat_map(idents))
+                .filter(|it| !inferable.contains(it))
+                .collect();
+            if newly_inferable.is_empty() {
+                break;
+            }
+            inferable.extend(newly_inferable);
+        }
+        bounds.into_iter().map(|(name, _)| name).filter(|it| !inferable.contains(it)).collect()
     }
 
     fn detail(&self) -> String {
-        function_declaration(&self.ast_node)
+        let mut detail = function_declaration(&self.ast_node);
+        if !self.substs.is_empty() {
+            detail = substitute_idents(&detail, &self.substs);
+        }
+        if self.is_not_const_in_const_context() {
+            // Calling it would be E0015.
+            detail.push_str(" (not const)");
+        }
+        detail
+    }
+
+    fn is_not_const_in_const_context(&self) -> bool {
+        self.ctx.completion.in_const_context && !self.func.is_const(self.ctx.db())
     }
 
     fn add_arg(&self, arg: &str, ty: &Type) -> String {
@@ -73,32 +218,44 @@ impl<'a> FunctionRender<'a> {
             None => return Params::Named(Vec::new()),
         };
 
-        let mut params_pats = Vec::new();
+        let mut params_names = Vec::new();
         let params_ty = if self.ctx.completion.dot_receiver.is_some() {
             self.func.method_params(self.ctx.db()).unwrap_or_default()
         } else {
             if let Some(s) = ast_params.self_param() {
                 mark::hit!(parens_for_method_call_as_assoc_fn);
-                params_pats.push(Some(s.to_string()));
+                params_names.push(Some(s.to_string()));
             }
             self.func.assoc_fn_params(self.ctx.db())
         };
-        params_pats
-            .extend(ast_params.params().into_iter().map(|it| it.pat().map(|it| it.to_string())));
+        params_names.extend(ast_params.params().into_iter().map(|it| param_name(it.pat())));
 
-        let params = params_pats
+        let params = params_names
             .into_iter()
             .zip(params_ty)
-            .flat_map(|(pat, param_ty)| {
-                let pat = pat?;
-                let name = pat.to_string();
-                let arg = name.trim_start_matches("mut ").trim_start_matches('_');
-                Some(self.add_arg(arg, param_ty.ty()))
+            .map(|(name, param_ty)| match name {
+                Some(name) => self.add_arg(&name, param_ty.ty()),
+                None => self.name_from_type(param_ty.ty()),
             })
             .collect();
         Params::Named(params)
     }
 
+    /// A placeholder for the arguments whose pattern is no good as one, like
+    /// `_` or a destructuring pattern.
+    fn name_from_type(&self, ty: &Type) -> String {
+        let db = self.ctx.db();
+        let ty = ty.remove_ref().unwrap_or_else(|| ty.clone());
+        let name = match ty.as_impl_traits(db).and_then(|traits| traits.into_iter().next()) {
+            Some(trait_) => trait_.name(db),
+            None => match ty.as_adt() {
+                Some(adt) => adt.name(db),
+                None => return "_".to_string(),
+            },
+        };
+        to_lower_snake_case(&name.to_string())
+    }
+
     fn kind(&self) -> CompletionItemKind {
         if self.func.self_param(self.ctx.db()).is_some() {
             CompletionItemKind::Method
@@ -108,15 +265,66 @@ impl<'a> FunctionRender<'a> {
     }
 }
 
+/// The name of a binding pattern, without the underscores that silence the
+/// unused warnings.
+fn param_name(pat: Option<ast::Pat>) -> Option<String> {
+    match pat? {
+        ast::Pat::IdentPat(it) if it.pat().is_none() => {
+            let name = it.name()?.to_string();
+            let name = name.trim_start_matches('_');
+            if name.is_empty() {
+                None
+            } else {
+                Some(name.to_string())
+            }
+        }
+        _ => None,
+    }
+}
+
+fn idents(node: &SyntaxNode) -> Vec<String> {
+    node.descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|it| it.kind() == SyntaxKind::IDENT)
+        .map(|it| it.text().to_string())
+        .collect()
+}
+
+/// Replaces the identifiers of `text` which have an entry in `substs`.
+fn substitute_idents(text: &str, substs: &[(String, String)]) -> String {
+    let mut res = String::with_capacity(text.len());
+    let mut ident = String::new();
+    for c in text.chars().chain(std::iter::once(' ')) {
+        if c.is_alphanumeric() || c == '_' {
+            ident.push(c);
+            continue;
+        }
+        match substs.iter().find(|(name, _)| *name == ident) {
+            Some((_, ty)) => res.push_str(ty),
+            None => res.push_str(&ident),
+        }
+        ident.clear();
+        res.push(c);
+    }
+    res.pop();
+    res
+}
+
 #[cfg(test)]
 mod tests {
+    use expect_test::{expect, Expect};
     use test_utils::mark;
 
     use crate::{
-        test_utils::{check_edit, check_edit_with_config},
-        CompletionConfig,
+        test_utils::{check_edit, check_edit_with_config, completion_list_with_config},
+        CallableSnippets, CompletionConfig, CompletionKind,
     };
 
+    fn check_with_config(config: CompletionConfig, ra_fixture: &str, expect: Expect) {
+        let actual = completion_list_with_config(config, ra_fixture, CompletionKind::Reference);
+        expect.assert_eq(&actual);
+    }
+
     #[test]
     fn inserts_parens_for_function_calls() {
         mark::check!(inserts_parens_for_function_calls);
@@ -211,7 +419,10 @@ fn main() { S::foo(${1:&self})$0 }
     fn suppress_arg_snippets() {
         mark::check!(suppress_arg_snippets);
         check_edit_with_config(
-            CompletionConfig { add_call_argument_snippets: false, ..CompletionConfig::default() },
+            CompletionConfig {
+                callable_snippets: CallableSnippets::AddParentheses,
+                ..CompletionConfig::default()
+            },
             "with_args",
             r#"
 fn with_args(x: i32, y: String) {}
@@ -224,6 +435,46 @@ fn main() { with_args($0) }
         );
     }
 
+    #[test]
+    fn no_parens_without_callable_snippets() {
+        check_edit_with_config(
+            CompletionConfig {
+                callable_snippets: CallableSnippets::None,
+                ..CompletionConfig::default()
+            },
+            "with_args",
+            r#"
+fn with_args(x: i32, y: String) {}
+fn main() { with_<|> }
+"#,
+            r#"
+fn with_args(x: i32, y: String) {}
+fn main() { with_args }
+"#,
+        );
+    }
+
+    #[test]
+    fn names_placeholders_after_types_for_unnamed_args() {
+        check_edit(
+            "foo",
+            r#"
+struct FooBar;
+trait Frobnicate {}
+trait ByteSource { type Item; }
+fn foo(_: &FooBar, (a, b): (i32, i32), _: impl Frobnicate, _: impl ByteSource<Item = u8>, _: u8) {}
+fn main() { f<|> }
+"#,
+            r#"
+struct FooBar;
+trait Frobnicate {}
+trait ByteSource { type Item; }
+fn foo(_: &FooBar, (a, b): (i32, i32), _: impl Frobnicate, _: impl ByteSource<Item = u8>, _: u8) {}
+fn main() { foo(${1:foo_bar}, ${2:_}, ${3:frobnicate}, ${4:byte_source}, ${5:_})$0 }
+"#,
+        );
+    }
+
     #[test]
     fn strips_underscores_from_args() {
         check_edit(
@@ -335,6 +586,101 @@ fn take_mutably(mut x: &i32) {}
 fn main() {
     take_mutably(${1:x})$0
 }
+"#,
+        );
+    }
+
+    #[test]
+    fn marks_non_const_fns_in_const_contexts() {
+        let fixture = r#"
+const fn const_helper() -> usize { 1 }
+fn helper() -> usize { 1 }
+
+const SIZE: usize = <|>;
+"#;
+        check_with_config(
+            CompletionConfig::default(),
+            fixture,
+            expect![[r#"
+                ct SIZE
+                fn const_helper() const fn const_helper() -> usize
+                fn helper()       fn helper() -> usize (not const)
+            "#]],
+        );
+        mark::check!(hides_non_const_fns_in_const_contexts);
+        check_with_config(
+            CompletionConfig { hide_non_const_fns: true, ..CompletionConfig::default() },
+            fixture,
+            expect![[r#"
+                ct SIZE
+                fn const_helper() const fn const_helper() -> usize
+            "#]],
+        );
+    }
+
+    #[test]
+    fn offers_turbofish_for_uninferable_type_params() {
+        let fixture = r#"
+trait FromStr {}
+trait Iterator { type Item; }
+struct Chars;
+impl Chars {
+    fn parse<F: FromStr>(&self) -> Result<F, ()> {}
+    fn collect<B>(self) -> B {}
+    fn find<T, P: Fn(&T) -> bool>(&self, predicate: P) -> Option<T> {}
+}
+fn main(chars: Chars) { chars.<|> }
+"#;
+        check_with_config(
+            CompletionConfig::default(),
+            fixture,
+            expect![[r#"
+                me collect()      fn collect<B>(self) -> B
+                me collect::<…>() fn collect<B>(self) -> B
+                me find(…)        fn find<T, P: Fn(&T) -> bool>(&self, predicate: P) -> Option<T>
+                me parse()        fn parse<F: FromStr>(&self) -> Result<F, ()>
+                me parse::<…>()   fn parse<F: FromStr>(&self) -> Result<F, ()>
+            "#]],
+        );
+        check_with_config(
+            CompletionConfig { turbofish_snippets: false, ..CompletionConfig::default() },
+            fixture,
+            expect![[r#"
+                me collect() fn collect<B>(self) -> B
+                me find(…)   fn find<T, P: Fn(&T) -> bool>(&self, predicate: P) -> Option<T>
+                me parse()   fn parse<F: FromStr>(&self) -> Result<F, ()>
+            "#]],
+        );
+    }
+
+    #[test]
+    fn inserts_turbofish_for_uninferable_type_params() {
+        check_edit(
+            "parse::<>",
+            r#"
+trait FromStr {}
+fn parse<F: FromStr>(s: &str) -> Result<F, ()> {}
+fn main() { par<|> }
+"#,
+            r#"
+trait FromStr {}
+fn parse<F: FromStr>(s: &str) -> Result<F, ()> {}
+fn main() { parse::<${1:F}>(${2:s})$0 }
+"#,
+        );
+        check_edit(
+            "collect::<>",
+            r#"
+struct Bag;
+struct Chars;
+impl Chars { fn collect<B>(self) -> B {} }
+fn main(chars: Chars) { let bag: Bag = chars.<|> }
+"#,
+            r#"
+struct Bag;
+struct Chars;
+impl Chars { fn collect<B>(self) -> B {} }
+fn main(chars: Chars) { let bag: Bag = chars.collect::<${1:Bag}>()$0 }
 "#,
         );
     }
@@ -1,6 +1,6 @@
 //! Renderer for macro invocations.
 
-use hir::{Documentation, HasSource};
+use hir::{Documentation, HasAttrs, HasSource};
 use syntax::display::macro_label;
 use test_utils::mark;
 
@@ -31,7 +31,9 @@ struct MacroRender<'a> {
 
 impl<'a> MacroRender<'a> {
     fn new(ctx: RenderContext<'a>, name: String, macro_: hir::MacroDef) -> MacroRender<'a> {
-        let docs = ctx.docs(macro_);
+        // The braces are guessed from the docs, so these are needed even when
+        // the item is resolved lazily.
+        let docs = macro_.docs(ctx.db());
         let docs_str = docs.as_ref().map_or("", |s| s.as_str());
         let (bra, ket) = guess_macro_braces(&name, docs_str);
 
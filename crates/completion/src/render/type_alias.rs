@@ -33,13 +33,13 @@ impl<'a> TypeAliasRender<'a> {
 
     fn render(self) -> Option<CompletionItem> {
         let name = self.name()?;
-        let detail = self.detail();
+        let detail = self.ctx.detail(|| self.detail());
 
         let item = CompletionItem::new(CompletionKind::Reference, self.ctx.source_range(), name)
             .kind(CompletionItemKind::TypeAlias)
             .set_documentation(self.ctx.docs(self.type_alias))
             .set_deprecated(self.ctx.is_deprecated(self.type_alias))
-            .detail(detail)
+            .set_detail(detail)
             .build();
 
         Some(item)
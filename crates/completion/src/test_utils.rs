@@ -1,17 +1,23 @@
 //! Runs completion for testing purposes.
+//!
+//! The crate's own tests use these helpers, and they are also available to
+//! other crates with the `test_harness` feature. Fixtures use the same syntax
+//! as `base_db::fixture`, with `<|>` marking the completion position.
 
 use hir::Semantics;
 use ide_db::base_db::{fixture::ChangeFixture, FileLoader, FilePosition};
-use ide_db::RootDatabase;
+use ide_db::{helpers::FamousDefs, RootDatabase};
 use itertools::Itertools;
 use stdx::{format_to, trim_indent};
 use syntax::{AstNode, NodeOrToken, SyntaxElement};
 use test_utils::{assert_eq_text, RangeOrOffset};
 
-use crate::{item::CompletionKind, CompletionConfig, CompletionItem};
+pub use crate::item::CompletionKind;
+
+use crate::{CompletionConfig, CompletionItem};
 
 /// Creates analysis from a multi-file fixture, returns positions marked with <|>.
-pub(crate) fn position(ra_fixture: &str) -> (RootDatabase, FilePosition) {
+pub fn position(ra_fixture: &str) -> (RootDatabase, FilePosition) {
     let change_fixture = ChangeFixture::parse(ra_fixture);
     let mut database = RootDatabase::default();
     database.apply_change(change_fixture.change);
@@ -23,11 +29,19 @@ pub(crate) fn position(ra_fixture: &str) -> (RootDatabase, FilePosition) {
     (database, FilePosition { file_id, offset })
 }
 
-pub(crate) fn do_completion(code: &str, kind: CompletionKind) -> Vec<CompletionItem> {
+/// Puts `ra_fixture` into a `main` crate which depends on the `core` and
+/// `alloc` of `FamousDefs::FIXTURE`. Like the raw strings of the tests,
+/// `ra_fixture` starts and ends with a newline.
+pub fn with_core(ra_fixture: &str) -> String {
+    format!("//- /main.rs crate:main deps:core,alloc{}{}", ra_fixture, FamousDefs::FIXTURE)
+}
+
+/// The completions of the given kind, sorted by label.
+pub fn do_completion(code: &str, kind: CompletionKind) -> Vec<CompletionItem> {
     do_completion_with_config(CompletionConfig::default(), code, kind)
 }
 
-pub(crate) fn do_completion_with_config(
+pub fn do_completion_with_config(
     config: CompletionConfig,
     code: &str,
     kind: CompletionKind,
@@ -38,11 +52,13 @@ pub(crate) fn do_completion_with_config(
     kind_completions
 }
 
-pub(crate) fn completion_list(code: &str, kind: CompletionKind) -> String {
+/// Renders the completions of the given kind, one per line, for use with
+/// `expect_test`.
+pub fn completion_list(code: &str, kind: CompletionKind) -> String {
     completion_list_with_config(CompletionConfig::default(), code, kind)
 }
 
-pub(crate) fn completion_list_with_config(
+pub fn completion_list_with_config(
     config: CompletionConfig,
     code: &str,
     kind: CompletionKind,
@@ -76,11 +92,13 @@ fn monospace_width(s: &str) -> usize {
     s.chars().count()
 }
 
-pub(crate) fn check_edit(what: &str, ra_fixture_before: &str, ra_fixture_after: &str) {
+/// Applies the completion whose lookup string is `what` and compares the
+/// resulting file to `ra_fixture_after`.
+pub fn check_edit(what: &str, ra_fixture_before: &str, ra_fixture_after: &str) {
     check_edit_with_config(CompletionConfig::default(), what, ra_fixture_before, ra_fixture_after)
 }
 
-pub(crate) fn check_edit_with_config(
+pub fn check_edit_with_config(
     config: CompletionConfig,
     what: &str,
     ra_fixture_before: &str,
@@ -100,7 +118,8 @@ pub(crate) fn check_edit_with_config(
     assert_eq_text!(&ra_fixture_after, &actual)
 }
 
-pub(crate) fn check_pattern_is_applicable(code: &str, check: fn(SyntaxElement) -> bool) {
+/// Checks `check` against the token to the left of the marker.
+pub fn check_pattern_is_applicable(code: &str, check: fn(SyntaxElement) -> bool) {
     let (db, pos) = position(code);
 
     let sema = Semantics::new(&db);
@@ -109,7 +128,7 @@ pub(crate) fn check_pattern_is_applicable(code: &str, check: fn(SyntaxElement) -
     assert!(check(NodeOrToken::Token(token)));
 }
 
-pub(crate) fn check_pattern_is_not_applicable(code: &str, check: fn(SyntaxElement) -> bool) {
+pub fn check_pattern_is_not_applicable(code: &str, check: fn(SyntaxElement) -> bool) {
     let (db, pos) = position(code);
     let sema = Semantics::new(&db);
     let original_file = sema.parse(pos.file_id);
@@ -117,7 +136,8 @@ pub(crate) fn check_pattern_is_not_applicable(code: &str, check: fn(SyntaxElemen
     assert!(!check(NodeOrToken::Token(token)));
 }
 
-pub(crate) fn get_all_items(config: CompletionConfig, code: &str) -> Vec<CompletionItem> {
+/// All completions at the marked position, unfiltered and unsorted.
+pub fn get_all_items(config: CompletionConfig, code: &str) -> Vec<CompletionItem> {
     let (db, position) = position(code);
     crate::completions(&db, &config, position).unwrap().into()
 }
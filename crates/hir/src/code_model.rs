@@ -2,7 +2,7 @@
 use std::{iter, sync::Arc};
 
 use arrayvec::ArrayVec;
-use base_db::{CrateDisplayName, CrateId, Edition, FileId};
+use base_db::{CrateDisplayName, CrateId, DependencyKind, Edition, FileId};
 use either::Either;
 use hir_def::{
     adt::ReprKind,
@@ -29,13 +29,13 @@ use hir_expand::{
     MacroDefId, MacroDefKind,
 };
 use hir_ty::{
-    autoderef,
+    autoderef, could_unify,
     display::{HirDisplayError, HirFormatter},
     method_resolution,
     traits::{FnTrait, Solution, SolutionVariables},
     ApplicationTy, BoundVar, CallableDefId, Canonical, DebruijnIndex, FnSig, GenericPredicate,
-    InEnvironment, Obligation, ProjectionPredicate, ProjectionTy, Substs, TraitEnvironment, Ty,
-    TyDefId, TyKind, TypeCtor,
+    ImplTraitLoweringMode, InEnvironment, Obligation, ProjectionPredicate, ProjectionTy, Substs,
+    TraitEnvironment, Ty, TyDefId, TyKind, TypeCtor,
 };
 use rustc_hash::FxHashSet;
 use stdx::impl_from;
@@ -63,6 +63,10 @@ pub struct Crate {
 pub struct CrateDependency {
     pub krate: Crate,
     pub name: Name,
+    /// Whether the dependency is only available when building the tests.
+    pub is_dev: bool,
+    /// Whether the dependency is only available when building the build script.
+    pub is_build: bool,
 }
 
 impl Crate {
@@ -73,7 +77,9 @@ impl Crate {
             .map(|dep| {
                 let krate = Crate { id: dep.crate_id };
                 let name = dep.as_name();
-                CrateDependency { krate, name }
+                let is_dev = dep.kind == DependencyKind::Dev;
+                let is_build = dep.kind == DependencyKind::Build;
+                CrateDependency { krate, name, is_dev, is_build }
             })
             .collect()
     }
@@ -107,6 +113,18 @@ impl Crate {
         db.crate_graph()[self.id].display_name.clone()
     }
 
+    /// Whether this is a test, bench or example target, which can use the
+    /// dev-dependencies anywhere.
+    pub fn is_dev_target(self, db: &dyn HirDatabase) -> bool {
+        db.crate_graph()[self.id].is_dev_target
+    }
+
+    /// The version of the package of the crate, as set by Cargo in
+    /// `CARGO_PKG_VERSION`.
+    pub fn version(self, db: &dyn HirDatabase) -> Option<String> {
+        db.crate_graph()[self.id].env.get("CARGO_PKG_VERSION")
+    }
+
     pub fn query_external_importables(
         self,
         db: &dyn DefDatabase,
@@ -122,6 +140,46 @@ impl Crate {
         db.crate_graph().iter().map(|id| Crate { id }).collect()
     }
 
+    /// The features declared in the manifest of the crate, like Cargo
+    /// features, whether they are enabled or not.
+    pub fn cargo_features(self, db: &dyn HirDatabase) -> Vec<SmolStr> {
+        let mut features: Vec<_> = db.crate_graph()[self.id]
+            .potential_cfg_options
+            .get_cfg_values("feature")
+            .cloned()
+            .collect();
+        features.sort();
+        features
+    }
+
+    /// The values of the enabled cfg options with the given key, like
+    /// `x86_64` for `target_arch`.
+    pub fn cfg_values(self, db: &dyn HirDatabase, key: &str) -> Vec<SmolStr> {
+        db.crate_graph()[self.id].cfg_options.get_cfg_values(key).cloned().collect()
+    }
+
+    /// The environment variables set for this crate, sorted by name.
+    pub fn env_vars(self, db: &dyn HirDatabase) -> Vec<(String, String)> {
+        let env = &db.crate_graph()[self.id].env;
+        let mut vars: Vec<_> = env.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        vars.sort();
+        vars
+    }
+
+    /// The unstable features enabled with `#![feature(...)]` in the crate root.
+    pub fn features(self, db: &dyn HirDatabase) -> Vec<SmolStr> {
+        let attrs = db.attrs(AttrDefId::ModuleId(self.root_module(db).into()));
+        attrs
+            .by_key("feature")
+            .tt_values()
+            .flat_map(|tt| tt.token_trees.iter())
+            .filter_map(|tt| match tt {
+                TokenTree::Leaf(Leaf::Ident(ident)) => Some(ident.text.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Try to get the root URL of the documentation of a crate.
     pub fn get_html_root_url(self: &Crate, db: &dyn HirDatabase) -> Option<String> {
         // Look for #![doc(html_root_url = "...")]
@@ -319,6 +377,14 @@ impl Module {
         Some(self.with_module_id(parent_id))
     }
 
+    /// Whether the module is only compiled for the tests, because it or one of
+    /// its parents is gated behind `#[cfg(test)]`.
+    pub fn is_test_only(self, db: &dyn HirDatabase) -> bool {
+        self.path_to_root(db)
+            .into_iter()
+            .any(|it| db.attrs(AttrDefId::ModuleId(it.id)).is_test_only())
+    }
+
     pub fn path_to_root(self, db: &dyn HirDatabase) -> Vec<Module> {
         let mut res = vec![self];
         let mut curr = self;
@@ -503,6 +569,10 @@ impl Struct {
         db.struct_data(self.id).repr.clone()
     }
 
+    pub fn kind(self, db: &dyn HirDatabase) -> StructKind {
+        self.variant_data(db).kind()
+    }
+
     fn variant_data(self, db: &dyn HirDatabase) -> Arc<VariantData> {
         db.struct_data(self.id).variant_data.clone()
     }
@@ -746,7 +816,10 @@ impl Function {
 
     pub fn assoc_fn_params(self, db: &dyn HirDatabase) -> Vec<Param> {
         let resolver = self.id.resolver(db.upcast());
-        let ctx = hir_ty::TyLoweringContext::new(db, &resolver);
+        // Lowers `impl Trait` arguments to their type parameters, instead of
+        // the unknown type.
+        let ctx = hir_ty::TyLoweringContext::new(db, &resolver)
+            .with_impl_trait_mode(ImplTraitLoweringMode::Param);
         let environment = TraitEnvironment::lower(db, &resolver);
         db.function_data(self.id)
             .params
@@ -763,6 +836,18 @@ impl Function {
             })
             .collect()
     }
+
+    pub fn ret_type(self, db: &dyn HirDatabase) -> Type {
+        let resolver = self.id.resolver(db.upcast());
+        let ret_type = &db.function_data(self.id).ret_type;
+        let ctx = hir_ty::TyLoweringContext::new(db, &resolver);
+        let environment = TraitEnvironment::lower(db, &resolver);
+        Type {
+            krate: self.id.lookup(db.upcast()).container.module(db.upcast()).krate,
+            ty: InEnvironment { value: Ty::from_hir_ext(&ctx, ret_type).0, environment },
+        }
+    }
+
     pub fn method_params(self, db: &dyn HirDatabase) -> Option<Vec<Param>> {
         if self.self_param(db).is_none() {
             return None;
@@ -776,6 +861,10 @@ impl Function {
         db.function_data(self.id).is_unsafe
     }
 
+    pub fn is_const(self, db: &dyn HirDatabase) -> bool {
+        db.function_data(self.id).is_const
+    }
+
     pub fn diagnostics(self, db: &dyn HirDatabase, sink: &mut DiagnosticSink) {
         hir_def::diagnostics::validate_body(db.upcast(), self.id.into(), sink);
         hir_ty::diagnostics::validate_module_item(db, self.id.into(), sink);
@@ -861,6 +950,13 @@ impl Const {
     pub fn name(self, db: &dyn HirDatabase) -> Option<Name> {
         db.const_data(self.id).name.clone()
     }
+
+    pub fn ty(self, db: &dyn HirDatabase) -> Type {
+        let resolver = self.id.resolver(db.upcast());
+        let ctx = hir_ty::TyLoweringContext::new(db, &resolver);
+        let ty = Ty::from_hir(&ctx, &db.const_data(self.id).type_ref);
+        Type::new_with_resolver_inner(db, self.module(db).id.krate, &resolver, ty)
+    }
 }
 
 impl HasVisibility for Const {
@@ -892,6 +988,13 @@ impl Static {
     pub fn is_mut(self, db: &dyn HirDatabase) -> bool {
         db.static_data(self.id).mutable
     }
+
+    pub fn ty(self, db: &dyn HirDatabase) -> Type {
+        let resolver = self.id.resolver(db.upcast());
+        let ctx = hir_ty::TyLoweringContext::new(db, &resolver);
+        let ty = Ty::from_hir(&ctx, &db.static_data(self.id).type_ref);
+        Type::new_with_resolver_inner(db, self.module(db).id.krate, &resolver, ty)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -944,6 +1047,13 @@ impl TypeAlias {
         Type::from_def(db, self.id.lookup(db.upcast()).module(db.upcast()).krate, self.id)
     }
 
+    /// Like `ty`, but the type parameters of a generic alias are kept as they
+    /// are instead of being replaced by unknown types.
+    pub fn ty_with_params(self, db: &dyn HirDatabase) -> Type {
+        let ty = db.ty(self.id.into()).subst(&Substs::type_params(db, self.id));
+        Type::new(db, self.id.lookup(db.upcast()).module(db.upcast()).krate, self.id, ty)
+    }
+
     pub fn name(self, db: &dyn HirDatabase) -> Name {
         db.type_alias_data(self.id).name.clone()
     }
@@ -986,6 +1096,65 @@ impl MacroDef {
     pub fn is_derive_macro(&self) -> bool {
         matches!(self.id.kind, MacroDefKind::ProcMacro(_) | MacroDefKind::BuiltInDerive(_))
     }
+
+    /// The helper attributes declared by this derive macro, like `foo` in
+    /// `#[proc_macro_derive(Foo, attributes(foo))]`.
+    ///
+    /// The declaration is found through the name the macro is exported with
+    /// from its crate. `name`, the one it was resolved with, is only used to
+    /// pick between the derives of a crate whose proc macros aren't loaded, as
+    /// those can't be told apart otherwise.
+    pub fn derive_helpers(self, db: &dyn HirDatabase, name: &Name) -> Vec<Name> {
+        let root = match self.module(db) {
+            Some(it) => it,
+            None => return Vec::new(),
+        };
+        let exported: Vec<Name> = root
+            .scope(db, None)
+            .into_iter()
+            .filter_map(|(it, def)| match def {
+                ScopeDef::MacroDef(mac) if mac == self => Some(it),
+                _ => None,
+            })
+            .collect();
+        let derive = if exported.contains(name) {
+            name
+        } else if let [it] = exported.as_slice() {
+            it
+        } else {
+            return Vec::new();
+        };
+        for def in root.declarations(db) {
+            let func = match def {
+                ModuleDef::Function(it) => it,
+                _ => continue,
+            };
+            let attrs = db.attrs(AttrDefId::FunctionId(func.id));
+            for tt in attrs.by_key("proc_macro_derive").tt_values() {
+                match tt.token_trees.first() {
+                    Some(TokenTree::Leaf(Leaf::Ident(ident))) if ident.as_name() == *derive => {}
+                    _ => continue,
+                }
+                let helpers = tt.token_trees.windows(2).find_map(|pair| match pair {
+                    [TokenTree::Leaf(Leaf::Ident(ident)), TokenTree::Subtree(helpers)]
+                        if ident.text == "attributes" =>
+                    {
+                        Some(helpers)
+                    }
+                    _ => None,
+                });
+                return helpers
+                    .into_iter()
+                    .flat_map(|helpers| helpers.token_trees.iter())
+                    .filter_map(|tt| match tt {
+                        TokenTree::Leaf(Leaf::Ident(ident)) => Some(ident.as_name()),
+                        _ => None,
+                    })
+                    .collect();
+            }
+        }
+        Vec::new()
+    }
 }
 
 /// Invariant: `inner.as_assoc_item(db).is_some()`
@@ -1251,6 +1420,25 @@ impl ImplDef {
         }
     }
 
+    /// The types the type parameters of this impl take when it applies to
+    /// `self_ty`, e.g. `K = u32` for `impl<K> Map<K>` and `Map<u32>`.
+    pub fn type_params_for(
+        self,
+        db: &dyn HirDatabase,
+        self_ty: &Type,
+    ) -> Option<Vec<(Name, Type)>> {
+        let canonical = Canonical { value: self_ty.ty.value.clone(), kinds: Arc::new([]) };
+        let substs = method_resolution::inherent_impl_substs(db, self.id, &canonical)?;
+        let params = db.generic_params(self.id.into());
+        let res = params
+            .types
+            .iter()
+            .zip(substs.iter())
+            .filter_map(|((_, param), ty)| Some((param.name.clone()?, self_ty.derived(ty.clone()))))
+            .collect();
+        Some(res)
+    }
+
     pub fn items(self, db: &dyn HirDatabase) -> Vec<AssocItem> {
         db.impl_data(self.id).items.iter().map(|it| (*it).into()).collect()
     }
@@ -1330,6 +1518,12 @@ impl Type {
         Type::new(db, krate, def, ty)
     }
 
+    /// The type of a builtin type (like `u32`) referred to from `module`.
+    pub fn from_builtin(db: &dyn HirDatabase, module: Module, builtin: BuiltinType) -> Type {
+        let ty = db.ty(TyDefId::BuiltinType(builtin)).value;
+        Type::new(db, module.id.krate, module.id, ty)
+    }
+
     pub fn is_unit(&self) -> bool {
         matches!(
             self.ty.value,
@@ -1340,6 +1534,14 @@ impl Type {
         matches!(self.ty.value, Ty::Apply(ApplicationTy { ctor: TypeCtor::Bool, .. }))
     }
 
+    pub fn is_integral(&self) -> bool {
+        matches!(self.ty.value, Ty::Apply(ApplicationTy { ctor: TypeCtor::Int(_), .. }))
+    }
+
+    pub fn is_float(&self) -> bool {
+        matches!(self.ty.value, Ty::Apply(ApplicationTy { ctor: TypeCtor::Float(_), .. }))
+    }
+
     pub fn is_mutable_reference(&self) -> bool {
         matches!(
             self.ty.value,
@@ -1359,6 +1561,10 @@ impl Type {
         matches!(self.ty.value, Ty::Unknown)
     }
 
+    pub fn could_unify_with(&self, other: &Type) -> bool {
+        could_unify(&self.ty.value, &other.ty.value)
+    }
+
     /// Checks that particular type `ty` implements `std::future::Future`.
     /// This function is used in `.await` syntax completion.
     pub fn impls_future(&self, db: &dyn HirDatabase) -> bool {
@@ -1482,9 +1688,10 @@ impl Type {
     }
 
     pub fn is_fn(&self) -> bool {
-        matches!(&self.ty.value,
-            Ty::Apply(ApplicationTy { ctor: TypeCtor::FnDef(..), .. }) |
-            Ty::Apply(ApplicationTy { ctor: TypeCtor::FnPtr { .. }, .. })
+        matches!(
+            &self.ty.value,
+            Ty::Apply(ApplicationTy { ctor: TypeCtor::FnDef(..), .. })
+                | Ty::Apply(ApplicationTy { ctor: TypeCtor::FnPtr { .. }, .. })
         )
     }
 
@@ -1517,6 +1724,18 @@ impl Type {
         }
     }
 
+    pub fn contains_type_params(&self) -> bool {
+        return go(&self.ty.value);
+
+        fn go(ty: &Ty) -> bool {
+            match ty {
+                Ty::Placeholder(_) => true,
+                Ty::Apply(a_ty) => a_ty.parameters.iter().any(go),
+                _ => false,
+            }
+        }
+    }
+
     pub fn fields(&self, db: &dyn HirDatabase) -> Vec<(Field, Type)> {
         if let Ty::Apply(a_ty) = &self.ty.value {
             let variant_id = match a_ty.ctor {
@@ -1600,6 +1819,22 @@ impl Type {
         let env = self.ty.environment.clone();
         let krate = krate.id;
 
+        if name.is_none() {
+            // Listing all methods is what completion does on every keystroke,
+            // so use the memoized candidates.
+            let candidates = method_resolution::all_method_candidates(
+                &canonical,
+                db,
+                env,
+                krate,
+                traits_in_scope,
+            );
+            return candidates.iter().find_map(|(ty, it)| match it {
+                AssocItemId::FunctionId(f) => callback(ty, (*f).into()),
+                _ => None,
+            });
+        }
+
         method_resolution::iterate_method_candidates(
             &canonical,
             db,
@@ -1643,11 +1878,38 @@ impl Type {
         )
     }
 
+    /// Like `iterate_path_candidates`, but considers every trait with an impl
+    /// for this type in `krate` and its dependencies, whether it is in scope
+    /// or not. Blanket impls are not taken into account.
+    pub fn iterate_path_candidates_of_implemented_traits<T>(
+        &self,
+        db: &dyn HirDatabase,
+        krate: Crate,
+        callback: impl FnMut(&Ty, AssocItem) -> Option<T>,
+    ) -> Option<T> {
+        let in_crate = db.trait_impls_in_crate(krate.id);
+        let in_deps = db.trait_impls_in_deps(krate.id);
+        let traits: FxHashSet<TraitId> = in_crate
+            .traits_for_self_ty(&self.ty.value)
+            .chain(in_deps.traits_for_self_ty(&self.ty.value))
+            .collect();
+        self.iterate_path_candidates(db, krate, &traits, None, callback)
+    }
+
     pub fn as_adt(&self) -> Option<Adt> {
         let (adt, _subst) = self.ty.value.as_adt()?;
         Some(adt.into())
     }
 
+    /// The type arguments of an applied type, like `T` for `Box<T>`.
+    pub fn type_arguments(&self) -> impl Iterator<Item = Type> + '_ {
+        let substs = match &self.ty.value {
+            Ty::Apply(a_ty) => Some(&a_ty.parameters),
+            _ => None,
+        };
+        substs.into_iter().flat_map(|it| it.iter()).map(move |ty| self.derived(ty.clone()))
+    }
+
     pub fn as_dyn_trait(&self) -> Option<Trait> {
         self.ty.value.dyn_trait().map(Into::into)
     }
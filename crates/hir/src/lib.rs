@@ -46,7 +46,7 @@ pub use hir_def::{
     adt::StructKind,
     attr::Attrs,
     body::scope::ExprScopes,
-    builtin_type::BuiltinType,
+    builtin_type::{BuiltinInt, BuiltinType},
     docs::Documentation,
     find_path::PrefixKind,
     import_map,
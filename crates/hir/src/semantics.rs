@@ -6,11 +6,13 @@ use std::{cell::RefCell, fmt, iter::successors};
 
 use base_db::{FileId, FileRange};
 use hir_def::{
+    body::LowerCtx,
     resolver::{self, HasResolver, Resolver, TypeNs},
+    type_ref::TypeRef,
     AsMacroCall, FunctionId, TraitId, VariantId,
 };
 use hir_expand::{hygiene::Hygiene, name::AsName, ExpansionInfo};
-use hir_ty::associated_type_shorthand_candidates;
+use hir_ty::{associated_type_shorthand_candidates, Ty, TyLoweringContext};
 use itertools::Itertools;
 use rustc_hash::{FxHashMap, FxHashSet};
 use syntax::{
@@ -188,6 +190,10 @@ impl<'db, DB: HirDatabase> Semantics<'db, DB> {
         self.imp.type_of_self(param)
     }
 
+    pub fn resolve_type(&self, ty: &ast::Type) -> Option<Type> {
+        self.imp.resolve_type(ty)
+    }
+
     pub fn resolve_method_call(&self, call: &ast::MethodCallExpr) -> Option<Function> {
         self.imp.resolve_method_call(call).map(Function::from)
     }
@@ -410,6 +416,14 @@ impl<'db> SemanticsImpl<'db> {
         self.analyze(param.syntax()).type_of_self(self.db, &param)
     }
 
+    fn resolve_type(&self, ty: &ast::Type) -> Option<Type> {
+        let scope = self.scope(ty.syntax());
+        let lower_ctx = LowerCtx::new(self.db.upcast(), scope.file_id);
+        let type_ref = TypeRef::from_ast(&lower_ctx, ty.clone());
+        let ty = Ty::from_hir(&TyLoweringContext::new(self.db, &scope.resolver), &type_ref);
+        Type::new_with_resolver(self.db, &scope.resolver, ty)
+    }
+
     fn resolve_method_call(&self, call: &ast::MethodCallExpr) -> Option<FunctionId> {
         self.analyze(call.syntax()).resolve_method_call(self.db, call)
     }
@@ -2,7 +2,7 @@
 
 use std::{ops, sync::Arc};
 
-use cfg::{CfgExpr, CfgOptions};
+use cfg::{CfgAtom, CfgExpr, CfgOptions};
 use either::Either;
 use hir_expand::{hygiene::Hygiene, AstId, InFile};
 use mbe::ast_to_token_tree;
@@ -134,6 +134,16 @@ impl Attrs {
             _ => Some(CfgExpr::All(cfgs)),
         }
     }
+    /// Whether the `cfg` of the owner can only hold in test builds, as in
+    /// `#[cfg(test)]` or `#[cfg(all(test, unix))]`.
+    pub fn is_test_only(&self) -> bool {
+        let test = CfgAtom::Flag("test".into());
+        match self.cfg() {
+            Some(cfg) => cfg.fold(&|atom| *atom != test) == Some(false),
+            None => false,
+        }
+    }
+
     pub(crate) fn is_cfg_enabled(&self, cfg_options: &CfgOptions) -> bool {
         match self.cfg() {
             None => true,
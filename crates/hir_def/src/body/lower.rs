@@ -16,7 +16,9 @@ use syntax::{
         self, ArgListOwner, ArrayExprKind, AstChildren, LiteralKind, LoopBodyOwner, NameOwner,
         SlicePatComponents,
     },
-    AstNode, AstPtr, SyntaxNodePtr,
+    AstNode, AstPtr,
+    SyntaxKind::FLOAT_NUMBER,
+    SyntaxNodePtr,
 };
 use test_utils::mark;
 
@@ -31,21 +33,23 @@ use crate::{
         LogicOp, MatchArm, Ordering, Pat, PatId, RecordFieldPat, RecordLitField, Statement,
     },
     item_scope::BuiltinShadowMode,
-    item_tree::{ItemTree, ItemTreeId, ItemTreeNode},
-    path::{GenericArgs, Path},
+    item_tree::{Import, ItemTree, ItemTreeId, ItemTreeNode},
+    path::{GenericArgs, ImportAlias, Path},
+    per_ns::PerNs,
     type_ref::{Mutability, Rawness, TypeRef},
-    AdtId, ConstLoc, ContainerId, DefWithBodyId, EnumLoc, FunctionLoc, Intern, ModuleDefId,
-    StaticLoc, StructLoc, TraitLoc, TypeAliasLoc, UnionLoc,
+    visibility::Visibility,
+    AdtId, ConstLoc, ContainerId, DefWithBodyId, EnumLoc, EnumVariantId, FunctionLoc, Intern,
+    ModuleDefId, StaticLoc, StructLoc, TraitLoc, TypeAliasLoc, UnionLoc,
 };
 
 use super::{diagnostics::BodyDiagnostic, ExprSource, PatSource};
 
-pub(crate) struct LowerCtx {
+pub struct LowerCtx {
     hygiene: Hygiene,
 }
 
 impl LowerCtx {
-    pub(crate) fn new(db: &dyn DefDatabase, file_id: HirFileId) -> Self {
+    pub fn new(db: &dyn DefDatabase, file_id: HirFileId) -> Self {
         LowerCtx { hygiene: Hygiene::new(db.upcast(), file_id) }
     }
     pub(crate) fn with_hygiene(hygiene: &Hygiene) -> Self {
@@ -436,8 +440,23 @@ impl ExprCollector<'_> {
                 res
             }
             ast::Expr::FieldExpr(e) => {
-                let expr = self.collect_expr_opt(e.expr());
+                let mut expr = self.collect_expr_opt(e.expr());
                 let name = match e.field_access() {
+                    // `x.0.1` is lexed with the float `0.1` as its index (and
+                    // `x.0.` with `0.`), so lower it as nested field accesses.
+                    Some(ast::FieldKind::Index(idx)) if idx.kind() == FLOAT_NUMBER => {
+                        let mut names: Vec<_> = idx
+                            .text()
+                            .split('.')
+                            .filter(|it| !it.is_empty())
+                            .map(|it| Name::new_tuple_field(it.parse().unwrap_or(0)))
+                            .collect();
+                        let name = names.pop().unwrap_or_else(Name::missing);
+                        for name in names {
+                            expr = self.alloc_expr_desugared(Expr::Field { expr, name });
+                        }
+                        name
+                    }
                     Some(kind) => kind.as_name(),
                     _ => Name::missing(),
                 };
@@ -739,6 +758,14 @@ impl ExprCollector<'_> {
             })
             .collect::<Vec<_>>();
 
+        let uses = block
+            .statements()
+            .filter_map(|stmt| match stmt {
+                ast::Stmt::Item(ast::Item::Use(it)) => Some(it),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
         for (def, name) in items {
             self.body.item_scope.define_def(def);
             if let Some(name) = name {
@@ -755,6 +782,91 @@ impl ExprCollector<'_> {
                 );
             }
         }
+
+        self.collect_block_imports(&uses);
+    }
+
+    /// Brings the names imported by the `use` items of a block into the scope
+    /// of the body. Names defined in the block itself take precedence over
+    /// the imported ones, and explicit imports over glob imports.
+    fn collect_block_imports(&mut self, uses: &[ast::Use]) {
+        let mut imports = Vec::new();
+        for use_item in uses {
+            let id = self.expander.ast_id(use_item);
+            let tree = &self.item_trees[&id.file_id];
+            imports.extend(
+                tree.all_inner_items()
+                    .filter_map(|mod_item| mod_item.downcast::<Import>())
+                    .map(|import| &tree[import])
+                    .filter(|import| import.ast_id == id.value)
+                    .cloned(),
+            );
+        }
+        imports.sort_by_key(|import| import.is_glob);
+
+        let module = self.expander.module;
+        for import in imports {
+            let (res, _) = self.expander.crate_def_map.resolve_path(
+                self.db,
+                module.local_id,
+                &import.path,
+                BuiltinShadowMode::Module,
+            );
+            if !import.is_glob {
+                let name = match import.alias {
+                    Some(ImportAlias::Alias(name)) => name,
+                    Some(ImportAlias::Underscore) => continue,
+                    None => match import.path.segments.last() {
+                        Some(name) => name.clone(),
+                        None => continue,
+                    },
+                };
+                self.body.item_scope.push_res(name, res);
+                continue;
+            }
+
+            let resolutions = match res.take_types() {
+                Some(ModuleDefId::ModuleId(m)) => {
+                    let crate_def_map = self.expander.crate_def_map.clone();
+                    let def_map = if m.krate == module.krate {
+                        crate_def_map.clone()
+                    } else {
+                        self.db.crate_def_map(m.krate)
+                    };
+                    def_map[m.local_id]
+                        .scope
+                        .resolutions()
+                        .filter_map(|(name, res)| {
+                            let res = res.filter_visibility(|vis| {
+                                vis.is_visible_from_def_map(&crate_def_map, module.local_id)
+                            });
+                            Some((name?, res))
+                        })
+                        .collect::<Vec<_>>()
+                }
+                Some(ModuleDefId::AdtId(AdtId::EnumId(e))) => {
+                    let vis = Visibility::Public;
+                    self.db
+                        .enum_data(e)
+                        .variants
+                        .iter()
+                        .map(|(local_id, variant_data)| {
+                            let variant = EnumVariantId { parent: e, local_id };
+                            (
+                                variant_data.name.clone(),
+                                PerNs::both(variant.into(), variant.into(), vis),
+                            )
+                        })
+                        .collect()
+                }
+                _ => continue,
+            };
+            for (name, res) in resolutions {
+                if !res.is_none() {
+                    self.body.item_scope.push_res(name, res);
+                }
+            }
+        }
     }
 
     fn collect_block_opt(&mut self, expr: Option<ast::BlockExpr>) -> ExprId {
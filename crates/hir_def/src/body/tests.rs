@@ -134,3 +134,29 @@ fn f() {
       "#,
     );
 }
+
+#[test]
+fn block_imports_are_in_the_body_scope() {
+    let body = lower(
+        r#"
+fn f() {
+    struct Glob;
+    use m::*;
+    use m::Explicit as Renamed;
+    use E::*;
+}
+mod m {
+    pub struct Glob;
+    pub struct Explicit;
+    struct Private;
+}
+enum E { V }
+"#,
+    );
+    let mut names = body.item_scope.entries().map(|(name, _)| name.to_string()).collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, ["Explicit", "Glob", "Renamed", "V"]);
+    // The struct defined in the block shadows the glob-imported one.
+    let glob = body.item_scope.entries().find(|(name, _)| name.to_string() == "Glob");
+    assert_eq!(glob.unwrap().1.take_types(), body.item_scope.declarations().next());
+}
@@ -27,6 +27,7 @@ pub struct FunctionData {
     pub has_self_param: bool,
     pub has_body: bool,
     pub is_unsafe: bool,
+    pub is_const: bool,
     pub is_varargs: bool,
     pub visibility: RawVisibility,
 }
@@ -45,6 +46,7 @@ impl FunctionData {
             has_self_param: func.has_self_param,
             has_body: func.has_body,
             is_unsafe: func.is_unsafe,
+            is_const: func.is_const,
             is_varargs: func.is_varargs,
             visibility: item_tree[func.visibility].clone(),
         })
@@ -507,6 +507,7 @@ pub struct Function {
     pub has_self_param: bool,
     pub has_body: bool,
     pub is_unsafe: bool,
+    pub is_const: bool,
     pub params: Box<[TypeRef]>,
     pub is_varargs: bool,
     pub ret_type: TypeRef,
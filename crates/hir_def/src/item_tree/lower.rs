@@ -340,6 +340,7 @@ impl Ctx {
             has_self_param,
             has_body,
             is_unsafe: func.unsafe_token().is_some(),
+            is_const: func.const_token().is_some(),
             params: params.into_boxed_slice(),
             is_varargs,
             ret_type,
@@ -496,6 +496,10 @@ impl Scope {
                     f(name.clone(), ScopeDef::PerNs(def));
                 });
                 m.crate_def_map[m.module_id].scope.legacy_macros().for_each(|(name, macro_)| {
+                    // `#[macro_export]` macros of the crate root are in both scopes.
+                    if m.crate_def_map[m.module_id].scope.get(name).take_macros() == Some(macro_) {
+                        return;
+                    }
                     f(name.clone(), ScopeDef::PerNs(PerNs::macros(macro_, Visibility::Public)));
                 });
                 m.crate_def_map.extern_prelude.iter().for_each(|(name, &def)| {
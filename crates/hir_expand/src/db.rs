@@ -80,6 +80,14 @@ pub trait AstDatabase: SourceDatabase {
         macro_file: MacroFile,
     ) -> ExpandResult<Option<(Parse<SyntaxNode>, Arc<mbe::TokenMap>)>>;
     fn macro_expand(&self, macro_call: MacroCallId) -> ExpandResult<Option<Arc<tt::Subtree>>>;
+    /// Expands the macro call with `hypothetical_args` instead of the actual
+    /// arguments. Returns the parsed expansion together with the token maps of
+    /// the arguments and of the expansion.
+    fn hypothetical_macro_expansion(
+        &self,
+        macro_call: MacroCallId,
+        hypothetical_args: GreenNode,
+    ) -> Option<(Parse<SyntaxNode>, Arc<mbe::TokenMap>, Arc<mbe::TokenMap>)>;
 
     /// Firewall query that returns the error from the `macro_expand` query.
     fn macro_expand_error(&self, macro_call: MacroCallId) -> Option<ExpandError>;
@@ -100,20 +108,35 @@ pub fn expand_hypothetical(
     hypothetical_args: &syntax::ast::TokenTree,
     token_to_map: syntax::SyntaxToken,
 ) -> Option<(SyntaxNode, syntax::SyntaxToken)> {
-    let macro_file = MacroFile { macro_call_id: actual_macro_call };
-    let (tt, tmap_1) = mbe::syntax_node_to_token_tree(hypothetical_args.syntax()).unwrap();
     let range =
         token_to_map.text_range().checked_sub(hypothetical_args.syntax().text_range().start())?;
+    // The expansion is memoized, as completion requests at the same position
+    // would otherwise expand the same call over and over within a revision.
+    let (node, tmap_1, tmap_2) = db.hypothetical_macro_expansion(
+        actual_macro_call,
+        hypothetical_args.syntax().green().clone(),
+    )?;
     let token_id = tmap_1.token_by_range(range)?;
     let macro_def = expander(db, actual_macro_call)?;
-    let (node, tmap_2) =
-        parse_macro_with_arg(db, macro_file, Some(std::sync::Arc::new((tt, tmap_1)))).value?;
     let token_id = macro_def.0.map_id_down(token_id);
     let range = tmap_2.range_by_token(token_id)?.by_kind(token_to_map.kind())?;
     let token = syntax::algo::find_covering_element(&node.syntax_node(), range).into_token()?;
     Some((node.syntax_node(), token))
 }
 
+fn hypothetical_macro_expansion(
+    db: &dyn AstDatabase,
+    actual_macro_call: MacroCallId,
+    hypothetical_args: GreenNode,
+) -> Option<(Parse<SyntaxNode>, Arc<mbe::TokenMap>, Arc<mbe::TokenMap>)> {
+    let macro_file = MacroFile { macro_call_id: actual_macro_call };
+    let hypothetical_args = SyntaxNode::new_root(hypothetical_args);
+    let (tt, tmap_1) = mbe::syntax_node_to_token_tree(&hypothetical_args)?;
+    let (node, tmap_2) =
+        parse_macro_with_arg(db, macro_file, Some(Arc::new((tt, tmap_1.clone())))).value?;
+    Some((node, Arc::new(tmap_1), tmap_2))
+}
+
 fn ast_id_map(db: &dyn AstDatabase, file_id: HirFileId) -> Arc<AstIdMap> {
     let map =
         db.parse_or_expand(file_id).map_or_else(AstIdMap::default, |it| AstIdMap::from_source(&it));
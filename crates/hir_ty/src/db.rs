@@ -5,15 +5,15 @@ use std::sync::Arc;
 use arena::map::ArenaMap;
 use base_db::{impl_intern_key, salsa, CrateId, Upcast};
 use hir_def::{
-    db::DefDatabase, expr::ExprId, DefWithBodyId, FunctionId, GenericDefId, ImplId, LocalFieldId,
-    TypeParamId, VariantId,
+    db::DefDatabase, expr::ExprId, AssocItemId, DefWithBodyId, FunctionId, GenericDefId, ImplId,
+    LocalFieldId, TraitId, TypeParamId, VariantId,
 };
 
 use crate::{
     method_resolution::{InherentImpls, TraitImpls},
     traits::chalk,
-    Binders, CallableDefId, GenericPredicate, InferenceResult, OpaqueTyId, PolyFnSig,
-    ReturnTypeImplTraits, TraitRef, Ty, TyDefId, ValueTyDefId,
+    Binders, CallableDefId, Canonical, GenericPredicate, InferenceResult, OpaqueTyId, PolyFnSig,
+    ReturnTypeImplTraits, TraitEnvironment, TraitRef, Ty, TyDefId, ValueTyDefId,
 };
 use hir_expand::name::Name;
 
@@ -74,6 +74,17 @@ pub trait HirDatabase: DefDatabase + Upcast<dyn DefDatabase> {
     #[salsa::invoke(TraitImpls::trait_impls_in_deps_query)]
     fn trait_impls_in_deps(&self, krate: CrateId) -> Arc<TraitImpls>;
 
+    /// All method call candidates for the receiver type, in the order
+    /// `iterate_method_candidates` visits them. `traits_in_scope` must be sorted.
+    #[salsa::invoke(crate::method_resolution::method_candidates_query)]
+    fn method_candidates(
+        &self,
+        krate: CrateId,
+        ty: Canonical<Ty>,
+        env: Arc<TraitEnvironment>,
+        traits_in_scope: Arc<[TraitId]>,
+    ) -> Arc<[(Ty, AssocItemId)]>;
+
     // Interned IDs for Chalk integration
     #[salsa::interned]
     fn intern_callable_def(&self, callable_def: CallableDefId) -> InternedCallableDefId;
@@ -38,7 +38,8 @@ use syntax::SmolStr;
 use super::{
     primitive::{FloatTy, IntTy},
     traits::{Guidance, Obligation, ProjectionPredicate, Solution},
-    InEnvironment, ProjectionTy, Substs, TraitEnvironment, TraitRef, Ty, TypeCtor, TypeWalk,
+    Canonical, InEnvironment, ProjectionTy, Substs, TraitEnvironment, TraitRef, Ty, TypeCtor,
+    TypeWalk,
 };
 use crate::{
     db::HirDatabase, infer::diagnostics::InferenceDiagnostic, lower::ImplTraitLoweringMode,
@@ -62,6 +63,12 @@ mod pat;
 mod coerce;
 
 /// The entry point of type inference.
+/// Whether `t1` and `t2` unify, regardless of the environments they come from.
+pub fn could_unify(t1: &Ty, t2: &Ty) -> bool {
+    let tys = Canonical { value: (t1.clone(), t2.clone()), kinds: Arc::new([]) };
+    unify(&tys).is_some()
+}
+
 pub(crate) fn infer_query(db: &dyn HirDatabase, def: DefWithBodyId) -> Arc<InferenceResult> {
     let _p = profile::span("infer_query");
     let resolver = def.resolver(db.upcast());
@@ -5,7 +5,7 @@
 use std::{iter, sync::Arc};
 
 use arrayvec::ArrayVec;
-use base_db::CrateId;
+use base_db::{salsa::InternKey, CrateId};
 use hir_def::{
     builtin_type::{IntBitness, Signedness},
     lang_item::LangItemTarget,
@@ -176,6 +176,16 @@ impl TraitImpls {
             .flat_map(|v| v.iter().copied())
     }
 
+    /// Queries all traits with an impl specifically for `self_ty`, ignoring
+    /// the blanket impls.
+    pub fn traits_for_self_ty(&self, self_ty: &Ty) -> impl Iterator<Item = TraitId> + '_ {
+        let self_ty = TyFingerprint::for_impl(self_ty);
+        self.map
+            .iter()
+            .filter(move |(_, map)| self_ty.is_some() && map.contains_key(&self_ty))
+            .map(|(trait_, _)| *trait_)
+    }
+
     pub fn all_impls(&self) -> impl Iterator<Item = ImplId> + '_ {
         self.map.values().flat_map(|map| map.values().flat_map(|v| v.iter().copied()))
     }
@@ -310,6 +320,49 @@ pub(crate) fn lookup_method(
     )
 }
 
+/// Returns all method call candidates for `ty`, like
+/// `iterate_method_candidates` with no name would visit them. Unlike the
+/// latter, the result is memoized, so that repeatedly asking for the methods
+/// of the same receiver (like when completing `foo.<|>`) doesn't redo trait
+/// solving.
+pub fn all_method_candidates(
+    ty: &Canonical<Ty>,
+    db: &dyn HirDatabase,
+    env: Arc<TraitEnvironment>,
+    krate: CrateId,
+    traits_in_scope: &FxHashSet<TraitId>,
+) -> Arc<[(Ty, AssocItemId)]> {
+    let mut traits_in_scope: Vec<TraitId> = traits_in_scope.iter().copied().collect();
+    traits_in_scope.sort_by_key(|it| it.as_intern_id());
+    db.method_candidates(krate, ty.clone(), env, traits_in_scope.into())
+}
+
+pub(crate) fn method_candidates_query(
+    db: &dyn HirDatabase,
+    krate: CrateId,
+    ty: Canonical<Ty>,
+    env: Arc<TraitEnvironment>,
+    traits_in_scope: Arc<[TraitId]>,
+) -> Arc<[(Ty, AssocItemId)]> {
+    let _p = profile::span("method_candidates_query");
+    let traits_in_scope: FxHashSet<TraitId> = traits_in_scope.iter().copied().collect();
+    let mut res = Vec::new();
+    iterate_method_candidates_impl(
+        &ty,
+        db,
+        env,
+        krate,
+        &traits_in_scope,
+        None,
+        LookupMode::MethodCall,
+        &mut |ty, item| {
+            res.push((ty.clone(), item));
+            false
+        },
+    );
+    res.into()
+}
+
 /// Whether we're looking up a dotted method call (like `v.len()`) or a path
 /// (like `Vec::new`).
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -668,7 +721,7 @@ fn is_valid_candidate(
     }
 }
 
-pub(crate) fn inherent_impl_substs(
+pub fn inherent_impl_substs(
     db: &dyn HirDatabase,
     impl_id: ImplId,
     self_ty: &Canonical<Ty>,
@@ -33,7 +33,8 @@ use tracing_subscriber::{layer::SubscriberExt, EnvFilter, Registry};
 use tracing_tree::HierarchicalLayer;
 
 use crate::{
-    db::HirDatabase, display::HirDisplay, infer::TypeMismatch, test_db::TestDB, InferenceResult, Ty,
+    db::HirDatabase, display::HirDisplay, infer::TypeMismatch, test_db::TestDB, Canonical,
+    InferenceResult, Substs, TraitEnvironment, Ty, TypeCtor,
 };
 
 // These tests compare the inference results for all expressions in a file
@@ -352,6 +353,56 @@ fn typing_whitespace_inside_a_function_should_not_invalidate_types() {
     }
 }
 
+#[test]
+fn typing_inside_a_function_should_not_invalidate_method_candidates() {
+    let (mut db, pos) = TestDB::with_position(
+        "
+        //- /lib.rs
+        struct S;
+        impl S { fn foo(&self) {} }
+        fn bar() {
+            <|>
+        }
+    ",
+    );
+    let module = db.module_for_file(pos.file_id);
+    let crate_def_map = db.crate_def_map(module.krate);
+    let adt = crate_def_map[module.local_id]
+        .scope
+        .declarations()
+        .find_map(|def| match def {
+            ModuleDefId::AdtId(it) => Some(it),
+            _ => None,
+        })
+        .unwrap();
+    let ty =
+        Canonical { value: Ty::apply(TypeCtor::Adt(adt), Substs::empty()), kinds: Arc::new([]) };
+    let env = Arc::new(TraitEnvironment { predicates: Vec::new() });
+    let method_candidates =
+        |db: &TestDB| db.method_candidates(module.krate, ty.clone(), env.clone(), Arc::new([]));
+
+    {
+        let events = db.log_executed(|| assert_eq!(method_candidates(&db).len(), 1));
+        assert!(format!("{:?}", events).contains("method_candidates"))
+    }
+
+    let new_text = "
+        struct S;
+        impl S { fn foo(&self) {} }
+        fn bar() {
+            S.f
+        }
+    "
+    .to_string();
+
+    db.set_file_text(pos.file_id, Arc::new(new_text));
+
+    {
+        let events = db.log_executed(|| assert_eq!(method_candidates(&db).len(), 1));
+        assert!(!format!("{:?}", events).contains("method_candidates"), "{:#?}", events)
+    }
+}
+
 fn check_infer(ra_fixture: &str, expect: Expect) {
     let mut actual = infer(ra_fixture);
     actual.push('\n');
@@ -1181,6 +1181,19 @@ fn infer_tuple() {
     );
 }
 
+#[test]
+fn infer_nested_tuple_index() {
+    check_types(
+        r#"
+fn test() {
+    let t = ((1u8, (2u16, 3u32)), 4i64);
+    t.0.1.1;
+  //^^^^^^^ u32
+}
+"#,
+    );
+}
+
 #[test]
 fn infer_array() {
     check_infer(
@@ -80,7 +80,9 @@ pub use crate::{
     },
 };
 pub use completion::{
-    CompletionConfig, CompletionItem, CompletionItemKind, CompletionScore, InsertTextFormat,
+    AcceptedCompletion, CallableSnippets, CompletionConfig, CompletionItem, CompletionItemKind,
+    CompletionRanker, CompletionResolveToken, CompletionScore, CustomSnippet, FrequencyRanker,
+    InsertTextFormat, PostfixTemplate, SnippetScope,
 };
 pub use ide_db::{
     call_info::CallInfo,
@@ -207,8 +209,10 @@ impl Analysis {
             file_id,
             Edition::Edition2018,
             None,
+            cfg_options.clone(),
             cfg_options,
             Env::default(),
+            false,
             Default::default(),
         );
         change.change_file(file_id, Some(Arc::new(text)));
@@ -468,6 +472,28 @@ impl Analysis {
         self.with_db(|db| completion::completions(db, config, position).map(Into::into))
     }
 
+    /// Computes completions at the given position, reordered by `ranker`.
+    pub fn completions_ranked(
+        &self,
+        config: &CompletionConfig,
+        position: FilePosition,
+        ranker: &dyn CompletionRanker,
+    ) -> Cancelable<Option<Vec<CompletionItem>>> {
+        self.with_db(|db| {
+            completion::completions_ranked(db, config, position, ranker).map(Into::into)
+        })
+    }
+
+    /// Fills in a completion item that was left unresolved, see
+    /// `CompletionConfig::resolve_lazily`.
+    pub fn resolve_completion(
+        &self,
+        config: &CompletionConfig,
+        token: &CompletionResolveToken,
+    ) -> Cancelable<Option<CompletionItem>> {
+        self.with_db(|db| completion::resolve_completion(db, config, token))
+    }
+
     /// Computes resolved assists with source changes for the given position.
     pub fn resolved_assists(
         &self,
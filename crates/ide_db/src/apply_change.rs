@@ -77,6 +77,11 @@ impl RootDatabase {
 
         base_db::ParseQuery.in_db(self).sweep(sweep);
         hir::db::ParseMacroExpansionQuery.in_db(self).sweep(sweep);
+        // Hypothetical expansions are keyed by the edited macro arguments, so
+        // the keys become garbage as soon as the user types on.
+        hir::db::HypotheticalMacroExpansionQuery
+            .in_db(self)
+            .sweep(SweepStrategy::default().discard_everything().sweep_all_revisions());
 
         // Macros do take significant space, but less then the syntax trees
         // self.query(hir::db::MacroDefQuery).sweep(sweep);
@@ -145,6 +150,7 @@ impl RootDatabase {
             hir::db::MacroDefQuery
             hir::db::ParseMacroExpansionQuery
             hir::db::MacroExpandQuery
+            hir::db::HypotheticalMacroExpansionQuery
 
             // DefDatabase
             hir::db::ItemTreeQuery
@@ -183,6 +189,7 @@ impl RootDatabase {
             hir::db::InherentImplsInCrateQuery
             hir::db::TraitImplsInCrateQuery
             hir::db::TraitImplsInDepsQuery
+            hir::db::MethodCandidatesQuery
             hir::db::AssociatedTyDataQuery
             hir::db::AssociatedTyDataQuery
             hir::db::TraitDatumQuery
@@ -1,6 +1,6 @@
 //! A module with ide helpers for high-level ide features.
 use crate::RootDatabase;
-use hir::{Crate, Enum, Module, ScopeDef, Semantics, Trait};
+use hir::{Crate, Enum, Module, ScopeDef, Semantics, Struct, Trait};
 use syntax::ast::{self, make};
 
 pub mod insert_use;
@@ -119,11 +119,24 @@ pub mod option {
     pub enum Option<T> { None, Some(T)}
 }
 
+pub mod result {
+    pub enum Result<T, E> { Ok(T), Err(E) }
+}
+
 pub mod prelude {
-    pub use crate::{convert::From, iter::{IntoIterator, Iterator}, option::Option::{self, *}, default::Default};
+    pub use crate::{convert::From, iter::{IntoIterator, Iterator}, option::Option::{self, *}, result::Result::{self, *}, default::Default};
 }
 #[prelude_import]
 pub use prelude::*;
+//- /liballoc.rs crate:alloc deps:core
+pub mod boxed {
+    pub struct Box<T: ?Sized>(T);
+    impl<T> Box<T> {
+        pub fn new(x: T) -> Box<T> {
+            Box(x)
+        }
+    }
+}
 "#;
 
     pub fn core(&self) -> Option<Crate> {
@@ -138,10 +151,46 @@ pub use prelude::*;
         self.find_enum("core:option:Option")
     }
 
+    pub fn core_result_Result(&self) -> Option<Enum> {
+        self.find_enum("core:result:Result")
+    }
+
     pub fn core_default_Default(&self) -> Option<Trait> {
         self.find_trait("core:default:Default")
     }
 
+    pub fn core_clone_Clone(&self) -> Option<Trait> {
+        self.find_trait("core:clone:Clone")
+    }
+
+    pub fn core_marker_Copy(&self) -> Option<Trait> {
+        self.find_trait("core:marker:Copy")
+    }
+
+    pub fn core_fmt_Debug(&self) -> Option<Trait> {
+        self.find_trait("core:fmt:Debug")
+    }
+
+    pub fn core_hash_Hash(&self) -> Option<Trait> {
+        self.find_trait("core:hash:Hash")
+    }
+
+    pub fn core_cmp_PartialEq(&self) -> Option<Trait> {
+        self.find_trait("core:cmp:PartialEq")
+    }
+
+    pub fn core_cmp_Eq(&self) -> Option<Trait> {
+        self.find_trait("core:cmp:Eq")
+    }
+
+    pub fn core_cmp_PartialOrd(&self) -> Option<Trait> {
+        self.find_trait("core:cmp:PartialOrd")
+    }
+
+    pub fn core_cmp_Ord(&self) -> Option<Trait> {
+        self.find_trait("core:cmp:Ord")
+    }
+
     pub fn core_iter_Iterator(&self) -> Option<Trait> {
         self.find_trait("core:iter:traits:iterator:Iterator")
     }
@@ -150,6 +199,10 @@ pub use prelude::*;
         self.find_module("core:iter")
     }
 
+    pub fn alloc_boxed_Box(&self) -> Option<Struct> {
+        self.find_struct("alloc:boxed:Box")
+    }
+
     fn find_trait(&self, path: &str) -> Option<Trait> {
         match self.find_def(path)? {
             hir::ScopeDef::ModuleDef(hir::ModuleDef::Trait(it)) => Some(it),
@@ -164,6 +217,13 @@ pub use prelude::*;
         }
     }
 
+    fn find_struct(&self, path: &str) -> Option<Struct> {
+        match self.find_def(path)? {
+            hir::ScopeDef::ModuleDef(hir::ModuleDef::Adt(hir::Adt::Struct(it))) => Some(it),
+            _ => None,
+        }
+    }
+
     fn find_module(&self, path: &str) -> Option<Module> {
         match self.find_def(path)? {
             hir::ScopeDef::ModuleDef(hir::ModuleDef::Module(it)) => Some(it),
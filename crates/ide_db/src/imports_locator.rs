@@ -13,10 +13,10 @@ use either::Either;
 use rustc_hash::FxHashSet;
 
 pub fn find_exact_imports<'a>(
-    sema: &Semantics<'a, RootDatabase>,
+    sema: &'a Semantics<'a, RootDatabase>,
     krate: Crate,
     name_to_import: &str,
-) -> impl Iterator<Item = Either<ModuleDef, MacroDef>> {
+) -> impl Iterator<Item = Either<ModuleDef, MacroDef>> + 'a {
     let _p = profile::span("find_exact_imports");
     find_imports(
         sema,
@@ -31,13 +31,15 @@ pub fn find_exact_imports<'a>(
     )
 }
 
+/// Finds items whose names fuzzy-match `name_to_import`. The candidates are
+/// looked up lazily, so it's cheap to stop iterating early.
 pub fn find_similar_imports<'a>(
-    sema: &Semantics<'a, RootDatabase>,
+    sema: &'a Semantics<'a, RootDatabase>,
     krate: Crate,
     name_to_import: &str,
     limit: usize,
     ignore_modules: bool,
-) -> impl Iterator<Item = Either<ModuleDef, MacroDef>> {
+) -> impl Iterator<Item = Either<ModuleDef, MacroDef>> + 'a {
     let _p = profile::span("find_similar_imports");
 
     let mut external_query = import_map::Query::new(name_to_import).limit(limit);
@@ -58,33 +60,28 @@ pub fn find_similar_imports<'a>(
 }
 
 fn find_imports<'a>(
-    sema: &Semantics<'a, RootDatabase>,
+    sema: &'a Semantics<'a, RootDatabase>,
     krate: Crate,
     local_query: symbol_index::Query,
     external_query: import_map::Query,
-) -> impl Iterator<Item = Either<ModuleDef, MacroDef>> {
+) -> impl Iterator<Item = Either<ModuleDef, MacroDef>> + 'a {
     let _p = profile::span("find_similar_imports");
     let db = sema.db;
 
     // Query dependencies first.
-    let mut candidates: FxHashSet<_> =
-        krate.query_external_importables(db, external_query).collect();
+    let external_results = krate.query_external_importables(db, external_query);
 
     // Query the local crate using the symbol index.
-    let local_results = symbol_index::crate_symbols(db, krate.into(), local_query);
-
-    candidates.extend(
-        local_results
-            .into_iter()
-            .filter_map(|import_candidate| get_name_definition(sema, &import_candidate))
-            .filter_map(|name_definition_to_import| match name_definition_to_import {
-                Definition::ModuleDef(module_def) => Some(Either::Left(module_def)),
-                Definition::Macro(macro_def) => Some(Either::Right(macro_def)),
-                _ => None,
-            }),
-    );
+    let local_results = symbol_index::crate_symbols_in_batches(db, krate.into(), local_query)
+        .filter_map(move |import_candidate| get_name_definition(sema, &import_candidate))
+        .filter_map(|name_definition_to_import| match name_definition_to_import {
+            Definition::ModuleDef(module_def) => Some(Either::Left(module_def)),
+            Definition::Macro(macro_def) => Some(Either::Right(macro_def)),
+            _ => None,
+        });
 
-    candidates.into_iter()
+    let mut seen = FxHashSet::default();
+    external_results.chain(local_results).filter(move |candidate| seen.insert(*candidate))
 }
 
 fn get_name_definition<'a>(
@@ -116,6 +116,7 @@ impl RootDatabase {
         base_db::ParseQuery.in_db_mut(self).set_lru_capacity(lru_capacity);
         hir::db::ParseMacroExpansionQuery.in_db_mut(self).set_lru_capacity(lru_capacity);
         hir::db::MacroExpandQuery.in_db_mut(self).set_lru_capacity(lru_capacity);
+        hir::db::HypotheticalMacroExpansionQuery.in_db_mut(self).set_lru_capacity(lru_capacity);
     }
 }
 
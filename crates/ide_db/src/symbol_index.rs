@@ -24,7 +24,7 @@ use std::{
     cmp::Ordering,
     fmt,
     hash::{Hash, Hasher},
-    mem,
+    iter, mem,
     sync::Arc,
 };
 
@@ -186,6 +186,53 @@ pub fn world_symbols(db: &RootDatabase, query: Query) -> Vec<FileSymbol> {
 }
 
 pub fn crate_symbols(db: &RootDatabase, krate: CrateId, query: Query) -> Vec<FileSymbol> {
+    let files = crate_files(db, krate);
+
+    let snap = Snap(db.snapshot());
+
+    let buf = files
+        .par_iter()
+        .map_with(snap, |db, &file_id| db.0.file_symbols(file_id))
+        .collect::<Vec<_>>();
+    let buf = buf.iter().map(|it| &**it).collect::<Vec<_>>();
+
+    query.search(&buf)
+}
+
+/// Like `crate_symbols`, but indexes and searches the files of the crate
+/// lazily, a batch of files at a time. Callers which stop iterating early,
+/// because they've got enough results or ran out of time, don't pay for
+/// indexing the rest of the crate.
+pub fn crate_symbols_in_batches(
+    db: &RootDatabase,
+    krate: CrateId,
+    mut query: Query,
+) -> impl Iterator<Item = FileSymbol> + '_ {
+    const BATCH_SIZE: usize = 32;
+
+    let mut files = crate_files(db, krate);
+    let mut remaining = query.limit;
+    iter::from_fn(move || {
+        if remaining == 0 || files.is_empty() {
+            return None;
+        }
+        let batch: Vec<FileId> = files.drain(..files.len().min(BATCH_SIZE)).collect();
+        let snap = Snap(db.snapshot());
+        let buf = batch
+            .par_iter()
+            .map_with(snap, |db, &file_id| db.0.file_symbols(file_id))
+            .collect::<Vec<_>>();
+        let buf = buf.iter().map(|it| &**it).collect::<Vec<_>>();
+
+        query.limit = remaining;
+        let res = query.search(&buf);
+        remaining -= res.len();
+        Some(res)
+    })
+    .flatten()
+}
+
+fn crate_files(db: &RootDatabase, krate: CrateId) -> Vec<FileId> {
     // FIXME(#4842): This now depends on CrateDefMap, why not build the entire symbol index from
     // that instead?
 
@@ -197,16 +244,7 @@ pub fn crate_symbols(db: &RootDatabase, krate: CrateId, query: Query) -> Vec<Fil
         files.extend(data.origin.file_id());
         modules.extend(data.children.values());
     }
-
-    let snap = Snap(db.snapshot());
-
-    let buf = files
-        .par_iter()
-        .map_with(snap, |db, &file_id| db.0.file_symbols(file_id))
-        .collect::<Vec<_>>();
-    let buf = buf.iter().map(|it| &**it).collect::<Vec<_>>();
-
-    query.search(&buf)
+    files
 }
 
 pub fn index_resolve(db: &RootDatabase, name_ref: &ast::NameRef) -> Vec<FileSymbol> {
@@ -310,7 +348,7 @@ impl SymbolIndex {
 }
 
 impl Query {
-    pub(crate) fn search(self, indices: &[&SymbolIndex]) -> Vec<FileSymbol> {
+    pub(crate) fn search(&self, indices: &[&SymbolIndex]) -> Vec<FileSymbol> {
         let mut op = fst::map::OpBuilder::new();
         for file_symbols in indices.iter() {
             let automaton = fst::automaton::Subsequence::new(&self.lowercased);
@@ -427,3 +465,6 @@ fn to_file_symbol(node: &SyntaxNode, file_id: FileId) -> Option<FileSymbol> {
         container_name: None,
     })
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,48 @@
+use base_db::{fixture::ChangeFixture, SourceDatabase};
+
+use crate::{
+    symbol_index::{crate_symbols, crate_symbols_in_batches, Query},
+    RootDatabase,
+};
+
+/// A crate spanning more files than fit into a single batch.
+fn many_files_crate() -> RootDatabase {
+    let mut fixture = String::from("//- /lib.rs\n");
+    for i in 0..100 {
+        fixture.push_str(&format!("mod m{};\n", i));
+    }
+    for i in 0..100 {
+        fixture.push_str(&format!("//- /m{0}.rs\npub struct Item{0};\n", i));
+    }
+    let mut db = RootDatabase::default();
+    db.apply_change(ChangeFixture::parse(&fixture).change);
+    db
+}
+
+#[test]
+fn batched_search_finds_all_symbols() {
+    let db = many_files_crate();
+    let krate = db.crate_graph().iter().next().unwrap();
+
+    let mut batched: Vec<_> = crate_symbols_in_batches(&db, krate, Query::new("item".to_string()))
+        .map(|it| it.name)
+        .collect();
+    let mut all: Vec<_> = crate_symbols(&db, krate, Query::new("item".to_string()))
+        .into_iter()
+        .map(|it| it.name)
+        .collect();
+    batched.sort();
+    all.sort();
+    assert_eq!(batched.len(), 100);
+    assert_eq!(batched, all);
+}
+
+#[test]
+fn batched_search_respects_limit() {
+    let db = many_files_crate();
+    let krate = db.crate_graph().iter().next().unwrap();
+
+    let mut query = Query::new("item".to_string());
+    query.limit(40);
+    assert_eq!(crate_symbols_in_batches(&db, krate, query).count(), 40);
+}
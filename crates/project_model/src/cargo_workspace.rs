@@ -9,7 +9,7 @@ use std::{
 
 use anyhow::{Context, Result};
 use arena::{Arena, Idx};
-use base_db::Edition;
+use base_db::{DependencyKind, Edition};
 use cargo_metadata::{BuildScript, CargoOpt, Message, MetadataCommand, PackageId};
 use paths::{AbsPath, AbsPathBuf};
 use rustc_hash::FxHashMap;
@@ -86,7 +86,10 @@ pub struct PackageData {
     pub is_member: bool,
     pub dependencies: Vec<PackageDependency>,
     pub edition: Edition,
+    /// The enabled features.
     pub features: Vec<String>,
+    /// All the features declared in the manifest.
+    pub declared_features: Vec<String>,
     pub cfgs: Vec<CfgFlag>,
     pub out_dir: Option<AbsPathBuf>,
     pub proc_macro_dylib_path: Option<AbsPathBuf>,
@@ -96,6 +99,9 @@ pub struct PackageData {
 pub struct PackageDependency {
     pub pkg: Package,
     pub name: String,
+    /// Whether the package is a normal dependency, or only a
+    /// `[dev-dependency]` or a `[build-dependency]`.
+    pub kind: DependencyKind,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -189,8 +195,9 @@ impl CargoWorkspace {
 
         meta.packages.sort_by(|a, b| a.id.cmp(&b.id));
         for meta_pkg in meta.packages {
-            let cargo_metadata::Package { id, edition, name, manifest_path, version, .. } =
-                meta_pkg;
+            let cargo_metadata::Package {
+                id, edition, name, manifest_path, version, features, ..
+            } = meta_pkg;
             let is_member = ws_members.contains(&id);
             let edition = edition
                 .parse::<Edition>()
@@ -204,6 +211,7 @@ impl CargoWorkspace {
                 edition,
                 dependencies: Vec::new(),
                 features: Vec::new(),
+                declared_features: features.into_iter().map(|(name, _)| name).collect(),
                 cfgs: cfgs.get(&id).cloned().unwrap_or_default(),
                 out_dir: out_dir_by_id.get(&id).cloned(),
                 proc_macro_dylib_path: proc_macro_dylib_paths.get(&id).cloned(),
@@ -246,7 +254,19 @@ impl CargoWorkspace {
                         continue;
                     }
                 };
-                let dep = PackageDependency { name: dep_node.name, pkg };
+                // Older versions of cargo do not report the kinds at all.
+                let only_kind = |kind| {
+                    !dep_node.dep_kinds.is_empty()
+                        && dep_node.dep_kinds.iter().all(|it| it.kind == kind)
+                };
+                let kind = if only_kind(cargo_metadata::DependencyKind::Development) {
+                    DependencyKind::Dev
+                } else if only_kind(cargo_metadata::DependencyKind::Build) {
+                    DependencyKind::Build
+                } else {
+                    DependencyKind::Normal
+                };
+                let dep = PackageDependency { name: dep_node.name, pkg, kind };
                 packages[source].dependencies.push(dep);
             }
             packages[source].features.extend(node.features);
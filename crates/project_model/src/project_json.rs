@@ -2,7 +2,7 @@
 
 use std::path::PathBuf;
 
-use base_db::{CrateDisplayName, CrateId, CrateName, Dependency, Edition};
+use base_db::{CrateDisplayName, CrateId, CrateName, Dependency, DependencyKind, Edition};
 use paths::{AbsPath, AbsPathBuf};
 use rustc_hash::FxHashMap;
 use serde::{de, Deserialize};
@@ -80,6 +80,7 @@ impl ProjectJson {
                             .map(|dep_data| Dependency {
                                 crate_id: CrateId(dep_data.krate as u32),
                                 name: dep_data.name,
+                                kind: DependencyKind::Normal,
                             })
                             .collect::<Vec<_>>(),
                         cfg: crate_data.cfg,
@@ -5,7 +5,9 @@
 use std::{fmt, fs, path::Component, process::Command};
 
 use anyhow::{Context, Result};
-use base_db::{CrateDisplayName, CrateGraph, CrateId, CrateName, Edition, Env, FileId};
+use base_db::{
+    CrateDisplayName, CrateGraph, CrateId, CrateName, DependencyKind, Edition, Env, FileId,
+};
 use cfg::CfgOptions;
 use paths::{AbsPath, AbsPathBuf};
 use proc_macro_api::ProcMacroClient;
@@ -251,8 +253,10 @@ fn project_json_to_crate_graph(
                     file_id,
                     krate.edition,
                     krate.display_name.clone(),
+                    cfg_options.clone(),
                     cfg_options,
                     env,
+                    false,
                     proc_macro.unwrap_or_default(),
                 ),
             )
@@ -308,6 +312,7 @@ fn cargo_to_crate_graph(
                 let crate_id = add_target_crate_root(
                     &mut crate_graph,
                     &cargo[pkg],
+                    cargo[tgt].kind,
                     &cfg_options,
                     proc_macro_client,
                     file_id,
@@ -355,7 +360,15 @@ fn cargo_to_crate_graph(
             let name = CrateName::new(&dep.name).unwrap();
             if let Some(&to) = pkg_to_lib_crate.get(&dep.pkg) {
                 for &from in pkg_crates.get(&pkg).into_iter().flatten() {
-                    add_dep(&mut crate_graph, from, name.clone(), to)
+                    match dep.kind {
+                        DependencyKind::Normal => add_dep(&mut crate_graph, from, name.clone(), to),
+                        DependencyKind::Dev => {
+                            add_dev_dep(&mut crate_graph, from, name.clone(), to)
+                        }
+                        DependencyKind::Build => {
+                            add_build_dep(&mut crate_graph, from, name.clone(), to)
+                        }
+                    }
                 }
             }
         }
@@ -384,6 +397,7 @@ fn cargo_to_crate_graph(
                     let crate_id = add_target_crate_root(
                         &mut crate_graph,
                         &rustc_workspace[pkg],
+                        rustc_workspace[tgt].kind,
                         &cfg_options,
                         proc_macro_client,
                         file_id,
@@ -432,11 +446,13 @@ fn cargo_to_crate_graph(
 fn add_target_crate_root(
     crate_graph: &mut CrateGraph,
     pkg: &cargo_workspace::PackageData,
+    kind: TargetKind,
     cfg_options: &CfgOptions,
     proc_macro_client: &ProcMacroClient,
     file_id: FileId,
 ) -> CrateId {
     let edition = pkg.edition;
+    let is_dev_target = matches!(kind, TargetKind::Test | TargetKind::Bench | TargetKind::Example);
     let cfg_options = {
         let mut opts = cfg_options.clone();
         for feature in pkg.features.iter() {
@@ -445,7 +461,16 @@ fn add_target_crate_root(
         opts.extend(pkg.cfgs.iter().cloned());
         opts
     };
+    let potential_cfg_options = {
+        let mut opts = cfg_options.clone();
+        for feature in pkg.declared_features.iter() {
+            opts.insert_key_value("feature".into(), feature.into());
+        }
+        opts
+    };
     let mut env = Env::default();
+    env.set("CARGO_PKG_NAME", pkg.name.clone());
+    env.set("CARGO_PKG_VERSION", pkg.version.clone());
     if let Some(out_dir) = &pkg.out_dir {
         // NOTE: cargo and rustc seem to hide non-UTF-8 strings from env! and option_env!()
         if let Some(out_dir) = out_dir.to_str().map(|s| s.to_owned()) {
@@ -464,7 +489,9 @@ fn add_target_crate_root(
         edition,
         Some(display_name),
         cfg_options,
+        potential_cfg_options,
         env,
+        is_dev_target,
         proc_macro.clone(),
     );
 
@@ -492,7 +519,9 @@ fn sysroot_to_crate_graph(
                 Edition::Edition2018,
                 Some(display_name),
                 cfg_options.clone(),
+                cfg_options.clone(),
                 env,
+                false,
                 proc_macro,
             );
             Some((krate, crate_id))
@@ -550,3 +579,15 @@ fn add_dep(graph: &mut CrateGraph, from: CrateId, name: CrateName, to: CrateId)
         log::error!("{}", err)
     }
 }
+
+fn add_dev_dep(graph: &mut CrateGraph, from: CrateId, name: CrateName, to: CrateId) {
+    if let Err(err) = graph.add_dev_dep(from, name, to) {
+        log::error!("{}", err)
+    }
+}
+
+fn add_build_dep(graph: &mut CrateGraph, from: CrateId, name: CrateName, to: CrateId) {
+    if let Err(err) = graph.add_build_dep(from, name, to) {
+        log::error!("{}", err)
+    }
+}
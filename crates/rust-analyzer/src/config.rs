@@ -7,16 +7,19 @@
 //! configure the server itself, feature flags are passed into analysis, and
 //! tweak things like automatic insertion of `()` in completions.
 
-use std::{convert::TryFrom, ffi::OsString, path::PathBuf};
+use std::{collections::BTreeMap, convert::TryFrom, ffi::OsString, path::PathBuf, time::Duration};
 
 use flycheck::FlycheckConfig;
 use hir::PrefixKind;
-use ide::{AssistConfig, CompletionConfig, DiagnosticsConfig, HoverConfig, InlayHintsConfig};
+use ide::{
+    AssistConfig, CallableSnippets, CompletionConfig, CustomSnippet, DiagnosticsConfig,
+    HoverConfig, InlayHintsConfig, PostfixTemplate, SnippetScope,
+};
 use ide_db::helpers::insert_use::MergeBehaviour;
 use lsp_types::{ClientCapabilities, MarkupKind};
 use project_model::{CargoConfig, ProjectJson, ProjectJsonData, ProjectManifest};
 use rustc_hash::FxHashSet;
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize};
 use vfs::AbsPathBuf;
 
 use crate::diagnostics::DiagnosticsMapConfig;
@@ -182,9 +185,8 @@ impl Config {
             },
             completion: CompletionConfig {
                 enable_postfix_completions: true,
-                enable_experimental_completions: true,
-                add_call_parenthesis: true,
-                add_call_argument_snippets: true,
+                enable_autoimport_completions: true,
+                time_budget: Some(Duration::from_millis(250)),
                 ..CompletionConfig::default()
             },
             assist: AssistConfig::default(),
@@ -305,10 +307,65 @@ impl Config {
         };
 
         self.completion.enable_postfix_completions = data.completion_postfix_enable;
-        self.completion.enable_experimental_completions = data.completion_enableExperimental;
-        self.completion.add_call_parenthesis = data.completion_addCallParenthesis;
-        self.completion.add_call_argument_snippets = data.completion_addCallArgumentSnippets;
+        self.completion.postfix_templates = parse_entries(data.completion_postfix_templates)
+            .filter_map(|(label, template): (String, PostfixTemplateDef)| {
+                let (snippet, imports) = match template {
+                    PostfixTemplateDef::Snippet(it) => (it, Vec::new()),
+                    PostfixTemplateDef::WithImports { snippet, imports } => (snippet, imports),
+                };
+                match PostfixTemplate::new(&label, &snippet, &imports) {
+                    Ok(it) => Some(it),
+                    Err(e) => {
+                        log::error!("{}", e);
+                        None
+                    }
+                }
+            })
+            .collect();
+        self.completion.snippets = parse_entries(data.completion_snippets)
+            .filter_map(|(label, snippet): (String, SnippetDef)| {
+                let scope = match snippet.scope {
+                    SnippetScopeDef::Expr => SnippetScope::Expr,
+                    SnippetScopeDef::Item => SnippetScope::Item,
+                };
+                let description = snippet.description.as_deref();
+                match CustomSnippet::new(
+                    &label,
+                    &snippet.body,
+                    description,
+                    scope,
+                    &snippet.imports,
+                ) {
+                    Ok(it) => Some(it),
+                    Err(e) => {
+                        log::error!("{}", e);
+                        None
+                    }
+                }
+            })
+            .collect();
+        // `completion.enableExperimental` used to turn import completion on
+        // and off, so it still does for the users who haven't switched over.
+        self.completion.enable_autoimport_completions = data
+            .completion_autoimport_enable
+            .or(data.completion_enableExperimental)
+            .unwrap_or(true);
+        self.completion.autoimport_min_prefix_length = data.completion_autoimport_minPrefixLength;
+        self.completion.autoimport_max_candidates = data.completion_autoimport_maxCandidates;
+        self.completion.enable_term_search = data.completion_termSearch_enable;
+        self.completion.callable_snippets =
+            match (data.completion_addCallParenthesis, data.completion_addCallArgumentSnippets) {
+                (false, _) => CallableSnippets::None,
+                (true, false) => CallableSnippets::AddParentheses,
+                (true, true) => CallableSnippets::FillArguments,
+            };
         self.completion.merge = self.assist.insert_use.merge;
+        self.completion.test_attributes = data.completion_testAttributes;
+        self.completion.hide_non_const_fns = data.completion_hideNonConstFns;
+        self.completion.hide_non_object_safe_traits = data.completion_hideNonObjectSafeTraits;
+        self.completion.turbofish_snippets = data.completion_turbofishSnippets;
+        self.completion.qualify_variants_with_self = data.completion_qualifyVariantsWithSelf;
+        self.completion.import_enum_variants = data.completion_importEnumVariants;
 
         self.call_info_full = data.callInfo_full;
 
@@ -393,6 +450,14 @@ impl Config {
                     if let Some(value) = completion_item.snippet_support {
                         self.completion.allow_snippets(value);
                     }
+                    // The items are only left unresolved if everything that
+                    // is left out can be filled in by the resolve request.
+                    if let Some(resolve_support) = &completion_item.resolve_support {
+                        self.completion.resolve_lazily =
+                            ["detail", "documentation", "additionalTextEdits"]
+                                .iter()
+                                .all(|it| resolve_support.properties.iter().any(|p| p == it));
+                    }
                 }
             }
 
@@ -441,6 +506,55 @@ enum ManifestOrProjectJson {
     ProjectJson(ProjectJsonData),
 }
 
+/// Parses the values of a map from the settings one by one, so that an invalid
+/// entry is reported and skipped instead of resetting the whole map.
+fn parse_entries<T: DeserializeOwned>(
+    entries: BTreeMap<String, serde_json::Value>,
+) -> impl Iterator<Item = (String, T)> {
+    entries.into_iter().filter_map(|(label, value)| match serde_json::from_value(value) {
+        Ok(it) => Some((label, it)),
+        Err(e) => {
+            log::error!("invalid settings entry `{}`: {}", label, e);
+            None
+        }
+    })
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PostfixTemplateDef {
+    Snippet(String),
+    WithImports {
+        snippet: String,
+        #[serde(default)]
+        imports: Vec<String>,
+    },
+}
+
+#[derive(Deserialize)]
+struct SnippetDef {
+    body: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    scope: SnippetScopeDef,
+    #[serde(default)]
+    imports: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum SnippetScopeDef {
+    Expr,
+    Item,
+}
+
+impl Default for SnippetScopeDef {
+    fn default() -> Self {
+        SnippetScopeDef::Expr
+    }
+}
+
 #[derive(Deserialize)]
 #[serde(rename_all = "snake_case")]
 enum MergeBehaviourDef {
@@ -503,10 +617,22 @@ config_data! {
         checkOnSave_features: Option<Vec<String>>        = None,
         checkOnSave_overrideCommand: Option<Vec<String>> = None,
 
-        completion_addCallArgumentSnippets: bool = true,
-        completion_addCallParenthesis: bool      = true,
-        completion_postfix_enable: bool          = true,
-        completion_enableExperimental: bool      = true,
+        completion_addCallArgumentSnippets: bool                           = true,
+        completion_addCallParenthesis: bool                                = true,
+        completion_postfix_enable: bool                                    = true,
+        completion_postfix_templates: BTreeMap<String, serde_json::Value>  = BTreeMap::new(),
+        completion_enableExperimental: Option<bool>                        = None,
+        completion_autoimport_enable: Option<bool>                         = None,
+        completion_autoimport_minPrefixLength: usize                       = 1,
+        completion_autoimport_maxCandidates: usize                         = 20,
+        completion_termSearch_enable: bool                                 = false,
+        completion_snippets: BTreeMap<String, serde_json::Value>           = BTreeMap::new(),
+        completion_testAttributes: Vec<String>                             = vec!["test".into()],
+        completion_hideNonConstFns: bool                                   = false,
+        completion_hideNonObjectSafeTraits: bool                           = false,
+        completion_turbofishSnippets: bool                                 = true,
+        completion_qualifyVariantsWithSelf: bool                           = true,
+        completion_importEnumVariants: bool                                = false,
 
         diagnostics_enable: bool                = true,
         diagnostics_enableExperimental: bool    = true,
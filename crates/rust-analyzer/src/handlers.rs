@@ -8,8 +8,8 @@ use std::{
 };
 
 use ide::{
-    FileId, FilePosition, FileRange, HoverAction, HoverGotoTypeData, NavigationTarget, Query,
-    RangeInfo, Runnable, RunnableKind, SearchScope, TextEdit,
+    CompletionResolveToken, FileId, FilePosition, FileRange, HoverAction, HoverGotoTypeData,
+    NavigationTarget, Query, RangeInfo, Runnable, RunnableKind, SearchScope, TextEdit,
 };
 use itertools::Itertools;
 use lsp_server::ErrorCode;
@@ -539,6 +539,7 @@ pub(crate) fn handle_completion(
     params: lsp_types::CompletionParams,
 ) -> Result<Option<lsp_types::CompletionResponse>> {
     let _p = profile::span("handle_completion");
+    let text_document_position = params.text_document_position.clone();
     let position = from_proto::file_position(&snap, params.text_document_position)?;
     let completion_triggered_after_single_colon = {
         let mut res = false;
@@ -570,13 +571,70 @@ pub(crate) fn handle_completion(
     let line_endings = snap.file_line_endings(position.file_id);
     let items: Vec<CompletionItem> = items
         .into_iter()
-        .flat_map(|item| to_proto::completion_item(&line_index, line_endings, item))
+        .flat_map(|item| {
+            let data = item.resolve_token().map(|token| {
+                to_value(CompletionResolveData {
+                    position: text_document_position.clone(),
+                    provider: token.provider.clone(),
+                    label: token.label.clone(),
+                    lookup: token.lookup.clone(),
+                })
+                .unwrap()
+            });
+            let mut res = to_proto::completion_item(&line_index, line_endings, item);
+            res.iter_mut().for_each(|it| it.data = data.clone());
+            res
+        })
         .collect();
 
     let completion_list = lsp_types::CompletionList { is_incomplete: true, items };
     Ok(Some(completion_list.into()))
 }
 
+/// What an item needs to be resolved, see `CompletionConfig::resolve_lazily`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CompletionResolveData {
+    position: lsp_types::TextDocumentPositionParams,
+    provider: String,
+    label: String,
+    lookup: String,
+}
+
+pub(crate) fn handle_completion_resolve(
+    snap: GlobalStateSnapshot,
+    mut original_completion: CompletionItem,
+) -> Result<CompletionItem> {
+    let _p = profile::span("handle_completion_resolve");
+    let data = match original_completion.data.take() {
+        Some(it) => from_json::<CompletionResolveData>("CompletionResolveData", it)?,
+        None => return Ok(original_completion),
+    };
+    let position = from_proto::file_position(&snap, data.position)?;
+    let token = CompletionResolveToken {
+        position,
+        provider: data.provider,
+        label: data.label,
+        lookup: data.lookup,
+    };
+    let resolved = match snap.analysis.resolve_completion(&snap.config.completion, &token)? {
+        Some(it) => it,
+        None => return Ok(original_completion),
+    };
+    let line_index = snap.analysis.file_line_index(position.file_id)?;
+    let line_endings = snap.file_line_endings(position.file_id);
+    // Only the parts left out lazily are taken, the item keeps the label it
+    // was shown with, like the `&` of a reference.
+    if let Some(resolved) =
+        to_proto::completion_item(&line_index, line_endings, resolved).into_iter().next()
+    {
+        original_completion.detail = resolved.detail;
+        original_completion.documentation = resolved.documentation;
+        original_completion.additional_text_edits = resolved.additional_text_edits;
+    }
+    Ok(original_completion)
+}
+
 pub(crate) fn handle_folding_range(
     snap: GlobalStateSnapshot,
     params: FoldingRangeParams,
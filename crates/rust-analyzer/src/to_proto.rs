@@ -5,10 +5,11 @@ use std::{
 };
 
 use ide::{
-    Assist, AssistKind, CallInfo, CompletionItem, CompletionItemKind, Documentation,
-    FileSystemEdit, Fold, FoldKind, Highlight, HighlightModifier, HighlightTag, HighlightedRange,
-    Indel, InlayHint, InlayKind, InsertTextFormat, LineIndex, Markup, NavigationTarget,
-    ReferenceAccess, ResolvedAssist, Runnable, Severity, SourceChange, SourceFileEdit, TextEdit,
+    Assist, AssistKind, CallInfo, CompletionItem, CompletionItemKind, CompletionScore,
+    Documentation, FileSystemEdit, Fold, FoldKind, Highlight, HighlightModifier, HighlightTag,
+    HighlightedRange, Indel, InlayHint, InlayKind, InsertTextFormat, LineIndex, Markup,
+    NavigationTarget, ReferenceAccess, ResolvedAssist, Runnable, Severity, SourceChange,
+    SourceFileEdit, TextEdit,
 };
 use ide_db::base_db::{FileId, FileRange};
 use itertools::Itertools;
@@ -101,6 +102,7 @@ pub(crate) fn completion_item_kind(
         CompletionItemKind::TypeParam => lsp_types::CompletionItemKind::TypeParameter,
         CompletionItemKind::Macro => lsp_types::CompletionItemKind::Method,
         CompletionItemKind::Attribute => lsp_types::CompletionItemKind::EnumMember,
+        CompletionItemKind::Label => lsp_types::CompletionItemKind::Variable,
         CompletionItemKind::UnresolvedReference => lsp_types::CompletionItemKind::Reference,
     }
 }
@@ -203,8 +205,13 @@ pub(crate) fn completion_item(
         ..Default::default()
     };
 
-    if completion_item.score().is_some() {
-        set_score(&mut res, completion_item.label());
+    match completion_item.score() {
+        // Sorts after the letters and digits the labels start with.
+        Some(CompletionScore::Unlikely) => {
+            res.sort_text = Some(format!("~{}", completion_item.label()))
+        }
+        Some(_) => set_score(&mut res, completion_item.label()),
+        None => (),
     }
 
     if completion_item.deprecated() {
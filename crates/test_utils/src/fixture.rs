@@ -10,6 +10,8 @@ pub struct Fixture {
     pub text: String,
     pub krate: Option<String>,
     pub deps: Vec<String>,
+    pub dev_deps: Vec<String>,
+    pub build_deps: Vec<String>,
     pub cfg_atoms: Vec<String>,
     pub cfg_key_values: Vec<(String, String)>,
     pub edition: Option<String>,
@@ -66,6 +68,8 @@ impl Fixture {
 
         let mut krate = None;
         let mut deps = Vec::new();
+        let mut dev_deps = Vec::new();
+        let mut build_deps = Vec::new();
         let mut edition = None;
         let mut cfg_atoms = Vec::new();
         let mut cfg_key_values = Vec::new();
@@ -75,6 +79,8 @@ impl Fixture {
             match key {
                 "crate" => krate = Some(value.to_string()),
                 "deps" => deps = value.split(',').map(|it| it.to_string()).collect(),
+                "dev-deps" => dev_deps = value.split(',').map(|it| it.to_string()).collect(),
+                "build-deps" => build_deps = value.split(',').map(|it| it.to_string()).collect(),
                 "edition" => edition = Some(value.to_string()),
                 "cfg" => {
                     for entry in value.split(',') {
@@ -100,6 +106,8 @@ impl Fixture {
             text: String::new(),
             krate: krate,
             deps,
+            dev_deps,
+            build_deps,
             cfg_atoms,
             cfg_key_values,
             edition,
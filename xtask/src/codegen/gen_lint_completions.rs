@@ -20,7 +20,7 @@ pub fn generate_lint_completions(mode: Mode) -> Result<()> {
 
     let ts_clippy = generate_descriptor_clippy(&Path::new("./target/clippy_lints.json"))?;
     let ts = quote! {
-        use crate::completions::attribute::LintCompletion;
+        use crate::completions::attribute::{FeatureCompletion, LintCompletion, LintGroupCompletion};
         #ts_features
         #ts_clippy
     };
@@ -46,12 +46,22 @@ fn generate_descriptor(src_dir: PathBuf) -> Result<proc_macro2::TokenStream> {
             let path = entry.path();
             let feature_ident = path.file_stem().unwrap().to_str().unwrap().replace("-", "_");
             let doc = read_file(path).unwrap();
+            let tracking_issue = match tracking_issue_url(&doc) {
+                Some(url) => quote! { Some(#url) },
+                None => quote! { None },
+            };
 
-            quote! { LintCompletion { label: #feature_ident, description: #doc } }
+            quote! {
+                FeatureCompletion {
+                    label: #feature_ident,
+                    description: #doc,
+                    tracking_issue: #tracking_issue
+                }
+            }
         });
 
     let ts = quote! {
-        pub(super) const FEATURES:  &[LintCompletion] = &[
+        pub(super) const FEATURES:  &[FeatureCompletion] = &[
             #(#definitions),*
         ];
     };
@@ -59,12 +69,44 @@ fn generate_descriptor(src_dir: PathBuf) -> Result<proc_macro2::TokenStream> {
     Ok(ts)
 }
 
+/// The Unstable Book links the tracking issue of a feature like
+/// `[#55467]: https://github.com/rust-lang/rust/issues/55467`.
+fn tracking_issue_url(doc: &str) -> Option<&str> {
+    const ISSUES: &str = "https://github.com/rust-lang/rust/issues/";
+    doc.lines().find_map(|line| {
+        let url = line.trim().strip_prefix("[#")?.splitn(2, "]: ").nth(1)?;
+        if url.starts_with(ISSUES) {
+            Some(url.trim())
+        } else {
+            None
+        }
+    })
+}
+
 #[derive(Default)]
 struct ClippyLint {
     help: String,
     id: String,
+    group: String,
 }
 
+/// The lint groups of clippy with their description and default level, in
+/// addition to `clippy::all`.
+const CLIPPY_GROUPS: &[(&str, &str, &str)] = &[
+    ("correctness", "code that is outright wrong or very useless", "deny"),
+    ("suspicious", "code that is most likely wrong or useless", "warn"),
+    ("style", "code that should be written in a more idiomatic way", "warn"),
+    ("complexity", "code that does something simple but in a complex way", "warn"),
+    ("perf", "code that can be written to run faster", "warn"),
+    ("pedantic", "lints which are rather strict or might have false positives", "allow"),
+    ("nursery", "new lints that are still under development", "allow"),
+    ("restriction", "lints which prevent the use of language and library features", "allow"),
+    ("cargo", "lints for the cargo manifest", "allow"),
+];
+
+/// The groups making up `clippy::all`.
+const CLIPPY_ALL: &[&str] = &["correctness", "suspicious", "style", "complexity", "perf"];
+
 fn generate_descriptor_clippy(path: &Path) -> Result<proc_macro2::TokenStream> {
     let file_content = read_file(path)?;
     let mut clippy_lints: Vec<ClippyLint> = vec![];
@@ -79,8 +121,17 @@ fn generate_descriptor_clippy(path: &Path) -> Result<proc_macro2::TokenStream> {
                     .expect("should be suffixed by comma")
                     .into(),
                 help: String::new(),
+                group: String::new(),
             };
             clippy_lints.push(clippy_lint)
+        } else if line.starts_with(r#""group":"#) {
+            let clippy_lint = clippy_lints.last_mut().expect("clippy lint must already exist");
+            clippy_lint.group = line
+                .strip_prefix(r#""group": ""#)
+                .expect("should be prefixed by group")
+                .trim_end_matches(',')
+                .trim_end_matches('"')
+                .into();
         } else if line.starts_with(r#""What it does":"#) {
             // Typical line to strip: "What is doest": "Here is my useful content",
             let prefix_to_strip = r#""What it does": ""#;
@@ -96,17 +147,52 @@ fn generate_descriptor_clippy(path: &Path) -> Result<proc_macro2::TokenStream> {
         }
     }
 
-    let definitions = clippy_lints.into_iter().map(|clippy_lint| {
+    let definitions = clippy_lints.iter().map(|clippy_lint| {
         let lint_ident = format!("clippy::{}", clippy_lint.id);
-        let doc = clippy_lint.help;
+        let doc = &clippy_lint.help;
+        // Deprecated lints don't belong to a group and aren't emitted anymore.
+        let default_level = CLIPPY_GROUPS
+            .iter()
+            .find(|(group, ..)| *group == clippy_lint.group)
+            .map_or("none", |(.., level)| level);
+
+        quote! {
+            LintCompletion { label: #lint_ident, description: #doc, default_level: #default_level }
+        }
+    });
 
-        quote! { LintCompletion { label: #lint_ident, description: #doc } }
+    let members = |in_group: &dyn Fn(&str) -> bool| {
+        clippy_lints
+            .iter()
+            .filter(|it| in_group(&it.group))
+            .map(|it| format!("clippy::{}", it.id))
+            .collect::<Vec<_>>()
+    };
+    let all = {
+        let lints = members(&|group| CLIPPY_ALL.contains(&group));
+        quote! {
+            LintGroupCompletion {
+                label: "clippy::all",
+                description: "all the lints that are on by default",
+                lints: &[#(#lints),*]
+            }
+        }
+    };
+    let groups = CLIPPY_GROUPS.iter().map(|(group, description, _)| {
+        let label = format!("clippy::{}", group);
+        let lints = members(&|it| it == *group);
+        quote! {
+            LintGroupCompletion { label: #label, description: #description, lints: &[#(#lints),*] }
+        }
     });
 
     let ts = quote! {
         pub(super) const CLIPPY_LINTS:  &[LintCompletion] = &[
             #(#definitions),*
         ];
+        pub(super) const CLIPPY_LINT_GROUPS:  &[LintGroupCompletion] = &[
+            #all, #(#groups),*
+        ];
     };
 
     Ok(ts)